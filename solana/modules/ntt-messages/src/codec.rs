@@ -0,0 +1,338 @@
+//! Strict encoding and decoding of messages.
+//!
+//! Every length-prefixed sub-payload must be consumed exactly by its contents
+//! when decoding, and [`decode`] additionally rejects any bytes left after the
+//! end of the message. Errors are reported as [`DecodeError`]s and
+//! [`EncodeError`]s, regardless of which layer of the message they occur in.
+//!
+//! For reading individual fields without deserializing (or allocating), each
+//! layer also has a zero-copy view (e.g.
+//! [`crate::ntt_manager::NttManagerMessageView`]), which works over either
+//! [`Encoding`].
+
+use std::io;
+
+use wormhole_io::{Readable, TypePrefixedPayload};
+
+use crate::errors::{DecodeError, EncodeError};
+
+/// The layout of an encoded message, as read by the zero-copy views.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Encoding {
+    /// The wire format, as sent between chains: big endian integers, and
+    /// length-prefixed sub-payloads.
+    Wire,
+    /// The Borsh layout, as stored in accounts: little endian integers, and
+    /// no length prefixes around sub-payloads.
+    Borsh,
+}
+
+/// Decodes a `T` from `bytes`, which must contain exactly one message.
+pub fn decode<T: TypePrefixedPayload>(bytes: &[u8]) -> Result<T, DecodeError> {
+    let mut reader = bytes;
+    let message = T::read_payload(&mut reader)?;
+    if !reader.is_empty() {
+        return Err(DecodeError::TrailingBytes);
+    }
+    Ok(message)
+}
+
+/// Encodes `payload`, failing (instead of panicking) if it can't be represented
+/// on the wire.
+pub fn encode<T: TypePrefixedPayload>(payload: &T) -> Result<Vec<u8>, EncodeError> {
+    let mut buf = Vec::with_capacity(payload.payload_written_size());
+    payload.write_payload(&mut buf)?;
+    Ok(buf)
+}
+
+/// Reads a u16 length prefix, then reads the sub-payload that follows it with
+/// `read`, which must consume exactly that many bytes.
+pub(crate) fn read_length_prefixed<R, T>(
+    reader: &mut R,
+    read: impl FnOnce(&mut io::Take<&mut R>) -> io::Result<T>,
+) -> io::Result<T>
+where
+    R: io::Read,
+{
+    let len: u16 = Readable::read(reader)?;
+    let mut span = io::Read::take(reader, len.into());
+    let result = read(&mut span);
+    let value = result.map_err(|err| {
+        // running out of bytes when the span is exhausted means that the
+        // sub-payload is longer than declared
+        if err.kind() == io::ErrorKind::UnexpectedEof && span.limit() == 0 {
+            DecodeError::LengthMismatch.into()
+        } else {
+            err
+        }
+    })?;
+    if span.limit() != 0 {
+        // the sub-payload is shorter than declared, unless the input itself
+        // ended early
+        let mut byte = [0u8; 1];
+        return Err(match io::Read::read(&mut span, &mut byte)? {
+            0 => DecodeError::UnexpectedEnd,
+            _ => DecodeError::LengthMismatch,
+        }
+        .into());
+    }
+    Ok(value)
+}
+
+/// Splits the first `N` bytes off `span`, without copying them.
+pub(crate) fn split_array<'a, const N: usize>(
+    span: &mut &'a [u8],
+) -> Result<&'a [u8; N], DecodeError> {
+    if span.len() < N {
+        return Err(DecodeError::UnexpectedEnd);
+    }
+    let (head, rest) = span.split_at(N);
+    *span = rest;
+    head.try_into().map_err(|_| DecodeError::UnexpectedEnd)
+}
+
+/// Converts the length of a sub-payload into its u16 length prefix.
+pub(crate) fn length_prefix(len: usize) -> io::Result<u16> {
+    u16::try_from(len).map_err(|_| EncodeError::LengthOverflow.into())
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        ntt::{AdditionalPayload, EmptyPayload, NativeTokenTransfer, NativeTokenTransferView},
+        ntt_manager::NttManagerMessageView,
+        transceiver::TransceiverMessage,
+        transceivers::wormhole::WormholeTransceiver,
+    };
+
+    use super::*;
+
+    type Message = TransceiverMessage<WormholeTransceiver, NativeTokenTransfer<AdditionalPayload>>;
+
+    /// Offset of the length prefix of the [`crate::ntt_manager::NttManagerMessage`]
+    const NTT_MANAGER_PAYLOAD_LEN_OFFSET: usize = 4 + 32 + 32;
+    /// Offset of the length prefix of the [`NativeTokenTransfer`]
+    const NTT_PAYLOAD_LEN_OFFSET: usize = NTT_MANAGER_PAYLOAD_LEN_OFFSET + 2 + 32 + 32;
+
+    fn transceiver_message_1() -> Vec<u8> {
+        hex::decode(
+            include_str!("../../../../evm/test/payloads/transceiver_message_1.txt").trim_end(),
+        )
+        .unwrap()
+    }
+
+    fn with_len_delta(mut data: Vec<u8>, offset: usize, delta: i32) -> Vec<u8> {
+        let len = u16::from_be_bytes([data[offset], data[offset + 1]]);
+        let len = u16::try_from(i32::from(len) + delta).unwrap();
+        data[offset..offset + 2].copy_from_slice(&len.to_be_bytes());
+        data
+    }
+
+    #[test]
+    fn test_roundtrip() {
+        let data = transceiver_message_1();
+        let message: Message = decode(&data).unwrap();
+        assert_eq!(encode(&message).unwrap(), data);
+    }
+
+    #[test]
+    fn test_trailing_bytes() {
+        let mut data = transceiver_message_1();
+        data.push(0);
+        assert_eq!(
+            decode::<Message>(&data).unwrap_err(),
+            DecodeError::TrailingBytes
+        );
+    }
+
+    #[test]
+    fn test_truncated() {
+        let data = transceiver_message_1();
+        for len in 0..data.len() {
+            assert_eq!(
+                decode::<Message>(&data[..len]).unwrap_err(),
+                DecodeError::UnexpectedEnd,
+                "truncated to {len} bytes"
+            );
+        }
+    }
+
+    #[test]
+    fn test_invalid_prefix() {
+        let mut data = transceiver_message_1();
+        data[0] ^= 0xFF;
+        assert_eq!(
+            decode::<Message>(&data).unwrap_err(),
+            DecodeError::InvalidPrefix
+        );
+    }
+
+    #[test]
+    fn test_ntt_manager_payload_length_mismatch() {
+        for delta in [-1, 1] {
+            let data = with_len_delta(
+                transceiver_message_1(),
+                NTT_MANAGER_PAYLOAD_LEN_OFFSET,
+                delta,
+            );
+            assert_eq!(
+                decode::<Message>(&data).unwrap_err(),
+                DecodeError::LengthMismatch,
+                "declared length off by {delta}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_ntt_payload_length_mismatch() {
+        for delta in [-1, 1] {
+            let data = with_len_delta(transceiver_message_1(), NTT_PAYLOAD_LEN_OFFSET, delta);
+            assert_eq!(
+                decode::<Message>(&data).unwrap_err(),
+                DecodeError::LengthMismatch,
+                "declared length off by {delta}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_unexpected_additional_payload() {
+        // the additional payload is left unconsumed when it's not expected
+        let data = hex::decode(
+            include_str!(
+                "../../../../evm/test/payloads/transceiver_message_with_32byte_payload.txt"
+            )
+            .trim_end(),
+        )
+        .unwrap();
+        assert!(decode::<Message>(&data).is_ok());
+        assert_eq!(
+            decode::<TransceiverMessage<WormholeTransceiver, NativeTokenTransfer<EmptyPayload>>>(
+                &data
+            )
+            .unwrap_err(),
+            DecodeError::LengthMismatch
+        );
+    }
+
+    #[test]
+    fn test_encode_length_overflow() {
+        let mut message: Message = decode(&transceiver_message_1()).unwrap();
+        message.transceiver_payload = vec![0; usize::from(u16::MAX)];
+        assert!(encode(&message).is_ok());
+        message.transceiver_payload.push(0);
+        assert_eq!(encode(&message).unwrap_err(), EncodeError::LengthOverflow);
+    }
+
+    fn transceiver_message_with_32byte_payload() -> Vec<u8> {
+        hex::decode(
+            include_str!(
+                "../../../../evm/test/payloads/transceiver_message_with_32byte_payload.txt"
+            )
+            .trim_end(),
+        )
+        .unwrap()
+    }
+
+    fn assert_view_matches(view: NttManagerMessageView, message: &Message) {
+        let expected = &message.ntt_manager_payload;
+        assert_eq!(view.id(), &expected.id);
+        assert_eq!(view.sender(), &expected.sender);
+
+        let ntt = view.native_token_transfer().unwrap();
+        assert_eq!(ntt.amount().trimmed_amount(), expected.payload.amount);
+        assert_eq!(ntt.source_token(), &expected.payload.source_token);
+        assert_eq!(ntt.to_chain(), expected.payload.to_chain);
+        assert_eq!(ntt.to(), &expected.payload.to);
+    }
+
+    #[test]
+    fn test_wire_views() {
+        let data = transceiver_message_with_32byte_payload();
+        let message: Message = decode(&data).unwrap();
+
+        let view = NttManagerMessageView::parse(
+            &data[NTT_MANAGER_PAYLOAD_LEN_OFFSET + 2..],
+            Encoding::Wire,
+        )
+        .unwrap();
+        assert_view_matches(view, &message);
+
+        // the additional payload is still prefixed by its length
+        let additional_payload = &message.ntt_manager_payload.payload.additional_payload.bytes;
+        let mut expected = u16::try_from(additional_payload.len())
+            .unwrap()
+            .to_be_bytes()
+            .to_vec();
+        expected.extend(additional_payload);
+        assert_eq!(
+            view.native_token_transfer().unwrap().additional_payload(),
+            expected
+        );
+    }
+
+    #[cfg(feature = "anchor")]
+    #[test]
+    fn test_borsh_views() {
+        use anchor_lang::AnchorSerialize;
+
+        use crate::transceiver::TransceiverMessageDataBytes;
+
+        let message: Message = decode(&transceiver_message_with_32byte_payload()).unwrap();
+        let data = message.message_data.try_to_vec().unwrap();
+
+        let bytes =
+            TransceiverMessageDataBytes::<NativeTokenTransfer<AdditionalPayload>>::parse(&data)
+                .unwrap();
+        assert_eq!(bytes.source_ntt_manager(), message.source_ntt_manager);
+        assert_eq!(bytes.recipient_ntt_manager(), message.recipient_ntt_manager);
+        assert_eq!(
+            bytes.ntt_manager_payload().unwrap(),
+            message.ntt_manager_payload
+        );
+        assert_view_matches(bytes.ntt_manager_message(), &message);
+        assert_eq!(
+            bytes
+                .ntt_manager_message()
+                .native_token_transfer()
+                .unwrap()
+                .additional_payload(),
+            message
+                .ntt_manager_payload
+                .payload
+                .additional_payload
+                .try_to_vec()
+                .unwrap()
+        );
+    }
+
+    #[test]
+    fn test_views_reject_malformed() {
+        let data = transceiver_message_with_32byte_payload();
+        let span = &data[NTT_MANAGER_PAYLOAD_LEN_OFFSET + 2..];
+
+        // the declared payload length doesn't fit
+        assert_eq!(
+            NttManagerMessageView::parse(&span[..span.len() - 3], Encoding::Wire).unwrap_err(),
+            DecodeError::UnexpectedEnd
+        );
+        assert_eq!(
+            NttManagerMessageView::parse(&span[..63], Encoding::Borsh).unwrap_err(),
+            DecodeError::UnexpectedEnd
+        );
+
+        let payload = NttManagerMessageView::parse(span, Encoding::Wire)
+            .unwrap()
+            .payload();
+        assert_eq!(
+            NativeTokenTransferView::parse(&payload[..78], Encoding::Wire).unwrap_err(),
+            DecodeError::UnexpectedEnd
+        );
+        let mut payload = payload.to_vec();
+        payload[0] ^= 0xFF;
+        assert_eq!(
+            NativeTokenTransferView::parse(&payload, Encoding::Wire).unwrap_err(),
+            DecodeError::InvalidPrefix
+        );
+    }
+}
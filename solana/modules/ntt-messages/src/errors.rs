@@ -1,4 +1,7 @@
-use std::fmt::{Display, Formatter};
+use std::{
+    fmt::{Display, Formatter},
+    io,
+};
 
 #[derive(Debug, PartialEq)]
 pub enum ScalingError {
@@ -21,3 +24,109 @@ impl Display for ScalingError {
         }
     }
 }
+
+/// Errors that can occur when decoding a message from its wire format.
+///
+/// The [`wormhole_io::Readable`] trait works with [`std::io::Error`]s, so these
+/// are carried inside them while decoding, and recovered by
+/// [`crate::codec::decode`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DecodeError {
+    /// The input ended before the message did.
+    UnexpectedEnd,
+    /// The message (or one of its sub-payloads) has an unexpected prefix.
+    InvalidPrefix,
+    /// A field has a value that doesn't correspond to any valid encoding.
+    InvalidValue,
+    /// A length-prefixed sub-payload didn't consume exactly its declared length.
+    LengthMismatch,
+    /// The additional payload exceeds [`crate::ntt::MAX_ADDITIONAL_PAYLOAD_LEN`].
+    PayloadTooLong,
+    /// There are bytes left in the input after the end of the message.
+    TrailingBytes,
+    /// Any other I/O error from the underlying reader.
+    Io(io::ErrorKind),
+}
+
+impl std::error::Error for DecodeError {}
+
+impl Display for DecodeError {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        match self {
+            DecodeError::UnexpectedEnd => write!(f, "Unexpected end of input"),
+            DecodeError::InvalidPrefix => write!(f, "Invalid prefix"),
+            DecodeError::InvalidValue => write!(f, "Invalid value"),
+            DecodeError::LengthMismatch => {
+                write!(f, "Payload length doesn't match its declared length")
+            }
+            DecodeError::PayloadTooLong => write!(f, "Additional payload too long"),
+            DecodeError::TrailingBytes => write!(f, "Trailing bytes after the message"),
+            DecodeError::Io(kind) => write!(f, "I/O error: {kind}"),
+        }
+    }
+}
+
+impl From<DecodeError> for io::Error {
+    fn from(err: DecodeError) -> Self {
+        let kind = match err {
+            DecodeError::UnexpectedEnd => io::ErrorKind::UnexpectedEof,
+            DecodeError::Io(kind) => kind,
+            _ => io::ErrorKind::InvalidData,
+        };
+        io::Error::new(kind, err)
+    }
+}
+
+impl From<io::Error> for DecodeError {
+    fn from(err: io::Error) -> Self {
+        if let Some(err) = err.get_ref().and_then(|e| e.downcast_ref::<DecodeError>()) {
+            return *err;
+        }
+        match err.kind() {
+            io::ErrorKind::UnexpectedEof => DecodeError::UnexpectedEnd,
+            io::ErrorKind::InvalidData => DecodeError::InvalidValue,
+            kind => DecodeError::Io(kind),
+        }
+    }
+}
+
+/// Errors that can occur when encoding a message into its wire format.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EncodeError {
+    /// A length-prefixed field is longer than its prefix can express.
+    LengthOverflow,
+    /// Any other I/O error from the underlying writer.
+    Io(io::ErrorKind),
+}
+
+impl std::error::Error for EncodeError {}
+
+impl Display for EncodeError {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        match self {
+            EncodeError::LengthOverflow => {
+                write!(f, "Overflow: payload length exceeds the max value of u16")
+            }
+            EncodeError::Io(kind) => write!(f, "I/O error: {kind}"),
+        }
+    }
+}
+
+impl From<EncodeError> for io::Error {
+    fn from(err: EncodeError) -> Self {
+        let kind = match err {
+            EncodeError::Io(kind) => kind,
+            EncodeError::LengthOverflow => io::ErrorKind::InvalidInput,
+        };
+        io::Error::new(kind, err)
+    }
+}
+
+impl From<io::Error> for EncodeError {
+    fn from(err: io::Error) -> Self {
+        match err.get_ref().and_then(|e| e.downcast_ref::<EncodeError>()) {
+            Some(err) => *err,
+            None => EncodeError::Io(err.kind()),
+        }
+    }
+}
@@ -1,4 +1,5 @@
 pub mod chain_id;
+pub mod codec;
 pub mod errors;
 pub mod mode;
 pub mod ntt;
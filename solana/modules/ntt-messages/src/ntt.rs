@@ -5,7 +5,13 @@ use std::io;
 
 use wormhole_io::{Readable, TypePrefixedPayload, Writeable};
 
-use crate::{chain_id::ChainId, trimmed_amount::TrimmedAmount, utils::maybe_space::MaybeSpace};
+use crate::{
+    chain_id::ChainId,
+    codec::{length_prefix, read_length_prefixed, split_array, Encoding},
+    errors::DecodeError,
+    trimmed_amount::{TrimmedAmount, TrimmedAmountView},
+    utils::maybe_space::MaybeSpace,
+};
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 #[cfg_attr(
@@ -24,40 +30,23 @@ pub struct NativeTokenTransfer<A: MaybeSpace> {
 
 impl<A: MaybeSpace> NativeTokenTransfer<A> {
     const PREFIX: [u8; 4] = [0x99, 0x4E, 0x54, 0x54];
-}
-
-impl<A: TypePrefixedPayload + MaybeSpace> TypePrefixedPayload for NativeTokenTransfer<A> {
-    const TYPE: Option<u8> = None;
-}
-
-impl<A: TypePrefixedPayload + MaybeSpace> Readable for NativeTokenTransfer<A> {
-    const SIZE: Option<usize> = None;
 
-    fn read<R>(reader: &mut R) -> io::Result<Self>
-    where
-        Self: Sized,
-        R: io::Read,
-    {
+    /// Reads the fields before the additional payload, and then the payload
+    /// with `read_payload`.
+    fn read_with<R: io::Read>(
+        reader: &mut R,
+        read_payload: impl FnOnce(&mut R) -> io::Result<A>,
+    ) -> io::Result<Self> {
         let prefix: [u8; 4] = Readable::read(reader)?;
         if prefix != Self::PREFIX {
-            return Err(io::Error::new(
-                io::ErrorKind::InvalidData,
-                "Invalid prefix for NativeTokenTransfer",
-            ));
+            return Err(DecodeError::InvalidPrefix.into());
         }
 
         let amount = Readable::read(reader)?;
         let source_token = Readable::read(reader)?;
         let to = Readable::read(reader)?;
         let to_chain = Readable::read(reader)?;
-
-        if A::SIZE != Some(0) {
-            // if the size is explicitly zero, this is an empty payload message
-            // and the size field should be skipped
-            // TODO: ditto todo in transceiver.rs
-            let _additional_payload_len: u16 = Readable::read(reader)?;
-        }
-        let additional_payload = A::read_payload(reader)?;
+        let additional_payload = read_payload(reader)?;
 
         Ok(Self {
             amount,
@@ -67,26 +56,23 @@ impl<A: TypePrefixedPayload + MaybeSpace> Readable for NativeTokenTransfer<A> {
             additional_payload,
         })
     }
-}
 
-impl<A: TypePrefixedPayload + MaybeSpace> Writeable for NativeTokenTransfer<A> {
-    fn written_size(&self) -> usize {
+    /// The size of the fields before the additional payload.
+    fn fields_size(&self) -> usize {
         Self::PREFIX.len()
             + TrimmedAmount::SIZE.unwrap()
             + self.source_token.len()
             + self.to.len()
             + ChainId::SIZE.unwrap()
-            + if A::SIZE != Some(0) {
-                u16::SIZE.unwrap() + self.additional_payload.written_size()
-            } else {
-                0
-            }
     }
 
-    fn write<W>(&self, writer: &mut W) -> io::Result<()>
-    where
-        W: io::Write,
-    {
+    /// Writes the fields before the additional payload, and then the payload
+    /// with `write_payload`.
+    fn write_with<W: io::Write>(
+        &self,
+        writer: &mut W,
+        write_payload: impl FnOnce(&A, &mut W) -> io::Result<()>,
+    ) -> io::Result<()> {
         let NativeTokenTransfer {
             amount,
             source_token,
@@ -100,16 +86,192 @@ impl<A: TypePrefixedPayload + MaybeSpace> Writeable for NativeTokenTransfer<A> {
         source_token.write(writer)?;
         to.write(writer)?;
         to_chain.write(writer)?;
+        write_payload(additional_payload, writer)
+    }
+}
 
-        if A::SIZE != Some(0) {
-            let len: u16 = u16::try_from(additional_payload.written_size()).expect("u16 overflow");
-            len.write(writer)?;
-            // TODO: ditto todo in transceiver.rs
+impl<A: TypePrefixedPayload + MaybeSpace> TypePrefixedPayload for NativeTokenTransfer<A> {
+    const TYPE: Option<u8> = None;
+}
+
+impl<A: TypePrefixedPayload + MaybeSpace> Readable for NativeTokenTransfer<A> {
+    const SIZE: Option<usize> = None;
+
+    fn read<R>(reader: &mut R) -> io::Result<Self>
+    where
+        Self: Sized,
+        R: io::Read,
+    {
+        // if the size is explicitly zero, this is an empty payload message,
+        // so the size field should be skipped
+        Self::read_with(reader, |reader| {
+            if A::SIZE != Some(0) {
+                read_length_prefixed(reader, |span| A::read_payload(span))
+            } else {
+                A::read_payload(reader)
+            }
+        })
+    }
+}
+
+impl<A: TypePrefixedPayload + MaybeSpace> Writeable for NativeTokenTransfer<A> {
+    fn written_size(&self) -> usize {
+        self.fields_size()
+            + if A::SIZE != Some(0) {
+                u16::SIZE.unwrap()
+            } else {
+                0
+            }
+            + self.additional_payload.written_size()
+    }
+
+    fn write<W>(&self, writer: &mut W) -> io::Result<()>
+    where
+        W: io::Write,
+    {
+        self.write_with(writer, |additional_payload, writer| {
+            if A::SIZE != Some(0) {
+                length_prefix(additional_payload.written_size())?.write(writer)?;
+            }
             A::write_payload(additional_payload, writer)
-        } else {
-            Ok(())
+        })
+    }
+}
+
+// [`AdditionalPayload`] is not [`Readable`] on its own (see its
+// documentation), so [`NativeTokenTransfer`] decodes it itself.
+
+impl TypePrefixedPayload for NativeTokenTransfer<AdditionalPayload> {
+    const TYPE: Option<u8> = None;
+}
+
+impl Readable for NativeTokenTransfer<AdditionalPayload> {
+    const SIZE: Option<usize> = None;
+
+    fn read<R>(reader: &mut R) -> io::Result<Self>
+    where
+        Self: Sized,
+        R: io::Read,
+    {
+        Self::read_with(reader, |reader| {
+            let mut len = [0u8; 2];
+            if reader.read(&mut len[..1])? == 0 {
+                // no payload
+                return Ok(AdditionalPayload::default());
+            }
+            reader.read_exact(&mut len[1..])?;
+            let len = usize::from(u16::from_be_bytes(len));
+            if len > MAX_ADDITIONAL_PAYLOAD_LEN {
+                return Err(DecodeError::PayloadTooLong.into());
+            }
+            let mut bytes = vec![0; len];
+            reader.read_exact(&mut bytes)?;
+            Ok(AdditionalPayload { bytes })
+        })
+    }
+}
+
+impl Writeable for NativeTokenTransfer<AdditionalPayload> {
+    fn written_size(&self) -> usize {
+        self.fields_size()
+            + if self.additional_payload.is_empty() {
+                0
+            } else {
+                u16::SIZE.unwrap() + self.additional_payload.bytes.len()
+            }
+    }
+
+    fn write<W>(&self, writer: &mut W) -> io::Result<()>
+    where
+        W: io::Write,
+    {
+        self.write_with(writer, |additional_payload, writer| {
+            if additional_payload.is_empty() {
+                return Ok(());
+            }
+            length_prefix(additional_payload.bytes.len())?.write(writer)?;
+            writer.write_all(&additional_payload.bytes)
+        })
+    }
+}
+
+/// A zero-copy view of an encoded [`NativeTokenTransfer`].
+///
+/// The additional payload is not interpreted, and is exposed in its encoded
+/// form by [`NativeTokenTransferView::additional_payload`].
+#[derive(Debug, Clone, Copy)]
+pub struct NativeTokenTransferView<'a> {
+    amount: TrimmedAmountView<'a>,
+    source_token: &'a [u8; 32],
+    to_chain: &'a [u8; 2],
+    to: &'a [u8; 32],
+    additional_payload: &'a [u8],
+    encoding: Encoding,
+}
+
+impl<'a> NativeTokenTransferView<'a> {
+    pub fn parse(mut span: &'a [u8], encoding: Encoding) -> std::result::Result<Self, DecodeError> {
+        let span = &mut span;
+        match encoding {
+            Encoding::Wire => {
+                if split_array(span)? != &NativeTokenTransfer::<EmptyPayload>::PREFIX {
+                    return Err(DecodeError::InvalidPrefix);
+                }
+                let amount = TrimmedAmountView::parse(span, encoding)?;
+                let source_token = split_array(span)?;
+                let to = split_array(span)?;
+                let to_chain = split_array(span)?;
+                Ok(Self {
+                    amount,
+                    source_token,
+                    to_chain,
+                    to,
+                    additional_payload: *span,
+                    encoding,
+                })
+            }
+            Encoding::Borsh => {
+                let amount = TrimmedAmountView::parse(span, encoding)?;
+                let source_token = split_array(span)?;
+                let to_chain = split_array(span)?;
+                let to = split_array(span)?;
+                Ok(Self {
+                    amount,
+                    source_token,
+                    to_chain,
+                    to,
+                    additional_payload: *span,
+                    encoding,
+                })
+            }
         }
     }
+
+    pub fn amount(&self) -> TrimmedAmountView<'a> {
+        self.amount
+    }
+
+    pub fn source_token(&self) -> &'a [u8; 32] {
+        self.source_token
+    }
+
+    pub fn to_chain(&self) -> ChainId {
+        let id = match self.encoding {
+            Encoding::Wire => u16::from_be_bytes(*self.to_chain),
+            Encoding::Borsh => u16::from_le_bytes(*self.to_chain),
+        };
+        ChainId { id }
+    }
+
+    pub fn to(&self) -> &'a [u8; 32] {
+        self.to
+    }
+
+    /// The additional payload, still encoded (including its length prefix, if
+    /// any).
+    pub fn additional_payload(&self) -> &'a [u8] {
+        self.additional_payload
+    }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -151,3 +313,42 @@ impl Writeable for EmptyPayload {
         Ok(())
     }
 }
+
+/// The maximum length of an [`AdditionalPayload`], in bytes.
+pub const MAX_ADDITIONAL_PAYLOAD_LEN: usize = 256;
+
+/// An opaque payload attached to a [`NativeTokenTransfer`], to be interpreted
+/// by the recipient.
+///
+/// On the wire, an empty payload is omitted entirely (as on EVM), and a
+/// non-empty payload is prefixed by its length as a u16.
+///
+/// The absence of the payload can only be detected by the end of the input,
+/// so it is not [`Readable`] on its own, and is only decoded as part of a
+/// [`NativeTokenTransfer`], whose reader is bounded by the length prefix of
+/// the [`crate::ntt_manager::NttManagerMessage`] payload.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+#[cfg_attr(
+    feature = "anchor",
+    derive(AnchorSerialize, AnchorDeserialize, InitSpace)
+)]
+pub struct AdditionalPayload {
+    #[cfg_attr(feature = "anchor", max_len(MAX_ADDITIONAL_PAYLOAD_LEN))]
+    pub bytes: Vec<u8>,
+}
+
+impl AdditionalPayload {
+    pub fn new(bytes: Vec<u8>) -> io::Result<Self> {
+        if bytes.len() > MAX_ADDITIONAL_PAYLOAD_LEN {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "AdditionalPayload too long",
+            ));
+        }
+        Ok(Self { bytes })
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.bytes.is_empty()
+    }
+}
@@ -5,7 +5,12 @@ use anchor_lang::prelude::*;
 
 use wormhole_io::{Readable, TypePrefixedPayload, Writeable};
 
-use crate::utils::maybe_space::MaybeSpace;
+use crate::{
+    codec::{length_prefix, read_length_prefixed, split_array, Encoding},
+    errors::DecodeError,
+    ntt::NativeTokenTransferView,
+    utils::maybe_space::MaybeSpace,
+};
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 #[cfg_attr(
@@ -45,9 +50,7 @@ impl<A: TypePrefixedPayload + MaybeSpace> Readable for NttManagerMessage<A> {
     {
         let id = Readable::read(reader)?;
         let sender = Readable::read(reader)?;
-        // TODO: ditto todo in transceiver.rs
-        let _payload_len: u16 = Readable::read(reader)?;
-        let payload = A::read_payload(reader)?;
+        let payload = read_length_prefixed(reader, |span| A::read_payload(span))?;
 
         Ok(Self {
             id,
@@ -77,9 +80,58 @@ impl<A: TypePrefixedPayload + MaybeSpace> Writeable for NttManagerMessage<A> {
 
         id.write(writer)?;
         writer.write_all(sender)?;
-        let len: u16 = u16::try_from(payload.written_size()).expect("u16 overflow");
-        len.write(writer)?;
-        // TODO: ditto todo in transceiver.rs
+        length_prefix(payload.written_size())?.write(writer)?;
         A::write_payload(payload, writer)
     }
 }
+
+/// A zero-copy view of an encoded [`NttManagerMessage`].
+#[derive(Debug, Clone, Copy)]
+pub struct NttManagerMessageView<'a> {
+    id: &'a [u8; 32],
+    sender: &'a [u8; 32],
+    payload: &'a [u8],
+    encoding: Encoding,
+}
+
+impl<'a> NttManagerMessageView<'a> {
+    /// In the wire format, the payload must fit in `span`, but anything after
+    /// it is ignored.
+    pub fn parse(mut span: &'a [u8], encoding: Encoding) -> std::result::Result<Self, DecodeError> {
+        let span = &mut span;
+        let id = split_array(span)?;
+        let sender = split_array(span)?;
+        let payload = match encoding {
+            Encoding::Wire => {
+                let len = usize::from(u16::from_be_bytes(*split_array(span)?));
+                span.get(..len).ok_or(DecodeError::UnexpectedEnd)?
+            }
+            Encoding::Borsh => *span,
+        };
+        Ok(Self {
+            id,
+            sender,
+            payload,
+            encoding,
+        })
+    }
+
+    pub fn id(&self) -> &'a [u8; 32] {
+        self.id
+    }
+
+    pub fn sender(&self) -> &'a [u8; 32] {
+        self.sender
+    }
+
+    /// The payload, still encoded.
+    pub fn payload(&self) -> &'a [u8] {
+        self.payload
+    }
+
+    pub fn native_token_transfer(
+        &self,
+    ) -> std::result::Result<NativeTokenTransferView<'a>, DecodeError> {
+        NativeTokenTransferView::parse(self.payload, self.encoding)
+    }
+}
@@ -6,7 +6,12 @@ use anchor_lang::prelude::*;
 
 use wormhole_io::{Readable, TypePrefixedPayload, Writeable};
 
-use crate::{ntt_manager::NttManagerMessage, utils::maybe_space::MaybeSpace};
+use crate::{
+    codec::{length_prefix, read_length_prefixed, split_array, Encoding},
+    errors::DecodeError,
+    ntt_manager::{NttManagerMessage, NttManagerMessageView},
+    utils::maybe_space::MaybeSpace,
+};
 
 pub trait Transceiver {
     const PREFIX: [u8; 4];
@@ -24,10 +29,14 @@ pub struct TransceiverMessageData<A: MaybeSpace> {
 }
 
 /// This struct is for zero-copy deserialization of
-/// `ValidatedTransceiverMessage::message()` in the redeem ix
+/// `ValidatedTransceiverMessage::message()` in the redeem ix, i.e. it reads the
+/// Borsh layout of [`TransceiverMessageData`].
 pub struct TransceiverMessageDataBytes<'a, A: MaybeSpace> {
     _phantom: PhantomData<A>,
     span: &'a [u8],
+    source_ntt_manager: &'a [u8; 32],
+    recipient_ntt_manager: &'a [u8; 32],
+    ntt_manager_message: NttManagerMessageView<'a>,
 }
 
 impl<A: MaybeSpace> AsRef<[u8]> for TransceiverMessageDataBytes<'_, A> {
@@ -38,25 +47,42 @@ impl<A: MaybeSpace> AsRef<[u8]> for TransceiverMessageDataBytes<'_, A> {
 
 impl<'a, A: MaybeSpace> TransceiverMessageDataBytes<'a, A> {
     pub fn source_ntt_manager(&self) -> [u8; 32] {
-        self.span[..32].try_into().unwrap()
+        *self.source_ntt_manager
     }
 
     pub fn recipient_ntt_manager(&self) -> [u8; 32] {
-        self.span[32..64].try_into().unwrap()
+        *self.recipient_ntt_manager
     }
 
-    pub fn ntt_manager_payload(&self) -> NttManagerMessage<A>
+    /// A zero-copy view of the manager message. Prefer this over
+    /// [`Self::ntt_manager_payload`] when only some fields are needed.
+    pub fn ntt_manager_message(&self) -> NttManagerMessageView<'a> {
+        self.ntt_manager_message
+    }
+
+    #[cfg(feature = "anchor")]
+    pub fn ntt_manager_payload(&self) -> std::result::Result<NttManagerMessage<A>, DecodeError>
     where
         A: AnchorDeserialize,
     {
-        NttManagerMessage::deserialize(&mut &self.span[64..]).unwrap()
+        let mut span = self.span.get(64..).ok_or(DecodeError::UnexpectedEnd)?;
+        Ok(NttManagerMessage::deserialize(&mut span)?)
     }
 
-    pub fn parse(span: &'a [u8]) -> TransceiverMessageDataBytes<'a, A> {
-        TransceiverMessageDataBytes {
+    pub fn parse(
+        span: &'a [u8],
+    ) -> std::result::Result<TransceiverMessageDataBytes<'a, A>, DecodeError> {
+        let mut rest = span;
+        let source_ntt_manager = split_array(&mut rest)?;
+        let recipient_ntt_manager = split_array(&mut rest)?;
+        let ntt_manager_message = NttManagerMessageView::parse(rest, Encoding::Borsh)?;
+        Ok(TransceiverMessageDataBytes {
             _phantom: PhantomData,
             span,
-        }
+            source_ntt_manager,
+            recipient_ntt_manager,
+            ntt_manager_message,
+        })
     }
 }
 
@@ -145,21 +171,18 @@ where
     {
         let prefix: [u8; 4] = Readable::read(reader)?;
         if prefix != E::PREFIX {
-            return Err(io::Error::new(
-                io::ErrorKind::InvalidData,
-                "Invalid prefix for TransceiverMessage",
-            ));
+            return Err(DecodeError::InvalidPrefix.into());
         }
 
         let source_ntt_manager = Readable::read(reader)?;
         let recipient_ntt_manager = Readable::read(reader)?;
-        // TODO: we need a way to easily check that decoding the payload
-        // consumes the expected amount of bytes
-        let _ntt_manager_payload_len: u16 = Readable::read(reader)?;
-        let ntt_manager_payload = NttManagerMessage::read(reader)?;
-        let transceiver_payload_len: u16 = Readable::read(reader)?;
-        let mut transceiver_payload = vec![0; transceiver_payload_len as usize];
-        reader.read_exact(&mut transceiver_payload)?;
+        let ntt_manager_payload =
+            read_length_prefixed(reader, |span| NttManagerMessage::read(span))?;
+        let transceiver_payload = read_length_prefixed(reader, |span| {
+            let mut transceiver_payload = Vec::new();
+            io::Read::read_to_end(span, &mut transceiver_payload)?;
+            Ok(transceiver_payload)
+        })?;
 
         Ok(TransceiverMessage::new(
             source_ntt_manager,
@@ -202,15 +225,13 @@ where
         E::PREFIX.write(writer)?;
         source_ntt_manager.write(writer)?;
         recipient_ntt_manager.write(writer)?;
-        let len: u16 = u16::try_from(ntt_manager_payload.written_size()).expect("u16 overflow");
-        len.write(writer)?;
+        length_prefix(ntt_manager_payload.written_size())?.write(writer)?;
         // TODO: review this in wormhole-io. The written_size logic is error prone. Instead,
         // a better API would be
         // foo.write_with_prefix_be::<u16>(writer)
         // which writes the length as a big endian u16.
         ntt_manager_payload.write(writer)?;
-        let len: u16 = u16::try_from(transceiver_payload.len()).expect("u16 overflow");
-        len.write(writer)?;
+        length_prefix(transceiver_payload.len())?.write(writer)?;
         writer.write_all(transceiver_payload)?;
         Ok(())
     }
@@ -219,8 +240,10 @@ where
 #[cfg(test)]
 mod test {
     use crate::{
-        chain_id::ChainId, ntt::EmptyPayload, ntt::NativeTokenTransfer,
-        transceivers::wormhole::WormholeTransceiver, trimmed_amount::TrimmedAmount,
+        chain_id::ChainId,
+        ntt::{AdditionalPayload, EmptyPayload, NativeTokenTransfer, MAX_ADDITIONAL_PAYLOAD_LEN},
+        transceivers::wormhole::WormholeTransceiver,
+        trimmed_amount::TrimmedAmount,
     };
 
     use super::*;
@@ -496,4 +519,103 @@ mod test {
         let encoded = TypePrefixedPayload::to_vec_payload(&expected);
         assert_eq!(encoded, data);
     }
+
+    #[test]
+    fn test_deserialize_transceiver_message_with_additional_payload() {
+        let data = hex::decode(
+            include_str!(
+                "../../../../evm/test/payloads/transceiver_message_with_32byte_payload.txt"
+            )
+            .trim_end(),
+        )
+        .unwrap();
+        let mut vec = &data[..];
+        let message: TransceiverMessage<
+            WormholeTransceiver,
+            NativeTokenTransfer<AdditionalPayload>,
+        > = TypePrefixedPayload::read_payload(&mut vec).unwrap();
+        assert_eq!(vec.len(), 0);
+
+        assert_eq!(
+            message.ntt_manager_payload.payload.additional_payload.bytes,
+            [
+                0xDE, 0xAD, 0xBE, 0xEF, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+                0, 0, 0, 0, 0xDE, 0xAD, 0xBE, 0xEF,
+            ]
+        );
+        assert_eq!(message.transceiver_payload, Vec::<u8>::new());
+
+        let encoded = TypePrefixedPayload::to_vec_payload(&message);
+        assert_eq!(encoded, data);
+    }
+
+    #[test]
+    fn test_deserialize_transceiver_message_without_additional_payload() {
+        // the payload is omitted entirely
+        let data = hex::decode(
+            include_str!("../../../../evm/test/payloads/transceiver_message_1.txt").trim_end(),
+        )
+        .unwrap();
+        let mut vec = &data[..];
+        let message: TransceiverMessage<
+            WormholeTransceiver,
+            NativeTokenTransfer<AdditionalPayload>,
+        > = TypePrefixedPayload::read_payload(&mut vec).unwrap();
+        assert_eq!(vec.len(), 0);
+        assert!(message
+            .ntt_manager_payload
+            .payload
+            .additional_payload
+            .is_empty());
+
+        let encoded = TypePrefixedPayload::to_vec_payload(&message);
+        assert_eq!(encoded, data);
+
+        // the payload is included with a length of 0. We always omit empty
+        // payloads when encoding, so this doesn't roundtrip.
+        let data = hex::decode(
+            include_str!(
+                "../../../../evm/test/payloads/transceiver_message_with_empty_payload.txt"
+            )
+            .trim_end(),
+        )
+        .unwrap();
+        let mut vec = &data[..];
+        let message_with_empty_payload: TransceiverMessage<
+            WormholeTransceiver,
+            NativeTokenTransfer<AdditionalPayload>,
+        > = TypePrefixedPayload::read_payload(&mut vec).unwrap();
+        assert_eq!(vec.len(), 0);
+        assert_eq!(message_with_empty_payload, message);
+    }
+
+    #[test]
+    fn test_additional_payload_too_long() {
+        assert!(AdditionalPayload::new(vec![0; MAX_ADDITIONAL_PAYLOAD_LEN]).is_ok());
+        assert!(AdditionalPayload::new(vec![0; MAX_ADDITIONAL_PAYLOAD_LEN + 1]).is_err());
+
+        let transfer = NativeTokenTransfer {
+            amount: TrimmedAmount {
+                amount: 1234567,
+                decimals: 7,
+            },
+            source_token: [0xBE; 32],
+            to_chain: ChainId { id: 17 },
+            to: [0xFE; 32],
+            additional_payload: AdditionalPayload::default(),
+        };
+        let mut data = TypePrefixedPayload::to_vec_payload(&transfer);
+        data.extend(
+            u16::try_from(MAX_ADDITIONAL_PAYLOAD_LEN + 1)
+                .unwrap()
+                .to_be_bytes(),
+        );
+        data.extend(vec![0; MAX_ADDITIONAL_PAYLOAD_LEN + 1]);
+        assert_eq!(
+            DecodeError::from(
+                NativeTokenTransfer::<AdditionalPayload>::read(&mut &data[..]).unwrap_err()
+            ),
+            DecodeError::PayloadTooLong
+        );
+    }
 }
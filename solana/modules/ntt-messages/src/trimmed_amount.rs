@@ -9,7 +9,10 @@
 
 use std::io;
 
-use crate::errors::ScalingError;
+use crate::{
+    codec::{split_array, Encoding},
+    errors::{DecodeError, ScalingError},
+};
 
 #[cfg(feature = "anchor")]
 use anchor_lang::prelude::{borsh, AnchorDeserialize, AnchorSerialize, InitSpace};
@@ -154,6 +157,49 @@ impl Writeable for TrimmedAmount {
     }
 }
 
+/// A zero-copy view of an encoded [`TrimmedAmount`].
+#[derive(Debug, Clone, Copy)]
+pub struct TrimmedAmountView<'a> {
+    span: &'a [u8; 9],
+    encoding: Encoding,
+}
+
+impl<'a> TrimmedAmountView<'a> {
+    /// Parses the view from the start of `span`, advancing it past the amount.
+    pub fn parse(span: &mut &'a [u8], encoding: Encoding) -> Result<Self, DecodeError> {
+        Ok(Self {
+            span: split_array(span)?,
+            encoding,
+        })
+    }
+
+    pub fn amount(&self) -> u64 {
+        let (amount, _) = self.split();
+        match self.encoding {
+            Encoding::Wire => u64::from_be_bytes(amount),
+            Encoding::Borsh => u64::from_le_bytes(amount),
+        }
+    }
+
+    pub fn decimals(&self) -> u8 {
+        let (_, decimals) = self.split();
+        decimals
+    }
+
+    pub fn trimmed_amount(&self) -> TrimmedAmount {
+        TrimmedAmount::new(self.amount(), self.decimals())
+    }
+
+    fn split(&self) -> ([u8; 8], u8) {
+        let [a0, a1, a2, a3, a4, a5, a6, a7, a8] = *self.span;
+        match self.encoding {
+            // see [`TrimmedAmount::read`] for the field order
+            Encoding::Wire => ([a1, a2, a3, a4, a5, a6, a7, a8], a0),
+            Encoding::Borsh => ([a0, a1, a2, a3, a4, a5, a6, a7], a8),
+        }
+    }
+}
+
 #[cfg(test)]
 mod test {
 
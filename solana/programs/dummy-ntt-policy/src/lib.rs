@@ -0,0 +1,163 @@
+use anchor_lang::{prelude::*, solana_program::program::set_return_data};
+use example_native_token_transfers::compliance::{
+    CheckTransferArgs, PolicyDecision, POLICY_EXTRA_ACCOUNT_METAS_SEED_PREFIX,
+};
+use spl_discriminator::SplDiscriminate;
+use spl_tlv_account_resolution::{
+    account::ExtraAccountMeta, seeds::Seed, state::ExtraAccountMetaList,
+};
+
+declare_id!("4C3LUwizvxa4HrLqWw6eCTiZZr5AtLkWkm9LjijVpQhz");
+
+/// Number of extra accounts in the ExtraAccountMetaList account
+pub const EXTRA_ACCOUNTS_LEN: usize = 2;
+
+/// A policy program for the NTT manager (see
+/// `example_native_token_transfers::compliance`). It denies transfers from or
+/// to the addresses it [`Blocked`].
+#[program]
+pub mod dummy_ntt_policy {
+    use super::*;
+
+    pub fn initialize_extra_account_meta_list(
+        ctx: Context<InitializeExtraAccountMetaList>,
+    ) -> Result<()> {
+        let blocked = |offset| {
+            ExtraAccountMeta::new_with_seeds(
+                &[
+                    Seed::Literal {
+                        bytes: Blocked::SEED_PREFIX.to_vec(),
+                    },
+                    Seed::InstructionData {
+                        index: offset,
+                        length: 32,
+                    },
+                ],
+                false, // is_signer
+                false, // is_writable
+            )
+        };
+        let account_metas = vec![
+            blocked(CheckTransferArgs::SENDER_OFFSET)?,
+            blocked(CheckTransferArgs::RECIPIENT_OFFSET)?,
+        ];
+
+        assert_eq!(EXTRA_ACCOUNTS_LEN, account_metas.len());
+
+        ExtraAccountMetaList::init::<CheckTransferArgs>(
+            &mut ctx.accounts.extra_account_meta_list.try_borrow_mut_data()?,
+            &account_metas,
+        )?;
+
+        Ok(())
+    }
+
+    pub fn block(_ctx: Context<Block>, _address: [u8; 32]) -> Result<()> {
+        Ok(())
+    }
+
+    pub fn check_transfer(ctx: Context<CheckTransfer>, _args: CheckTransferArgs) -> Result<()> {
+        let denied = [&ctx.accounts.sender_entry, &ctx.accounts.recipient_entry]
+            .iter()
+            .any(|entry| *entry.owner == crate::ID && !entry.data_is_empty());
+        let decision = if denied {
+            PolicyDecision::Deny
+        } else {
+            PolicyDecision::Allow
+        };
+        set_return_data(&decision.try_to_vec()?);
+        Ok(())
+    }
+
+    // NOTE: like the CPI of the token2022 program into transfer hooks, the CPI
+    // of the NTT manager doesn't follow the anchor calling convention, so we
+    // need a fallback instruction to handle it
+    pub fn fallback<'info>(
+        program_id: &Pubkey,
+        accounts: &'info [AccountInfo<'info>],
+        data: &[u8],
+    ) -> Result<()> {
+        match data.strip_prefix(CheckTransferArgs::SPL_DISCRIMINATOR_SLICE) {
+            Some(args) => __private::__global::check_transfer(program_id, accounts, args),
+            None => Err(ProgramError::InvalidInstructionData.into()),
+        }
+    }
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct Blocked {}
+
+impl Blocked {
+    pub const SEED_PREFIX: &'static [u8] = b"blocked";
+}
+
+#[derive(Accounts)]
+pub struct InitializeExtraAccountMetaList<'info> {
+    #[account(mut)]
+    payer: Signer<'info>,
+
+    /// CHECK: ExtraAccountMetaList Account, must use these seeds
+    #[account(
+        init,
+        payer = payer,
+        space = ExtraAccountMetaList::size_of(EXTRA_ACCOUNTS_LEN)?,
+        seeds = [POLICY_EXTRA_ACCOUNT_METAS_SEED_PREFIX, mint.key().as_ref()],
+        bump
+    )]
+    pub extra_account_meta_list: AccountInfo<'info>,
+
+    /// CHECK: only used as a seed
+    pub mint: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+#[instruction(address: [u8; 32])]
+pub struct Block<'info> {
+    #[account(mut)]
+    payer: Signer<'info>,
+
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + Blocked::INIT_SPACE,
+        seeds = [Blocked::SEED_PREFIX, address.as_ref()],
+        bump
+    )]
+    pub entry: Account<'info, Blocked>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+#[instruction(args: CheckTransferArgs)]
+/// NOTE: this is just a dummy policy to test that the accounts are passed in
+/// correctly. Do NOT use this as a starting point in a real application, as
+/// anyone can block addresses.
+pub struct CheckTransfer<'info> {
+    /// CHECK: only used as a seed
+    pub mint: UncheckedAccount<'info>,
+
+    /// CHECK: ExtraAccountMetaList Account,
+    #[account(
+        seeds = [POLICY_EXTRA_ACCOUNT_METAS_SEED_PREFIX, mint.key().as_ref()],
+        bump
+    )]
+    pub extra_account_meta_list: UncheckedAccount<'info>,
+
+    /// CHECK: the [`Blocked`] entry of the sender, if it exists
+    #[account(
+        seeds = [Blocked::SEED_PREFIX, args.sender.as_ref()],
+        bump
+    )]
+    pub sender_entry: UncheckedAccount<'info>,
+
+    /// CHECK: the [`Blocked`] entry of the recipient, if it exists
+    #[account(
+        seeds = [Blocked::SEED_PREFIX, args.recipient.as_ref()],
+        bump
+    )]
+    pub recipient_entry: UncheckedAccount<'info>,
+}
@@ -0,0 +1,94 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount};
+use example_native_token_transfers::{
+    instructions::ReceiveNttTransferArgs,
+    queue::inbox::{InboxItem, ReleaseStatus},
+};
+use ntt_messages::{chain_id::ChainId, ntt::MAX_ADDITIONAL_PAYLOAD_LEN};
+
+declare_id!("fFQtv5LfAJRqLReTXje2hZKvdYYYuLLMMpxSUpEXMG9");
+
+/// A recipient program for the `release_inbound_*_and_call` instructions of
+/// the NTT manager. It records every call in a [`Receipt`] account.
+#[program]
+pub mod dummy_ntt_recipient {
+    use super::*;
+
+    pub fn receive_ntt_transfer(
+        ctx: Context<ReceiveNttTransfer>,
+        args: ReceiveNttTransferArgs,
+    ) -> Result<()> {
+        let ReceiveNttTransferArgs {
+            source_chain,
+            sender,
+            amount,
+            additional_payload,
+        } = args;
+
+        ctx.accounts.receipt.set_inner(Receipt {
+            inbox_item: ctx.accounts.inbox_item.key(),
+            source_chain,
+            sender,
+            amount,
+            additional_payload,
+            balance: ctx.accounts.recipient_token_account.amount,
+        });
+
+        Ok(())
+    }
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct Receipt {
+    pub inbox_item: Pubkey,
+    pub source_chain: ChainId,
+    pub sender: [u8; 32],
+    pub amount: u64,
+    #[max_len(MAX_ADDITIONAL_PAYLOAD_LEN)]
+    pub additional_payload: Vec<u8>,
+    /// The balance of the recipient token account at the time of the call.
+    pub balance: u64,
+}
+
+impl Receipt {
+    pub const SEED_PREFIX: &'static [u8] = b"receipt";
+}
+
+#[derive(Accounts)]
+pub struct ReceiveNttTransfer<'info> {
+    #[account(
+        seeds = [example_native_token_transfers::RECIPIENT_CALL_AUTHORITY_SEED],
+        bump,
+        seeds::program = example_native_token_transfers::ID,
+    )]
+    pub call_authority: Signer<'info>,
+
+    #[account(
+        constraint = inbox_item.release_status == ReleaseStatus::Released,
+    )]
+    pub inbox_item: Account<'info, InboxItem>,
+
+    #[account(
+        mut,
+        token::mint = mint,
+        token::authority = Pubkey::find_program_address(&[example_native_token_transfers::RECIPIENT_SEED], &crate::ID).0,
+    )]
+    pub recipient_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + Receipt::INIT_SPACE,
+        seeds = [Receipt::SEED_PREFIX, inbox_item.key().as_ref()],
+        bump,
+    )]
+    pub receipt: Account<'info, Receipt>,
+
+    pub system_program: Program<'info, System>,
+}
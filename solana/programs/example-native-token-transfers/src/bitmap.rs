@@ -48,6 +48,12 @@ impl Bitmap {
             .expect("Bitmap length must not exceed the bounds of u8")
     }
 
+    /// The bits set in both `self` and `other`.
+    pub fn intersection(self, other: Bitmap) -> Bitmap {
+        let bm = BM::<128>::from_value(self.map) & BM::<128>::from_value(other.map);
+        Bitmap::from_value(*bm.as_value())
+    }
+
     pub fn len(self) -> u8 {
         BM::<128>::from_value(self.map)
             .len()
@@ -88,6 +94,15 @@ mod tests {
         assert_eq!(bm.count_enabled_votes(enabled), 1);
     }
 
+    #[test]
+    fn test_bitmap_intersection() {
+        let bm = Bitmap::from_value(0b1011);
+        let other = Bitmap::from_value(0b0110);
+        assert_eq!(bm.intersection(other), Bitmap::from_value(0b0010));
+        assert_eq!(bm.intersection(Bitmap::new()), Bitmap::new());
+        assert_eq!(bm.intersection(Bitmap::from_value(u128::MAX)), bm);
+    }
+
     #[test]
     fn test_bitmap_len() {
         let max_bitmap = Bitmap::from_value(u128::MAX);
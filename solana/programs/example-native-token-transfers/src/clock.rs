@@ -9,8 +9,12 @@ use anchor_lang::prelude::*;
 
 use anchor_lang::solana_program::clock::UnixTimestamp;
 
+// NOTE: thread local, so that unit tests (which run in parallel threads) don't
+// interfere with each other's clocks
 #[cfg(test)]
-static TEST_TIMESTAMP: std::sync::Mutex<i64> = std::sync::Mutex::new(0);
+thread_local! {
+    static TEST_TIMESTAMP: std::cell::Cell<i64> = const { std::cell::Cell::new(0) };
+}
 
 pub fn current_timestamp() -> UnixTimestamp {
     #[cfg(not(test))]
@@ -18,10 +22,10 @@ pub fn current_timestamp() -> UnixTimestamp {
         .unwrap()
         .unix_timestamp;
     #[cfg(test)]
-    return *TEST_TIMESTAMP.lock().unwrap();
+    return TEST_TIMESTAMP.get();
 }
 
 #[cfg(test)]
 pub fn set_test_timestamp(timestamp: UnixTimestamp) {
-    *TEST_TIMESTAMP.lock().unwrap() = timestamp;
+    TEST_TIMESTAMP.set(timestamp);
 }
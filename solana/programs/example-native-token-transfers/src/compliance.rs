@@ -0,0 +1,208 @@
+//! Compliance checks on transfers.
+//!
+//! When [`Config::denylist_enabled`] is set, transfers from or to an address
+//! on the denylist are blocked. The denylist is a set of [`DenylistEntry`]
+//! PDAs, one per address, managed by the holder of
+//! [`crate::config::Role::ComplianceAdmin`].
+//!
+//! Outbound transfers are checked against the sender (the owner of the token
+//! account the tokens are sent from) and the recipient, and fail if either is
+//! denied. Inbound transfers are checked against the sender on the source
+//! chain and the recipient when they are released. As the tokens have already
+//! left the source chain, a denied inbound transfer doesn't fail, but is
+//! quarantined instead (see [`crate::queue::inbox::ReleaseStatus::Quarantined`]),
+//! so it can be recovered later.
+//!
+//! Addresses are 32 bytes, so the denylist covers addresses on other chains
+//! too. Like the extra accounts of a transfer hook, the entries of the
+//! addresses to check are passed in the remaining accounts of the transfer
+//! and release instructions (in any order), whether the entries exist or not.
+//!
+//! On top of the denylist, [`Config::policy_program`] can point to a program
+//! that decides on each transfer. It's invoked with [`CheckTransferArgs`] and
+//! answers with a [`PolicyDecision`] in its return data. Like a transfer hook,
+//! the policy program lists the extra accounts it needs in an
+//! `ExtraAccountMetaList` PDA (see [`policy_extra_account_metas`]). The
+//! policy program, that PDA and the extra accounts it resolves to are passed
+//! in the remaining accounts too.
+
+use anchor_lang::{
+    prelude::*,
+    solana_program::{
+        instruction::Instruction,
+        program::{get_return_data, invoke},
+    },
+};
+use ntt_messages::chain_id::ChainId;
+use spl_discriminator::SplDiscriminate;
+use spl_tlv_account_resolution::state::ExtraAccountMetaList;
+
+use crate::{config::Config, error::NTTError};
+
+#[account]
+#[derive(InitSpace)]
+/// Marks an address as denied. Stored in a PDA seeded by the address.
+pub struct DenylistEntry {
+    pub bump: u8,
+    /// The account that paid the rent for this entry. The rent is returned to
+    /// this account when the entry is removed.
+    pub rent_payer: Pubkey,
+}
+
+impl DenylistEntry {
+    pub const SEED_PREFIX: &'static [u8] = b"denylist";
+
+    /// The address of the entry of `address`.
+    pub fn pda(address: &[u8; 32]) -> Pubkey {
+        Pubkey::find_program_address(&[Self::SEED_PREFIX, address.as_ref()], &crate::ID).0
+    }
+}
+
+/// The seed prefix of the `ExtraAccountMetaList` PDA of the policy program,
+/// the same as for transfer hooks.
+pub const POLICY_EXTRA_ACCOUNT_METAS_SEED_PREFIX: &[u8] = b"extra-account-metas";
+
+/// The address of the `ExtraAccountMetaList` PDA of `policy_program` for
+/// `mint`.
+pub fn policy_extra_account_metas(policy_program: &Pubkey, mint: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(
+        &[POLICY_EXTRA_ACCOUNT_METAS_SEED_PREFIX, mint.as_ref()],
+        policy_program,
+    )
+    .0
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum TransferDirection {
+    Outbound,
+    Inbound,
+}
+
+/// The instruction the policy program is invoked with. Its data is the
+/// discriminator of this type followed by the borsh encoding of the
+/// arguments, and its accounts are:
+///
+/// 0. `[]` the mint
+/// 1. `[]` the `ExtraAccountMetaList` PDA of the policy program
+/// 2. the extra accounts resolved from that list
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, Debug, SplDiscriminate)]
+#[discriminator_hash_input("ntt-policy-interface:check-transfer")]
+pub struct CheckTransferArgs {
+    pub direction: TransferDirection,
+    /// The destination chain of outbound transfers, the source chain of
+    /// inbound ones.
+    pub chain_id: ChainId,
+    pub sender: [u8; 32],
+    pub recipient: [u8; 32],
+    pub amount: u64,
+}
+
+impl CheckTransferArgs {
+    /// The offsets of the sender and the recipient in the instruction data,
+    /// for extra accounts seeded by them.
+    pub const SENDER_OFFSET: u8 = 8 + 1 + 2;
+    pub const RECIPIENT_OFFSET: u8 = Self::SENDER_OFFSET + 32;
+}
+
+/// The return data of the policy program.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum PolicyDecision {
+    Allow,
+    Deny,
+}
+
+/// Whether the sender or the recipient of `check` is on the denylist, or the
+/// policy program denies the transfer. The denylist is skipped when it's
+/// disabled, and the policy program when it's not set.
+///
+/// # Errors
+///
+/// Returns [`NTTError::MissingDenylistEntry`] or
+/// [`NTTError::MissingPolicyAccount`] if an account needed for the checks is
+/// not in `remaining_accounts`, and [`NTTError::InvalidPolicyDecision`] if
+/// the policy program doesn't return a [`PolicyDecision`].
+pub fn is_denied<'info>(
+    config: &Config,
+    mint: &AccountInfo<'info>,
+    check: CheckTransferArgs,
+    remaining_accounts: &[AccountInfo<'info>],
+) -> Result<bool> {
+    Ok(
+        is_denylisted(config, &[check.sender, check.recipient], remaining_accounts)?
+            || is_denied_by_policy(config, mint, check, remaining_accounts)?,
+    )
+}
+
+fn is_denylisted(
+    config: &Config,
+    addresses: &[[u8; 32]],
+    remaining_accounts: &[AccountInfo],
+) -> Result<bool> {
+    if !config.denylist_enabled {
+        return Ok(false);
+    }
+    for address in addresses {
+        let pda = DenylistEntry::pda(address);
+        let entry = remaining_accounts
+            .iter()
+            .find(|info| *info.key == pda)
+            .ok_or(NTTError::MissingDenylistEntry)?;
+        // the entry only exists if the address is denied. Removed entries
+        // are closed, so they are owned by the system program again
+        if *entry.owner == crate::ID && !entry.data_is_empty() {
+            return Ok(true);
+        }
+    }
+    Ok(false)
+}
+
+fn is_denied_by_policy<'info>(
+    config: &Config,
+    mint: &AccountInfo<'info>,
+    check: CheckTransferArgs,
+    remaining_accounts: &[AccountInfo<'info>],
+) -> Result<bool> {
+    let Some(policy_program) = config.policy_program else {
+        return Ok(false);
+    };
+    let find = |key: &Pubkey| {
+        remaining_accounts
+            .iter()
+            .find(|info| info.key == key)
+            .ok_or(NTTError::MissingPolicyAccount)
+    };
+    let program = find(&policy_program)?;
+    // only the policy program can create this PDA, so the list can be trusted
+    let extra_account_metas = find(&policy_extra_account_metas(&policy_program, mint.key))?;
+
+    let mut instruction = Instruction {
+        program_id: policy_program,
+        accounts: vec![
+            AccountMeta::new_readonly(*mint.key, false),
+            AccountMeta::new_readonly(*extra_account_metas.key, false),
+        ],
+        data: [
+            CheckTransferArgs::SPL_DISCRIMINATOR_SLICE,
+            &check.try_to_vec()?,
+        ]
+        .concat(),
+    };
+    let mut account_infos = vec![mint.clone(), extra_account_metas.clone()];
+    ExtraAccountMetaList::add_to_cpi_instruction::<CheckTransferArgs>(
+        &mut instruction,
+        &mut account_infos,
+        &extra_account_metas.try_borrow_data()?,
+        remaining_accounts,
+    )?;
+    account_infos.push(program.clone());
+    invoke(&instruction, &account_infos)?;
+
+    match get_return_data() {
+        Some((program_id, data)) if program_id == policy_program => {
+            let decision = PolicyDecision::try_from_slice(&data)
+                .map_err(|_| NTTError::InvalidPolicyDecision)?;
+            Ok(decision == PolicyDecision::Deny)
+        }
+        _ => Err(NTTError::InvalidPolicyDecision.into()),
+    }
+}
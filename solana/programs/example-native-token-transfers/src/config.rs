@@ -55,10 +55,111 @@ pub struct Config {
     pub paused: bool,
     /// The custody account that holds tokens in locking mode.
     pub custody: Pubkey,
+    /// When a transfer exceeds the rate limit, reserve the missing capacity
+    /// and queue the transfer only until it has refilled, instead of delaying
+    /// it by a full rate limit window.
+    /// See [`crate::queue::rate_limit::RateLimitState::consume_or_reserve_all`].
+    pub precise_queue_release: bool,
+    /// Reject outbound transfers whose amount has dust (i.e. it doesn't trim
+    /// cleanly to the recipient chain's decimals), instead of silently
+    /// transferring the amount with the dust removed.
+    pub strict_dust: bool,
+    /// Holder of [`Role::Pauser`].
+    pub pauser: Option<Pubkey>,
+    /// Pause outbound transfers (and releasing queued outbound transfers),
+    /// while inbound transfers keep working.
+    pub outbound_paused: bool,
+    /// Pause releasing inbound transfers, while they can still be redeemed
+    /// (and queued).
+    pub inbound_paused: bool,
+    /// Holder of [`Role::RateLimitAdmin`].
+    pub rate_limit_admin: Option<Pubkey>,
+    /// Holder of [`Role::PeerAdmin`].
+    pub peer_admin: Option<Pubkey>,
+    /// Holder of [`Role::TransceiverAdmin`].
+    pub transceiver_admin: Option<Pubkey>,
+    /// Delay (in seconds) between proposing a security-sensitive change and
+    /// executing it (see [`crate::pending_change::ConfigChange`]). Zero
+    /// disables the timelock, in which case these changes are made directly.
+    pub timelock_delay: i64,
+    /// Holder of [`Role::Guardian`].
+    pub guardian: Option<Pubkey>,
+    /// Holder of [`Role::ComplianceAdmin`].
+    pub compliance_admin: Option<Pubkey>,
+    /// Block transfers from and to the addresses on the denylist (see
+    /// [`crate::compliance`]).
+    pub denylist_enabled: bool,
+    /// A program that is asked whether to allow each transfer, in addition to
+    /// the denylist (see [`crate::compliance`]). `None` disables this check.
+    pub policy_program: Option<Pubkey>,
+    /// How long (in seconds) after it became releasable an inbound transfer
+    /// that is still not released can be moved to the escrow by anyone (see
+    /// [`crate::instructions::escrow_inbox_item`]). Zero disables this, so
+    /// only the owner can escrow transfers.
+    pub escrow_timeout: i64,
+    /// The layout version of this account (see [`Config::LAYOUT_VERSION`]).
+    /// Fields added in later releases go after this one, and existing
+    /// accounts are upgraded with [`crate::instructions::migrate`].
+    pub version: u8,
 }
 
 impl Config {
     pub const SEED_PREFIX: &'static [u8] = b"config";
+
+    /// The current layout version. Accounts created before accounts were
+    /// versioned have the layout of [`crate::legacy::Config`] (version 0).
+    pub const LAYOUT_VERSION: u8 = 1;
+
+    /// Whether `signer` is the owner, or the holder of `role`.
+    pub fn has_role(&self, signer: &Pubkey, role: Role) -> bool {
+        self.owner == *signer || self.role_holder(role) == Some(*signer)
+    }
+
+    pub fn role_holder(&self, role: Role) -> Option<Pubkey> {
+        match role {
+            Role::RateLimitAdmin => self.rate_limit_admin,
+            Role::PeerAdmin => self.peer_admin,
+            Role::TransceiverAdmin => self.transceiver_admin,
+            Role::Pauser => self.pauser,
+            Role::Guardian => self.guardian,
+            Role::ComplianceAdmin => self.compliance_admin,
+        }
+    }
+
+    pub fn role_holder_mut(&mut self, role: Role) -> &mut Option<Pubkey> {
+        match role {
+            Role::RateLimitAdmin => &mut self.rate_limit_admin,
+            Role::PeerAdmin => &mut self.peer_admin,
+            Role::TransceiverAdmin => &mut self.transceiver_admin,
+            Role::Pauser => &mut self.pauser,
+            Role::Guardian => &mut self.guardian,
+            Role::ComplianceAdmin => &mut self.compliance_admin,
+        }
+    }
+}
+
+/// Roles that the owner can delegate to other accounts (one holder each), so
+/// that routine operations don't need to go through the owner (which is
+/// typically a governance program or a multisig).
+/// The owner can always perform these operations too.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Role {
+    /// Can set the rate limits and their durations.
+    RateLimitAdmin,
+    /// Can set peers, and pause (or unpause) them.
+    PeerAdmin,
+    /// Can register and deregister transceivers, set the threshold, and set
+    /// the peers of the wormhole transceiver.
+    TransceiverAdmin,
+    /// Can pause the program (but not unpause it). Meant to be a hot key used
+    /// for incident response.
+    Pauser,
+    /// Can veto pending (timelocked) configuration changes, and freeze,
+    /// unfreeze or reject inbound transfers.
+    Guardian,
+    /// Can manage the denylist, and release quarantined inbound transfers
+    /// (see [`crate::compliance`]).
+    ComplianceAdmin,
 }
 
 #[derive(Accounts)]
@@ -1,5 +1,5 @@
 use anchor_lang::prelude::error_code;
-use ntt_messages::errors::ScalingError;
+use ntt_messages::errors::{DecodeError, EncodeError, ScalingError};
 
 #[error_code]
 // TODO(csongor): rename
@@ -65,6 +65,92 @@ pub enum NTTError {
     ThresholdTooHigh,
     #[msg("InvalidTransceiverProgram")]
     InvalidTransceiverProgram,
+    #[msg("OutboxItemCancelled")]
+    OutboxItemCancelled,
+    #[msg("OutboxItemAlreadyReleased")]
+    OutboxItemAlreadyReleased,
+    #[msg("OutboxItemNotReleased")]
+    OutboxItemNotReleased,
+    #[msg("InboxItemNotReleased")]
+    InboxItemNotReleased,
+    #[msg("AdditionalPayloadTooLong")]
+    AdditionalPayloadTooLong,
+    #[msg("InvalidRecipientProgram")]
+    InvalidRecipientProgram,
+    #[msg("MessageEncodingFailed")]
+    MessageEncodingFailed,
+    #[msg("MessageDecodingFailed")]
+    MessageDecodingFailed,
+    #[msg("InvalidRateLimitDuration")]
+    InvalidRateLimitDuration,
+    #[msg("AmountAfterFeeHasDust")]
+    AmountAfterFeeHasDust,
+    #[msg("InvalidTransferFee")]
+    InvalidTransferFee,
+    #[msg("TransferAmountHasDust")]
+    TransferAmountHasDust,
+    #[msg("InvalidPauser")]
+    InvalidPauser,
+    #[msg("OutboundPaused")]
+    OutboundPaused,
+    #[msg("InboundPaused")]
+    InboundPaused,
+    #[msg("PeerPaused")]
+    PeerPaused,
+    #[msg("Unauthorized")]
+    Unauthorized,
+    #[msg("TimelockRequired")]
+    TimelockRequired,
+    #[msg("TimelockNotExpired")]
+    TimelockNotExpired,
+    #[msg("InvalidTimelockDelay")]
+    InvalidTimelockDelay,
+    #[msg("InvalidConfigChange")]
+    InvalidConfigChange,
+    #[msg("AccountAlreadyMigrated")]
+    AccountAlreadyMigrated,
+    #[msg("PeerRetired")]
+    PeerRetired,
+    #[msg("PeerNotRetired")]
+    PeerNotRetired,
+    #[msg("InvalidGracePeriod")]
+    InvalidGracePeriod,
+    #[msg("GracePeriodNotOver")]
+    GracePeriodNotOver,
+    #[msg("TooManyLegacyAddresses")]
+    TooManyLegacyAddresses,
+    #[msg("ThresholdNotReached")]
+    ThresholdNotReached,
+    #[msg("InboxItemFrozen")]
+    InboxItemFrozen,
+    #[msg("InboxItemNotFrozen")]
+    InboxItemNotFrozen,
+    #[msg("InboxItemRejected")]
+    InboxItemRejected,
+    #[msg("TransferDenied")]
+    TransferDenied,
+    #[msg("MissingDenylistEntry")]
+    MissingDenylistEntry,
+    #[msg("InboxItemQuarantined")]
+    InboxItemQuarantined,
+    #[msg("InboxItemNotQuarantined")]
+    InboxItemNotQuarantined,
+    #[msg("InboxItemEscrowed")]
+    InboxItemEscrowed,
+    #[msg("InboxItemNotEscrowed")]
+    InboxItemNotEscrowed,
+    #[msg("CantEscrowYet")]
+    CantEscrowYet,
+    #[msg("InvalidEscrowTimeout")]
+    InvalidEscrowTimeout,
+    #[msg("RecipientCallRequired")]
+    RecipientCallRequired,
+    #[msg("MissingPolicyAccount")]
+    MissingPolicyAccount,
+    #[msg("InvalidPolicyDecision")]
+    InvalidPolicyDecision,
+    #[msg("OutboxItemNotQueued")]
+    OutboxItemNotQueued,
 }
 
 impl From<ScalingError> for NTTError {
@@ -75,3 +161,15 @@ impl From<ScalingError> for NTTError {
         }
     }
 }
+
+impl From<EncodeError> for NTTError {
+    fn from(_: EncodeError) -> Self {
+        NTTError::MessageEncodingFailed
+    }
+}
+
+impl From<DecodeError> for NTTError {
+    fn from(_: DecodeError) -> Self {
+        NTTError::MessageDecodingFailed
+    }
+}
@@ -0,0 +1,400 @@
+//! Events emitted by the program.
+//!
+//! Every state transition of the outbox, the inbox and the configuration emits
+//! one of the events below, so that the full lifecycle of a transfer (and of
+//! the deployment) can be reconstructed from transaction logs alone.
+
+use anchor_lang::prelude::*;
+use ntt_messages::{chain_id::ChainId, trimmed_amount::TrimmedAmount};
+
+use crate::{
+    bitmap::Bitmap, config::Role, peer::LegacyAddress, pending_change::ConfigChange,
+    queue::inbox::ReleaseStatus,
+};
+
+// * Outbound
+
+/// A transfer was burned or locked, and inserted into the outbox.
+#[event]
+pub struct TransferSent {
+    pub outbox_item: Pubkey,
+    pub sender: Pubkey,
+    pub amount: TrimmedAmount,
+    pub recipient_chain: ChainId,
+    pub recipient_ntt_manager: [u8; 32],
+    pub recipient_address: [u8; 32],
+    pub release_timestamp: i64,
+}
+
+/// An outbound transfer exceeded the rate limit and was queued until
+/// `release_timestamp`.
+#[event]
+pub struct OutboundTransferQueued {
+    pub outbox_item: Pubkey,
+    pub release_timestamp: i64,
+}
+
+/// An outbound transfer was released by a transceiver.
+#[event]
+pub struct OutboundTransferReleased {
+    pub outbox_item: Pubkey,
+    pub transceiver: Pubkey,
+    pub transceiver_index: u8,
+}
+
+/// An outbound transfer was cancelled by its sender, and the (untrimmed)
+/// `amount` returned to them.
+#[event]
+pub struct OutboundTransferCancelled {
+    pub outbox_item: Pubkey,
+    pub sender: Pubkey,
+    pub amount: u64,
+}
+
+// * Inbound
+
+/// A transceiver voted for an inbound transfer. `votes` is the number of
+/// enabled transceivers that have voted so far.
+#[event]
+pub struct InboundVoteRecorded {
+    pub inbox_item: Pubkey,
+    pub from_chain: ChainId,
+    pub message_id: [u8; 32],
+    pub transceiver: Pubkey,
+    pub transceiver_index: u8,
+    pub votes: u8,
+    pub threshold: u8,
+}
+
+/// An inbound transfer reached the threshold, and can be released after
+/// `release_timestamp`.
+#[event]
+pub struct InboundQuorumReached {
+    pub inbox_item: Pubkey,
+    pub release_timestamp: i64,
+}
+
+/// An inbound transfer exceeded the rate limit and was queued until
+/// `release_timestamp`.
+#[event]
+pub struct InboundTransferQueued {
+    pub inbox_item: Pubkey,
+    pub release_timestamp: i64,
+}
+
+/// An inbound transfer was minted or unlocked to the recipient.
+#[event]
+pub struct InboundTransferReleased {
+    pub inbox_item: Pubkey,
+    pub recipient: Pubkey,
+    pub amount: u64,
+}
+
+/// An inbound transfer was frozen by an admin.
+#[event]
+pub struct InboundTransferFrozen {
+    pub inbox_item: Pubkey,
+}
+
+/// A frozen inbound transfer was unfrozen, and is back in the status it was
+/// frozen in.
+#[event]
+pub struct InboundTransferUnfrozen {
+    pub inbox_item: Pubkey,
+    pub release_status: ReleaseStatus,
+}
+
+/// An inbound transfer was rejected by an admin, and can never be released.
+#[event]
+pub struct InboundTransferRejected {
+    pub inbox_item: Pubkey,
+}
+
+/// An inbound transfer was made releasable immediately by the owner,
+/// regardless of when the rate limit would have let it be released.
+#[event]
+pub struct InboundTransferForceReleased {
+    pub inbox_item: Pubkey,
+}
+
+/// An inbound transfer was quarantined on release, as its sender or recipient
+/// is on the denylist.
+#[event]
+pub struct InboundTransferQuarantined {
+    pub inbox_item: Pubkey,
+}
+
+/// A quarantined inbound transfer was made releasable again by the compliance
+/// admin.
+#[event]
+pub struct InboundTransferUnquarantined {
+    pub inbox_item: Pubkey,
+}
+
+/// The tokens of an inbound transfer that couldn't be delivered were moved to
+/// the escrow. `amount` is what the escrow received.
+#[event]
+pub struct InboundTransferEscrowed {
+    pub inbox_item: Pubkey,
+    pub amount: u64,
+}
+
+/// The escrowed tokens of an inbound transfer were sent to `recipient` (a
+/// token account) by the owner.
+#[event]
+pub struct EscrowedTransferRedirected {
+    pub inbox_item: Pubkey,
+    pub recipient: Pubkey,
+    pub amount: u64,
+}
+
+/// The escrowed tokens of an inbound transfer were sent back to the sender on
+/// the source chain, in `outbox_item`.
+#[event]
+pub struct EscrowedTransferReturned {
+    pub inbox_item: Pubkey,
+    pub outbox_item: Pubkey,
+}
+
+// * Admin
+
+#[event]
+pub struct PeerSet {
+    pub chain_id: ChainId,
+    pub address: [u8; 32],
+    pub token_decimals: u8,
+    pub inbound_limit: u64,
+}
+
+#[event]
+pub struct OutboundLimitSet {
+    pub limit: u64,
+}
+
+#[event]
+pub struct OutboundChainLimitSet {
+    pub chain_id: ChainId,
+    pub limit: u64,
+}
+
+#[event]
+pub struct InboundLimitSet {
+    pub chain_id: ChainId,
+    pub limit: u64,
+}
+
+#[event]
+pub struct OutboundRateLimitDurationSet {
+    pub duration: i64,
+}
+
+#[event]
+pub struct OutboundChainRateLimitDurationSet {
+    pub chain_id: ChainId,
+    pub duration: i64,
+}
+
+#[event]
+pub struct InboundRateLimitDurationSet {
+    pub chain_id: ChainId,
+    pub duration: i64,
+}
+
+#[event]
+pub struct ThresholdSet {
+    pub old_threshold: u8,
+    pub threshold: u8,
+}
+
+#[event]
+pub struct PausedSet {
+    pub paused: bool,
+}
+
+#[event]
+pub struct RoleGranted {
+    pub role: Role,
+    pub holder: Pubkey,
+}
+
+#[event]
+pub struct RoleRevoked {
+    pub role: Role,
+}
+
+#[event]
+pub struct OutboundPausedSet {
+    pub paused: bool,
+}
+
+#[event]
+pub struct InboundPausedSet {
+    pub paused: bool,
+}
+
+#[event]
+pub struct PeerPausedSet {
+    pub chain_id: ChainId,
+    pub paused: bool,
+}
+
+#[event]
+pub struct PeerThresholdSet {
+    pub chain_id: ChainId,
+    pub threshold: Option<u8>,
+    pub transceivers: Option<Bitmap>,
+}
+
+#[event]
+pub struct LegacyPeerAddressesSet {
+    pub chain_id: ChainId,
+    pub addresses: Vec<LegacyAddress>,
+}
+
+#[event]
+pub struct PeerRemoved {
+    pub chain_id: ChainId,
+    pub closable_at: i64,
+}
+
+#[event]
+pub struct PeerClosed {
+    pub chain_id: ChainId,
+}
+
+#[event]
+pub struct PreciseQueueReleaseSet {
+    pub enabled: bool,
+}
+
+#[event]
+pub struct StrictDustSet {
+    pub enabled: bool,
+}
+
+#[event]
+pub struct EscrowTimeoutSet {
+    pub timeout: i64,
+}
+
+#[event]
+pub struct TimelockDelaySet {
+    pub delay: i64,
+}
+
+#[event]
+pub struct ChangeProposed {
+    pub pending_change: Pubkey,
+    pub change: ConfigChange,
+    pub executable_at: i64,
+}
+
+#[event]
+pub struct ChangeVetoed {
+    pub pending_change: Pubkey,
+}
+
+#[event]
+pub struct ChangeExecuted {
+    pub pending_change: Pubkey,
+}
+
+#[event]
+pub struct TransceiverRegistered {
+    pub transceiver: Pubkey,
+    pub transceiver_index: u8,
+}
+
+/// A transceiver was deregistered. `threshold` is the threshold after
+/// deregistration (which is lowered if it exceeds the number of enabled
+/// transceivers).
+#[event]
+pub struct TransceiverDeregistered {
+    pub transceiver: Pubkey,
+    pub transceiver_index: u8,
+    pub threshold: u8,
+}
+
+#[event]
+pub struct OwnershipTransferInitiated {
+    pub owner: Pubkey,
+    pub pending_owner: Pubkey,
+}
+
+#[event]
+pub struct OwnershipTransferred {
+    pub previous_owner: Pubkey,
+    pub owner: Pubkey,
+}
+
+/// The program accepted the mint authority. `authority` is either the token
+/// authority PDA or the multisig it is a member of.
+#[event]
+pub struct TokenAuthorityAccepted {
+    pub mint: Pubkey,
+    pub authority: Pubkey,
+}
+
+#[event]
+pub struct TokenAuthorityTransferInitiated {
+    pub mint: Pubkey,
+    pub pending_authority: Pubkey,
+}
+
+#[event]
+pub struct TokenAuthorityTransferReverted {
+    pub mint: Pubkey,
+}
+
+#[event]
+pub struct TokenAuthorityTransferred {
+    pub mint: Pubkey,
+    pub new_authority: Pubkey,
+}
+
+// * Compliance
+
+#[event]
+pub struct DenylistEnabledSet {
+    pub enabled: bool,
+}
+
+#[event]
+pub struct PolicyProgramSet {
+    pub policy_program: Option<Pubkey>,
+}
+
+#[event]
+pub struct AddressDenylisted {
+    pub address: [u8; 32],
+}
+
+#[event]
+pub struct AddressRemovedFromDenylist {
+    pub address: [u8; 32],
+}
+
+// * Wormhole transceiver
+
+#[event]
+pub struct WormholePeerSet {
+    pub chain_id: ChainId,
+    pub address: [u8; 32],
+}
+
+/// A wormhole VAA was verified and stored as a transceiver message, ready to
+/// be redeemed.
+#[event]
+pub struct WormholeMessageReceived {
+    pub transceiver_message: Pubkey,
+    pub from_chain: ChainId,
+    pub message_id: [u8; 32],
+}
+
+/// An outbox item was posted to wormhole.
+#[event]
+pub struct WormholeMessageSent {
+    pub outbox_item: Pubkey,
+    pub wormhole_message: Pubkey,
+    pub recipient_chain: ChainId,
+}
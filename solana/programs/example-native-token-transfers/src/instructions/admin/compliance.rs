@@ -0,0 +1,160 @@
+//! Management of the denylist and the policy program (see
+//! [`crate::compliance`]), and of the inbound transfers they quarantined.
+//!
+//! The owner turns the denylist on and off, and sets the policy program. The entries are managed by the
+//! owner or the holder of [`Role::ComplianceAdmin`], who can also release
+//! quarantined transfers (e.g. once the recipient is cleared).
+
+use anchor_lang::prelude::*;
+
+use crate::{
+    clock::current_timestamp,
+    compliance::DenylistEntry,
+    config::{Config, Role},
+    error::NTTError,
+    events::{
+        AddressDenylisted, AddressRemovedFromDenylist, DenylistEnabledSet,
+        InboundTransferUnquarantined, PolicyProgramSet,
+    },
+    queue::inbox::{InboxItem, ReleaseStatus},
+};
+
+#[derive(Accounts)]
+pub struct SetDenylistEnabled<'info> {
+    pub owner: Signer<'info>,
+
+    #[account(
+        mut,
+        has_one = owner,
+    )]
+    pub config: Account<'info, Config>,
+}
+
+/// Enables (or disables) checking transfers against the denylist. While it's
+/// enabled, transfers and releases need the entries of the addresses they are
+/// checked against in their remaining accounts.
+pub fn set_denylist_enabled(ctx: Context<SetDenylistEnabled>, enabled: bool) -> Result<()> {
+    ctx.accounts.config.denylist_enabled = enabled;
+    emit!(DenylistEnabledSet { enabled });
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct SetPolicyProgram<'info> {
+    pub owner: Signer<'info>,
+
+    #[account(
+        mut,
+        has_one = owner,
+    )]
+    pub config: Account<'info, Config>,
+}
+
+/// Sets (or unsets, with `None`) the program that is asked whether to allow
+/// each transfer. While it's set, transfers and releases need the policy
+/// program, its `ExtraAccountMetaList` PDA and the extra accounts listed there
+/// in their remaining accounts.
+pub fn set_policy_program(
+    ctx: Context<SetPolicyProgram>,
+    policy_program: Option<Pubkey>,
+) -> Result<()> {
+    ctx.accounts.config.policy_program = policy_program;
+    emit!(PolicyProgramSet { policy_program });
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(address: [u8; 32])]
+pub struct AddToDenylist<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// The owner, or the holder of [`Role::ComplianceAdmin`].
+    pub authority: Signer<'info>,
+
+    #[account(
+        constraint = config.has_role(&authority.key(), Role::ComplianceAdmin) @ NTTError::Unauthorized,
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + DenylistEntry::INIT_SPACE,
+        seeds = [DenylistEntry::SEED_PREFIX, address.as_ref()],
+        bump,
+    )]
+    pub entry: Account<'info, DenylistEntry>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn add_to_denylist(ctx: Context<AddToDenylist>, address: [u8; 32]) -> Result<()> {
+    ctx.accounts.entry.set_inner(DenylistEntry {
+        bump: ctx.bumps.entry,
+        rent_payer: ctx.accounts.payer.key(),
+    });
+    emit!(AddressDenylisted { address });
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(address: [u8; 32])]
+pub struct RemoveFromDenylist<'info> {
+    /// The owner, or the holder of [`Role::ComplianceAdmin`].
+    pub authority: Signer<'info>,
+
+    #[account(
+        constraint = config.has_role(&authority.key(), Role::ComplianceAdmin) @ NTTError::Unauthorized,
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        mut,
+        seeds = [DenylistEntry::SEED_PREFIX, address.as_ref()],
+        bump = entry.bump,
+        has_one = rent_payer @ NTTError::IncorrectRentPayer,
+        close = rent_payer,
+    )]
+    pub entry: Account<'info, DenylistEntry>,
+
+    #[account(mut)]
+    /// CHECK: the `has_one` constraint on `entry` enforces that this is the correct address
+    pub rent_payer: UncheckedAccount<'info>,
+}
+
+pub fn remove_from_denylist(_ctx: Context<RemoveFromDenylist>, address: [u8; 32]) -> Result<()> {
+    emit!(AddressRemovedFromDenylist { address });
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct UnquarantineInboxItem<'info> {
+    /// The owner, or the holder of [`Role::ComplianceAdmin`].
+    pub authority: Signer<'info>,
+
+    #[account(
+        constraint = config.has_role(&authority.key(), Role::ComplianceAdmin) @ NTTError::Unauthorized,
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(mut)]
+    pub inbox_item: Account<'info, InboxItem>,
+}
+
+/// Makes a quarantined inbox item releasable immediately. It's checked
+/// against the denylist and the policy program again when it's released, so
+/// the sender and the recipient have to be removed from the denylist first (or
+/// the denylist disabled), and the policy program has to allow it.
+pub fn unquarantine_inbox_item(ctx: Context<UnquarantineInboxItem>) -> Result<()> {
+    let inbox_item = &mut ctx.accounts.inbox_item;
+    if inbox_item.release_status != ReleaseStatus::Quarantined {
+        return Err(NTTError::InboxItemNotQuarantined.into());
+    }
+    inbox_item.release_status = ReleaseStatus::ReleaseAfter(current_timestamp());
+
+    emit!(InboundTransferUnquarantined {
+        inbox_item: inbox_item.key(),
+    });
+    Ok(())
+}
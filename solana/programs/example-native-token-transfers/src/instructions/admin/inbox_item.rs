@@ -0,0 +1,139 @@
+//! Incident response for single inbound transfers, without pausing the whole
+//! program.
+//!
+//! The owner or the guardian can freeze a suspicious transfer (so it can't be
+//! released), unfreeze it, or reject it for good. The owner can also make an
+//! approved transfer releasable immediately, bypassing the rate limit delay.
+
+use anchor_lang::prelude::*;
+
+use crate::{
+    clock::current_timestamp,
+    config::{Config, Role},
+    error::NTTError,
+    events::{
+        InboundTransferForceReleased, InboundTransferFrozen, InboundTransferRejected,
+        InboundTransferUnfrozen,
+    },
+    queue::inbox::{InboxItem, ReleaseStatus},
+};
+
+#[derive(Accounts)]
+pub struct ManageInboxItem<'info> {
+    /// The owner, or the holder of [`Role::Guardian`].
+    pub authority: Signer<'info>,
+
+    #[account(
+        constraint = config.has_role(&authority.key(), Role::Guardian) @ NTTError::Unauthorized,
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(mut)]
+    pub inbox_item: Account<'info, InboxItem>,
+}
+
+/// Freezes an inbox item that hasn't been released yet. It can still be voted
+/// on, but not released until it's unfrozen.
+pub fn freeze_inbox_item(ctx: Context<ManageInboxItem>) -> Result<()> {
+    let inbox_item = &mut ctx.accounts.inbox_item;
+    inbox_item.release_status = match inbox_item.release_status {
+        ReleaseStatus::NotApproved => ReleaseStatus::Frozen(None),
+        ReleaseStatus::ReleaseAfter(release_timestamp) => {
+            ReleaseStatus::Frozen(Some(release_timestamp))
+        }
+        ReleaseStatus::Released => return Err(NTTError::TransferAlreadyRedeemed.into()),
+        ReleaseStatus::Frozen(_) => return Err(NTTError::InboxItemFrozen.into()),
+        ReleaseStatus::Rejected => return Err(NTTError::InboxItemRejected.into()),
+        ReleaseStatus::Quarantined => return Err(NTTError::InboxItemQuarantined.into()),
+        ReleaseStatus::Escrowed(_) => return Err(NTTError::InboxItemEscrowed.into()),
+    };
+
+    emit!(InboundTransferFrozen {
+        inbox_item: inbox_item.key(),
+    });
+    Ok(())
+}
+
+/// Puts a frozen inbox item back in the status it was frozen in.
+pub fn unfreeze_inbox_item(ctx: Context<ManageInboxItem>) -> Result<()> {
+    let inbox_item = &mut ctx.accounts.inbox_item;
+    inbox_item.release_status = match inbox_item.release_status {
+        ReleaseStatus::Frozen(Some(release_timestamp)) => {
+            ReleaseStatus::ReleaseAfter(release_timestamp)
+        }
+        ReleaseStatus::Frozen(None) => ReleaseStatus::NotApproved,
+        _ => return Err(NTTError::InboxItemNotFrozen.into()),
+    };
+
+    emit!(InboundTransferUnfrozen {
+        inbox_item: inbox_item.key(),
+        release_status: inbox_item.release_status.clone(),
+    });
+    Ok(())
+}
+
+/// Rejects an inbox item that hasn't been released yet, so that it can never
+/// be released. The rejected item can be closed (see
+/// [`crate::instructions::close_inbox_item`]), and as it's replaced with a
+/// tombstone, the transfer can't be redeemed again.
+///
+/// NOTE: the inbound capacity the transfer consumed (if it was approved) is
+/// not returned.
+pub fn reject_inbox_item(ctx: Context<ManageInboxItem>) -> Result<()> {
+    let inbox_item = &mut ctx.accounts.inbox_item;
+    match inbox_item.release_status {
+        ReleaseStatus::Released => return Err(NTTError::TransferAlreadyRedeemed.into()),
+        ReleaseStatus::Rejected => return Err(NTTError::InboxItemRejected.into()),
+        // the tokens have already left custody (or been minted)
+        ReleaseStatus::Escrowed(_) => return Err(NTTError::InboxItemEscrowed.into()),
+        ReleaseStatus::NotApproved
+        | ReleaseStatus::ReleaseAfter(_)
+        | ReleaseStatus::Frozen(_)
+        | ReleaseStatus::Quarantined => {}
+    }
+    inbox_item.release_status = ReleaseStatus::Rejected;
+
+    emit!(InboundTransferRejected {
+        inbox_item: inbox_item.key(),
+    });
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct ForceReleaseInboxItem<'info> {
+    pub owner: Signer<'info>,
+
+    #[account(
+        has_one = owner,
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(mut)]
+    pub inbox_item: Account<'info, InboxItem>,
+}
+
+/// Makes an approved inbox item releasable immediately, instead of after its
+/// release timestamp. The transfer still has to be released with
+/// [`crate::instructions::release_inbound_mint`] or
+/// [`crate::instructions::release_inbound_unlock`].
+///
+/// Only approved items can be force released, so this doesn't bypass the
+/// threshold. A frozen item can't be force released until it's unfrozen.
+pub fn force_release_inbox_item(ctx: Context<ForceReleaseInboxItem>) -> Result<()> {
+    let inbox_item = &mut ctx.accounts.inbox_item;
+    match inbox_item.release_status {
+        ReleaseStatus::ReleaseAfter(_) => {}
+        ReleaseStatus::NotApproved => return Err(NTTError::TransferNotApproved.into()),
+        ReleaseStatus::Released => return Err(NTTError::TransferAlreadyRedeemed.into()),
+        ReleaseStatus::Frozen(_) => return Err(NTTError::InboxItemFrozen.into()),
+        ReleaseStatus::Rejected => return Err(NTTError::InboxItemRejected.into()),
+        ReleaseStatus::Quarantined => return Err(NTTError::InboxItemQuarantined.into()),
+        ReleaseStatus::Escrowed(_) => return Err(NTTError::InboxItemEscrowed.into()),
+    }
+    inbox_item.release_status = ReleaseStatus::ReleaseAfter(current_timestamp());
+
+    emit!(InboundTransferForceReleased {
+        inbox_item: inbox_item.key(),
+    });
+    Ok(())
+}
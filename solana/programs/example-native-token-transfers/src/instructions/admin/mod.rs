@@ -2,19 +2,82 @@ use anchor_lang::prelude::*;
 use ntt_messages::chain_id::ChainId;
 
 use crate::{
-    config::Config,
+    bitmap::Bitmap,
+    clock::current_timestamp,
+    config::{Config, Role},
     error::NTTError,
-    peer::NttManagerPeer,
-    queue::{inbox::InboxRateLimit, outbox::OutboxRateLimit, rate_limit::RateLimitState},
+    events::{
+        EscrowTimeoutSet, InboundLimitSet, InboundPausedSet, InboundRateLimitDurationSet,
+        LegacyPeerAddressesSet, OutboundChainLimitSet, OutboundChainRateLimitDurationSet,
+        OutboundLimitSet, OutboundPausedSet, OutboundRateLimitDurationSet, PausedSet, PeerClosed,
+        PeerPausedSet, PeerRemoved, PeerSet, PeerThresholdSet, PreciseQueueReleaseSet, RoleGranted,
+        RoleRevoked, StrictDustSet, ThresholdSet, TimelockDelaySet, TransceiverDeregistered,
+        TransceiverRegistered,
+    },
+    peer::{LegacyAddress, NttManagerPeer},
+    queue::{
+        inbox::InboxRateLimit,
+        outbox::{OutboxChainRateLimit, OutboxRateLimit},
+        rate_limit::RateLimitState,
+    },
     registered_transceiver::RegisteredTransceiver,
 };
 
+pub mod compliance;
+pub mod inbox_item;
+pub mod timelock;
 pub mod transfer_ownership;
 pub mod transfer_token_authority;
 
+pub use compliance::*;
+pub use inbox_item::*;
+pub use timelock::*;
 pub use transfer_ownership::*;
 pub use transfer_token_authority::*;
 
+// * Roles
+
+#[derive(Accounts)]
+pub struct SetRole<'info> {
+    pub owner: Signer<'info>,
+
+    #[account(
+        mut,
+        has_one = owner,
+    )]
+    pub config: Account<'info, Config>,
+}
+
+/// Grant `role` to `holder`, replacing its current holder (if any).
+/// When the timelock is enabled, the guardian can only be changed through a
+/// [`crate::pending_change::ConfigChange::SetGuardian`] change.
+pub fn grant_role(ctx: Context<SetRole>, role: Role, holder: Pubkey) -> Result<()> {
+    require_role_not_timelocked(&ctx.accounts.config, role)?;
+    set_role_holder(&mut ctx.accounts.config, role, Some(holder));
+    Ok(())
+}
+
+pub fn revoke_role(ctx: Context<SetRole>, role: Role) -> Result<()> {
+    require_role_not_timelocked(&ctx.accounts.config, role)?;
+    set_role_holder(&mut ctx.accounts.config, role, None);
+    Ok(())
+}
+
+fn require_role_not_timelocked(config: &Config, role: Role) -> Result<()> {
+    if role == Role::Guardian && config.timelock_delay > 0 {
+        return Err(NTTError::TimelockRequired.into());
+    }
+    Ok(())
+}
+
+pub(crate) fn set_role_holder(config: &mut Config, role: Role, holder: Option<Pubkey>) {
+    *config.role_holder_mut(role) = holder;
+    match holder {
+        Some(holder) => emit!(RoleGranted { role, holder }),
+        None => emit!(RoleRevoked { role }),
+    }
+}
+
 // * Set peers
 
 #[derive(Accounts)]
@@ -23,10 +86,12 @@ pub struct SetPeer<'info> {
     #[account(mut)]
     pub payer: Signer<'info>,
 
+    /// The owner, or the holder of [`Role::PeerAdmin`].
     pub owner: Signer<'info>,
 
     #[account(
-        has_one = owner,
+        constraint = config.has_role(&owner.key(), Role::PeerAdmin) @ NTTError::Unauthorized,
+        constraint = config.timelock_delay == 0 @ NTTError::TimelockRequired,
         constraint = args.chain_id != config.chain_id @ NTTError::InvalidChainId
     )]
     pub config: Account<'info, Config>,
@@ -52,10 +117,22 @@ pub struct SetPeer<'info> {
     )]
     pub inbox_rate_limit: Account<'info, InboxRateLimit>,
 
+    #[account(
+        init_if_needed,
+        space = 8 + OutboxChainRateLimit::INIT_SPACE,
+        payer = payer,
+        seeds = [
+            OutboxChainRateLimit::SEED_PREFIX,
+            args.chain_id.id.to_be_bytes().as_ref()
+        ],
+        bump,
+    )]
+    pub outbox_chain_rate_limit: Account<'info, OutboxChainRateLimit>,
+
     pub system_program: Program<'info, System>,
 }
 
-#[derive(AnchorDeserialize, AnchorSerialize)]
+#[derive(AnchorDeserialize, AnchorSerialize, Clone, PartialEq, Eq, Debug, InitSpace)]
 pub struct SetPeerArgs {
     pub chain_id: ChainId,
     pub address: [u8; 32],
@@ -65,24 +142,75 @@ pub struct SetPeerArgs {
 }
 
 pub fn set_peer(ctx: Context<SetPeer>, args: SetPeerArgs) -> Result<()> {
-    ctx.accounts.peer.set_inner(NttManagerPeer {
-        bump: ctx.bumps.peer,
+    apply_set_peer(
+        &mut ctx.accounts.peer,
+        ctx.bumps.peer,
+        &mut ctx.accounts.inbox_rate_limit,
+        ctx.bumps.inbox_rate_limit,
+        &mut ctx.accounts.outbox_chain_rate_limit,
+        ctx.bumps.outbox_chain_rate_limit,
+        args,
+    )
+}
+
+/// Shared by [`set_peer`] and its timelocked counterpart.
+pub(crate) fn apply_set_peer(
+    peer: &mut Account<NttManagerPeer>,
+    peer_bump: u8,
+    inbox_rate_limit: &mut Account<InboxRateLimit>,
+    inbox_rate_limit_bump: u8,
+    outbox_chain_rate_limit: &mut Account<OutboxChainRateLimit>,
+    outbox_chain_rate_limit_bump: u8,
+    args: SetPeerArgs,
+) -> Result<()> {
+    // NOTE: setting a retired peer reinstates it
+    let paused = peer.paused;
+    let legacy_addresses = std::mem::take(&mut peer.legacy_addresses);
+    let inbound_threshold = peer.inbound_threshold;
+    let inbound_transceivers = peer.inbound_transceivers;
+    peer.set_inner(NttManagerPeer {
+        bump: peer_bump,
         address: args.address,
         token_decimals: args.token_decimals,
+        paused,
+        retired_at: None,
+        closable_at: 0,
+        rent_recipient: Pubkey::default(),
+        legacy_addresses,
+        inbound_threshold,
+        inbound_transceivers,
+        version: NttManagerPeer::LAYOUT_VERSION,
     });
 
     // if rate limit is uninitialized/unused, set new rate limit
-    if ctx.accounts.inbox_rate_limit.rate_limit.last_tx_timestamp == 0 {
-        ctx.accounts.inbox_rate_limit.set_inner(InboxRateLimit {
-            bump: ctx.bumps.inbox_rate_limit,
+    if inbox_rate_limit.rate_limit.last_tx_timestamp == 0 {
+        inbox_rate_limit.set_inner(InboxRateLimit {
+            bump: inbox_rate_limit_bump,
             rate_limit: RateLimitState::new(args.limit),
         });
     }
     // else update rate limit
     else {
-        ctx.accounts.inbox_rate_limit.set_limit(args.limit);
+        inbox_rate_limit.set_limit(args.limit);
+    }
+
+    // the outbound limit for the chain is set separately (see
+    // [`set_outbound_chain_limit`]), so only the global limit applies until
+    // then
+    if outbox_chain_rate_limit.rate_limit.last_tx_timestamp == 0 {
+        outbox_chain_rate_limit.set_inner(OutboxChainRateLimit {
+            bump: outbox_chain_rate_limit_bump,
+            rate_limit: RateLimitState::new(u64::MAX),
+        });
     }
 
+    emit!(PeerSet {
+        chain_id: args.chain_id,
+        address: args.address,
+        token_decimals: args.token_decimals,
+        inbound_limit: args.limit,
+    });
+
     Ok(())
 }
 
@@ -92,10 +220,12 @@ pub fn set_peer(ctx: Context<SetPeer>, args: SetPeerArgs) -> Result<()> {
 pub struct RegisterTransceiver<'info> {
     #[account(
         mut,
-        has_one = owner,
+        constraint = config.has_role(&owner.key(), Role::TransceiverAdmin) @ NTTError::Unauthorized,
+        constraint = config.timelock_delay == 0 @ NTTError::TimelockRequired,
     )]
     pub config: Account<'info, Config>,
 
+    /// The owner, or the holder of [`Role::TransceiverAdmin`].
     pub owner: Signer<'info>,
 
     #[account(mut)]
@@ -121,24 +251,47 @@ pub struct RegisterTransceiver<'info> {
     pub system_program: Program<'info, System>,
 }
 
+/// Registers a transceiver, or re-enables one that was deregistered (see
+/// [`deregister_transceiver`]). A re-enabled transceiver keeps its id, so it
+/// doesn't use up another slot of [`Config::enabled_transceivers`], and the
+/// votes it cast before it was deregistered count again.
 pub fn register_transceiver(ctx: Context<RegisterTransceiver>) -> Result<()> {
-    // initialize registered transceiver with new id on init
-    if ctx.accounts.registered_transceiver.transceiver_address == Pubkey::default() {
-        let id = ctx.accounts.config.next_transceiver_id;
-        ctx.accounts.config.next_transceiver_id += 1;
-        ctx.accounts
-            .registered_transceiver
-            .set_inner(RegisteredTransceiver {
-                bump: ctx.bumps.registered_transceiver,
-                id,
-                transceiver_address: ctx.accounts.transceiver.key(),
-            });
+    apply_register_transceiver(
+        &mut ctx.accounts.config,
+        &mut ctx.accounts.registered_transceiver,
+        ctx.bumps.registered_transceiver,
+        ctx.accounts.transceiver.key(),
+    )
+}
+
+/// Shared by [`register_transceiver`] and its timelocked counterpart.
+pub(crate) fn apply_register_transceiver(
+    config: &mut Config,
+    registered_transceiver: &mut Account<RegisteredTransceiver>,
+    registered_transceiver_bump: u8,
+    transceiver: Pubkey,
+) -> Result<()> {
+    // initialize registered transceiver with new id on init. Otherwise, the
+    // transceiver was registered before, and its id is reused
+    if registered_transceiver.transceiver_address == Pubkey::default() {
+        let id = config.next_transceiver_id;
+        config.next_transceiver_id += 1;
+        registered_transceiver.set_inner(RegisteredTransceiver {
+            bump: registered_transceiver_bump,
+            id,
+            transceiver_address: transceiver,
+            version: RegisteredTransceiver::LAYOUT_VERSION,
+        });
     }
 
-    ctx.accounts
-        .config
+    config
         .enabled_transceivers
-        .set(ctx.accounts.registered_transceiver.id, true)?;
+        .set(registered_transceiver.id, true)?;
+
+    emit!(TransceiverRegistered {
+        transceiver: registered_transceiver.transceiver_address,
+        transceiver_index: registered_transceiver.id,
+    });
     Ok(())
 }
 
@@ -146,10 +299,12 @@ pub fn register_transceiver(ctx: Context<RegisterTransceiver>) -> Result<()> {
 pub struct DeregisterTransceiver<'info> {
     #[account(
         mut,
-        has_one = owner,
+        constraint = config.has_role(&owner.key(), Role::TransceiverAdmin) @ NTTError::Unauthorized,
+        constraint = config.timelock_delay == 0 @ NTTError::TimelockRequired,
     )]
     pub config: Account<'info, Config>,
 
+    /// The owner, or the holder of [`Role::TransceiverAdmin`].
     pub owner: Signer<'info>,
 
     #[account(
@@ -160,37 +315,62 @@ pub struct DeregisterTransceiver<'info> {
     pub registered_transceiver: Account<'info, RegisteredTransceiver>,
 }
 
+/// Disables a transceiver. Its votes on inbox items stop counting towards the
+/// threshold, but they are kept, and the transceiver can be re-enabled with
+/// [`register_transceiver`].
+/// This lowers the threshold if there are fewer enabled transceivers left
+/// (and the per-peer thresholds, see
+/// [`NttManagerPeer::effective_inbound_threshold`]), so it's timelocked.
 pub fn deregister_transceiver(ctx: Context<DeregisterTransceiver>) -> Result<()> {
-    ctx.accounts
-        .config
+    apply_deregister_transceiver(
+        &mut ctx.accounts.config,
+        &ctx.accounts.registered_transceiver,
+    )
+}
+
+pub(crate) fn apply_deregister_transceiver(
+    config: &mut Config,
+    registered_transceiver: &RegisteredTransceiver,
+) -> Result<()> {
+    config
         .enabled_transceivers
-        .set(ctx.accounts.registered_transceiver.id, false)?;
+        .set(registered_transceiver.id, false)?;
 
     // decrement threshold if too high
-    let num_enabled_transceivers = ctx.accounts.config.enabled_transceivers.len();
-    if num_enabled_transceivers < ctx.accounts.config.threshold {
+    let num_enabled_transceivers = config.enabled_transceivers.len();
+    if num_enabled_transceivers < config.threshold {
         // threshold should be at least 1
-        ctx.accounts.config.threshold = num_enabled_transceivers.max(1);
+        config.threshold = num_enabled_transceivers.max(1);
     }
+
+    emit!(TransceiverDeregistered {
+        transceiver: registered_transceiver.transceiver_address,
+        transceiver_index: registered_transceiver.id,
+        threshold: config.threshold,
+    });
     Ok(())
 }
 
 // * Limit rate adjustment
+//
+// When the timelock is enabled, raising a limit (or shortening its window,
+// which makes it refill faster) has to be proposed. Lowering it is instant.
 
 #[derive(Accounts)]
 pub struct SetOutboundLimit<'info> {
     #[account(
-        has_one = owner,
+        constraint = config.has_role(&owner.key(), Role::RateLimitAdmin) @ NTTError::Unauthorized,
     )]
     pub config: Account<'info, Config>,
 
+    /// The owner, or the holder of [`Role::RateLimitAdmin`].
     pub owner: Signer<'info>,
 
     #[account(mut)]
     pub rate_limit: Account<'info, OutboxRateLimit>,
 }
 
-#[derive(AnchorDeserialize, AnchorSerialize)]
+#[derive(AnchorDeserialize, AnchorSerialize, Clone, PartialEq, Eq, Debug, InitSpace)]
 pub struct SetOutboundLimitArgs {
     pub limit: u64,
 }
@@ -199,7 +379,70 @@ pub fn set_outbound_limit(
     ctx: Context<SetOutboundLimit>,
     args: SetOutboundLimitArgs,
 ) -> Result<()> {
-    ctx.accounts.rate_limit.set_limit(args.limit);
+    require!(
+        ctx.accounts.config.timelock_delay == 0 || args.limit <= ctx.accounts.rate_limit.limit,
+        NTTError::TimelockRequired
+    );
+    apply_set_outbound_limit(&mut ctx.accounts.rate_limit, args)
+}
+
+pub(crate) fn apply_set_outbound_limit(
+    rate_limit: &mut OutboxRateLimit,
+    args: SetOutboundLimitArgs,
+) -> Result<()> {
+    rate_limit.set_limit(args.limit);
+    emit!(OutboundLimitSet { limit: args.limit });
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(args: SetOutboundChainLimitArgs)]
+pub struct SetOutboundChainLimit<'info> {
+    #[account(
+        constraint = config.has_role(&owner.key(), Role::RateLimitAdmin) @ NTTError::Unauthorized,
+    )]
+    pub config: Account<'info, Config>,
+
+    /// The owner, or the holder of [`Role::RateLimitAdmin`].
+    pub owner: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            OutboxChainRateLimit::SEED_PREFIX,
+            args.chain_id.id.to_be_bytes().as_ref()
+        ],
+        bump = rate_limit.bump
+    )]
+    pub rate_limit: Account<'info, OutboxChainRateLimit>,
+}
+
+#[derive(AnchorDeserialize, AnchorSerialize, Clone, PartialEq, Eq, Debug, InitSpace)]
+pub struct SetOutboundChainLimitArgs {
+    pub limit: u64,
+    pub chain_id: ChainId,
+}
+
+pub fn set_outbound_chain_limit(
+    ctx: Context<SetOutboundChainLimit>,
+    args: SetOutboundChainLimitArgs,
+) -> Result<()> {
+    require!(
+        ctx.accounts.config.timelock_delay == 0 || args.limit <= ctx.accounts.rate_limit.limit,
+        NTTError::TimelockRequired
+    );
+    apply_set_outbound_chain_limit(&mut ctx.accounts.rate_limit, args)
+}
+
+pub(crate) fn apply_set_outbound_chain_limit(
+    rate_limit: &mut OutboxChainRateLimit,
+    args: SetOutboundChainLimitArgs,
+) -> Result<()> {
+    rate_limit.set_limit(args.limit);
+    emit!(OutboundChainLimitSet {
+        chain_id: args.chain_id,
+        limit: args.limit,
+    });
     Ok(())
 }
 
@@ -207,10 +450,11 @@ pub fn set_outbound_limit(
 #[instruction(args: SetInboundLimitArgs)]
 pub struct SetInboundLimit<'info> {
     #[account(
-        has_one = owner,
+        constraint = config.has_role(&owner.key(), Role::RateLimitAdmin) @ NTTError::Unauthorized,
     )]
     pub config: Account<'info, Config>,
 
+    /// The owner, or the holder of [`Role::RateLimitAdmin`].
     pub owner: Signer<'info>,
 
     #[account(
@@ -224,14 +468,166 @@ pub struct SetInboundLimit<'info> {
     pub rate_limit: Account<'info, InboxRateLimit>,
 }
 
-#[derive(AnchorDeserialize, AnchorSerialize)]
+#[derive(AnchorDeserialize, AnchorSerialize, Clone, PartialEq, Eq, Debug, InitSpace)]
 pub struct SetInboundLimitArgs {
     pub limit: u64,
     pub chain_id: ChainId,
 }
 
 pub fn set_inbound_limit(ctx: Context<SetInboundLimit>, args: SetInboundLimitArgs) -> Result<()> {
-    ctx.accounts.rate_limit.set_limit(args.limit);
+    require!(
+        ctx.accounts.config.timelock_delay == 0 || args.limit <= ctx.accounts.rate_limit.limit,
+        NTTError::TimelockRequired
+    );
+    apply_set_inbound_limit(&mut ctx.accounts.rate_limit, args)
+}
+
+pub(crate) fn apply_set_inbound_limit(
+    rate_limit: &mut InboxRateLimit,
+    args: SetInboundLimitArgs,
+) -> Result<()> {
+    rate_limit.set_limit(args.limit);
+    emit!(InboundLimitSet {
+        chain_id: args.chain_id,
+        limit: args.limit,
+    });
+    Ok(())
+}
+
+// * Rate limit window adjustment
+
+#[derive(AnchorDeserialize, AnchorSerialize, Clone, PartialEq, Eq, Debug, InitSpace)]
+pub struct SetOutboundRateLimitDurationArgs {
+    pub duration: i64,
+}
+
+pub fn set_outbound_rate_limit_duration(
+    ctx: Context<SetOutboundLimit>,
+    args: SetOutboundRateLimitDurationArgs,
+) -> Result<()> {
+    require!(
+        ctx.accounts.config.timelock_delay == 0
+            || args.duration >= ctx.accounts.rate_limit.rate_limit_duration,
+        NTTError::TimelockRequired
+    );
+    apply_set_outbound_rate_limit_duration(&mut ctx.accounts.rate_limit, args)
+}
+
+pub(crate) fn apply_set_outbound_rate_limit_duration(
+    rate_limit: &mut OutboxRateLimit,
+    args: SetOutboundRateLimitDurationArgs,
+) -> Result<()> {
+    require!(args.duration > 0, NTTError::InvalidRateLimitDuration);
+    rate_limit.set_rate_limit_duration(args.duration);
+    emit!(OutboundRateLimitDurationSet {
+        duration: args.duration
+    });
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(args: SetOutboundChainRateLimitDurationArgs)]
+pub struct SetOutboundChainRateLimitDuration<'info> {
+    #[account(
+        constraint = config.has_role(&owner.key(), Role::RateLimitAdmin) @ NTTError::Unauthorized,
+    )]
+    pub config: Account<'info, Config>,
+
+    /// The owner, or the holder of [`Role::RateLimitAdmin`].
+    pub owner: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            OutboxChainRateLimit::SEED_PREFIX,
+            args.chain_id.id.to_be_bytes().as_ref()
+        ],
+        bump = rate_limit.bump
+    )]
+    pub rate_limit: Account<'info, OutboxChainRateLimit>,
+}
+
+#[derive(AnchorDeserialize, AnchorSerialize, Clone, PartialEq, Eq, Debug, InitSpace)]
+pub struct SetOutboundChainRateLimitDurationArgs {
+    pub duration: i64,
+    pub chain_id: ChainId,
+}
+
+pub fn set_outbound_chain_rate_limit_duration(
+    ctx: Context<SetOutboundChainRateLimitDuration>,
+    args: SetOutboundChainRateLimitDurationArgs,
+) -> Result<()> {
+    require!(
+        ctx.accounts.config.timelock_delay == 0
+            || args.duration >= ctx.accounts.rate_limit.rate_limit_duration,
+        NTTError::TimelockRequired
+    );
+    apply_set_outbound_chain_rate_limit_duration(&mut ctx.accounts.rate_limit, args)
+}
+
+pub(crate) fn apply_set_outbound_chain_rate_limit_duration(
+    rate_limit: &mut OutboxChainRateLimit,
+    args: SetOutboundChainRateLimitDurationArgs,
+) -> Result<()> {
+    require!(args.duration > 0, NTTError::InvalidRateLimitDuration);
+    rate_limit.set_rate_limit_duration(args.duration);
+    emit!(OutboundChainRateLimitDurationSet {
+        chain_id: args.chain_id,
+        duration: args.duration,
+    });
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(args: SetInboundRateLimitDurationArgs)]
+pub struct SetInboundRateLimitDuration<'info> {
+    #[account(
+        constraint = config.has_role(&owner.key(), Role::RateLimitAdmin) @ NTTError::Unauthorized,
+    )]
+    pub config: Account<'info, Config>,
+
+    /// The owner, or the holder of [`Role::RateLimitAdmin`].
+    pub owner: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            InboxRateLimit::SEED_PREFIX,
+            args.chain_id.id.to_be_bytes().as_ref()
+        ],
+        bump = rate_limit.bump
+    )]
+    pub rate_limit: Account<'info, InboxRateLimit>,
+}
+
+#[derive(AnchorDeserialize, AnchorSerialize, Clone, PartialEq, Eq, Debug, InitSpace)]
+pub struct SetInboundRateLimitDurationArgs {
+    pub duration: i64,
+    pub chain_id: ChainId,
+}
+
+pub fn set_inbound_rate_limit_duration(
+    ctx: Context<SetInboundRateLimitDuration>,
+    args: SetInboundRateLimitDurationArgs,
+) -> Result<()> {
+    require!(
+        ctx.accounts.config.timelock_delay == 0
+            || args.duration >= ctx.accounts.rate_limit.rate_limit_duration,
+        NTTError::TimelockRequired
+    );
+    apply_set_inbound_rate_limit_duration(&mut ctx.accounts.rate_limit, args)
+}
+
+pub(crate) fn apply_set_inbound_rate_limit_duration(
+    rate_limit: &mut InboxRateLimit,
+    args: SetInboundRateLimitDurationArgs,
+) -> Result<()> {
+    require!(args.duration > 0, NTTError::InvalidRateLimitDuration);
+    rate_limit.set_rate_limit_duration(args.duration);
+    emit!(InboundRateLimitDurationSet {
+        chain_id: args.chain_id,
+        duration: args.duration,
+    });
     Ok(())
 }
 
@@ -250,6 +646,398 @@ pub struct SetPaused<'info> {
 
 pub fn set_paused(ctx: Context<SetPaused>, paused: bool) -> Result<()> {
     ctx.accounts.config.paused = paused;
+    emit!(PausedSet { paused });
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct Pause<'info> {
+    pub pauser: Signer<'info>,
+
+    #[account(
+        mut,
+        constraint = config.has_role(&pauser.key(), Role::Pauser) @ NTTError::InvalidPauser,
+    )]
+    pub config: Account<'info, Config>,
+}
+
+/// Pause the program. Unlike [`set_paused`], this can be called by the holder
+/// of [`Role::Pauser`] too, but only the owner can unpause the program.
+pub fn pause(ctx: Context<Pause>) -> Result<()> {
+    ctx.accounts.config.paused = true;
+    emit!(PausedSet { paused: true });
+    Ok(())
+}
+
+pub fn set_outbound_paused(ctx: Context<SetPaused>, paused: bool) -> Result<()> {
+    ctx.accounts.config.outbound_paused = paused;
+    emit!(OutboundPausedSet { paused });
+    Ok(())
+}
+
+/// Pause outbound transfers. Like [`pause`], this can be called by the holder
+/// of [`Role::Pauser`] too, but only the owner can unpause them.
+pub fn pause_outbound(ctx: Context<Pause>) -> Result<()> {
+    ctx.accounts.config.outbound_paused = true;
+    emit!(OutboundPausedSet { paused: true });
+    Ok(())
+}
+
+pub fn set_inbound_paused(ctx: Context<SetPaused>, paused: bool) -> Result<()> {
+    ctx.accounts.config.inbound_paused = paused;
+    emit!(InboundPausedSet { paused });
+    Ok(())
+}
+
+/// Pause releasing inbound transfers. Like [`pause`], this can be called by
+/// the holder of [`Role::Pauser`] too, but only the owner can unpause them.
+pub fn pause_inbound(ctx: Context<Pause>) -> Result<()> {
+    ctx.accounts.config.inbound_paused = true;
+    emit!(InboundPausedSet { paused: true });
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(args: SetPeerPausedArgs)]
+pub struct SetPeerPaused<'info> {
+    /// The owner, or the holder of [`Role::PeerAdmin`].
+    pub owner: Signer<'info>,
+
+    #[account(
+        constraint = config.has_role(&owner.key(), Role::PeerAdmin) @ NTTError::Unauthorized,
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        mut,
+        seeds = [NttManagerPeer::SEED_PREFIX, args.chain_id.id.to_be_bytes().as_ref()],
+        bump = peer.bump,
+    )]
+    pub peer: Account<'info, NttManagerPeer>,
+}
+
+#[derive(AnchorDeserialize, AnchorSerialize)]
+pub struct SetPeerPausedArgs {
+    pub chain_id: ChainId,
+    pub paused: bool,
+}
+
+pub fn set_peer_paused(ctx: Context<SetPeerPaused>, args: SetPeerPausedArgs) -> Result<()> {
+    ctx.accounts.peer.paused = args.paused;
+    emit!(PeerPausedSet {
+        chain_id: args.chain_id,
+        paused: args.paused,
+    });
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(args: PausePeerArgs)]
+pub struct PausePeer<'info> {
+    pub pauser: Signer<'info>,
+
+    #[account(
+        constraint = config.has_role(&pauser.key(), Role::Pauser) @ NTTError::InvalidPauser,
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        mut,
+        seeds = [NttManagerPeer::SEED_PREFIX, args.chain_id.id.to_be_bytes().as_ref()],
+        bump = peer.bump,
+    )]
+    pub peer: Account<'info, NttManagerPeer>,
+}
+
+#[derive(AnchorDeserialize, AnchorSerialize)]
+pub struct PausePeerArgs {
+    pub chain_id: ChainId,
+}
+
+/// Pause transfers to and from a peer. Unlike [`set_peer_paused`], this can
+/// be called by the holder of [`Role::Pauser`] too, but only the owner or the
+/// holder of [`Role::PeerAdmin`] can unpause the peer.
+pub fn pause_peer(ctx: Context<PausePeer>, args: PausePeerArgs) -> Result<()> {
+    ctx.accounts.peer.paused = true;
+    emit!(PeerPausedSet {
+        chain_id: args.chain_id,
+        paused: true,
+    });
+    Ok(())
+}
+
+// * Legacy peer addresses
+
+#[derive(Accounts)]
+#[instruction(args: SetLegacyPeerAddressesArgs)]
+pub struct SetLegacyPeerAddresses<'info> {
+    /// The owner, or the holder of [`Role::PeerAdmin`].
+    pub owner: Signer<'info>,
+
+    #[account(
+        constraint = config.has_role(&owner.key(), Role::PeerAdmin) @ NTTError::Unauthorized,
+        constraint = config.timelock_delay == 0 @ NTTError::TimelockRequired,
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        mut,
+        seeds = [NttManagerPeer::SEED_PREFIX, args.chain_id.id.to_be_bytes().as_ref()],
+        bump = peer.bump,
+    )]
+    pub peer: Account<'info, NttManagerPeer>,
+}
+
+#[derive(AnchorDeserialize, AnchorSerialize, Clone, PartialEq, Eq, Debug, InitSpace)]
+pub struct SetLegacyPeerAddressesArgs {
+    pub chain_id: ChainId,
+    #[max_len(4)]
+    pub addresses: Vec<LegacyAddress>,
+}
+
+/// Replaces the legacy addresses of a peer, i.e. the addresses (other than
+/// its current one) that inbound transfers are accepted from until they
+/// expire.
+pub fn set_legacy_peer_addresses(
+    ctx: Context<SetLegacyPeerAddresses>,
+    args: SetLegacyPeerAddressesArgs,
+) -> Result<()> {
+    apply_set_legacy_peer_addresses(&mut ctx.accounts.peer, args)
+}
+
+/// Shared by [`set_legacy_peer_addresses`] and its timelocked counterpart.
+pub(crate) fn apply_set_legacy_peer_addresses(
+    peer: &mut NttManagerPeer,
+    args: SetLegacyPeerAddressesArgs,
+) -> Result<()> {
+    if args.addresses.len() > NttManagerPeer::MAX_LEGACY_ADDRESSES {
+        return Err(NTTError::TooManyLegacyAddresses.into());
+    }
+    peer.legacy_addresses = args.addresses.clone();
+    emit!(LegacyPeerAddressesSet {
+        chain_id: args.chain_id,
+        addresses: args.addresses,
+    });
+    Ok(())
+}
+
+// * Remove peers
+
+#[derive(Accounts)]
+#[instruction(args: RemovePeerArgs)]
+pub struct RemovePeer<'info> {
+    /// The owner, or the holder of [`Role::PeerAdmin`].
+    pub owner: Signer<'info>,
+
+    #[account(
+        constraint = config.has_role(&owner.key(), Role::PeerAdmin) @ NTTError::Unauthorized,
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        mut,
+        seeds = [NttManagerPeer::SEED_PREFIX, args.chain_id.id.to_be_bytes().as_ref()],
+        bump = peer.bump,
+        constraint = peer.retired_at.is_none() @ NTTError::PeerRetired,
+    )]
+    pub peer: Account<'info, NttManagerPeer>,
+}
+
+#[derive(AnchorDeserialize, AnchorSerialize)]
+pub struct RemovePeerArgs {
+    pub chain_id: ChainId,
+    /// How long (in seconds) inbound transfers from the peer can still be
+    /// redeemed for, before the peer can be closed.
+    pub grace_period: i64,
+    /// Receives the rent of the peer and its rate limits when they are closed.
+    pub rent_recipient: Pubkey,
+}
+
+/// Retires a peer. Outbound transfers to it are rejected immediately, and
+/// inbound transfers from it can only be redeemed until the end of the grace
+/// period. Once it's over, the peer and its rate limits can be closed with
+/// [`close_peer`].
+pub fn remove_peer(ctx: Context<RemovePeer>, args: RemovePeerArgs) -> Result<()> {
+    if args.grace_period < 0 {
+        return Err(NTTError::InvalidGracePeriod.into());
+    }
+    let now = current_timestamp();
+    let closable_at = now
+        .checked_add(args.grace_period)
+        .ok_or(NTTError::InvalidGracePeriod)?;
+
+    let peer = &mut ctx.accounts.peer;
+    peer.retired_at = Some(now);
+    peer.closable_at = closable_at;
+    peer.rent_recipient = args.rent_recipient;
+
+    emit!(PeerRemoved {
+        chain_id: args.chain_id,
+        closable_at,
+    });
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(args: ClosePeerArgs)]
+pub struct ClosePeer<'info> {
+    /// The owner, or the holder of [`Role::PeerAdmin`].
+    pub owner: Signer<'info>,
+
+    #[account(
+        constraint = config.has_role(&owner.key(), Role::PeerAdmin) @ NTTError::Unauthorized,
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        mut,
+        seeds = [NttManagerPeer::SEED_PREFIX, args.chain_id.id.to_be_bytes().as_ref()],
+        bump = peer.bump,
+        constraint = peer.retired_at.is_some() @ NTTError::PeerNotRetired,
+        constraint = peer.closable_at <= current_timestamp() @ NTTError::GracePeriodNotOver,
+        has_one = rent_recipient @ NTTError::IncorrectRentPayer,
+        close = rent_recipient,
+    )]
+    pub peer: Account<'info, NttManagerPeer>,
+
+    #[account(
+        mut,
+        seeds = [InboxRateLimit::SEED_PREFIX, args.chain_id.id.to_be_bytes().as_ref()],
+        bump = inbox_rate_limit.bump,
+        close = rent_recipient,
+    )]
+    pub inbox_rate_limit: Account<'info, InboxRateLimit>,
+
+    #[account(
+        mut,
+        seeds = [OutboxChainRateLimit::SEED_PREFIX, args.chain_id.id.to_be_bytes().as_ref()],
+        bump = outbox_chain_rate_limit.bump,
+        close = rent_recipient,
+    )]
+    pub outbox_chain_rate_limit: Account<'info, OutboxChainRateLimit>,
+
+    #[account(mut)]
+    /// CHECK: the `peer` constraint enforces that this is the correct address
+    pub rent_recipient: UncheckedAccount<'info>,
+}
+
+#[derive(AnchorDeserialize, AnchorSerialize)]
+pub struct ClosePeerArgs {
+    pub chain_id: ChainId,
+}
+
+/// Closes a retired peer and its rate limits once its grace period is over.
+///
+/// Queued outbound transfers to the peer (which need its outbound rate limit
+/// to be cancelled) and approved inbound transfers from it (which need the
+/// peer to be released) can't be processed once it's closed, so they should be
+/// drained first. This is why closing is restricted to the peer admins.
+pub fn close_peer(_ctx: Context<ClosePeer>, args: ClosePeerArgs) -> Result<()> {
+    emit!(PeerClosed {
+        chain_id: args.chain_id,
+    });
+    Ok(())
+}
+
+// * Precise queue release
+
+#[derive(Accounts)]
+pub struct SetPreciseQueueRelease<'info> {
+    pub owner: Signer<'info>,
+
+    #[account(
+        mut,
+        has_one = owner,
+    )]
+    pub config: Account<'info, Config>,
+}
+
+/// Enables (or disables) reserving the missing capacity for transfers that
+/// exceed the rate limits, so they are queued only until it has refilled.
+/// Capacity that was already reserved stays reserved when this is disabled.
+pub fn set_precise_queue_release(
+    ctx: Context<SetPreciseQueueRelease>,
+    enabled: bool,
+) -> Result<()> {
+    ctx.accounts.config.precise_queue_release = enabled;
+    emit!(PreciseQueueReleaseSet { enabled });
+    Ok(())
+}
+
+// * Strict dust
+
+#[derive(Accounts)]
+pub struct SetStrictDust<'info> {
+    pub owner: Signer<'info>,
+
+    #[account(
+        mut,
+        has_one = owner,
+    )]
+    pub config: Account<'info, Config>,
+}
+
+/// Enables (or disables) rejecting outbound transfers whose amount has dust.
+pub fn set_strict_dust(ctx: Context<SetStrictDust>, enabled: bool) -> Result<()> {
+    ctx.accounts.config.strict_dust = enabled;
+    emit!(StrictDustSet { enabled });
+    Ok(())
+}
+
+// * Escrow timeout
+
+#[derive(Accounts)]
+pub struct SetEscrowTimeout<'info> {
+    pub owner: Signer<'info>,
+
+    #[account(
+        mut,
+        has_one = owner,
+    )]
+    pub config: Account<'info, Config>,
+}
+
+/// Sets how long after they became releasable inbound transfers can be moved
+/// to the escrow by anyone (see [`crate::instructions::escrow_inbox_item`]).
+/// Zero disables it.
+pub fn set_escrow_timeout(ctx: Context<SetEscrowTimeout>, timeout: i64) -> Result<()> {
+    if timeout < 0 {
+        return Err(NTTError::InvalidEscrowTimeout.into());
+    }
+    ctx.accounts.config.escrow_timeout = timeout;
+    emit!(EscrowTimeoutSet { timeout });
+    Ok(())
+}
+
+// * Timelock delay
+
+#[derive(Accounts)]
+pub struct SetTimelockDelay<'info> {
+    pub owner: Signer<'info>,
+
+    #[account(
+        mut,
+        has_one = owner,
+    )]
+    pub config: Account<'info, Config>,
+}
+
+/// Enables the timelock, or raises its delay. Lowering the delay (or disabling
+/// the timelock) has to go through a [`crate::pending_change::ConfigChange::SetTimelockDelay`]
+/// change, so it can't be used to bypass the timelock.
+pub fn set_timelock_delay(ctx: Context<SetTimelockDelay>, delay: i64) -> Result<()> {
+    if delay < ctx.accounts.config.timelock_delay {
+        return Err(NTTError::TimelockRequired.into());
+    }
+    apply_set_timelock_delay(&mut ctx.accounts.config, delay)
+}
+
+pub(crate) fn apply_set_timelock_delay(config: &mut Config, delay: i64) -> Result<()> {
+    if delay < 0 {
+        return Err(NTTError::InvalidTimelockDelay.into());
+    }
+    config.timelock_delay = delay;
+    emit!(TimelockDelaySet { delay });
     Ok(())
 }
 
@@ -258,20 +1046,102 @@ pub fn set_paused(ctx: Context<SetPaused>, paused: bool) -> Result<()> {
 #[derive(Accounts)]
 #[instruction(threshold: u8)]
 pub struct SetThreshold<'info> {
+    /// The owner, or the holder of [`Role::TransceiverAdmin`].
     pub owner: Signer<'info>,
 
     #[account(
         mut,
-        has_one = owner,
+        constraint = config.has_role(&owner.key(), Role::TransceiverAdmin) @ NTTError::Unauthorized,
+        constraint = config.timelock_delay == 0 @ NTTError::TimelockRequired,
         constraint = threshold <= config.enabled_transceivers.len() @ NTTError::ThresholdTooHigh
     )]
     pub config: Account<'info, Config>,
 }
 
 pub fn set_threshold(ctx: Context<SetThreshold>, threshold: u8) -> Result<()> {
+    apply_set_threshold(&mut ctx.accounts.config, threshold)
+}
+
+/// Shared by [`set_threshold`] and its timelocked counterpart.
+pub(crate) fn apply_set_threshold(config: &mut Config, threshold: u8) -> Result<()> {
     if threshold == 0 {
         return Err(NTTError::ZeroThreshold.into());
     }
-    ctx.accounts.config.threshold = threshold;
+    if threshold > config.enabled_transceivers.len() {
+        return Err(NTTError::ThresholdTooHigh.into());
+    }
+    let old_threshold = config.threshold;
+    config.threshold = threshold;
+    emit!(ThresholdSet {
+        old_threshold,
+        threshold,
+    });
+    Ok(())
+}
+
+// * Set Peer Threshold
+
+#[derive(Accounts)]
+#[instruction(args: SetPeerThresholdArgs)]
+pub struct SetPeerThreshold<'info> {
+    /// The owner, or the holder of [`Role::TransceiverAdmin`].
+    pub owner: Signer<'info>,
+
+    #[account(
+        constraint = config.has_role(&owner.key(), Role::TransceiverAdmin) @ NTTError::Unauthorized,
+        constraint = config.timelock_delay == 0 @ NTTError::TimelockRequired,
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        mut,
+        seeds = [NttManagerPeer::SEED_PREFIX, args.chain_id.id.to_be_bytes().as_ref()],
+        bump = peer.bump,
+    )]
+    pub peer: Account<'info, NttManagerPeer>,
+}
+
+#[derive(AnchorDeserialize, AnchorSerialize, Clone, PartialEq, Eq, Debug, InitSpace)]
+pub struct SetPeerThresholdArgs {
+    pub chain_id: ChainId,
+    /// See [`NttManagerPeer::inbound_threshold`].
+    pub threshold: Option<u8>,
+    /// See [`NttManagerPeer::inbound_transceivers`].
+    pub transceivers: Option<Bitmap>,
+}
+
+/// Sets (or unsets) the threshold and the transceivers used for inbound
+/// transfers from a peer, instead of the global ones.
+pub fn set_peer_threshold(
+    ctx: Context<SetPeerThreshold>,
+    args: SetPeerThresholdArgs,
+) -> Result<()> {
+    apply_set_peer_threshold(&ctx.accounts.config, &mut ctx.accounts.peer, args)
+}
+
+/// Shared by [`set_peer_threshold`] and its timelocked counterpart.
+pub(crate) fn apply_set_peer_threshold(
+    config: &Config,
+    peer: &mut NttManagerPeer,
+    args: SetPeerThresholdArgs,
+) -> Result<()> {
+    peer.inbound_threshold = args.threshold;
+    peer.inbound_transceivers = args.transceivers;
+
+    if args.threshold == Some(0) {
+        return Err(NTTError::ZeroThreshold.into());
+    }
+    // the transceivers have to be able to reach the threshold, whether it's
+    // overridden or the global one
+    let threshold = args.threshold.unwrap_or(config.threshold);
+    if threshold > peer.enabled_inbound_transceivers(config).len() {
+        return Err(NTTError::ThresholdTooHigh.into());
+    }
+
+    emit!(PeerThresholdSet {
+        chain_id: args.chain_id,
+        threshold: args.threshold,
+        transceivers: args.transceivers,
+    });
     Ok(())
 }
@@ -0,0 +1,736 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface;
+
+use crate::{
+    clock::current_timestamp,
+    config::{Config, Role},
+    error::NTTError,
+    events::{ChangeExecuted, ChangeProposed, ChangeVetoed},
+    peer::NttManagerPeer,
+    pending_change::{ConfigChange, PendingChange},
+    queue::{
+        inbox::InboxRateLimit,
+        outbox::{OutboxChainRateLimit, OutboxRateLimit},
+    },
+    registered_transceiver::RegisteredTransceiver,
+    spl_multisig::SplMultisig,
+};
+
+use super::{
+    apply_deregister_transceiver, apply_register_transceiver, apply_set_inbound_limit,
+    apply_set_inbound_rate_limit_duration, apply_set_legacy_peer_addresses,
+    apply_set_outbound_chain_limit, apply_set_outbound_chain_rate_limit_duration,
+    apply_set_outbound_limit, apply_set_outbound_rate_limit_duration, apply_set_peer,
+    apply_set_peer_threshold, apply_set_threshold, apply_set_timelock_delay, set_role_holder,
+    transfer_token_authority, SetInboundLimitArgs, SetInboundRateLimitDurationArgs,
+    SetLegacyPeerAddressesArgs, SetOutboundChainLimitArgs, SetOutboundChainRateLimitDurationArgs,
+    SetOutboundLimitArgs, SetOutboundRateLimitDurationArgs, SetPeerArgs, SetPeerThresholdArgs,
+};
+
+// * Propose
+
+#[derive(Accounts)]
+#[instruction(change: ConfigChange)]
+pub struct ProposeChange<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// The owner, or the holder of the role that can make `change` directly
+    /// (see [`ConfigChange::can_propose`]).
+    pub proposer: Signer<'info>,
+
+    #[account(
+        constraint = change.can_propose(&config, &proposer.key()) @ NTTError::Unauthorized,
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        init,
+        space = 8 + PendingChange::INIT_SPACE,
+        payer = payer,
+        seeds = [PendingChange::SEED_PREFIX, change.keccak256()?.as_ref()],
+        bump,
+    )]
+    pub pending_change: Account<'info, PendingChange>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn propose_change(ctx: Context<ProposeChange>, change: ConfigChange) -> Result<()> {
+    let executable_at = current_timestamp()
+        .checked_add(ctx.accounts.config.timelock_delay)
+        .ok_or(NTTError::InvalidTimelockDelay)?;
+
+    ctx.accounts.pending_change.set_inner(PendingChange {
+        bump: ctx.bumps.pending_change,
+        change: change.clone(),
+        executable_at,
+        rent_payer: ctx.accounts.payer.key(),
+    });
+
+    emit!(ChangeProposed {
+        pending_change: ctx.accounts.pending_change.key(),
+        change,
+        executable_at,
+    });
+    Ok(())
+}
+
+// * Veto
+
+#[derive(Accounts)]
+pub struct VetoChange<'info> {
+    /// The owner, or the holder of [`Role::Guardian`].
+    pub authority: Signer<'info>,
+
+    #[account(
+        constraint = config.has_role(&authority.key(), Role::Guardian) @ NTTError::Unauthorized,
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(mut)]
+    /// CHECK: the `pending_change` constraint enforces that this is the correct address
+    pub rent_payer: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        has_one = rent_payer @ NTTError::IncorrectRentPayer,
+        close = rent_payer,
+    )]
+    pub pending_change: Account<'info, PendingChange>,
+}
+
+pub fn veto_change(ctx: Context<VetoChange>) -> Result<()> {
+    emit!(ChangeVetoed {
+        pending_change: ctx.accounts.pending_change.key(),
+    });
+    Ok(())
+}
+
+// * Execute
+//
+// Executing a pending change is permissionless once its delay has passed. The
+// changes that need additional accounts have their own instruction, and the
+// pending change is derived from the change they apply, so they can only apply
+// what was proposed.
+
+#[derive(Accounts)]
+pub struct ExecuteChange<'info> {
+    #[account(mut)]
+    pub config: Account<'info, Config>,
+
+    #[account(mut)]
+    /// CHECK: the `pending_change` constraint enforces that this is the correct address
+    pub rent_payer: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        has_one = rent_payer @ NTTError::IncorrectRentPayer,
+        constraint = pending_change.executable_at <= current_timestamp() @ NTTError::TimelockNotExpired,
+        close = rent_payer,
+    )]
+    pub pending_change: Account<'info, PendingChange>,
+}
+
+/// Executes a pending change that only modifies the config.
+pub fn execute_change(ctx: Context<ExecuteChange>) -> Result<()> {
+    let config = &mut ctx.accounts.config;
+    match ctx.accounts.pending_change.change {
+        ConfigChange::SetThreshold { threshold } => apply_set_threshold(config, threshold)?,
+        ConfigChange::SetTimelockDelay { delay } => apply_set_timelock_delay(config, delay)?,
+        ConfigChange::SetGuardian { guardian } => set_role_holder(config, Role::Guardian, guardian),
+        ConfigChange::SetPeer(_)
+        | ConfigChange::RegisterTransceiver { .. }
+        | ConfigChange::SetTokenAuthority { .. }
+        | ConfigChange::SetLegacyPeerAddresses(_)
+        | ConfigChange::SetPeerThreshold(_)
+        | ConfigChange::DeregisterTransceiver { .. }
+        | ConfigChange::SetOutboundLimit(_)
+        | ConfigChange::SetOutboundChainLimit(_)
+        | ConfigChange::SetInboundLimit(_)
+        | ConfigChange::SetOutboundRateLimitDuration(_)
+        | ConfigChange::SetOutboundChainRateLimitDuration(_)
+        | ConfigChange::SetInboundRateLimitDuration(_) => {
+            return Err(NTTError::InvalidConfigChange.into())
+        }
+    }
+
+    emit!(ChangeExecuted {
+        pending_change: ctx.accounts.pending_change.key(),
+    });
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(args: SetPeerArgs)]
+pub struct ExecuteSetPeer<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    #[account(
+        constraint = args.chain_id != config.chain_id @ NTTError::InvalidChainId
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(mut)]
+    /// CHECK: the `pending_change` constraint enforces that this is the correct address
+    pub rent_payer: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            PendingChange::SEED_PREFIX,
+            ConfigChange::SetPeer(args.clone()).keccak256()?.as_ref(),
+        ],
+        bump = pending_change.bump,
+        has_one = rent_payer @ NTTError::IncorrectRentPayer,
+        constraint = pending_change.executable_at <= current_timestamp() @ NTTError::TimelockNotExpired,
+        close = rent_payer,
+    )]
+    pub pending_change: Account<'info, PendingChange>,
+
+    #[account(
+        init_if_needed,
+        space = 8 + NttManagerPeer::INIT_SPACE,
+        payer = payer,
+        seeds = [NttManagerPeer::SEED_PREFIX, args.chain_id.id.to_be_bytes().as_ref()],
+        bump
+    )]
+    pub peer: Account<'info, NttManagerPeer>,
+
+    #[account(
+        init_if_needed,
+        space = 8 + InboxRateLimit::INIT_SPACE,
+        payer = payer,
+        seeds = [
+            InboxRateLimit::SEED_PREFIX,
+            args.chain_id.id.to_be_bytes().as_ref()
+        ],
+        bump,
+    )]
+    pub inbox_rate_limit: Account<'info, InboxRateLimit>,
+
+    #[account(
+        init_if_needed,
+        space = 8 + OutboxChainRateLimit::INIT_SPACE,
+        payer = payer,
+        seeds = [
+            OutboxChainRateLimit::SEED_PREFIX,
+            args.chain_id.id.to_be_bytes().as_ref()
+        ],
+        bump,
+    )]
+    pub outbox_chain_rate_limit: Account<'info, OutboxChainRateLimit>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn execute_set_peer(ctx: Context<ExecuteSetPeer>, args: SetPeerArgs) -> Result<()> {
+    apply_set_peer(
+        &mut ctx.accounts.peer,
+        ctx.bumps.peer,
+        &mut ctx.accounts.inbox_rate_limit,
+        ctx.bumps.inbox_rate_limit,
+        &mut ctx.accounts.outbox_chain_rate_limit,
+        ctx.bumps.outbox_chain_rate_limit,
+        args,
+    )?;
+
+    emit!(ChangeExecuted {
+        pending_change: ctx.accounts.pending_change.key(),
+    });
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(args: SetLegacyPeerAddressesArgs)]
+pub struct ExecuteSetLegacyPeerAddresses<'info> {
+    #[account(mut)]
+    /// CHECK: the `pending_change` constraint enforces that this is the correct address
+    pub rent_payer: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            PendingChange::SEED_PREFIX,
+            ConfigChange::SetLegacyPeerAddresses(args.clone()).keccak256()?.as_ref(),
+        ],
+        bump = pending_change.bump,
+        has_one = rent_payer @ NTTError::IncorrectRentPayer,
+        constraint = pending_change.executable_at <= current_timestamp() @ NTTError::TimelockNotExpired,
+        close = rent_payer,
+    )]
+    pub pending_change: Account<'info, PendingChange>,
+
+    #[account(
+        mut,
+        seeds = [NttManagerPeer::SEED_PREFIX, args.chain_id.id.to_be_bytes().as_ref()],
+        bump = peer.bump,
+    )]
+    pub peer: Account<'info, NttManagerPeer>,
+}
+
+pub fn execute_set_legacy_peer_addresses(
+    ctx: Context<ExecuteSetLegacyPeerAddresses>,
+    args: SetLegacyPeerAddressesArgs,
+) -> Result<()> {
+    apply_set_legacy_peer_addresses(&mut ctx.accounts.peer, args)?;
+
+    emit!(ChangeExecuted {
+        pending_change: ctx.accounts.pending_change.key(),
+    });
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(args: SetPeerThresholdArgs)]
+pub struct ExecuteSetPeerThreshold<'info> {
+    pub config: Account<'info, Config>,
+
+    #[account(mut)]
+    /// CHECK: the `pending_change` constraint enforces that this is the correct address
+    pub rent_payer: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            PendingChange::SEED_PREFIX,
+            ConfigChange::SetPeerThreshold(args.clone()).keccak256()?.as_ref(),
+        ],
+        bump = pending_change.bump,
+        has_one = rent_payer @ NTTError::IncorrectRentPayer,
+        constraint = pending_change.executable_at <= current_timestamp() @ NTTError::TimelockNotExpired,
+        close = rent_payer,
+    )]
+    pub pending_change: Account<'info, PendingChange>,
+
+    #[account(
+        mut,
+        seeds = [NttManagerPeer::SEED_PREFIX, args.chain_id.id.to_be_bytes().as_ref()],
+        bump = peer.bump,
+    )]
+    pub peer: Account<'info, NttManagerPeer>,
+}
+
+pub fn execute_set_peer_threshold(
+    ctx: Context<ExecuteSetPeerThreshold>,
+    args: SetPeerThresholdArgs,
+) -> Result<()> {
+    apply_set_peer_threshold(&ctx.accounts.config, &mut ctx.accounts.peer, args)?;
+
+    emit!(ChangeExecuted {
+        pending_change: ctx.accounts.pending_change.key(),
+    });
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct ExecuteRegisterTransceiver<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    #[account(mut)]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        executable,
+        constraint = transceiver.key() != Pubkey::default() @ NTTError::InvalidTransceiverProgram
+    )]
+    /// CHECK: transceiver is meant to be a transceiver program.
+    pub transceiver: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    /// CHECK: the `pending_change` constraint enforces that this is the correct address
+    pub rent_payer: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            PendingChange::SEED_PREFIX,
+            ConfigChange::RegisterTransceiver { transceiver: transceiver.key() }.keccak256()?.as_ref(),
+        ],
+        bump = pending_change.bump,
+        has_one = rent_payer @ NTTError::IncorrectRentPayer,
+        constraint = pending_change.executable_at <= current_timestamp() @ NTTError::TimelockNotExpired,
+        close = rent_payer,
+    )]
+    pub pending_change: Account<'info, PendingChange>,
+
+    #[account(
+        init_if_needed,
+        space = 8 + RegisteredTransceiver::INIT_SPACE,
+        payer = payer,
+        seeds = [RegisteredTransceiver::SEED_PREFIX, transceiver.key().as_ref()],
+        bump
+    )]
+    pub registered_transceiver: Account<'info, RegisteredTransceiver>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn execute_register_transceiver(ctx: Context<ExecuteRegisterTransceiver>) -> Result<()> {
+    apply_register_transceiver(
+        &mut ctx.accounts.config,
+        &mut ctx.accounts.registered_transceiver,
+        ctx.bumps.registered_transceiver,
+        ctx.accounts.transceiver.key(),
+    )?;
+
+    emit!(ChangeExecuted {
+        pending_change: ctx.accounts.pending_change.key(),
+    });
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct ExecuteSetTokenAuthority<'info> {
+    #[account(
+        has_one = mint,
+        constraint = config.paused @ NTTError::NotPaused,
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(mut)]
+    pub mint: InterfaceAccount<'info, token_interface::Mint>,
+
+    #[account(
+        seeds = [crate::TOKEN_AUTHORITY_SEED],
+        bump,
+    )]
+    /// CHECK: The constraints enforce this is valid mint authority
+    pub token_authority: UncheckedAccount<'info>,
+
+    #[account(
+        constraint = multisig_token_authority.m == 1
+            && multisig_token_authority.signers.contains(&token_authority.key())
+            @ NTTError::InvalidMultisig,
+    )]
+    pub multisig_token_authority: Option<InterfaceAccount<'info, SplMultisig>>,
+
+    /// CHECK: The `pending_change` seeds enforce that this is the proposed authority
+    pub new_authority: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    /// CHECK: the `pending_change` constraint enforces that this is the correct address
+    pub rent_payer: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            PendingChange::SEED_PREFIX,
+            ConfigChange::SetTokenAuthority { new_authority: new_authority.key() }.keccak256()?.as_ref(),
+        ],
+        bump = pending_change.bump,
+        has_one = rent_payer @ NTTError::IncorrectRentPayer,
+        constraint = pending_change.executable_at <= current_timestamp() @ NTTError::TimelockNotExpired,
+        close = rent_payer,
+    )]
+    pub pending_change: Account<'info, PendingChange>,
+
+    pub token_program: Interface<'info, token_interface::TokenInterface>,
+}
+
+pub fn execute_set_token_authority(ctx: Context<ExecuteSetTokenAuthority>) -> Result<()> {
+    transfer_token_authority(
+        ctx.accounts.token_program.to_account_info(),
+        ctx.accounts.mint.to_account_info(),
+        ctx.accounts
+            .multisig_token_authority
+            .as_ref()
+            .map(|multisig_token_authority| multisig_token_authority.to_account_info()),
+        ctx.accounts.token_authority.to_account_info(),
+        ctx.bumps.token_authority,
+        ctx.accounts.new_authority.key(),
+    )?;
+
+    emit!(ChangeExecuted {
+        pending_change: ctx.accounts.pending_change.key(),
+    });
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct ExecuteDeregisterTransceiver<'info> {
+    #[account(mut)]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        seeds = [RegisteredTransceiver::SEED_PREFIX, registered_transceiver.transceiver_address.as_ref()],
+        bump,
+        constraint = config.enabled_transceivers.get(registered_transceiver.id)? @ NTTError::DisabledTransceiver,
+    )]
+    pub registered_transceiver: Account<'info, RegisteredTransceiver>,
+
+    #[account(mut)]
+    /// CHECK: the `pending_change` constraint enforces that this is the correct address
+    pub rent_payer: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            PendingChange::SEED_PREFIX,
+            ConfigChange::DeregisterTransceiver { transceiver: registered_transceiver.transceiver_address }.keccak256()?.as_ref(),
+        ],
+        bump = pending_change.bump,
+        has_one = rent_payer @ NTTError::IncorrectRentPayer,
+        constraint = pending_change.executable_at <= current_timestamp() @ NTTError::TimelockNotExpired,
+        close = rent_payer,
+    )]
+    pub pending_change: Account<'info, PendingChange>,
+}
+
+pub fn execute_deregister_transceiver(ctx: Context<ExecuteDeregisterTransceiver>) -> Result<()> {
+    apply_deregister_transceiver(
+        &mut ctx.accounts.config,
+        &ctx.accounts.registered_transceiver,
+    )?;
+
+    emit!(ChangeExecuted {
+        pending_change: ctx.accounts.pending_change.key(),
+    });
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(args: SetOutboundLimitArgs)]
+pub struct ExecuteSetOutboundLimit<'info> {
+    #[account(mut)]
+    /// CHECK: the `pending_change` constraint enforces that this is the correct address
+    pub rent_payer: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            PendingChange::SEED_PREFIX,
+            ConfigChange::SetOutboundLimit(args.clone()).keccak256()?.as_ref(),
+        ],
+        bump = pending_change.bump,
+        has_one = rent_payer @ NTTError::IncorrectRentPayer,
+        constraint = pending_change.executable_at <= current_timestamp() @ NTTError::TimelockNotExpired,
+        close = rent_payer,
+    )]
+    pub pending_change: Account<'info, PendingChange>,
+
+    #[account(mut)]
+    pub rate_limit: Account<'info, OutboxRateLimit>,
+}
+
+pub fn execute_set_outbound_limit(
+    ctx: Context<ExecuteSetOutboundLimit>,
+    args: SetOutboundLimitArgs,
+) -> Result<()> {
+    apply_set_outbound_limit(&mut ctx.accounts.rate_limit, args)?;
+
+    emit!(ChangeExecuted {
+        pending_change: ctx.accounts.pending_change.key(),
+    });
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(args: SetOutboundChainLimitArgs)]
+pub struct ExecuteSetOutboundChainLimit<'info> {
+    #[account(mut)]
+    /// CHECK: the `pending_change` constraint enforces that this is the correct address
+    pub rent_payer: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            PendingChange::SEED_PREFIX,
+            ConfigChange::SetOutboundChainLimit(args.clone()).keccak256()?.as_ref(),
+        ],
+        bump = pending_change.bump,
+        has_one = rent_payer @ NTTError::IncorrectRentPayer,
+        constraint = pending_change.executable_at <= current_timestamp() @ NTTError::TimelockNotExpired,
+        close = rent_payer,
+    )]
+    pub pending_change: Account<'info, PendingChange>,
+
+    #[account(
+        mut,
+        seeds = [
+            OutboxChainRateLimit::SEED_PREFIX,
+            args.chain_id.id.to_be_bytes().as_ref()
+        ],
+        bump = rate_limit.bump
+    )]
+    pub rate_limit: Account<'info, OutboxChainRateLimit>,
+}
+
+pub fn execute_set_outbound_chain_limit(
+    ctx: Context<ExecuteSetOutboundChainLimit>,
+    args: SetOutboundChainLimitArgs,
+) -> Result<()> {
+    apply_set_outbound_chain_limit(&mut ctx.accounts.rate_limit, args)?;
+
+    emit!(ChangeExecuted {
+        pending_change: ctx.accounts.pending_change.key(),
+    });
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(args: SetInboundLimitArgs)]
+pub struct ExecuteSetInboundLimit<'info> {
+    #[account(mut)]
+    /// CHECK: the `pending_change` constraint enforces that this is the correct address
+    pub rent_payer: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            PendingChange::SEED_PREFIX,
+            ConfigChange::SetInboundLimit(args.clone()).keccak256()?.as_ref(),
+        ],
+        bump = pending_change.bump,
+        has_one = rent_payer @ NTTError::IncorrectRentPayer,
+        constraint = pending_change.executable_at <= current_timestamp() @ NTTError::TimelockNotExpired,
+        close = rent_payer,
+    )]
+    pub pending_change: Account<'info, PendingChange>,
+
+    #[account(
+        mut,
+        seeds = [
+            InboxRateLimit::SEED_PREFIX,
+            args.chain_id.id.to_be_bytes().as_ref()
+        ],
+        bump = rate_limit.bump
+    )]
+    pub rate_limit: Account<'info, InboxRateLimit>,
+}
+
+pub fn execute_set_inbound_limit(
+    ctx: Context<ExecuteSetInboundLimit>,
+    args: SetInboundLimitArgs,
+) -> Result<()> {
+    apply_set_inbound_limit(&mut ctx.accounts.rate_limit, args)?;
+
+    emit!(ChangeExecuted {
+        pending_change: ctx.accounts.pending_change.key(),
+    });
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(args: SetOutboundRateLimitDurationArgs)]
+pub struct ExecuteSetOutboundRateLimitDuration<'info> {
+    #[account(mut)]
+    /// CHECK: the `pending_change` constraint enforces that this is the correct address
+    pub rent_payer: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            PendingChange::SEED_PREFIX,
+            ConfigChange::SetOutboundRateLimitDuration(args.clone()).keccak256()?.as_ref(),
+        ],
+        bump = pending_change.bump,
+        has_one = rent_payer @ NTTError::IncorrectRentPayer,
+        constraint = pending_change.executable_at <= current_timestamp() @ NTTError::TimelockNotExpired,
+        close = rent_payer,
+    )]
+    pub pending_change: Account<'info, PendingChange>,
+
+    #[account(mut)]
+    pub rate_limit: Account<'info, OutboxRateLimit>,
+}
+
+pub fn execute_set_outbound_rate_limit_duration(
+    ctx: Context<ExecuteSetOutboundRateLimitDuration>,
+    args: SetOutboundRateLimitDurationArgs,
+) -> Result<()> {
+    apply_set_outbound_rate_limit_duration(&mut ctx.accounts.rate_limit, args)?;
+
+    emit!(ChangeExecuted {
+        pending_change: ctx.accounts.pending_change.key(),
+    });
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(args: SetOutboundChainRateLimitDurationArgs)]
+pub struct ExecuteSetOutboundChainRateLimitDuration<'info> {
+    #[account(mut)]
+    /// CHECK: the `pending_change` constraint enforces that this is the correct address
+    pub rent_payer: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            PendingChange::SEED_PREFIX,
+            ConfigChange::SetOutboundChainRateLimitDuration(args.clone()).keccak256()?.as_ref(),
+        ],
+        bump = pending_change.bump,
+        has_one = rent_payer @ NTTError::IncorrectRentPayer,
+        constraint = pending_change.executable_at <= current_timestamp() @ NTTError::TimelockNotExpired,
+        close = rent_payer,
+    )]
+    pub pending_change: Account<'info, PendingChange>,
+
+    #[account(
+        mut,
+        seeds = [
+            OutboxChainRateLimit::SEED_PREFIX,
+            args.chain_id.id.to_be_bytes().as_ref()
+        ],
+        bump = rate_limit.bump
+    )]
+    pub rate_limit: Account<'info, OutboxChainRateLimit>,
+}
+
+pub fn execute_set_outbound_chain_rate_limit_duration(
+    ctx: Context<ExecuteSetOutboundChainRateLimitDuration>,
+    args: SetOutboundChainRateLimitDurationArgs,
+) -> Result<()> {
+    apply_set_outbound_chain_rate_limit_duration(&mut ctx.accounts.rate_limit, args)?;
+
+    emit!(ChangeExecuted {
+        pending_change: ctx.accounts.pending_change.key(),
+    });
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(args: SetInboundRateLimitDurationArgs)]
+pub struct ExecuteSetInboundRateLimitDuration<'info> {
+    #[account(mut)]
+    /// CHECK: the `pending_change` constraint enforces that this is the correct address
+    pub rent_payer: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            PendingChange::SEED_PREFIX,
+            ConfigChange::SetInboundRateLimitDuration(args.clone()).keccak256()?.as_ref(),
+        ],
+        bump = pending_change.bump,
+        has_one = rent_payer @ NTTError::IncorrectRentPayer,
+        constraint = pending_change.executable_at <= current_timestamp() @ NTTError::TimelockNotExpired,
+        close = rent_payer,
+    )]
+    pub pending_change: Account<'info, PendingChange>,
+
+    #[account(
+        mut,
+        seeds = [
+            InboxRateLimit::SEED_PREFIX,
+            args.chain_id.id.to_be_bytes().as_ref()
+        ],
+        bump = rate_limit.bump
+    )]
+    pub rate_limit: Account<'info, InboxRateLimit>,
+}
+
+pub fn execute_set_inbound_rate_limit_duration(
+    ctx: Context<ExecuteSetInboundRateLimitDuration>,
+    args: SetInboundRateLimitDurationArgs,
+) -> Result<()> {
+    apply_set_inbound_rate_limit_duration(&mut ctx.accounts.rate_limit, args)?;
+
+    emit!(ChangeExecuted {
+        pending_change: ctx.accounts.pending_change.key(),
+    });
+    Ok(())
+}
@@ -4,7 +4,11 @@ use wormhole_solana_utils::cpi::bpf_loader_upgradeable::{self, BpfLoaderUpgradea
 #[cfg(feature = "idl-build")]
 use crate::messages::Hack;
 
-use crate::{config::Config, error::NTTError};
+use crate::{
+    config::Config,
+    error::NTTError,
+    events::{OwnershipTransferInitiated, OwnershipTransferred},
+};
 
 // * Transfer ownership
 
@@ -58,6 +62,11 @@ pub struct TransferOwnership<'info> {
 pub fn transfer_ownership(ctx: Context<TransferOwnership>) -> Result<()> {
     ctx.accounts.config.pending_owner = Some(ctx.accounts.new_owner.key());
 
+    emit!(OwnershipTransferInitiated {
+        owner: ctx.accounts.config.owner,
+        pending_owner: ctx.accounts.new_owner.key(),
+    });
+
     // only transfer authority when the authority is not already the upgrade lock
     if ctx.accounts.program_data.upgrade_authority_address != Some(ctx.accounts.upgrade_lock.key())
     {
@@ -83,6 +92,11 @@ pub fn transfer_ownership_one_step_unchecked(ctx: Context<TransferOwnership>) ->
     ctx.accounts.config.pending_owner = None;
     ctx.accounts.config.owner = ctx.accounts.new_owner.key();
 
+    emit!(OwnershipTransferred {
+        previous_owner: ctx.accounts.owner.key(),
+        owner: ctx.accounts.new_owner.key(),
+    });
+
     // NOTE: unlike in `transfer_ownership`, we use the unchecked version of the
     // `set_upgrade_authority` instruction here. The checked version requires
     // the new owner to be a signer, which is what we want to avoid here.
@@ -135,9 +149,15 @@ pub struct ClaimOwnership<'info> {
 }
 
 pub fn claim_ownership(ctx: Context<ClaimOwnership>) -> Result<()> {
+    let previous_owner = ctx.accounts.config.owner;
     ctx.accounts.config.pending_owner = None;
     ctx.accounts.config.owner = ctx.accounts.new_owner.key();
 
+    emit!(OwnershipTransferred {
+        previous_owner,
+        owner: ctx.accounts.new_owner.key(),
+    });
+
     bpf_loader_upgradeable::set_upgrade_authority_checked(
         CpiContext::new_with_signer(
             ctx.accounts
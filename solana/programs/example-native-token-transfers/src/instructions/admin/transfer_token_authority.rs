@@ -2,7 +2,13 @@ use anchor_lang::prelude::*;
 use anchor_spl::{token_2022::spl_token_2022::instruction::AuthorityType, token_interface};
 
 use crate::{
-    config::Config, error::NTTError, pending_token_authority::PendingTokenAuthority,
+    config::Config,
+    error::NTTError,
+    events::{
+        TokenAuthorityAccepted, TokenAuthorityTransferInitiated, TokenAuthorityTransferReverted,
+        TokenAuthorityTransferred,
+    },
+    pending_token_authority::PendingTokenAuthority,
     spl_multisig::SplMultisig,
 };
 
@@ -44,6 +50,11 @@ pub struct AcceptTokenAuthority<'info> {
 }
 
 pub fn accept_token_authority(ctx: Context<AcceptTokenAuthority>) -> Result<()> {
+    let new_authority = match &ctx.accounts.common.multisig_token_authority {
+        Some(multisig_token_authority) => multisig_token_authority.key(),
+        None => ctx.accounts.common.token_authority.key(),
+    };
+
     token_interface::set_authority(
         CpiContext::new(
             ctx.accounts.common.token_program.to_account_info(),
@@ -53,11 +64,14 @@ pub fn accept_token_authority(ctx: Context<AcceptTokenAuthority>) -> Result<()>
             },
         ),
         AuthorityType::MintTokens,
-        Some(match &ctx.accounts.common.multisig_token_authority {
-            Some(multisig_token_authority) => multisig_token_authority.key(),
-            None => ctx.accounts.common.token_authority.key(),
-        }),
-    )
+        Some(new_authority),
+    )?;
+
+    emit!(TokenAuthorityAccepted {
+        mint: ctx.accounts.common.mint.key(),
+        authority: new_authority,
+    });
+    Ok(())
 }
 
 #[derive(Accounts)]
@@ -100,6 +114,11 @@ pub fn accept_token_authority_from_multisig<'info>(
         )?,
         account_infos.as_slice(),
     )?;
+
+    emit!(TokenAuthorityAccepted {
+        mint: ctx.accounts.common.mint.key(),
+        authority: new_authority.key(),
+    });
     Ok(())
 }
 
@@ -111,6 +130,7 @@ pub struct SetTokenAuthority<'info> {
         has_one = owner,
         has_one = mint,
         constraint = config.paused @ NTTError::NotPaused,
+        constraint = config.timelock_delay == 0 @ NTTError::TimelockRequired,
     )]
     pub config: Account<'info, Config>,
 
@@ -147,23 +167,55 @@ pub struct SetTokenAuthorityUnchecked<'info> {
 pub fn set_token_authority_one_step_unchecked(
     ctx: Context<SetTokenAuthorityUnchecked>,
 ) -> Result<()> {
-    match &ctx.accounts.common.multisig_token_authority {
+    transfer_token_authority(
+        ctx.accounts.token_program.to_account_info(),
+        ctx.accounts.common.mint.to_account_info(),
+        ctx.accounts
+            .common
+            .multisig_token_authority
+            .as_ref()
+            .map(|multisig_token_authority| multisig_token_authority.to_account_info()),
+        ctx.accounts.common.token_authority.to_account_info(),
+        ctx.bumps.common.token_authority,
+        ctx.accounts.common.new_authority.key(),
+    )
+}
+
+/// Transfers the mint authority from the token authority (or the multisig
+/// token authority, if set) to `new_authority`. Shared by
+/// [`set_token_authority_one_step_unchecked`] and its timelocked counterpart.
+pub(super) fn transfer_token_authority<'info>(
+    token_program: AccountInfo<'info>,
+    mint: AccountInfo<'info>,
+    multisig_token_authority: Option<AccountInfo<'info>>,
+    token_authority: AccountInfo<'info>,
+    token_authority_bump: u8,
+    new_authority: Pubkey,
+) -> Result<()> {
+    let mint_key = mint.key();
+    match multisig_token_authority {
         Some(multisig_token_authority) => claim_from_multisig_token_authority(
-            ctx.accounts.token_program.to_account_info(),
-            ctx.accounts.common.mint.to_account_info(),
-            multisig_token_authority.to_account_info(),
-            ctx.accounts.common.token_authority.to_account_info(),
-            ctx.bumps.common.token_authority,
-            ctx.accounts.common.new_authority.key(),
-        ),
+            token_program,
+            mint,
+            multisig_token_authority,
+            token_authority,
+            token_authority_bump,
+            new_authority,
+        )?,
         None => claim_from_token_authority(
-            ctx.accounts.token_program.to_account_info(),
-            ctx.accounts.common.mint.to_account_info(),
-            ctx.accounts.common.token_authority.to_account_info(),
-            ctx.bumps.common.token_authority,
-            ctx.accounts.common.new_authority.key(),
-        ),
+            token_program,
+            mint,
+            token_authority,
+            token_authority_bump,
+            new_authority,
+        )?,
     }
+
+    emit!(TokenAuthorityTransferred {
+        mint: mint_key,
+        new_authority,
+    });
+    Ok(())
 }
 
 #[derive(Accounts)]
@@ -206,6 +258,11 @@ pub fn set_token_authority(ctx: Context<SetTokenAuthorityChecked>) -> Result<()>
                 ctx.accounts.rent_payer.key()
             },
         });
+
+    emit!(TokenAuthorityTransferInitiated {
+        mint: ctx.accounts.common.mint.key(),
+        pending_authority: ctx.accounts.common.new_authority.key(),
+    });
     Ok(())
 }
 
@@ -265,7 +322,10 @@ pub struct RevertTokenAuthority<'info> {
     pub owner: Signer<'info>,
 }
 
-pub fn revert_token_authority(_ctx: Context<RevertTokenAuthority>) -> Result<()> {
+pub fn revert_token_authority(ctx: Context<RevertTokenAuthority>) -> Result<()> {
+    emit!(TokenAuthorityTransferReverted {
+        mint: ctx.accounts.common.mint.key(),
+    });
     Ok(())
 }
 
@@ -288,15 +348,21 @@ pub fn claim_token_authority(ctx: Context<ClaimTokenAuthority>) -> Result<()> {
             ctx.accounts.common.token_authority.to_account_info(),
             ctx.bumps.common.token_authority,
             ctx.accounts.new_authority.key(),
-        ),
+        )?,
         None => claim_from_token_authority(
             ctx.accounts.common.token_program.to_account_info(),
             ctx.accounts.common.mint.to_account_info(),
             ctx.accounts.common.token_authority.to_account_info(),
             ctx.bumps.common.token_authority,
             ctx.accounts.new_authority.key(),
-        ),
+        )?,
     }
+
+    emit!(TokenAuthorityTransferred {
+        mint: ctx.accounts.common.mint.key(),
+        new_authority: ctx.accounts.new_authority.key(),
+    });
+    Ok(())
 }
 
 #[derive(Accounts)]
@@ -334,15 +400,21 @@ pub fn claim_token_authority_to_multisig(
             ctx.accounts.common.token_authority.to_account_info(),
             ctx.bumps.common.token_authority,
             ctx.accounts.new_multisig_authority.key(),
-        ),
+        )?,
         None => claim_from_token_authority(
             ctx.accounts.common.token_program.to_account_info(),
             ctx.accounts.common.mint.to_account_info(),
             ctx.accounts.common.token_authority.to_account_info(),
             ctx.bumps.common.token_authority,
             ctx.accounts.new_multisig_authority.key(),
-        ),
+        )?,
     }
+
+    emit!(TokenAuthorityTransferred {
+        mint: ctx.accounts.common.mint.key(),
+        new_authority: ctx.accounts.new_multisig_authority.key(),
+    });
+    Ok(())
 }
 
 fn claim_from_token_authority<'info>(
@@ -0,0 +1,183 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface;
+use ntt_messages::mode::Mode;
+use spl_token_2022::onchain;
+
+use crate::{
+    clock::current_timestamp,
+    config::*,
+    error::NTTError,
+    events::OutboundTransferCancelled,
+    instructions::release_inbound::{
+        mint_to_custody_from_multisig_token_authority, mint_to_custody_from_token_authority,
+    },
+    queue::{
+        inbox::InboxRateLimit,
+        outbox::{OutboxChainRateLimit, OutboxItem, OutboxRateLimit},
+    },
+    spl_multisig::SplMultisig,
+};
+
+#[derive(Accounts)]
+pub struct CancelOutboundQueuedTransfer<'info> {
+    pub sender: Signer<'info>,
+
+    pub config: NotPausedConfig<'info>,
+
+    #[account(
+        mut,
+        has_one = sender,
+        constraint = !outbox_item.cancelled @ NTTError::OutboxItemCancelled,
+        constraint = outbox_item.released.is_empty() @ NTTError::OutboxItemAlreadyReleased,
+        constraint = outbox_item.queued
+            || outbox_item.release_timestamp > current_timestamp()
+            @ NTTError::OutboxItemNotQueued,
+    )]
+    pub outbox_item: Account<'info, OutboxItem>,
+
+    #[account(
+        mut,
+        address = config.mint,
+    )]
+    /// CHECK: the mint address matches the config
+    pub mint: InterfaceAccount<'info, token_interface::Mint>,
+
+    #[account(
+        mut,
+        token::mint = mint,
+        token::authority = outbox_item.sender,
+    )]
+    /// The token account the tokens are returned to. Must be owned by the
+    /// original sender.
+    pub sender_token_account: InterfaceAccount<'info, token_interface::TokenAccount>,
+
+    #[account(
+        seeds = [crate::TOKEN_AUTHORITY_SEED],
+        bump,
+    )]
+    /// CHECK: The seeds constraint ensures that this is the correct address
+    pub token_authority: UncheckedAccount<'info>,
+
+    pub token_program: Interface<'info, token_interface::TokenInterface>,
+
+    #[account(
+        mut,
+        address = config.custody
+    )]
+    pub custody: InterfaceAccount<'info, token_interface::TokenAccount>,
+
+    #[account(mut)]
+    pub outbox_rate_limit: Account<'info, OutboxRateLimit>,
+
+    #[account(
+        mut,
+        seeds = [OutboxChainRateLimit::SEED_PREFIX, outbox_item.recipient_chain.id.to_be_bytes().as_ref()],
+        bump = outbox_chain_rate_limit.bump,
+    )]
+    pub outbox_chain_rate_limit: Account<'info, OutboxChainRateLimit>,
+
+    #[account(
+        mut,
+        seeds = [InboxRateLimit::SEED_PREFIX, outbox_item.recipient_chain.id.to_be_bytes().as_ref()],
+        bump = inbox_rate_limit.bump,
+    )]
+    pub inbox_rate_limit: Account<'info, InboxRateLimit>,
+
+    #[account(
+        constraint = multisig_token_authority.m == 1
+            && multisig_token_authority.signers.contains(&token_authority.key())
+            @ NTTError::InvalidMultisig,
+    )]
+    /// Only used in burning mode, when the mint authority is a multisig.
+    pub multisig_token_authority: Option<InterfaceAccount<'info, SplMultisig>>,
+}
+
+/// Cancels a queued outbound transfer that has not been released by any
+/// transceiver yet, and returns the tokens to the sender (minting them in
+/// burning mode, unlocking them from custody in locking mode).
+/// The outbox item is marked as cancelled, so that it can never be released.
+/// Transfers that weren't queued can't be cancelled, as they can be released
+/// right away.
+///
+/// If the transfer consumed outbound capacity when it was inserted (i.e. it
+/// reserved the capacity), that capacity is returned (both globally and for the
+/// destination chain), and the backflow to the destination chain's inbound
+/// rate limit is taken back.
+///
+/// For tokens with transfer fees, the refund is charged the fee again: the
+/// amount in the outbox item is what arrived in custody (after the fee of the
+/// original transfer), and the sender receives that amount minus the fee on
+/// it. The fee isn't made up for, as in locking mode that would come out of
+/// the tokens locked for other transfers.
+pub fn cancel_outbound_queued_transfer<'info>(
+    ctx: Context<'_, '_, '_, 'info, CancelOutboundQueuedTransfer<'info>>,
+) -> Result<()> {
+    let accs = ctx.accounts;
+
+    let amount = accs
+        .outbox_item
+        .amount
+        .untrim(accs.mint.decimals)
+        .map_err(NTTError::from)?;
+
+    accs.outbox_item.cancelled = true;
+
+    // NOTE: the backflow is taken back in full, even if it was capped by the
+    // inbound limit when it was refilled. This errs on the side of less
+    // inbound capacity.
+    if !accs.outbox_item.queued || accs.outbox_item.reserved {
+        let now = current_timestamp();
+        accs.outbox_rate_limit.refill(now, amount);
+        accs.outbox_chain_rate_limit.refill(now, amount);
+        accs.inbox_rate_limit.drain(now, amount);
+    }
+
+    let token_authority_sig: &[&[&[u8]]] =
+        &[&[crate::TOKEN_AUTHORITY_SEED, &[ctx.bumps.token_authority]]];
+
+    // In burning mode, the tokens are minted to the custody account first,
+    // then transferred to the sender. See [`crate::instructions::release_inbound_mint`]
+    // for an explanation.
+    match accs.config.mode {
+        Mode::Burning => match &accs.multisig_token_authority {
+            Some(multisig_token_authority) => mint_to_custody_from_multisig_token_authority(
+                accs.token_program.to_account_info(),
+                accs.mint.to_account_info(),
+                accs.custody.to_account_info(),
+                multisig_token_authority.to_account_info(),
+                accs.token_authority.to_account_info(),
+                token_authority_sig,
+                amount,
+            )?,
+            None => mint_to_custody_from_token_authority(
+                accs.token_program.to_account_info(),
+                accs.mint.to_account_info(),
+                accs.custody.to_account_info(),
+                accs.token_authority.to_account_info(),
+                token_authority_sig,
+                amount,
+            )?,
+        },
+        Mode::Locking => {}
+    }
+
+    onchain::invoke_transfer_checked(
+        &accs.token_program.key(),
+        accs.custody.to_account_info(),
+        accs.mint.to_account_info(),
+        accs.sender_token_account.to_account_info(),
+        accs.token_authority.to_account_info(),
+        ctx.remaining_accounts,
+        amount,
+        accs.mint.decimals,
+        token_authority_sig,
+    )?;
+
+    emit!(OutboundTransferCancelled {
+        outbox_item: accs.outbox_item.key(),
+        sender: accs.sender.key(),
+        amount,
+    });
+
+    Ok(())
+}
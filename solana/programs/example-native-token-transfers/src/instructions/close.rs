@@ -0,0 +1,99 @@
+//! Instructions for reclaiming the rent of outbox and inbox items once they
+//! are done.
+//!
+//! These instructions are permissionless, as the rent is always returned to
+//! the account that originally paid for it.
+
+use anchor_lang::{prelude::*, Discriminator};
+
+use crate::{
+    config::Config,
+    error::NTTError,
+    queue::{
+        inbox::{InboxItem, InboxItemTombstone},
+        outbox::OutboxItem,
+    },
+};
+
+// * Close outbox item
+
+#[derive(Accounts)]
+pub struct CloseOutboxItem<'info> {
+    pub config: Account<'info, Config>,
+
+    #[account(
+        mut,
+        has_one = rent_payer @ NTTError::IncorrectRentPayer,
+        constraint = outbox_item.is_done(config.enabled_transceivers) @ NTTError::OutboxItemNotReleased,
+        close = rent_payer,
+    )]
+    pub outbox_item: Account<'info, OutboxItem>,
+
+    #[account(mut)]
+    /// CHECK: the `outbox_item` constraint enforces that this is the correct address
+    pub rent_payer: UncheckedAccount<'info>,
+}
+
+/// Closes an outbox item that has been released by every enabled transceiver
+/// (or cancelled), returning the rent to the original payer.
+///
+/// NOTE: transceivers that are enabled after the item is closed will not be
+/// able to release it.
+pub fn close_outbox_item(_ctx: Context<CloseOutboxItem>) -> Result<()> {
+    Ok(())
+}
+
+// * Close inbox item
+
+#[derive(Accounts)]
+pub struct CloseInboxItem<'info> {
+    #[account(
+        mut,
+        owner = crate::ID,
+    )]
+    /// CHECK: deserialized and checked in the handler. We don't use
+    /// `Account<InboxItem>` here, because anchor would serialize the item back
+    /// into the account at the end of the instruction, overwriting the
+    /// tombstone.
+    pub inbox_item: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    /// CHECK: checked against the rent payer stored in the inbox item
+    pub rent_payer: UncheckedAccount<'info>,
+}
+
+/// Closes a released (or rejected) inbox item, returning (most of) the rent to
+/// the original payer.
+///
+/// The account is not deleted, but replaced with an [`InboxItemTombstone`],
+/// which keeps the message from being redeemed again. The tombstone is
+/// only large enough to hold its discriminator, so only the rent for that is
+/// kept.
+pub fn close_inbox_item(ctx: Context<CloseInboxItem>) -> Result<()> {
+    let info = ctx.accounts.inbox_item.to_account_info();
+
+    let inbox_item = InboxItem::try_deserialize(&mut &info.try_borrow_data()?[..])?;
+
+    if !inbox_item.is_final() {
+        return Err(NTTError::InboxItemNotReleased.into());
+    }
+    if inbox_item.rent_payer != ctx.accounts.rent_payer.key() {
+        return Err(NTTError::IncorrectRentPayer.into());
+    }
+
+    let tombstone_space = 8 + InboxItemTombstone::INIT_SPACE;
+    info.realloc(tombstone_space, false)?;
+    info.try_borrow_mut_data()?
+        .copy_from_slice(&InboxItemTombstone::DISCRIMINATOR);
+
+    let rent_exempt_lamports = Rent::get()?.minimum_balance(tombstone_space);
+    let excess_lamports = info
+        .lamports()
+        .checked_sub(rent_exempt_lamports)
+        .ok_or(ProgramError::InsufficientFunds)?;
+
+    **info.try_borrow_mut_lamports()? -= excess_lamports;
+    **ctx.accounts.rent_payer.try_borrow_mut_lamports()? += excess_lamports;
+
+    Ok(())
+}
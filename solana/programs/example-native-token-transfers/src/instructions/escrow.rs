@@ -0,0 +1,496 @@
+//! Escrow for inbound transfers that can't be delivered.
+//!
+//! [`crate::instructions::release_inbound_mint`] and
+//! [`crate::instructions::release_inbound_unlock`] send the tokens to the
+//! associated token account of the recipient, and fail if it can't receive
+//! them (e.g. it's frozen). Such a transfer can be moved to the escrow (the
+//! associated token account of [`crate::ESCROW_AUTHORITY_SEED`]) with
+//! [`escrow_inbox_item`], by the owner at any time, or by anyone once
+//! [`Config::escrow_timeout`] has passed since the transfer became releasable.
+//!
+//! From the escrow, the owner can send the tokens to another account with
+//! [`redirect_escrowed_inbox_item`], or back to the sender on the source chain
+//! with [`return_escrowed_inbox_item`].
+
+use anchor_lang::prelude::*;
+use anchor_spl::{associated_token::AssociatedToken, token_interface};
+use ntt_messages::{mode::Mode, ntt::AdditionalPayload, trimmed_amount::TrimmedAmount};
+use spl_token_2022::onchain;
+
+use crate::{
+    bitmap::Bitmap,
+    clock::current_timestamp,
+    config::*,
+    error::NTTError,
+    events::{
+        EscrowedTransferRedirected, EscrowedTransferReturned, InboundTransferEscrowed,
+        OutboundTransferQueued, TransferSent,
+    },
+    instructions::{
+        release_inbound::{
+            mint_to_custody_from_multisig_token_authority, mint_to_custody_from_token_authority,
+        },
+        transfer::consume_outbound_rate_limits,
+    },
+    peer::NttManagerPeer,
+    queue::{
+        inbox::{InboxItem, InboxRateLimit, ReleaseStatus},
+        outbox::{OutboxChainRateLimit, OutboxItem, OutboxRateLimit, OutboxSequence},
+    },
+    spl_multisig::SplMultisig,
+};
+
+#[derive(Accounts)]
+pub struct EscrowInboxItem<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// The owner, to escrow the transfer before [`Config::escrow_timeout`] has
+    /// passed (or when it's disabled).
+    pub owner: Option<Signer<'info>>,
+
+    #[account(
+        constraint = !config.inbound_paused @ NTTError::InboundPaused,
+        constraint = owner.as_ref().filter(|owner| owner.key() != config.owner).is_none()
+            @ NTTError::Unauthorized,
+    )]
+    pub config: NotPausedConfig<'info>,
+
+    #[account(mut)]
+    pub inbox_item: Account<'info, InboxItem>,
+
+    #[account(
+        seeds = [crate::TOKEN_AUTHORITY_SEED],
+        bump,
+    )]
+    /// CHECK: The seeds constraint ensures that this is the correct address
+    pub token_authority: UncheckedAccount<'info>,
+
+    #[account(
+        constraint = multisig_token_authority.m == 1
+            && multisig_token_authority.signers.contains(&token_authority.key())
+            @ NTTError::InvalidMultisig,
+    )]
+    pub multisig_token_authority: Option<InterfaceAccount<'info, SplMultisig>>,
+
+    #[account(
+        seeds = [crate::ESCROW_AUTHORITY_SEED],
+        bump,
+    )]
+    /// CHECK: The seeds constraint ensures that this is the correct address
+    pub escrow_authority: UncheckedAccount<'info>,
+
+    #[account(
+        init_if_needed,
+        payer = payer,
+        associated_token::mint = mint,
+        associated_token::authority = escrow_authority,
+        associated_token::token_program = token_program,
+    )]
+    pub escrow: InterfaceAccount<'info, token_interface::TokenAccount>,
+
+    #[account(
+        mut,
+        address = config.mint,
+    )]
+    /// CHECK: the mint address matches the config
+    pub mint: InterfaceAccount<'info, token_interface::Mint>,
+
+    #[account(
+        mut,
+        address = config.custody,
+    )]
+    pub custody: InterfaceAccount<'info, token_interface::TokenAccount>,
+
+    pub token_program: Interface<'info, token_interface::TokenInterface>,
+
+    pub associated_token_program: Program<'info, AssociatedToken>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Moves the tokens of an approved inbound transfer to the escrow, instead of
+/// releasing them to the recipient. In burning mode, the tokens are minted to
+/// the escrow, and in locking mode they are transferred there from custody.
+///
+/// Anyone can escrow a transfer that became releasable at least
+/// [`Config::escrow_timeout`] ago and still hasn't been released. The owner
+/// can escrow it at any time, and can also escrow approved transfers that are
+/// frozen or quarantined.
+pub fn escrow_inbox_item<'info>(
+    ctx: Context<'_, '_, '_, 'info, EscrowInboxItem<'info>>,
+) -> Result<()> {
+    let by_owner = ctx.accounts.owner.is_some();
+    match ctx.accounts.inbox_item.release_status {
+        ReleaseStatus::ReleaseAfter(release_timestamp) if !by_owner => {
+            let timeout = ctx.accounts.config.escrow_timeout;
+            if timeout == 0 {
+                return Err(NTTError::Unauthorized.into());
+            }
+            if release_timestamp.saturating_add(timeout) > current_timestamp() {
+                return Err(NTTError::CantEscrowYet.into());
+            }
+        }
+        ReleaseStatus::ReleaseAfter(_) => {}
+        ReleaseStatus::Frozen(Some(_)) | ReleaseStatus::Quarantined if by_owner => {}
+        ReleaseStatus::NotApproved | ReleaseStatus::Frozen(None) => {
+            return Err(NTTError::TransferNotApproved.into())
+        }
+        ReleaseStatus::Frozen(Some(_)) => return Err(NTTError::InboxItemFrozen.into()),
+        ReleaseStatus::Quarantined => return Err(NTTError::InboxItemQuarantined.into()),
+        ReleaseStatus::Released => return Err(NTTError::TransferAlreadyRedeemed.into()),
+        ReleaseStatus::Rejected => return Err(NTTError::InboxItemRejected.into()),
+        ReleaseStatus::Escrowed(_) => return Err(NTTError::InboxItemEscrowed.into()),
+    }
+
+    let accs = &mut *ctx.accounts;
+    let amount = accs.inbox_item.amount;
+    let token_authority_sig: &[&[&[u8]]] =
+        &[&[crate::TOKEN_AUTHORITY_SEED, &[ctx.bumps.token_authority]]];
+    let before = accs.escrow.amount;
+
+    match accs.config.mode {
+        // NOTE: unlike in [`crate::instructions::release_inbound_mint`], the
+        // tokens are minted to the escrow directly. The transfer hook (if any)
+        // is called when they leave the escrow.
+        Mode::Burning => match &accs.multisig_token_authority {
+            Some(multisig_token_authority) => mint_to_custody_from_multisig_token_authority(
+                accs.token_program.to_account_info(),
+                accs.mint.to_account_info(),
+                accs.escrow.to_account_info(),
+                multisig_token_authority.to_account_info(),
+                accs.token_authority.to_account_info(),
+                token_authority_sig,
+                amount,
+            )?,
+            None => mint_to_custody_from_token_authority(
+                accs.token_program.to_account_info(),
+                accs.mint.to_account_info(),
+                accs.escrow.to_account_info(),
+                accs.token_authority.to_account_info(),
+                token_authority_sig,
+                amount,
+            )?,
+        },
+        Mode::Locking => onchain::invoke_transfer_checked(
+            &accs.token_program.key(),
+            accs.custody.to_account_info(),
+            accs.mint.to_account_info(),
+            accs.escrow.to_account_info(),
+            accs.token_authority.to_account_info(),
+            ctx.remaining_accounts,
+            amount,
+            accs.mint.decimals,
+            token_authority_sig,
+        )?,
+    }
+
+    // less than `amount` arrives if the token has transfer fees
+    accs.escrow.reload()?;
+    let escrowed = accs
+        .escrow
+        .amount
+        .checked_sub(before)
+        .ok_or(NTTError::BadAmountAfterTransfer)?;
+
+    accs.inbox_item.release_status = ReleaseStatus::Escrowed(escrowed);
+
+    emit!(InboundTransferEscrowed {
+        inbox_item: accs.inbox_item.key(),
+        amount: escrowed,
+    });
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct RedirectEscrowedInboxItem<'info> {
+    pub owner: Signer<'info>,
+
+    #[account(
+        has_one = owner,
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(mut)]
+    pub inbox_item: Account<'info, InboxItem>,
+
+    #[account(
+        seeds = [crate::ESCROW_AUTHORITY_SEED],
+        bump,
+    )]
+    /// CHECK: The seeds constraint ensures that this is the correct address
+    pub escrow_authority: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = escrow_authority,
+        associated_token::token_program = token_program,
+    )]
+    pub escrow: InterfaceAccount<'info, token_interface::TokenAccount>,
+
+    #[account(
+        mut,
+        token::mint = mint,
+    )]
+    /// The token account the tokens are sent to. It can be owned by anyone.
+    pub recipient: InterfaceAccount<'info, token_interface::TokenAccount>,
+
+    #[account(
+        address = config.mint,
+    )]
+    /// CHECK: the mint address matches the config
+    pub mint: InterfaceAccount<'info, token_interface::Mint>,
+
+    pub token_program: Interface<'info, token_interface::TokenInterface>,
+}
+
+/// Sends the escrowed tokens of an inbound transfer to `recipient`, e.g. a
+/// token account of the intended recipient that isn't frozen.
+pub fn redirect_escrowed_inbox_item<'info>(
+    ctx: Context<'_, '_, '_, 'info, RedirectEscrowedInboxItem<'info>>,
+) -> Result<()> {
+    let ReleaseStatus::Escrowed(amount) = ctx.accounts.inbox_item.release_status else {
+        return Err(NTTError::InboxItemNotEscrowed.into());
+    };
+
+    onchain::invoke_transfer_checked(
+        &ctx.accounts.token_program.key(),
+        ctx.accounts.escrow.to_account_info(),
+        ctx.accounts.mint.to_account_info(),
+        ctx.accounts.recipient.to_account_info(),
+        ctx.accounts.escrow_authority.to_account_info(),
+        ctx.remaining_accounts,
+        amount,
+        ctx.accounts.mint.decimals,
+        &[&[crate::ESCROW_AUTHORITY_SEED, &[ctx.bumps.escrow_authority]]],
+    )?;
+
+    ctx.accounts.inbox_item.release_status = ReleaseStatus::Released;
+
+    emit!(EscrowedTransferRedirected {
+        inbox_item: ctx.accounts.inbox_item.key(),
+        recipient: ctx.accounts.recipient.key(),
+        amount,
+    });
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct ReturnEscrowedInboxItem<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub owner: Signer<'info>,
+
+    #[account(
+        has_one = owner,
+        constraint = !config.paused @ NTTError::Paused,
+        constraint = !config.outbound_paused @ NTTError::OutboundPaused,
+        constraint = !config.enabled_transceivers.is_empty() @ NTTError::NoRegisteredTransceivers,
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(mut)]
+    pub inbox_item: Account<'info, InboxItem>,
+
+    #[account(
+        seeds = [NttManagerPeer::SEED_PREFIX, inbox_item.source_chain.id.to_be_bytes().as_ref()],
+        bump = peer.bump,
+        constraint = !peer.paused @ NTTError::PeerPaused,
+        constraint = peer.retired_at.is_none() @ NTTError::PeerRetired,
+    )]
+    pub peer: Account<'info, NttManagerPeer>,
+
+    #[account(
+        seeds = [crate::ESCROW_AUTHORITY_SEED],
+        bump,
+    )]
+    /// CHECK: The seeds constraint ensures that this is the correct address
+    pub escrow_authority: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = escrow_authority,
+        associated_token::token_program = token_program,
+    )]
+    pub escrow: InterfaceAccount<'info, token_interface::TokenAccount>,
+
+    #[account(
+        mut,
+        address = config.mint,
+    )]
+    /// CHECK: the mint address matches the config
+    pub mint: InterfaceAccount<'info, token_interface::Mint>,
+
+    #[account(
+        mut,
+        address = config.custody,
+    )]
+    pub custody: InterfaceAccount<'info, token_interface::TokenAccount>,
+
+    pub token_program: Interface<'info, token_interface::TokenInterface>,
+
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = 8 + OutboxSequence::INIT_SPACE,
+        seeds = [OutboxSequence::SEED_PREFIX],
+        bump,
+    )]
+    pub outbox_sequence: Account<'info, OutboxSequence>,
+
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + OutboxItem::INIT_SPACE,
+        seeds = [
+            OutboxItem::SEED_PREFIX,
+            outbox_sequence.next.to_be_bytes().as_ref(),
+        ],
+        bump,
+    )]
+    pub outbox_item: Account<'info, OutboxItem>,
+
+    #[account(mut)]
+    pub outbox_rate_limit: Account<'info, OutboxRateLimit>,
+
+    #[account(
+        mut,
+        seeds = [InboxRateLimit::SEED_PREFIX, inbox_item.source_chain.id.to_be_bytes().as_ref()],
+        bump = inbox_rate_limit.bump,
+    )]
+    pub inbox_rate_limit: Account<'info, InboxRateLimit>,
+
+    #[account(
+        mut,
+        seeds = [OutboxChainRateLimit::SEED_PREFIX, inbox_item.source_chain.id.to_be_bytes().as_ref()],
+        bump = outbox_chain_rate_limit.bump,
+    )]
+    pub outbox_chain_rate_limit: Account<'info, OutboxChainRateLimit>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Sends the escrowed tokens of an inbound transfer back to its sender on the
+/// source chain. Like an outbound transfer, the tokens are burned in burning
+/// mode, and locked in custody in locking mode, and the transfer is inserted
+/// into the outbox for the transceivers to send. The sender of the returned
+/// transfer is the escrow authority.
+///
+/// The returned transfer goes through the outbound rate limits like any other
+/// transfer to the source chain, and is queued if it's outside them. Any dust
+/// (see [`TrimmedAmount`]) is burned or left in custody.
+pub fn return_escrowed_inbox_item<'info>(
+    ctx: Context<'_, '_, '_, 'info, ReturnEscrowedInboxItem<'info>>,
+) -> Result<()> {
+    let accs = &mut *ctx.accounts;
+    let ReleaseStatus::Escrowed(escrowed) = accs.inbox_item.release_status else {
+        return Err(NTTError::InboxItemNotEscrowed.into());
+    };
+    // inbox items migrated from the old layout don't record the sender
+    if accs.inbox_item.sender == [0; 32] {
+        return Err(NTTError::InvalidRecipientAddress.into());
+    }
+
+    let escrow_authority_sig: &[&[&[u8]]] =
+        &[&[crate::ESCROW_AUTHORITY_SEED, &[ctx.bumps.escrow_authority]]];
+
+    let mut amount = match accs.config.mode {
+        Mode::Burning => {
+            token_interface::burn(
+                CpiContext::new_with_signer(
+                    accs.token_program.to_account_info(),
+                    token_interface::Burn {
+                        mint: accs.mint.to_account_info(),
+                        from: accs.escrow.to_account_info(),
+                        authority: accs.escrow_authority.to_account_info(),
+                    },
+                    escrow_authority_sig,
+                ),
+                escrowed,
+            )?;
+            escrowed
+        }
+        Mode::Locking => {
+            let before = accs.custody.amount;
+            onchain::invoke_transfer_checked(
+                &accs.token_program.key(),
+                accs.escrow.to_account_info(),
+                accs.mint.to_account_info(),
+                accs.custody.to_account_info(),
+                accs.escrow_authority.to_account_info(),
+                ctx.remaining_accounts,
+                escrowed,
+                accs.mint.decimals,
+                escrow_authority_sig,
+            )?;
+            accs.custody.reload()?;
+            accs.custody
+                .amount
+                .checked_sub(before)
+                .ok_or(NTTError::BadAmountAfterTransfer)?
+        }
+    };
+    let trimmed_amount =
+        TrimmedAmount::remove_dust(&mut amount, accs.mint.decimals, accs.peer.token_decimals)
+            .map_err(NTTError::from)?;
+
+    let (release_timestamp, queued, reserved) = consume_outbound_rate_limits(
+        &accs.config,
+        &mut accs.outbox_rate_limit,
+        &mut accs.outbox_chain_rate_limit,
+        &mut accs.inbox_rate_limit,
+        amount,
+        true,
+    )?;
+
+    let sequence = accs.outbox_sequence.next;
+    accs.outbox_sequence.next += 1;
+
+    let sender = accs.escrow_authority.key();
+    let recipient_chain = accs.inbox_item.source_chain;
+    let recipient_ntt_manager = accs.peer.address;
+    let recipient_address = accs.inbox_item.sender;
+
+    accs.outbox_item.set_inner(OutboxItem {
+        amount: trimmed_amount,
+        sender,
+        recipient_chain,
+        recipient_ntt_manager,
+        recipient_address,
+        release_timestamp,
+        released: Bitmap::new(),
+        queued,
+        reserved,
+        cancelled: false,
+        rent_payer: accs.payer.key(),
+        additional_payload: AdditionalPayload::default(),
+        sequence,
+        version: OutboxItem::LAYOUT_VERSION,
+    });
+
+    accs.inbox_item.release_status = ReleaseStatus::Released;
+
+    emit!(TransferSent {
+        outbox_item: accs.outbox_item.key(),
+        sender,
+        amount: trimmed_amount,
+        recipient_chain,
+        recipient_ntt_manager,
+        recipient_address,
+        release_timestamp,
+    });
+    if queued {
+        emit!(OutboundTransferQueued {
+            outbox_item: accs.outbox_item.key(),
+            release_timestamp,
+        });
+    }
+    emit!(EscrowedTransferReturned {
+        inbox_item: accs.inbox_item.key(),
+        outbox_item: accs.outbox_item.key(),
+    });
+    Ok(())
+}
@@ -136,6 +136,21 @@ fn initialize_config_and_rate_limit(
         threshold: 1,
         enabled_transceivers: Bitmap::new(),
         custody: common.custody.key(),
+        precise_queue_release: false,
+        strict_dust: false,
+        pauser: None,
+        outbound_paused: false,
+        inbound_paused: false,
+        rate_limit_admin: None,
+        peer_admin: None,
+        transceiver_admin: None,
+        timelock_delay: 0,
+        guardian: None,
+        compliance_admin: None,
+        denylist_enabled: false,
+        policy_program: None,
+        escrow_timeout: 0,
+        version: crate::config::Config::LAYOUT_VERSION,
     });
 
     common.rate_limit.set_inner(OutboxRateLimit {
@@ -1,6 +1,6 @@
 use crate::{
-    config::*, error::NTTError, queue::outbox::OutboxItem,
-    registered_transceiver::RegisteredTransceiver,
+    config::*, error::NTTError, events::OutboundTransferReleased, peer::NttManagerPeer,
+    queue::outbox::OutboxItem, registered_transceiver::RegisteredTransceiver,
 };
 use anchor_lang::prelude::*;
 
@@ -15,6 +15,9 @@ pub struct MarkOutboxItemAsReleased<'info> {
     )]
     pub signer: Signer<'info>,
 
+    #[account(
+        constraint = !config.outbound_paused @ NTTError::OutboundPaused,
+    )]
     pub config: NotPausedConfig<'info>,
 
     #[account(
@@ -23,6 +26,13 @@ pub struct MarkOutboxItemAsReleased<'info> {
     )]
     pub outbox_item: Account<'info, OutboxItem>,
 
+    #[account(
+        seeds = [NttManagerPeer::SEED_PREFIX, outbox_item.recipient_chain.id.to_be_bytes().as_ref()],
+        bump = peer.bump,
+        constraint = !peer.paused @ NTTError::PeerPaused,
+    )]
+    pub peer: Account<'info, NttManagerPeer>,
+
     #[account(
         constraint = config.enabled_transceivers.get(transceiver.id)? @ NTTError::DisabledTransceiver
     )]
@@ -32,5 +42,12 @@ pub struct MarkOutboxItemAsReleased<'info> {
 pub fn mark_outbox_item_as_released(ctx: Context<MarkOutboxItemAsReleased>) -> Result<bool> {
     let accs = ctx.accounts;
     let released = accs.outbox_item.try_release(accs.transceiver.id)?;
+    if released {
+        emit!(OutboundTransferReleased {
+            outbox_item: accs.outbox_item.key(),
+            transceiver: accs.transceiver.transceiver_address,
+            transceiver_index: accs.transceiver.id,
+        });
+    }
     Ok(released)
 }
@@ -0,0 +1,353 @@
+//! Migration of accounts to their current layout.
+//!
+//! [`Config`], [`NttManagerPeer`], [`RegisteredTransceiver`], [`OutboxItem`]
+//! and [`InboxItem`] accounts carry a layout version (see
+//! [`Config::version`]). Accounts created before they were versioned have the
+//! layouts in [`crate::legacy`]. Migrating an account grows it to the current
+//! size, fills in the new fields with their defaults, and sets the version.
+//!
+//! [`OutboxRateLimit`] and [`InboxRateLimit`] accounts aren't versioned, but
+//! [`RateLimitState::rate_limit_duration`] was added to them after 3.0.0, so
+//! they are migrated the same way. They get the default duration, which is
+//! what they used implicitly.
+//!
+//! Until they are migrated, old accounts can't be used by the program. After
+//! upgrading it, the program should stay paused until the config, the rate
+//! limits, and the peers and registered transceivers are migrated. Outbox and inbox items can
+//! be migrated as needed, as can the [`ValidatedTransceiverMessage`]s received
+//! (but not redeemed yet) by the transceiver built into this program.
+//!
+//! Only the owner can migrate accounts, as the defaults of some new fields are
+//! a policy decision (e.g. who gets the rent of migrated items back), and some
+//! can't be derived from the old account (e.g. the source chain of an inbox
+//! item, see [`MigrateArgs`]).
+//!
+//! Peers set by the 3.0.0 release also have no [`OutboxChainRateLimit`], which
+//! is created by [`init_outbox_chain_rate_limit`].
+
+use anchor_lang::{prelude::*, system_program, Discriminator};
+use ntt_messages::{
+    chain_id::ChainId,
+    ntt::{AdditionalPayload, NativeTokenTransfer},
+};
+
+use crate::{
+    config::Config,
+    error::NTTError,
+    legacy,
+    messages::ValidatedTransceiverMessage,
+    peer::NttManagerPeer,
+    queue::{
+        inbox::{InboxItem, InboxRateLimit, ReleaseStatus},
+        outbox::{OutboxChainRateLimit, OutboxItem, OutboxRateLimit},
+        rate_limit::RateLimitState,
+    },
+    registered_transceiver::RegisteredTransceiver,
+    transfer::Payload,
+};
+
+#[derive(Accounts)]
+pub struct Migrate<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub owner: Signer<'info>,
+
+    #[account(
+        seeds = [Config::SEED_PREFIX],
+        bump,
+        owner = crate::ID,
+    )]
+    /// CHECK: the config may not be migrated yet (it can be `account` itself),
+    /// so only its owner is read in [`migrate`]. It comes before the fields
+    /// added since, so its offset is the same in every layout.
+    pub config: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        owner = crate::ID,
+    )]
+    /// CHECK: the account can't be deserialized before the migration. The
+    /// discriminator is checked in [`migrate`].
+    pub account: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(AnchorDeserialize, AnchorSerialize)]
+pub struct MigrateArgs {
+    /// The chain the transfer of an [`InboxItem`] came from, which the 3.0.0
+    /// release didn't store. Required when migrating an inbox item, and
+    /// ignored for other accounts.
+    pub source_chain: Option<ChainId>,
+}
+
+pub fn migrate(ctx: Context<Migrate>, args: MigrateArgs) -> Result<()> {
+    {
+        let data = ctx.accounts.config.try_borrow_data()?;
+        if !data.starts_with(&Config::DISCRIMINATOR) {
+            return Err(ErrorCode::AccountDiscriminatorMismatch.into());
+        }
+        let (_bump, owner) = <(u8, Pubkey)>::deserialize(&mut &data[8..])?;
+        if owner != ctx.accounts.owner.key() {
+            return Err(ErrorCode::ConstraintHasOne.into());
+        }
+    }
+
+    let account = &ctx.accounts.account;
+    let discriminator: [u8; 8] = account
+        .try_borrow_data()?
+        .get(..8)
+        .and_then(|d| d.try_into().ok())
+        .ok_or(ErrorCode::AccountDiscriminatorNotFound)?;
+
+    let payer = ctx.accounts.payer.key();
+    match discriminator {
+        Config::DISCRIMINATOR => migrate_account(&ctx, upgrade_config),
+        NttManagerPeer::DISCRIMINATOR => migrate_account(&ctx, upgrade_peer),
+        RegisteredTransceiver::DISCRIMINATOR => {
+            migrate_account(&ctx, upgrade_registered_transceiver)
+        }
+        OutboxItem::DISCRIMINATOR => migrate_account(&ctx, |old| upgrade_outbox_item(old, payer)),
+        InboxItem::DISCRIMINATOR => {
+            let source_chain = args.source_chain.ok_or(NTTError::InvalidChainId)?;
+            migrate_account(&ctx, |old| upgrade_inbox_item(old, source_chain, payer))
+        }
+        ValidatedTransceiverMessage::<NativeTokenTransfer<Payload>>::DISCRIMINATOR => {
+            migrate_account(&ctx, |old| upgrade_transceiver_message(old, payer))
+        }
+        OutboxRateLimit::DISCRIMINATOR => {
+            migrate_account(&ctx, |old: legacy::OutboxRateLimit| OutboxRateLimit {
+                rate_limit: upgrade_rate_limit_state(old.rate_limit),
+            })
+        }
+        InboxRateLimit::DISCRIMINATOR => {
+            migrate_account(&ctx, |old: legacy::InboxRateLimit| InboxRateLimit {
+                bump: old.bump,
+                rate_limit: upgrade_rate_limit_state(old.rate_limit),
+            })
+        }
+        _ => Err(ErrorCode::AccountDiscriminatorMismatch.into()),
+    }
+}
+
+/// Rewrites `ctx.accounts.account` from the `Old` layout to the `New` one,
+/// growing it (and topping up its rent) as needed.
+fn migrate_account<Old, New>(ctx: &Context<Migrate>, upgrade: impl FnOnce(Old) -> New) -> Result<()>
+where
+    Old: AnchorDeserialize + Space,
+    New: AccountSerialize + Space,
+{
+    let account = &ctx.accounts.account;
+    let old_len = 8 + Old::INIT_SPACE;
+    let new_len = 8 + New::INIT_SPACE;
+
+    match account.data_len() {
+        len if len == new_len => return Err(NTTError::AccountAlreadyMigrated.into()),
+        len if len != old_len => return Err(ErrorCode::AccountDidNotDeserialize.into()),
+        _ => {}
+    }
+
+    let old = Old::deserialize(&mut &account.try_borrow_data()?[8..])?;
+
+    let missing_rent = Rent::get()?
+        .minimum_balance(new_len)
+        .saturating_sub(account.lamports());
+    if missing_rent > 0 {
+        system_program::transfer(
+            CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                system_program::Transfer {
+                    from: ctx.accounts.payer.to_account_info(),
+                    to: account.to_account_info(),
+                },
+            ),
+            missing_rent,
+        )?;
+    }
+
+    account.realloc(new_len, true)?;
+    upgrade(old).try_serialize(&mut &mut account.try_borrow_mut_data()?[..])?;
+
+    Ok(())
+}
+
+fn upgrade_config(old: legacy::Config) -> Config {
+    Config {
+        bump: old.bump,
+        owner: old.owner,
+        pending_owner: old.pending_owner,
+        mint: old.mint,
+        token_program: old.token_program,
+        mode: old.mode,
+        chain_id: old.chain_id,
+        next_transceiver_id: old.next_transceiver_id,
+        threshold: old.threshold,
+        enabled_transceivers: old.enabled_transceivers,
+        paused: old.paused,
+        custody: old.custody,
+        precise_queue_release: false,
+        strict_dust: false,
+        pauser: None,
+        outbound_paused: false,
+        inbound_paused: false,
+        rate_limit_admin: None,
+        peer_admin: None,
+        transceiver_admin: None,
+        timelock_delay: 0,
+        guardian: None,
+        compliance_admin: None,
+        denylist_enabled: false,
+        policy_program: None,
+        escrow_timeout: 0,
+        version: Config::LAYOUT_VERSION,
+    }
+}
+
+fn upgrade_peer(old: legacy::NttManagerPeer) -> NttManagerPeer {
+    NttManagerPeer {
+        bump: old.bump,
+        address: old.address,
+        token_decimals: old.token_decimals,
+        paused: false,
+        retired_at: None,
+        closable_at: 0,
+        rent_recipient: Pubkey::default(),
+        legacy_addresses: Vec::new(),
+        inbound_threshold: None,
+        inbound_transceivers: None,
+        version: NttManagerPeer::LAYOUT_VERSION,
+    }
+}
+
+fn upgrade_registered_transceiver(old: legacy::RegisteredTransceiver) -> RegisteredTransceiver {
+    RegisteredTransceiver {
+        bump: old.bump,
+        id: old.id,
+        transceiver_address: old.transceiver_address,
+        version: RegisteredTransceiver::LAYOUT_VERSION,
+    }
+}
+
+/// The rent of a migrated outbox item is returned to `payer` (who paid for
+/// growing it) when the item is closed, as the original payer isn't known.
+fn upgrade_outbox_item(old: legacy::OutboxItem, payer: Pubkey) -> OutboxItem {
+    OutboxItem {
+        amount: old.amount,
+        sender: old.sender,
+        recipient_chain: old.recipient_chain,
+        recipient_ntt_manager: old.recipient_ntt_manager,
+        recipient_address: old.recipient_address,
+        release_timestamp: old.release_timestamp,
+        released: old.released,
+        // the old program didn't track whether items were queued. Treating
+        // them as queued errs on the side of less capacity, as cancelling
+        // them won't return capacity they may not have consumed
+        queued: true,
+        reserved: false,
+        cancelled: false,
+        rent_payer: payer,
+        additional_payload: AdditionalPayload::default(),
+        // old outbox items weren't derived from a sequence
+        sequence: 0,
+        version: OutboxItem::LAYOUT_VERSION,
+    }
+}
+
+/// Like [`upgrade_outbox_item`], the rent is returned to `payer`. The source
+/// chain of the transfer is passed in by the owner, as the old program didn't
+/// store it. Neither did it store the sender, which is left empty.
+fn upgrade_inbox_item(old: legacy::InboxItem, source_chain: ChainId, payer: Pubkey) -> InboxItem {
+    InboxItem {
+        init: old.init,
+        bump: old.bump,
+        amount: old.amount,
+        recipient_address: old.recipient_address,
+        votes: old.votes,
+        release_status: match old.release_status {
+            legacy::ReleaseStatus::NotApproved => ReleaseStatus::NotApproved,
+            legacy::ReleaseStatus::ReleaseAfter(timestamp) => {
+                ReleaseStatus::ReleaseAfter(timestamp)
+            }
+            legacy::ReleaseStatus::Released => ReleaseStatus::Released,
+        },
+        rent_payer: payer,
+        source_chain,
+        sender: [0; 32],
+        additional_payload: AdditionalPayload::default(),
+        version: InboxItem::LAYOUT_VERSION,
+    }
+}
+
+/// Like [`upgrade_outbox_item`], the rent is returned to `payer`. The message
+/// was received before the upgrade, so before any peer could be retired, and
+/// its attestation time is left as zero.
+fn upgrade_transceiver_message(
+    old: legacy::ValidatedTransceiverMessage,
+    payer: Pubkey,
+) -> ValidatedTransceiverMessage<NativeTokenTransfer<Payload>> {
+    ValidatedTransceiverMessage {
+        from_chain: old.from_chain,
+        message: old.into_message_data(),
+        rent_payer: payer,
+        attested_at: 0,
+    }
+}
+
+fn upgrade_rate_limit_state(old: legacy::RateLimitState) -> RateLimitState {
+    RateLimitState {
+        limit: old.limit,
+        capacity_at_last_tx: old.capacity_at_last_tx,
+        last_tx_timestamp: old.last_tx_timestamp,
+        rate_limit_duration: RateLimitState::DEFAULT_RATE_LIMIT_DURATION,
+    }
+}
+
+#[derive(Accounts)]
+#[instruction(args: InitOutboxChainRateLimitArgs)]
+pub struct InitOutboxChainRateLimit<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    #[account(
+        seeds = [NttManagerPeer::SEED_PREFIX, args.chain_id.id.to_be_bytes().as_ref()],
+        bump = peer.bump,
+    )]
+    pub peer: Account<'info, NttManagerPeer>,
+
+    #[account(
+        init,
+        space = 8 + OutboxChainRateLimit::INIT_SPACE,
+        payer = payer,
+        seeds = [OutboxChainRateLimit::SEED_PREFIX, args.chain_id.id.to_be_bytes().as_ref()],
+        bump,
+    )]
+    pub outbox_chain_rate_limit: Account<'info, OutboxChainRateLimit>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(AnchorDeserialize, AnchorSerialize)]
+pub struct InitOutboxChainRateLimitArgs {
+    pub chain_id: ChainId,
+}
+
+/// Creates the [`OutboxChainRateLimit`] of a peer set before it existed, without
+/// a limit of its own, as [`crate::instructions::set_peer`] does. Transfers to
+/// and from the peer fail until it's created.
+///
+/// This instruction is permissionless, as it doesn't change the behaviour of
+/// the rate limits (only the global limit applies until the chain limit is
+/// lowered with [`crate::instructions::set_outbound_chain_limit`]).
+pub fn init_outbox_chain_rate_limit(
+    ctx: Context<InitOutboxChainRateLimit>,
+    _args: InitOutboxChainRateLimitArgs,
+) -> Result<()> {
+    ctx.accounts
+        .outbox_chain_rate_limit
+        .set_inner(OutboxChainRateLimit {
+            bump: ctx.bumps.outbox_chain_rate_limit,
+            rate_limit: RateLimitState::new(u64::MAX),
+        });
+    Ok(())
+}
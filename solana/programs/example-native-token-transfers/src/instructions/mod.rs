@@ -1,15 +1,23 @@
 pub mod admin;
+pub mod cancel_outbound;
+pub mod close;
+pub mod escrow;
 pub mod initialize;
 pub mod luts;
 pub mod mark_outbox_item_as_released;
+pub mod migrate;
 pub mod redeem;
 pub mod release_inbound;
 pub mod transfer;
 
 pub use admin::*;
+pub use cancel_outbound::*;
+pub use close::*;
+pub use escrow::*;
 pub use initialize::*;
 pub use luts::*;
 pub use mark_outbox_item_as_released::*;
+pub use migrate::*;
 pub use redeem::*;
 pub use release_inbound::*;
 pub use transfer::*;
@@ -4,13 +4,15 @@ use ntt_messages::{ntt::NativeTokenTransfer, ntt_manager::NttManagerMessage};
 
 use crate::{
     bitmap::Bitmap,
+    clock::current_timestamp,
     config::*,
     error::NTTError,
+    events::{InboundQuorumReached, InboundTransferQueued, InboundVoteRecorded},
     messages::ValidatedTransceiverMessage,
     peer::NttManagerPeer,
     queue::{
         inbox::{InboxItem, InboxRateLimit, ReleaseStatus},
-        outbox::OutboxRateLimit,
+        outbox::{OutboxChainRateLimit, OutboxRateLimit},
         rate_limit::RateLimitResult,
     },
     registered_transceiver::*,
@@ -30,14 +32,15 @@ pub struct Redeem<'info> {
 
     #[account(
         seeds = [NttManagerPeer::SEED_PREFIX, ValidatedTransceiverMessage::<NativeTokenTransfer<Payload>>::from_chain(&transceiver_message)?.id.to_be_bytes().as_ref()],
-        constraint = peer.address == ValidatedTransceiverMessage::<NativeTokenTransfer<Payload>>::message(&transceiver_message.try_borrow_data()?[..])?.source_ntt_manager() @ NTTError::InvalidNttManagerPeer,
+        constraint = peer.accepts_inbound_from(&ValidatedTransceiverMessage::<NativeTokenTransfer<Payload>>::message(&transceiver_message.try_borrow_data()?[..])?.source_ntt_manager()) @ NTTError::InvalidNttManagerPeer,
         bump = peer.bump,
+        constraint = !peer.paused @ NTTError::PeerPaused,
     )]
     pub peer: Account<'info, NttManagerPeer>,
 
     #[account(
         // check that the message is targeted to this chain
-        constraint = ValidatedTransceiverMessage::<NativeTokenTransfer<Payload>>::message(&transceiver_message.try_borrow_data()?[..])?.ntt_manager_payload().payload.to_chain == config.chain_id @ NTTError::InvalidChainId,
+        constraint = ValidatedTransceiverMessage::<NativeTokenTransfer<Payload>>::message(&transceiver_message.try_borrow_data()?[..])?.ntt_manager_message().native_token_transfer().map_err(NTTError::from)?.to_chain() == config.chain_id @ NTTError::InvalidChainId,
         // check that we're the intended recipient
         constraint = ValidatedTransceiverMessage::<NativeTokenTransfer<Payload>>::message(&transceiver_message.try_borrow_data()?[..])?.recipient_ntt_manager() == crate::ID.to_bytes() @ NTTError::InvalidRecipientNttManager,
         // NOTE: we don't replay protect VAAs. Instead, we replay protect
@@ -49,7 +52,7 @@ pub struct Redeem<'info> {
     pub transceiver_message: UncheckedAccount<'info>,
 
     #[account(
-        constraint = config.enabled_transceivers.get(transceiver.id)? @ NTTError::DisabledTransceiver
+        constraint = peer.enabled_inbound_transceivers(&config).get(transceiver.id)? @ NTTError::DisabledTransceiver
     )]
     pub transceiver: Account<'info, RegisteredTransceiver>,
 
@@ -64,7 +67,7 @@ pub struct Redeem<'info> {
         space = 8 + InboxItem::INIT_SPACE,
         seeds = [
             InboxItem::SEED_PREFIX,
-            ValidatedTransceiverMessage::<NativeTokenTransfer<Payload>>::message(&transceiver_message.try_borrow_data()?[..])?.ntt_manager_payload().keccak256(
+            ValidatedTransceiverMessage::<NativeTokenTransfer<Payload>>::message(&transceiver_message.try_borrow_data()?[..])?.ntt_manager_payload().map_err(NTTError::from)?.keccak256(
                 ValidatedTransceiverMessage::<NativeTokenTransfer<Payload>>::from_chain(&transceiver_message)?
             ).as_ref(),
         ],
@@ -97,6 +100,16 @@ pub struct Redeem<'info> {
     #[account(mut)]
     pub outbox_rate_limit: Account<'info, OutboxRateLimit>,
 
+    #[account(
+        mut,
+        seeds = [
+            OutboxChainRateLimit::SEED_PREFIX,
+            ValidatedTransceiverMessage::<NativeTokenTransfer<Payload>>::from_chain(&transceiver_message)?.id.to_be_bytes().as_ref(),
+        ],
+        bump = outbox_chain_rate_limit.bump,
+    )]
+    pub outbox_chain_rate_limit: Account<'info, OutboxChainRateLimit>,
+
     pub system_program: Program<'info, System>,
 }
 
@@ -124,6 +137,17 @@ pub fn redeem(ctx: Context<Redeem>, _args: RedeemArgs) -> Result<()> {
         .untrim(accs.mint.decimals)
         .map_err(NTTError::from)?;
 
+    // Once the peer is retired, transfers from it that were already in flight
+    // can still be redeemed until the end of the grace period. Transfers
+    // attested after it was retired are rejected.
+    if let Some(retired_at) = accs.peer.retired_at {
+        if transceiver_message.attested_at > retired_at
+            || accs.peer.closable_at <= current_timestamp()
+        {
+            return Err(NTTError::PeerRetired.into());
+        }
+    }
+
     if !accs.inbox_item.init {
         let recipient_address =
             Pubkey::try_from(message.payload.to).map_err(|_| NTTError::InvalidRecipientAddress)?;
@@ -135,32 +159,176 @@ pub fn redeem(ctx: Context<Redeem>, _args: RedeemArgs) -> Result<()> {
             recipient_address,
             release_status: ReleaseStatus::NotApproved,
             votes: Bitmap::new(),
+            rent_payer: accs.payer.key(),
+            source_chain: transceiver_message.from_chain,
+            sender: message.sender,
+            additional_payload: message.payload.additional_payload.clone(),
+            version: InboxItem::LAYOUT_VERSION,
         });
     }
 
     // idempotent
     accs.inbox_item.votes.set(accs.transceiver.id, true)?;
 
-    if accs
+    let votes = accs
         .inbox_item
         .votes
-        .count_enabled_votes(accs.config.enabled_transceivers)
-        < accs.config.threshold
-    {
+        .count_enabled_votes(accs.peer.enabled_inbound_transceivers(&accs.config));
+    let threshold = accs.peer.effective_inbound_threshold(&accs.config);
+
+    emit!(InboundVoteRecorded {
+        inbox_item: accs.inbox_item.key(),
+        from_chain: transceiver_message.from_chain,
+        message_id: message.id,
+        transceiver: accs.transceiver.transceiver_address,
+        transceiver_index: accs.transceiver.id,
+        votes,
+        threshold,
+    });
+
+    if votes < threshold {
         return Ok(());
     }
 
-    let release_timestamp = match accs.inbox_rate_limit.rate_limit.consume_or_delay(amount) {
+    approve(
+        &accs.config,
+        &mut accs.inbox_item,
+        &mut accs.inbox_rate_limit,
+        &mut accs.outbox_rate_limit,
+        &mut accs.outbox_chain_rate_limit,
+    )
+}
+
+/// Approves an inbox item that has reached quorum: it consumes the inbound
+/// rate limit (or queues the transfer if it's exceeded) and sets the time
+/// the transfer can be released at.
+fn approve(
+    config: &Config,
+    inbox_item: &mut Account<InboxItem>,
+    inbox_rate_limit: &mut InboxRateLimit,
+    outbox_rate_limit: &mut OutboxRateLimit,
+    outbox_chain_rate_limit: &mut OutboxChainRateLimit,
+) -> Result<()> {
+    let amount = inbox_item.amount;
+    let rate_limit_result = if config.precise_queue_release {
+        inbox_rate_limit.rate_limit.consume_or_reserve(amount)
+    } else {
+        inbox_rate_limit.rate_limit.consume_or_delay(amount)
+    };
+    let release_timestamp = match rate_limit_result {
         RateLimitResult::Consumed(now) => {
-            // When receiving a transfer, we refill the outbound rate limit with
-            // the same amount (we call this "backflow")
-            accs.outbox_rate_limit.rate_limit.refill(now, amount);
+            // When receiving a transfer, we refill the outbound rate limit
+            // (both the global one and the one for the source chain) with the
+            // same amount (we call this "backflow")
+            outbox_rate_limit.rate_limit.refill(now, amount);
+            outbox_chain_rate_limit.rate_limit.refill(now, amount);
             now
         }
-        RateLimitResult::Delayed(release_timestamp) => release_timestamp,
+        RateLimitResult::Reserved(release_timestamp) => {
+            // The reserved capacity counts as consumed, so the backflow is
+            // refilled too
+            let now = current_timestamp();
+            outbox_rate_limit.rate_limit.refill(now, amount);
+            outbox_chain_rate_limit.rate_limit.refill(now, amount);
+            emit!(InboundTransferQueued {
+                inbox_item: inbox_item.key(),
+                release_timestamp,
+            });
+            release_timestamp
+        }
+        RateLimitResult::Delayed(release_timestamp) => {
+            emit!(InboundTransferQueued {
+                inbox_item: inbox_item.key(),
+                release_timestamp,
+            });
+            release_timestamp
+        }
     };
 
-    accs.inbox_item.release_after(release_timestamp)?;
+    inbox_item.release_after(release_timestamp)?;
+
+    emit!(InboundQuorumReached {
+        inbox_item: inbox_item.key(),
+        release_timestamp,
+    });
 
     Ok(())
 }
+
+#[derive(Accounts)]
+pub struct ReevaluateInboxItem<'info> {
+    #[account(
+        constraint = config.threshold > 0 @ NTTError::ZeroThreshold
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        seeds = [NttManagerPeer::SEED_PREFIX, inbox_item.source_chain.id.to_be_bytes().as_ref()],
+        bump = peer.bump,
+        constraint = !peer.paused @ NTTError::PeerPaused,
+    )]
+    pub peer: Account<'info, NttManagerPeer>,
+
+    #[account(
+        mut,
+        constraint = inbox_item.release_status == ReleaseStatus::NotApproved @ NTTError::TransferCannotBeRedeemed,
+    )]
+    pub inbox_item: Account<'info, InboxItem>,
+
+    #[account(
+        mut,
+        seeds = [
+            InboxRateLimit::SEED_PREFIX,
+            inbox_item.source_chain.id.to_be_bytes().as_ref(),
+        ],
+        bump = inbox_rate_limit.bump,
+    )]
+    pub inbox_rate_limit: Account<'info, InboxRateLimit>,
+
+    #[account(mut)]
+    pub outbox_rate_limit: Account<'info, OutboxRateLimit>,
+
+    #[account(
+        mut,
+        seeds = [
+            OutboxChainRateLimit::SEED_PREFIX,
+            inbox_item.source_chain.id.to_be_bytes().as_ref(),
+        ],
+        bump = outbox_chain_rate_limit.bump,
+    )]
+    pub outbox_chain_rate_limit: Account<'info, OutboxChainRateLimit>,
+}
+
+/// Recounts the votes of an inbox item that hasn't reached quorum yet, and
+/// approves it if it has now. Votes are only counted when they are cast in
+/// [`redeem`], so an item can have enough votes but still be unapproved after
+/// the threshold is lowered or a transceiver is re-enabled.
+///
+/// This is permissionless.
+pub fn reevaluate_inbox_item(ctx: Context<ReevaluateInboxItem>) -> Result<()> {
+    let accs = ctx.accounts;
+
+    // as in [`redeem`], transfers from a retired peer can only be approved
+    // until the end of its grace period. The inbox item was created by
+    // [`redeem`], which already checked that the transfer was attested before
+    // the peer was retired
+    if accs.peer.retired_at.is_some() && accs.peer.closable_at <= current_timestamp() {
+        return Err(NTTError::PeerRetired.into());
+    }
+
+    let votes = accs
+        .inbox_item
+        .votes
+        .count_enabled_votes(accs.peer.enabled_inbound_transceivers(&accs.config));
+    if votes < accs.peer.effective_inbound_threshold(&accs.config) {
+        return Err(NTTError::ThresholdNotReached.into());
+    }
+
+    approve(
+        &accs.config,
+        &mut accs.inbox_item,
+        &mut accs.inbox_rate_limit,
+        &mut accs.outbox_rate_limit,
+        &mut accs.outbox_chain_rate_limit,
+    )
+}
@@ -1,11 +1,17 @@
 use anchor_lang::prelude::*;
 use anchor_spl::token_interface;
-use ntt_messages::mode::Mode;
-use spl_token_2022::onchain;
+use ntt_messages::{chain_id::ChainId, mode::Mode};
+use spl_token_2022::{
+    onchain,
+    solana_zk_token_sdk::curve25519::edwards::{validate_edwards, PodEdwardsPoint},
+};
 
 use crate::{
+    compliance::{self, CheckTransferArgs, TransferDirection},
     config::*,
     error::NTTError,
+    events::{InboundTransferQuarantined, InboundTransferReleased},
+    peer::NttManagerPeer,
     queue::inbox::{InboxItem, ReleaseStatus},
     spl_multisig::SplMultisig,
 };
@@ -15,11 +21,21 @@ pub struct ReleaseInbound<'info> {
     #[account(mut)]
     pub payer: Signer<'info>,
 
+    #[account(
+        constraint = !config.inbound_paused @ NTTError::InboundPaused,
+    )]
     pub config: NotPausedConfig<'info>,
 
     #[account(mut)]
     pub inbox_item: Account<'info, InboxItem>,
 
+    #[account(
+        seeds = [NttManagerPeer::SEED_PREFIX, inbox_item.source_chain.id.to_be_bytes().as_ref()],
+        bump = peer.bump,
+        constraint = !peer.paused @ NTTError::PeerPaused,
+    )]
+    pub peer: Account<'info, NttManagerPeer>,
+
     #[account(
         mut,
         associated_token::authority = inbox_item.recipient_address,
@@ -57,6 +73,22 @@ pub struct ReleaseInboundArgs {
     pub revert_when_not_ready: bool,
 }
 
+/// Transfers with an additional payload, or to an address off the ed25519
+/// curve (which may be the PDA of a recipient program, see
+/// [`crate::RECIPIENT_SEED`]), are meant to be delivered with one of the
+/// `release_inbound_*_and_call` instructions, so they can't be released
+/// without calling the recipient.
+fn check_release_without_call(inbox_item: &InboxItem) -> Result<()> {
+    // NOTE: [`Pubkey::is_on_curve`] is not available on-chain, so we go
+    // through the curve25519 syscall instead
+    let on_curve = validate_edwards(&PodEdwardsPoint(inbox_item.recipient_address.to_bytes()));
+    require!(
+        inbox_item.additional_payload.bytes.is_empty() && on_curve,
+        NTTError::RecipientCallRequired
+    );
+    Ok(())
+}
+
 // Burn/mint
 
 #[derive(Accounts)]
@@ -86,9 +118,20 @@ pub struct ReleaseInboundMint<'info> {
 pub fn release_inbound_mint<'info>(
     ctx: Context<'_, '_, '_, 'info, ReleaseInboundMint<'info>>,
     args: ReleaseInboundArgs,
+) -> Result<()> {
+    check_release_without_call(&ctx.accounts.common.inbox_item)?;
+    mint_inbound(ctx, args)
+}
+
+fn mint_inbound<'info>(
+    ctx: Context<'_, '_, '_, 'info, ReleaseInboundMint<'info>>,
+    args: ReleaseInboundArgs,
 ) -> Result<()> {
     let inbox_item = release_inbox_item(
         &mut ctx.accounts.common.inbox_item,
+        &ctx.accounts.common.config,
+        &ctx.accounts.common.mint.to_account_info(),
+        ctx.remaining_accounts,
         args.revert_when_not_ready,
     )?;
     if inbox_item.is_none() {
@@ -150,10 +193,16 @@ pub fn release_inbound_mint<'info>(
         ctx.accounts.common.mint.decimals,
         token_authority_sig,
     )?;
+
+    emit!(InboundTransferReleased {
+        inbox_item: ctx.accounts.common.inbox_item.key(),
+        recipient: ctx.accounts.common.inbox_item.recipient_address,
+        amount: ctx.accounts.common.inbox_item.amount,
+    });
     Ok(())
 }
 
-fn mint_to_custody_from_token_authority<'info>(
+pub(crate) fn mint_to_custody_from_token_authority<'info>(
     token_program: AccountInfo<'info>,
     mint: AccountInfo<'info>,
     custody: AccountInfo<'info>,
@@ -176,7 +225,7 @@ fn mint_to_custody_from_token_authority<'info>(
     Ok(())
 }
 
-fn mint_to_custody_from_multisig_token_authority<'info>(
+pub(crate) fn mint_to_custody_from_multisig_token_authority<'info>(
     token_program: AccountInfo<'info>,
     mint: AccountInfo<'info>,
     custody: AccountInfo<'info>,
@@ -222,9 +271,20 @@ pub struct ReleaseInboundUnlock<'info> {
 pub fn release_inbound_unlock<'info>(
     ctx: Context<'_, '_, '_, 'info, ReleaseInboundUnlock<'info>>,
     args: ReleaseInboundArgs,
+) -> Result<()> {
+    check_release_without_call(&ctx.accounts.common.inbox_item)?;
+    unlock_inbound(ctx, args)
+}
+
+fn unlock_inbound<'info>(
+    ctx: Context<'_, '_, '_, 'info, ReleaseInboundUnlock<'info>>,
+    args: ReleaseInboundArgs,
 ) -> Result<()> {
     let inbox_item = release_inbox_item(
         &mut ctx.accounts.common.inbox_item,
+        &ctx.accounts.common.config,
+        &ctx.accounts.common.mint.to_account_info(),
+        ctx.remaining_accounts,
         args.revert_when_not_ready,
     )?;
     if inbox_item.is_none() {
@@ -247,22 +307,266 @@ pub fn release_inbound_unlock<'info>(
             &[ctx.bumps.common.token_authority],
         ]],
     )?;
+
+    emit!(InboundTransferReleased {
+        inbox_item: ctx.accounts.common.inbox_item.key(),
+        recipient: ctx.accounts.common.inbox_item.recipient_address,
+        amount: ctx.accounts.common.inbox_item.amount,
+    });
     Ok(())
 }
 
-fn release_inbox_item(
-    inbox_item: &mut InboxItem,
+// Release and call
+
+/// The instruction data of the call into the recipient program, after the
+/// [`ReceiveNttTransferArgs::DISCRIMINATOR`].
+///
+/// The accounts passed to the recipient program are:
+/// 0. the recipient call authority (signer, see [`crate::RECIPIENT_CALL_AUTHORITY_SEED`])
+/// 1. the (released) inbox item
+/// 2. the recipient token account (writable)
+/// 3. the mint
+/// 4. ..and the last [`ReleaseInboundAndCallArgs::recipient_accounts`]
+///    remaining accounts passed to the release instruction.
+///
+/// This matches an anchor instruction called `receive_ntt_transfer` that takes
+/// a single argument of this type.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct ReceiveNttTransferArgs {
+    pub source_chain: ChainId,
+    pub sender: [u8; 32],
+    /// The amount that was released to the recipient token account.
+    pub amount: u64,
+    pub additional_payload: Vec<u8>,
+}
+
+impl ReceiveNttTransferArgs {
+    /// `sha256("global:receive_ntt_transfer")[..8]`
+    pub const DISCRIMINATOR: [u8; 8] = [75, 173, 1, 129, 164, 216, 244, 65];
+}
+
+#[derive(AnchorDeserialize, AnchorSerialize)]
+pub struct ReleaseInboundAndCallArgs {
+    pub revert_when_not_ready: bool,
+    /// The number of remaining accounts (at the end) that are passed to the
+    /// recipient program. The ones before them are used by the release itself
+    /// (e.g. the transfer hook and denylist accounts).
+    pub recipient_accounts: u8,
+}
+
+impl ReleaseInboundAndCallArgs {
+    /// Splits `remaining_accounts` into the ones used by the release and the
+    /// ones passed to the recipient program.
+    fn split_remaining_accounts<'a, 'info>(
+        &self,
+        remaining_accounts: &'a [AccountInfo<'info>],
+    ) -> Result<(&'a [AccountInfo<'info>], &'a [AccountInfo<'info>])> {
+        let release_accounts = remaining_accounts
+            .len()
+            .checked_sub(self.recipient_accounts.into())
+            .ok_or(ErrorCode::AccountNotEnoughKeys)?;
+        Ok(remaining_accounts.split_at(release_accounts))
+    }
+}
+
+#[derive(Accounts)]
+pub struct RecipientCall<'info> {
+    #[account(
+        seeds = [crate::RECIPIENT_CALL_AUTHORITY_SEED],
+        bump,
+    )]
+    /// CHECK: The seeds constraint ensures that this is the correct address
+    pub call_authority: UncheckedAccount<'info>,
+
+    #[account(executable)]
+    /// CHECK: checked against the recipient address of the inbox item
+    pub recipient_program: UncheckedAccount<'info>,
+}
+
+#[derive(Accounts)]
+pub struct ReleaseInboundMintAndCall<'info> {
+    #[account(
+        constraint = Pubkey::find_program_address(&[crate::RECIPIENT_SEED], &call.recipient_program.key()).0
+            == release.common.inbox_item.recipient_address
+            @ NTTError::InvalidRecipientProgram,
+    )]
+    pub release: ReleaseInboundMint<'info>,
+
+    pub call: RecipientCall<'info>,
+}
+
+/// Same as [`release_inbound_mint`], but once the tokens are minted, the
+/// recipient program is called with the details of the transfer (see
+/// [`ReceiveNttTransferArgs`]).
+///
+/// Transfers with an additional payload, or to a PDA, can only be released
+/// this way (see [`check_release_without_call`]).
+pub fn release_inbound_mint_and_call<'info>(
+    ctx: Context<'_, '_, '_, 'info, ReleaseInboundMintAndCall<'info>>,
+    args: ReleaseInboundAndCallArgs,
+) -> Result<()> {
+    let call_authority_bump = ctx.bumps.call.call_authority;
+    let (release_accounts, recipient_accounts) =
+        args.split_remaining_accounts(ctx.remaining_accounts)?;
+    mint_inbound(
+        Context::new(
+            ctx.program_id,
+            &mut ctx.accounts.release,
+            release_accounts,
+            ctx.bumps.release,
+        ),
+        ReleaseInboundArgs {
+            revert_when_not_ready: args.revert_when_not_ready,
+        },
+    )?;
+    call_recipient(
+        &ctx.accounts.release.common,
+        &ctx.accounts.call,
+        call_authority_bump,
+        recipient_accounts,
+    )
+}
+
+#[derive(Accounts)]
+pub struct ReleaseInboundUnlockAndCall<'info> {
+    #[account(
+        constraint = Pubkey::find_program_address(&[crate::RECIPIENT_SEED], &call.recipient_program.key()).0
+            == release.common.inbox_item.recipient_address
+            @ NTTError::InvalidRecipientProgram,
+    )]
+    pub release: ReleaseInboundUnlock<'info>,
+
+    pub call: RecipientCall<'info>,
+}
+
+/// Same as [`release_inbound_unlock`], but once the tokens are unlocked, the
+/// recipient program is called with the details of the transfer (see
+/// [`ReceiveNttTransferArgs`]).
+///
+/// Transfers with an additional payload, or to a PDA, can only be released
+/// this way (see [`check_release_without_call`]).
+pub fn release_inbound_unlock_and_call<'info>(
+    ctx: Context<'_, '_, '_, 'info, ReleaseInboundUnlockAndCall<'info>>,
+    args: ReleaseInboundAndCallArgs,
+) -> Result<()> {
+    let call_authority_bump = ctx.bumps.call.call_authority;
+    let (release_accounts, recipient_accounts) =
+        args.split_remaining_accounts(ctx.remaining_accounts)?;
+    unlock_inbound(
+        Context::new(
+            ctx.program_id,
+            &mut ctx.accounts.release,
+            release_accounts,
+            ctx.bumps.release,
+        ),
+        ReleaseInboundArgs {
+            revert_when_not_ready: args.revert_when_not_ready,
+        },
+    )?;
+    call_recipient(
+        &ctx.accounts.release.common,
+        &ctx.accounts.call,
+        call_authority_bump,
+        recipient_accounts,
+    )
+}
+
+fn call_recipient<'info>(
+    common: &ReleaseInbound<'info>,
+    call: &RecipientCall<'info>,
+    call_authority_bump: u8,
+    remaining_accounts: &[AccountInfo<'info>],
+) -> Result<()> {
+    // not released yet (and `revert_when_not_ready` was false)
+    if common.inbox_item.release_status != ReleaseStatus::Released {
+        return Ok(());
+    }
+
+    // persist the release before handing over control, so the recipient
+    // program sees the inbox item as released
+    common.inbox_item.exit(&crate::ID)?;
+
+    let args = ReceiveNttTransferArgs {
+        source_chain: common.inbox_item.source_chain,
+        sender: common.inbox_item.sender,
+        amount: common.inbox_item.amount,
+        additional_payload: common.inbox_item.additional_payload.bytes.clone(),
+    };
+    let mut data = ReceiveNttTransferArgs::DISCRIMINATOR.to_vec();
+    args.serialize(&mut data)?;
+
+    let mut accounts = vec![
+        AccountMeta::new_readonly(call.call_authority.key(), true),
+        AccountMeta::new_readonly(common.inbox_item.key(), false),
+        AccountMeta::new(common.recipient.key(), false),
+        AccountMeta::new_readonly(common.mint.key(), false),
+    ];
+    accounts.extend(remaining_accounts.iter().map(|info| AccountMeta {
+        pubkey: info.key(),
+        is_signer: info.is_signer,
+        is_writable: info.is_writable,
+    }));
+
+    let mut account_infos = vec![
+        call.call_authority.to_account_info(),
+        common.inbox_item.to_account_info(),
+        common.recipient.to_account_info(),
+        common.mint.to_account_info(),
+    ];
+    account_infos.extend_from_slice(remaining_accounts);
+
+    solana_program::program::invoke_signed(
+        &solana_program::instruction::Instruction {
+            program_id: call.recipient_program.key(),
+            accounts,
+            data,
+        },
+        &account_infos,
+        &[&[crate::RECIPIENT_CALL_AUTHORITY_SEED, &[call_authority_bump]]],
+    )?;
+
+    Ok(())
+}
+
+/// Marks the inbox item as released if it's ready, and returns it if so.
+///
+/// If the sender or the recipient is on the denylist, or the policy program
+/// denies the transfer, the item is quarantined instead (and `None` is returned, regardless of `revert_when_not_ready`, so
+/// that the quarantine persists).
+fn release_inbox_item<'a, 'info>(
+    inbox_item: &'a mut Account<'info, InboxItem>,
+    config: &Config,
+    mint: &AccountInfo<'info>,
+    remaining_accounts: &[AccountInfo<'info>],
     revert_when_not_ready: bool,
-) -> Result<Option<&mut InboxItem>> {
+) -> Result<Option<&'a mut Account<'info, InboxItem>>> {
     if inbox_item.try_release()? {
+        let check = CheckTransferArgs {
+            direction: TransferDirection::Inbound,
+            chain_id: inbox_item.source_chain,
+            sender: inbox_item.sender,
+            recipient: inbox_item.recipient_address.to_bytes(),
+            amount: inbox_item.amount,
+        };
+        if compliance::is_denied(config, mint, check, remaining_accounts)? {
+            inbox_item.release_status = ReleaseStatus::Quarantined;
+            emit!(InboundTransferQuarantined {
+                inbox_item: inbox_item.key(),
+            });
+            return Ok(None);
+        }
         Ok(Some(inbox_item))
     } else if revert_when_not_ready {
         match inbox_item.release_status {
             ReleaseStatus::NotApproved => Err(NTTError::TransferNotApproved.into()),
             ReleaseStatus::ReleaseAfter(_) => Err(NTTError::CantReleaseYet.into()),
-            // Unreachable: if released, [`InboxItem::try_release`] will return an Error immediately
-            // rather than Ok(bool).
+            // Unreachable: in these states, [`InboxItem::try_release`] will return an Error
+            // immediately rather than Ok(bool).
             ReleaseStatus::Released => Err(NTTError::TransferAlreadyRedeemed.into()),
+            ReleaseStatus::Frozen(_) => Err(NTTError::InboxItemFrozen.into()),
+            ReleaseStatus::Rejected => Err(NTTError::InboxItemRejected.into()),
+            ReleaseStatus::Quarantined => Err(NTTError::InboxItemQuarantined.into()),
+            ReleaseStatus::Escrowed(_) => Err(NTTError::InboxItemEscrowed.into()),
         }
     } else {
         Ok(None)
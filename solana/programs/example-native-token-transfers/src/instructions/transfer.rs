@@ -9,25 +9,36 @@
 //! amount of duplication between `TransferBurn` and `TransferLock` (exactly the
 //! accounts whose constraints refer to the instruction data).
 //!
+//! Both types of transfers come in a variant that attaches an additional
+//! payload to the transfer (`transfer_burn_with_payload` and
+//! `transfer_lock_with_payload`). These have their own account structs for the
+//! same reason.
+//!
 //! See the documentation of [`crate::SESSION_AUTHORITY_SEED`] for an
 //! explanation of the approval flow.
 
 #![allow(clippy::too_many_arguments)]
 use anchor_lang::prelude::*;
 use anchor_spl::token_interface;
-use ntt_messages::{chain_id::ChainId, mode::Mode, trimmed_amount::TrimmedAmount};
+use ntt_messages::{
+    chain_id::ChainId, mode::Mode, ntt::AdditionalPayload, trimmed_amount::TrimmedAmount,
+};
 use spl_token_2022::onchain;
 
 use crate::{
     bitmap::Bitmap,
+    clock::current_timestamp,
+    compliance::{self, CheckTransferArgs, TransferDirection},
     config::*,
     error::NTTError,
+    events::{OutboundTransferQueued, TransferSent},
     peer::NttManagerPeer,
     queue::{
         inbox::InboxRateLimit,
-        outbox::{OutboxItem, OutboxRateLimit},
-        rate_limit::RateLimitResult,
+        outbox::{OutboxChainRateLimit, OutboxItem, OutboxRateLimit, OutboxSequence},
+        rate_limit::{RateLimitResult, RateLimitState},
     },
+    transfer::transfer_fee_config,
 };
 
 // this will burn the funds and create an account that either allows sending the
@@ -40,6 +51,7 @@ pub struct Transfer<'info> {
     // Ensure that there exists at least one enabled transceiver
     #[account(
         constraint = !config.enabled_transceivers.is_empty() @ NTTError::NoRegisteredTransceivers,
+        constraint = !config.outbound_paused @ NTTError::OutboundPaused,
     )]
     pub config: NotPausedConfig<'info>,
 
@@ -60,11 +72,28 @@ pub struct Transfer<'info> {
 
     pub token_program: Interface<'info, token_interface::TokenInterface>,
 
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = 8 + OutboxSequence::INIT_SPACE,
+        seeds = [OutboxSequence::SEED_PREFIX],
+        bump,
+    )]
+    pub outbox_sequence: Account<'info, OutboxSequence>,
+
     #[account(
         init,
         payer = payer,
         space = 8 + OutboxItem::INIT_SPACE,
+        seeds = [
+            OutboxItem::SEED_PREFIX,
+            outbox_sequence.next.to_be_bytes().as_ref(),
+        ],
+        bump,
     )]
+    /// NOTE: the item is derived from the next sequence number, so if another
+    /// transfer takes it first, this transfer fails instead of being sent
+    /// twice. Clients can then retry with the new sequence number.
     pub outbox_item: Account<'info, OutboxItem>,
 
     #[account(mut)]
@@ -107,6 +136,56 @@ impl TransferArgs {
     }
 }
 
+/// Same as [`TransferArgs`], with an additional payload attached to the
+/// transfer, to be interpreted by the recipient.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct TransferWithPayloadArgs {
+    pub amount: u64,
+    pub recipient_chain: ChainId,
+    pub recipient_address: [u8; 32],
+    pub should_queue: bool,
+    pub additional_payload: Vec<u8>,
+}
+
+impl TransferWithPayloadArgs {
+    /// NOTE: with an empty payload, this is the same hash as
+    /// [`TransferArgs::keccak256`], so the same session authority is used.
+    pub fn keccak256(&self) -> solana_program::keccak::Hash {
+        let TransferWithPayloadArgs {
+            amount,
+            recipient_chain,
+            recipient_address,
+            should_queue,
+            additional_payload,
+        } = self;
+        solana_program::keccak::hashv(&[
+            amount.to_be_bytes().as_ref(),
+            recipient_chain.id.to_be_bytes().as_ref(),
+            recipient_address,
+            &[u8::from(*should_queue)],
+            additional_payload,
+        ])
+    }
+}
+
+impl From<TransferArgs> for TransferWithPayloadArgs {
+    fn from(args: TransferArgs) -> Self {
+        let TransferArgs {
+            amount,
+            recipient_chain,
+            recipient_address,
+            should_queue,
+        } = args;
+        TransferWithPayloadArgs {
+            amount,
+            recipient_chain,
+            recipient_address,
+            should_queue,
+            additional_payload: Vec::new(),
+        }
+    }
+}
+
 // Burn/mint
 
 #[derive(Accounts)]
@@ -126,9 +205,18 @@ pub struct TransferBurn<'info> {
     // have access to the instruction args
     pub inbox_rate_limit: Account<'info, InboxRateLimit>,
 
+    #[account(
+        mut,
+        seeds = [OutboxChainRateLimit::SEED_PREFIX, args.recipient_chain.id.to_be_bytes().as_ref()],
+        bump = outbox_chain_rate_limit.bump,
+    )]
+    pub outbox_chain_rate_limit: Account<'info, OutboxChainRateLimit>,
+
     #[account(
         seeds = [NttManagerPeer::SEED_PREFIX, args.recipient_chain.id.to_be_bytes().as_ref()],
         bump = peer.bump,
+        constraint = !peer.paused @ NTTError::PeerPaused,
+        constraint = peer.retired_at.is_none() @ NTTError::PeerRetired,
     )]
     pub peer: Account<'info, NttManagerPeer>,
 
@@ -159,23 +247,125 @@ pub fn transfer_burn<'info>(
     args: TransferArgs,
 ) -> Result<()> {
     let accs = ctx.accounts;
+    burn_and_insert_into_outbox(
+        &mut accs.common,
+        &mut accs.inbox_rate_limit,
+        &mut accs.outbox_chain_rate_limit,
+        &accs.peer,
+        &accs.session_authority,
+        ctx.bumps.session_authority,
+        &accs.token_authority,
+        ctx.bumps.token_authority,
+        ctx.remaining_accounts,
+        args.into(),
+    )
+}
+
+#[derive(Accounts)]
+#[instruction(args: TransferWithPayloadArgs)]
+pub struct TransferBurnWithPayload<'info> {
+    #[account(
+        constraint = common.config.mode == Mode::Burning @ NTTError::InvalidMode,
+    )]
+    pub common: Transfer<'info>,
 
-    let TransferArgs {
-        mut amount,
+    #[account(
+        mut,
+        seeds = [InboxRateLimit::SEED_PREFIX, args.recipient_chain.id.to_be_bytes().as_ref()],
+        bump = inbox_rate_limit.bump,
+    )]
+    pub inbox_rate_limit: Account<'info, InboxRateLimit>,
+
+    #[account(
+        mut,
+        seeds = [OutboxChainRateLimit::SEED_PREFIX, args.recipient_chain.id.to_be_bytes().as_ref()],
+        bump = outbox_chain_rate_limit.bump,
+    )]
+    pub outbox_chain_rate_limit: Account<'info, OutboxChainRateLimit>,
+
+    #[account(
+        seeds = [NttManagerPeer::SEED_PREFIX, args.recipient_chain.id.to_be_bytes().as_ref()],
+        bump = peer.bump,
+        constraint = !peer.paused @ NTTError::PeerPaused,
+        constraint = peer.retired_at.is_none() @ NTTError::PeerRetired,
+    )]
+    pub peer: Account<'info, NttManagerPeer>,
+
+    #[account(
+        seeds = [
+            crate::SESSION_AUTHORITY_SEED,
+            common.from.owner.as_ref(),
+            args.keccak256().as_ref()
+        ],
+        bump,
+    )]
+    /// CHECK: The seeds constraint enforces that this is the correct account.
+    /// See [`crate::SESSION_AUTHORITY_SEED`] for an explanation of the flow.
+    pub session_authority: UncheckedAccount<'info>,
+
+    #[account(
+        seeds = [crate::TOKEN_AUTHORITY_SEED],
+        bump,
+    )]
+    /// CHECK: The seeds constraint enforces that this is the correct account.
+    pub token_authority: UncheckedAccount<'info>,
+}
+
+/// Same as [`transfer_burn`], but attaches `args.additional_payload` to the
+/// transfer.
+pub fn transfer_burn_with_payload<'info>(
+    ctx: Context<'_, '_, '_, 'info, TransferBurnWithPayload<'info>>,
+    args: TransferWithPayloadArgs,
+) -> Result<()> {
+    let accs = ctx.accounts;
+    burn_and_insert_into_outbox(
+        &mut accs.common,
+        &mut accs.inbox_rate_limit,
+        &mut accs.outbox_chain_rate_limit,
+        &accs.peer,
+        &accs.session_authority,
+        ctx.bumps.session_authority,
+        &accs.token_authority,
+        ctx.bumps.token_authority,
+        ctx.remaining_accounts,
+        args,
+    )
+}
+
+fn burn_and_insert_into_outbox<'info>(
+    common: &mut Transfer<'info>,
+    inbox_rate_limit: &mut InboxRateLimit,
+    outbox_chain_rate_limit: &mut OutboxChainRateLimit,
+    peer: &NttManagerPeer,
+    session_authority: &UncheckedAccount<'info>,
+    session_authority_bump: u8,
+    token_authority: &UncheckedAccount<'info>,
+    token_authority_bump: u8,
+    remaining_accounts: &[AccountInfo<'info>],
+    args: TransferWithPayloadArgs,
+) -> Result<()> {
+    let session_authority_hash = args.keccak256();
+
+    let TransferWithPayloadArgs {
+        amount,
         recipient_chain,
         recipient_address,
         should_queue,
+        additional_payload,
     } = args;
 
-    // TODO: should we revert if we have dust?
-    let trimmed_amount = TrimmedAmount::remove_dust(
-        &mut amount,
-        accs.common.mint.decimals,
-        accs.peer.token_decimals,
-    )
-    .map_err(NTTError::from)?;
+    let additional_payload = AdditionalPayload::new(additional_payload)
+        .map_err(|_| NTTError::AdditionalPayloadTooLong)?;
+
+    check_parties(
+        common,
+        recipient_chain,
+        recipient_address,
+        amount,
+        remaining_accounts,
+    )?;
 
-    let before = accs.common.custody.amount;
+    let before = common.custody.amount;
 
     // NOTE: burning tokens is a two-step process:
     // 1. Transfer the tokens to the custody account
@@ -193,65 +383,51 @@ pub fn transfer_burn<'info>(
     // (mint to custody, *then* transfer to recipient).
 
     // Step 1: transfer to custody account
-    onchain::invoke_transfer_checked(
-        &accs.common.token_program.key(),
-        accs.common.from.to_account_info(),
-        accs.common.mint.to_account_info(),
-        accs.common.custody.to_account_info(),
-        accs.session_authority.to_account_info(),
-        ctx.remaining_accounts,
+    let (amount, trimmed_amount) = transfer_to_custody(
+        common,
+        peer,
+        session_authority,
+        session_authority_bump,
+        &session_authority_hash,
+        remaining_accounts,
         amount,
-        accs.common.mint.decimals,
-        &[&[
-            crate::SESSION_AUTHORITY_SEED,
-            accs.common.from.owner.as_ref(),
-            args.keccak256().as_ref(),
-            &[ctx.bumps.session_authority],
-        ]],
     )?;
 
-    // Step 2: burn the tokens from the custody account
+    // Step 2: burn the tokens from the custody account. Only what arrived is
+    // burned, which is less than what was transferred if the token has fees.
     token_interface::burn(
         CpiContext::new_with_signer(
-            accs.common.token_program.to_account_info(),
+            common.token_program.to_account_info(),
             token_interface::Burn {
-                mint: accs.common.mint.to_account_info(),
-                from: accs.common.custody.to_account_info(),
-                authority: accs.token_authority.to_account_info(),
+                mint: common.mint.to_account_info(),
+                from: common.custody.to_account_info(),
+                authority: token_authority.to_account_info(),
             },
-            &[&[crate::TOKEN_AUTHORITY_SEED, &[ctx.bumps.token_authority]]],
+            &[&[crate::TOKEN_AUTHORITY_SEED, &[token_authority_bump]]],
         ),
         amount,
     )?;
 
-    accs.common.custody.reload()?;
-    let after = accs.common.custody.amount;
-
-    // NOTE: we currently do not support tokens with fees. Support could be
-    // added, but it would require the client to calculate the amount _before_
-    // paying fees that results in an amount that can safely be trimmed.
-    // Otherwise, if the amount after paying fees has dust, then that amount
-    // would be lost.
-    // To support fee tokens, we would first transfer the amount, _then_ assert
-    // that the resulting amount has no dust (instead of removing dust before
-    // the transfer like we do now). We would also need to burn the new amount
-    // _after_ paying fees so as to not burn more than what was transferred to
-    // the custody.
+    common.custody.reload()?;
+    let after = common.custody.amount;
+
     if after != before {
         return Err(NTTError::BadAmountAfterBurn.into());
     }
 
-    let recipient_ntt_manager = accs.peer.address;
+    let recipient_ntt_manager = peer.address;
 
     insert_into_outbox(
-        &mut accs.common,
-        &mut accs.inbox_rate_limit,
+        common,
+        inbox_rate_limit,
+        outbox_chain_rate_limit,
         amount,
         trimmed_amount,
         recipient_chain,
         recipient_ntt_manager,
         recipient_address,
         should_queue,
+        additional_payload,
     )
 }
 
@@ -274,9 +450,18 @@ pub struct TransferLock<'info> {
     // have access to the instruction args
     pub inbox_rate_limit: Account<'info, InboxRateLimit>,
 
+    #[account(
+        mut,
+        seeds = [OutboxChainRateLimit::SEED_PREFIX, args.recipient_chain.id.to_be_bytes().as_ref()],
+        bump = outbox_chain_rate_limit.bump,
+    )]
+    pub outbox_chain_rate_limit: Account<'info, OutboxChainRateLimit>,
+
     #[account(
         seeds = [NttManagerPeer::SEED_PREFIX, args.recipient_chain.id.to_be_bytes().as_ref()],
         bump = peer.bump,
+        constraint = !peer.paused @ NTTError::PeerPaused,
+        constraint = peer.retired_at.is_none() @ NTTError::PeerRetired,
     )]
     pub peer: Account<'info, NttManagerPeer>,
 
@@ -301,95 +486,310 @@ pub fn transfer_lock<'info>(
     args: TransferArgs,
 ) -> Result<()> {
     let accs = ctx.accounts;
+    lock_and_insert_into_outbox(
+        &mut accs.common,
+        &mut accs.inbox_rate_limit,
+        &mut accs.outbox_chain_rate_limit,
+        &accs.peer,
+        &accs.session_authority,
+        ctx.bumps.session_authority,
+        ctx.remaining_accounts,
+        args.into(),
+    )
+}
+
+#[derive(Accounts)]
+#[instruction(args: TransferWithPayloadArgs)]
+pub struct TransferLockWithPayload<'info> {
+    #[account(
+        constraint = common.config.mode == Mode::Locking @ NTTError::InvalidMode,
+    )]
+    pub common: Transfer<'info>,
+
+    #[account(
+        mut,
+        seeds = [InboxRateLimit::SEED_PREFIX, args.recipient_chain.id.to_be_bytes().as_ref()],
+        bump = inbox_rate_limit.bump,
+    )]
+    pub inbox_rate_limit: Account<'info, InboxRateLimit>,
+
+    #[account(
+        mut,
+        seeds = [OutboxChainRateLimit::SEED_PREFIX, args.recipient_chain.id.to_be_bytes().as_ref()],
+        bump = outbox_chain_rate_limit.bump,
+    )]
+    pub outbox_chain_rate_limit: Account<'info, OutboxChainRateLimit>,
+
+    #[account(
+        seeds = [NttManagerPeer::SEED_PREFIX, args.recipient_chain.id.to_be_bytes().as_ref()],
+        bump = peer.bump,
+        constraint = !peer.paused @ NTTError::PeerPaused,
+        constraint = peer.retired_at.is_none() @ NTTError::PeerRetired,
+    )]
+    pub peer: Account<'info, NttManagerPeer>,
+
+    #[account(
+        seeds = [
+            crate::SESSION_AUTHORITY_SEED,
+            common.from.owner.as_ref(),
+            args.keccak256().as_ref()
+        ],
+        bump,
+    )]
+    /// CHECK: The seeds constraint enforces that this is the correct account
+    /// See [`crate::SESSION_AUTHORITY_SEED`] for an explanation of the flow.
+    pub session_authority: UncheckedAccount<'info>,
+}
+
+/// Same as [`transfer_lock`], but attaches `args.additional_payload` to the
+/// transfer.
+pub fn transfer_lock_with_payload<'info>(
+    ctx: Context<'_, '_, '_, 'info, TransferLockWithPayload<'info>>,
+    args: TransferWithPayloadArgs,
+) -> Result<()> {
+    let accs = ctx.accounts;
+    lock_and_insert_into_outbox(
+        &mut accs.common,
+        &mut accs.inbox_rate_limit,
+        &mut accs.outbox_chain_rate_limit,
+        &accs.peer,
+        &accs.session_authority,
+        ctx.bumps.session_authority,
+        ctx.remaining_accounts,
+        args,
+    )
+}
+
+fn lock_and_insert_into_outbox<'info>(
+    common: &mut Transfer<'info>,
+    inbox_rate_limit: &mut InboxRateLimit,
+    outbox_chain_rate_limit: &mut OutboxChainRateLimit,
+    peer: &NttManagerPeer,
+    session_authority: &UncheckedAccount<'info>,
+    session_authority_bump: u8,
+    remaining_accounts: &[AccountInfo<'info>],
+    args: TransferWithPayloadArgs,
+) -> Result<()> {
+    let session_authority_hash = args.keccak256();
 
-    let TransferArgs {
-        mut amount,
+    let TransferWithPayloadArgs {
+        amount,
         recipient_chain,
         recipient_address,
         should_queue,
+        additional_payload,
     } = args;
 
-    // TODO: should we revert if we have dust?
-    let trimmed_amount = TrimmedAmount::remove_dust(
-        &mut amount,
-        accs.common.mint.decimals,
-        accs.peer.token_decimals,
-    )
-    .map_err(NTTError::from)?;
-
-    let before = accs.common.custody.amount;
+    let additional_payload = AdditionalPayload::new(additional_payload)
+        .map_err(|_| NTTError::AdditionalPayloadTooLong)?;
 
-    onchain::invoke_transfer_checked(
-        &accs.common.token_program.key(),
-        accs.common.from.to_account_info(),
-        accs.common.mint.to_account_info(),
-        accs.common.custody.to_account_info(),
-        accs.session_authority.to_account_info(),
-        ctx.remaining_accounts,
+    check_parties(
+        common,
+        recipient_chain,
+        recipient_address,
         amount,
-        accs.common.mint.decimals,
-        &[&[
-            crate::SESSION_AUTHORITY_SEED,
-            accs.common.from.owner.as_ref(),
-            args.keccak256().as_ref(),
-            &[ctx.bumps.session_authority],
-        ]],
+        remaining_accounts,
     )?;
 
-    accs.common.custody.reload()?;
-    let after = accs.common.custody.amount;
-
-    // NOTE: we currently do not support tokens with fees. Support could be
-    // added, but it would require the client to calculate the amount _before_
-    // paying fees that results in an amount that can safely be trimmed.
-    // Otherwise, if the amount after paying fees has dust, then that amount
-    // would be lost.
-    // To support fee tokens, we would first transfer the amount, _then_ assert
-    // that the resulting amount has no dust (instead of removing dust before
-    // the transfer like we do now).
-    if after != before + amount {
-        return Err(NTTError::BadAmountAfterTransfer.into());
-    }
+    // Only what arrived in custody is locked, which is less than what was
+    // transferred if the token has fees.
+    let (amount, trimmed_amount) = transfer_to_custody(
+        common,
+        peer,
+        session_authority,
+        session_authority_bump,
+        &session_authority_hash,
+        remaining_accounts,
+        amount,
+    )?;
 
-    let recipient_ntt_manager = accs.peer.address;
+    let recipient_ntt_manager = peer.address;
 
     insert_into_outbox(
-        &mut accs.common,
-        &mut accs.inbox_rate_limit,
+        common,
+        inbox_rate_limit,
+        outbox_chain_rate_limit,
         amount,
         trimmed_amount,
         recipient_chain,
         recipient_ntt_manager,
         recipient_address,
         should_queue,
+        additional_payload,
     )
 }
 
-fn insert_into_outbox(
-    common: &mut Transfer<'_>,
-    inbox_rate_limit: &mut InboxRateLimit,
-    amount: u64,
-    trimmed_amount: TrimmedAmount,
+/// Fails if the sender (the owner of the `from` account) or the recipient is
+/// on the denylist, or the policy program denies the transfer (see
+/// [`crate::compliance`]).
+fn check_parties<'info>(
+    common: &Transfer<'info>,
     recipient_chain: ChainId,
-    recipient_ntt_manager: [u8; 32],
     recipient_address: [u8; 32],
-    should_queue: bool,
+    amount: u64,
+    remaining_accounts: &[AccountInfo<'info>],
 ) -> Result<()> {
-    // consume the rate limit, or delay the transfer if it's outside the limit
-    let release_timestamp = match common.outbox_rate_limit.rate_limit.consume_or_delay(amount) {
+    let check = CheckTransferArgs {
+        direction: TransferDirection::Outbound,
+        chain_id: recipient_chain,
+        sender: common.from.owner.to_bytes(),
+        recipient: recipient_address,
+        amount,
+    };
+    if compliance::is_denied(
+        &common.config,
+        &common.mint.to_account_info(),
+        check,
+        remaining_accounts,
+    )? {
+        return Err(NTTError::TransferDenied.into());
+    }
+    Ok(())
+}
+
+/// Transfers `amount` (minus dust) from the sender to the custody account, and
+/// returns the amount that arrived (along with its trimmed equivalent).
+/// If [`Config::strict_dust`] is set, `amount` must have no dust.
+///
+/// For tokens with transfer fees, the dust can't be removed up front, as it
+/// depends on the amount after fees. The amount that arrived is less than
+/// `amount`, and it's what the transfer is for. Since we can't give the dust
+/// back at this point, the amount that arrived must have no dust, otherwise
+/// the transfer fails. Clients can compute an amount that satisfies this with
+/// [`crate::transfer::amount_before_transfer_fee`].
+fn transfer_to_custody<'info>(
+    common: &mut Transfer<'info>,
+    peer: &NttManagerPeer,
+    session_authority: &UncheckedAccount<'info>,
+    session_authority_bump: u8,
+    session_authority_hash: &solana_program::keccak::Hash,
+    remaining_accounts: &[AccountInfo<'info>],
+    mut amount: u64,
+) -> Result<(u64, TrimmedAmount)> {
+    let has_transfer_fee = transfer_fee_config(&common.mint.to_account_info())?.is_some();
+
+    if !has_transfer_fee {
+        let requested_amount = amount;
+        TrimmedAmount::remove_dust(&mut amount, common.mint.decimals, peer.token_decimals)
+            .map_err(NTTError::from)?;
+        // In strict mode, the transfer fails instead, reporting the largest
+        // amount that can be transferred without dust
+        if common.config.strict_dust && requested_amount != amount {
+            msg!(
+                "Transfer amount {} has dust, the largest amount without it is {}",
+                requested_amount,
+                amount
+            );
+            return Err(NTTError::TransferAmountHasDust.into());
+        }
+    }
+
+    let before = common.custody.amount;
+
+    onchain::invoke_transfer_checked(
+        &common.token_program.key(),
+        common.from.to_account_info(),
+        common.mint.to_account_info(),
+        common.custody.to_account_info(),
+        session_authority.to_account_info(),
+        remaining_accounts,
+        amount,
+        common.mint.decimals,
+        &[&[
+            crate::SESSION_AUTHORITY_SEED,
+            common.from.owner.as_ref(),
+            session_authority_hash.as_ref(),
+            &[session_authority_bump],
+        ]],
+    )?;
+
+    common.custody.reload()?;
+    let received = common
+        .custody
+        .amount
+        .checked_sub(before)
+        .filter(|received| *received == amount || (has_transfer_fee && *received < amount))
+        .ok_or(NTTError::BadAmountAfterTransfer)?;
+
+    let mut untrimmed = received;
+    let trimmed_amount =
+        TrimmedAmount::remove_dust(&mut untrimmed, common.mint.decimals, peer.token_decimals)
+            .map_err(NTTError::from)?;
+    if untrimmed != received {
+        return Err(NTTError::AmountAfterFeeHasDust.into());
+    }
+
+    Ok((received, trimmed_amount))
+}
+
+/// Consumes `amount` from the global and per-chain outbound rate limits, or
+/// delays the transfer if it's outside either of them (failing unless
+/// `should_queue` is set). Returns the release timestamp, and whether the
+/// transfer is queued and whether it reserved its capacity.
+pub(crate) fn consume_outbound_rate_limits(
+    config: &Config,
+    outbox_rate_limit: &mut OutboxRateLimit,
+    outbox_chain_rate_limit: &mut OutboxChainRateLimit,
+    inbox_rate_limit: &mut InboxRateLimit,
+    amount: u64,
+    should_queue: bool,
+) -> Result<(i64, bool, bool)> {
+    let rate_limits = &mut [
+        &mut outbox_rate_limit.rate_limit,
+        &mut outbox_chain_rate_limit.rate_limit,
+    ];
+    let rate_limit_result = if config.precise_queue_release && should_queue {
+        RateLimitState::consume_or_reserve_all(rate_limits, amount)
+    } else {
+        RateLimitState::consume_or_delay_all(rate_limits, amount)
+    };
+    match rate_limit_result {
         RateLimitResult::Consumed(now) => {
             // When sending a transfer, we refill the inbound rate limit for
             // that chain the same amount (we call this "backflow")
             inbox_rate_limit.rate_limit.refill(now, amount);
-            now
+            Ok((now, false, false))
+        }
+        RateLimitResult::Reserved(release_timestamp) => {
+            // The reserved capacity counts as consumed, so the backflow is
+            // refilled too
+            inbox_rate_limit
+                .rate_limit
+                .refill(current_timestamp(), amount);
+            Ok((release_timestamp, true, true))
         }
         RateLimitResult::Delayed(release_timestamp) => {
             if !should_queue {
                 return Err(NTTError::TransferExceedsRateLimit.into());
             }
-            release_timestamp
+            Ok((release_timestamp, true, false))
         }
-    };
+    }
+}
+
+fn insert_into_outbox(
+    common: &mut Transfer<'_>,
+    inbox_rate_limit: &mut InboxRateLimit,
+    outbox_chain_rate_limit: &mut OutboxChainRateLimit,
+    amount: u64,
+    trimmed_amount: TrimmedAmount,
+    recipient_chain: ChainId,
+    recipient_ntt_manager: [u8; 32],
+    recipient_address: [u8; 32],
+    should_queue: bool,
+    additional_payload: AdditionalPayload,
+) -> Result<()> {
+    let (release_timestamp, queued, reserved) = consume_outbound_rate_limits(
+        &common.config,
+        &mut common.outbox_rate_limit,
+        outbox_chain_rate_limit,
+        inbox_rate_limit,
+        amount,
+        should_queue,
+    )?;
+
+    let sequence = common.outbox_sequence.next;
+    common.outbox_sequence.next += 1;
 
     common.outbox_item.set_inner(OutboxItem {
         amount: trimmed_amount,
@@ -399,7 +799,31 @@ fn insert_into_outbox(
         recipient_address,
         release_timestamp,
         released: Bitmap::new(),
+        queued,
+        reserved,
+        cancelled: false,
+        rent_payer: common.payer.key(),
+        additional_payload,
+        sequence,
+        version: OutboxItem::LAYOUT_VERSION,
+    });
+
+    emit!(TransferSent {
+        outbox_item: common.outbox_item.key(),
+        sender: common.from.owner,
+        amount: trimmed_amount,
+        recipient_chain,
+        recipient_ntt_manager,
+        recipient_address,
+        release_timestamp,
     });
 
+    if queued {
+        emit!(OutboundTransferQueued {
+            outbox_item: common.outbox_item.key(),
+            release_timestamp,
+        });
+    }
+
     Ok(())
 }
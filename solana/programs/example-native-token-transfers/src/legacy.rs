@@ -0,0 +1,137 @@
+//! Account layouts of the 3.0.0 release, before accounts were versioned
+//! (layout version 0).
+//!
+//! These are only used to read accounts created by that release, so they can
+//! be upgraded to the current layouts with [`crate::instructions::migrate`].
+//! The discriminators are the same as those of the current accounts, so the
+//! layout of an account is told apart by its size.
+
+use anchor_lang::prelude::*;
+use ntt_messages::{
+    chain_id::ChainId,
+    mode::Mode,
+    ntt::{AdditionalPayload, EmptyPayload, NativeTokenTransfer},
+    ntt_manager::NttManagerMessage,
+    transceiver::TransceiverMessageData,
+    trimmed_amount::TrimmedAmount,
+};
+
+use crate::bitmap::Bitmap;
+
+#[derive(AnchorSerialize, AnchorDeserialize, InitSpace)]
+pub struct Config {
+    pub bump: u8,
+    pub owner: Pubkey,
+    pub pending_owner: Option<Pubkey>,
+    pub mint: Pubkey,
+    pub token_program: Pubkey,
+    pub mode: Mode,
+    pub chain_id: ChainId,
+    pub next_transceiver_id: u8,
+    pub threshold: u8,
+    pub enabled_transceivers: Bitmap,
+    pub paused: bool,
+    pub custody: Pubkey,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, InitSpace)]
+pub struct NttManagerPeer {
+    pub bump: u8,
+    pub address: [u8; 32],
+    pub token_decimals: u8,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, InitSpace)]
+pub struct RegisteredTransceiver {
+    pub bump: u8,
+    pub id: u8,
+    pub transceiver_address: Pubkey,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, InitSpace)]
+pub struct OutboxItem {
+    pub amount: TrimmedAmount,
+    pub sender: Pubkey,
+    pub recipient_chain: ChainId,
+    pub recipient_ntt_manager: [u8; 32],
+    pub recipient_address: [u8; 32],
+    pub release_timestamp: i64,
+    pub released: Bitmap,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, InitSpace)]
+pub struct InboxItem {
+    pub init: bool,
+    pub bump: u8,
+    pub amount: u64,
+    pub recipient_address: Pubkey,
+    pub votes: Bitmap,
+    pub release_status: ReleaseStatus,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, InitSpace)]
+pub struct RateLimitState {
+    pub limit: u64,
+    pub capacity_at_last_tx: u64,
+    pub last_tx_timestamp: i64,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, InitSpace)]
+pub struct OutboxRateLimit {
+    pub rate_limit: RateLimitState,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, InitSpace)]
+pub struct InboxRateLimit {
+    pub bump: u8,
+    pub rate_limit: RateLimitState,
+}
+
+/// [`crate::messages::ValidatedTransceiverMessage`] as of 3.0.0, when transfers
+/// had no additional payload. Transceiver messages are owned by the
+/// transceiver that received them, so this is also used by transceivers
+/// deployed as separate programs to migrate their own messages.
+#[derive(AnchorSerialize, AnchorDeserialize, InitSpace)]
+pub struct ValidatedTransceiverMessage {
+    pub from_chain: ChainId,
+    pub message: TransceiverMessageData<NativeTokenTransfer<EmptyPayload>>,
+}
+
+impl ValidatedTransceiverMessage {
+    /// The message with an empty additional payload.
+    pub fn into_message_data(
+        self,
+    ) -> TransceiverMessageData<NativeTokenTransfer<AdditionalPayload>> {
+        let TransceiverMessageData {
+            source_ntt_manager,
+            recipient_ntt_manager,
+            ntt_manager_payload,
+        } = self.message;
+        let transfer = ntt_manager_payload.payload;
+        TransceiverMessageData {
+            source_ntt_manager,
+            recipient_ntt_manager,
+            ntt_manager_payload: NttManagerMessage {
+                id: ntt_manager_payload.id,
+                sender: ntt_manager_payload.sender,
+                payload: NativeTokenTransfer {
+                    amount: transfer.amount,
+                    source_token: transfer.source_token,
+                    to_chain: transfer.to_chain,
+                    to: transfer.to,
+                    additional_payload: AdditionalPayload::default(),
+                },
+            },
+        }
+    }
+}
+
+/// [`crate::queue::inbox::ReleaseStatus`] as of 3.0.0. The variants added
+/// since make the current enum larger, so it can't be used to size the old
+/// [`InboxItem`].
+#[derive(AnchorSerialize, AnchorDeserialize, InitSpace)]
+pub enum ReleaseStatus {
+    NotApproved,
+    ReleaseAfter(i64),
+    Released,
+}
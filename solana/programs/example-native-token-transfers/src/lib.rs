@@ -13,11 +13,15 @@ compile_error!("Cannot enable both solana-devnet and tilt-devnet features at the
 
 pub mod bitmap;
 pub mod clock;
+pub mod compliance;
 pub mod config;
 pub mod error;
+pub mod events;
 pub mod instructions;
+pub mod legacy;
 pub mod messages;
 pub mod peer;
+pub mod pending_change;
 pub mod pending_token_authority;
 pub mod queue;
 pub mod registered_transceiver;
@@ -27,7 +31,9 @@ pub mod transfer;
 
 use transceivers::wormhole::instructions::*;
 
+use config::Role;
 use instructions::*;
+use pending_change::ConfigChange;
 
 cfg_if::cfg_if! {
     if #[cfg(feature = "tilt-devnet2")] {
@@ -41,6 +47,15 @@ cfg_if::cfg_if! {
 
 pub const TOKEN_AUTHORITY_SEED: &[u8] = b"token_authority";
 
+/// The seed for the account that owns the escrow token account (its
+/// associated token account for the mint).
+///
+/// The escrow holds the tokens of inbound transfers that couldn't be
+/// delivered (see [`instructions::escrow_inbox_item`]) until the owner
+/// redirects them or returns them to the source chain. It's separate from the
+/// custody account so that escrowed tokens are never mistaken for locked ones.
+pub const ESCROW_AUTHORITY_SEED: &[u8] = b"escrow_authority";
+
 /// The seed for the session authority account.
 ///
 /// These accounts are used in the `transfer_*` instructions. The user first
@@ -65,7 +80,22 @@ pub const TOKEN_AUTHORITY_SEED: &[u8] = b"token_authority";
 /// user, atomically).
 pub const SESSION_AUTHORITY_SEED: &[u8] = b"session_authority";
 
-pub const VERSION: &str = "3.0.0";
+/// The seed for the account that signs the call into the recipient program in
+/// the `release_inbound_*_and_call` instructions.
+///
+/// Recipient programs should check that this account (derived from the
+/// manager's program id) is a signer, to make sure that the call originates
+/// from the manager.
+pub const RECIPIENT_CALL_AUTHORITY_SEED: &[u8] = b"recipient_call_authority";
+
+/// The seed the recipient address of a transfer has to be derived from (as a
+/// PDA of the recipient program) for the recipient program to be called in
+/// the `release_inbound_*_and_call` instructions.
+///
+/// This ensures that only the program that controls the tokens can be called.
+pub const RECIPIENT_SEED: &[u8] = b"ntt_recipient";
+
+pub const VERSION: &str = "4.0.0";
 
 #[program]
 pub mod example_native_token_transfers {
@@ -80,8 +110,11 @@ pub mod example_native_token_transfers {
         instructions::initialize_lut(ctx, recent_slot)
     }
 
-    pub fn version(_ctx: Context<Version>) -> Result<String> {
-        Ok(VERSION.to_string())
+    pub fn version(_ctx: Context<Version>) -> Result<VersionInfo> {
+        Ok(VersionInfo {
+            program_version: VERSION.to_string(),
+            config_layout_version: config::Config::LAYOUT_VERSION,
+        })
     }
 
     pub fn transfer_burn<'info>(
@@ -98,10 +131,50 @@ pub mod example_native_token_transfers {
         instructions::transfer_lock(ctx, args)
     }
 
+    pub fn transfer_burn_with_payload<'info>(
+        ctx: Context<'_, '_, '_, 'info, TransferBurnWithPayload<'info>>,
+        args: TransferWithPayloadArgs,
+    ) -> Result<()> {
+        instructions::transfer_burn_with_payload(ctx, args)
+    }
+
+    pub fn transfer_lock_with_payload<'info>(
+        ctx: Context<'_, '_, '_, 'info, TransferLockWithPayload<'info>>,
+        args: TransferWithPayloadArgs,
+    ) -> Result<()> {
+        instructions::transfer_lock_with_payload(ctx, args)
+    }
+
+    pub fn cancel_outbound_queued_transfer<'info>(
+        ctx: Context<'_, '_, '_, 'info, CancelOutboundQueuedTransfer<'info>>,
+    ) -> Result<()> {
+        instructions::cancel_outbound_queued_transfer(ctx)
+    }
+
     pub fn redeem(ctx: Context<Redeem>, args: RedeemArgs) -> Result<()> {
         instructions::redeem(ctx, args)
     }
 
+    pub fn reevaluate_inbox_item(ctx: Context<ReevaluateInboxItem>) -> Result<()> {
+        instructions::reevaluate_inbox_item(ctx)
+    }
+
+    pub fn freeze_inbox_item(ctx: Context<ManageInboxItem>) -> Result<()> {
+        instructions::freeze_inbox_item(ctx)
+    }
+
+    pub fn unfreeze_inbox_item(ctx: Context<ManageInboxItem>) -> Result<()> {
+        instructions::unfreeze_inbox_item(ctx)
+    }
+
+    pub fn reject_inbox_item(ctx: Context<ManageInboxItem>) -> Result<()> {
+        instructions::reject_inbox_item(ctx)
+    }
+
+    pub fn force_release_inbox_item(ctx: Context<ForceReleaseInboxItem>) -> Result<()> {
+        instructions::force_release_inbox_item(ctx)
+    }
+
     pub fn release_inbound_mint<'info>(
         ctx: Context<'_, '_, '_, 'info, ReleaseInboundMint<'info>>,
         args: ReleaseInboundArgs,
@@ -116,6 +189,46 @@ pub mod example_native_token_transfers {
         instructions::release_inbound_unlock(ctx, args)
     }
 
+    pub fn release_inbound_mint_and_call<'info>(
+        ctx: Context<'_, '_, '_, 'info, ReleaseInboundMintAndCall<'info>>,
+        args: ReleaseInboundAndCallArgs,
+    ) -> Result<()> {
+        instructions::release_inbound_mint_and_call(ctx, args)
+    }
+
+    pub fn release_inbound_unlock_and_call<'info>(
+        ctx: Context<'_, '_, '_, 'info, ReleaseInboundUnlockAndCall<'info>>,
+        args: ReleaseInboundAndCallArgs,
+    ) -> Result<()> {
+        instructions::release_inbound_unlock_and_call(ctx, args)
+    }
+
+    pub fn close_outbox_item(ctx: Context<CloseOutboxItem>) -> Result<()> {
+        instructions::close_outbox_item(ctx)
+    }
+
+    pub fn close_inbox_item(ctx: Context<CloseInboxItem>) -> Result<()> {
+        instructions::close_inbox_item(ctx)
+    }
+
+    pub fn escrow_inbox_item<'info>(
+        ctx: Context<'_, '_, '_, 'info, EscrowInboxItem<'info>>,
+    ) -> Result<()> {
+        instructions::escrow_inbox_item(ctx)
+    }
+
+    pub fn redirect_escrowed_inbox_item<'info>(
+        ctx: Context<'_, '_, '_, 'info, RedirectEscrowedInboxItem<'info>>,
+    ) -> Result<()> {
+        instructions::redirect_escrowed_inbox_item(ctx)
+    }
+
+    pub fn return_escrowed_inbox_item<'info>(
+        ctx: Context<'_, '_, '_, 'info, ReturnEscrowedInboxItem<'info>>,
+    ) -> Result<()> {
+        instructions::return_escrowed_inbox_item(ctx)
+    }
+
     pub fn transfer_ownership(ctx: Context<TransferOwnership>) -> Result<()> {
         instructions::transfer_ownership(ctx)
     }
@@ -166,6 +279,192 @@ pub mod example_native_token_transfers {
         instructions::set_paused(ctx, pause)
     }
 
+    pub fn pause(ctx: Context<Pause>) -> Result<()> {
+        instructions::pause(ctx)
+    }
+
+    pub fn grant_role(ctx: Context<SetRole>, role: Role, holder: Pubkey) -> Result<()> {
+        instructions::grant_role(ctx, role, holder)
+    }
+
+    pub fn revoke_role(ctx: Context<SetRole>, role: Role) -> Result<()> {
+        instructions::revoke_role(ctx, role)
+    }
+
+    pub fn set_outbound_paused(ctx: Context<SetPaused>, paused: bool) -> Result<()> {
+        instructions::set_outbound_paused(ctx, paused)
+    }
+
+    pub fn set_inbound_paused(ctx: Context<SetPaused>, paused: bool) -> Result<()> {
+        instructions::set_inbound_paused(ctx, paused)
+    }
+
+    pub fn set_peer_paused(ctx: Context<SetPeerPaused>, args: SetPeerPausedArgs) -> Result<()> {
+        instructions::set_peer_paused(ctx, args)
+    }
+
+    pub fn pause_outbound(ctx: Context<Pause>) -> Result<()> {
+        instructions::pause_outbound(ctx)
+    }
+
+    pub fn pause_inbound(ctx: Context<Pause>) -> Result<()> {
+        instructions::pause_inbound(ctx)
+    }
+
+    pub fn pause_peer(ctx: Context<PausePeer>, args: PausePeerArgs) -> Result<()> {
+        instructions::pause_peer(ctx, args)
+    }
+
+    pub fn set_peer_threshold(
+        ctx: Context<SetPeerThreshold>,
+        args: SetPeerThresholdArgs,
+    ) -> Result<()> {
+        instructions::set_peer_threshold(ctx, args)
+    }
+
+    pub fn execute_set_peer_threshold(
+        ctx: Context<ExecuteSetPeerThreshold>,
+        args: SetPeerThresholdArgs,
+    ) -> Result<()> {
+        instructions::execute_set_peer_threshold(ctx, args)
+    }
+
+    pub fn set_legacy_peer_addresses(
+        ctx: Context<SetLegacyPeerAddresses>,
+        args: SetLegacyPeerAddressesArgs,
+    ) -> Result<()> {
+        instructions::set_legacy_peer_addresses(ctx, args)
+    }
+
+    pub fn execute_set_legacy_peer_addresses(
+        ctx: Context<ExecuteSetLegacyPeerAddresses>,
+        args: SetLegacyPeerAddressesArgs,
+    ) -> Result<()> {
+        instructions::execute_set_legacy_peer_addresses(ctx, args)
+    }
+
+    pub fn remove_peer(ctx: Context<RemovePeer>, args: RemovePeerArgs) -> Result<()> {
+        instructions::remove_peer(ctx, args)
+    }
+
+    pub fn close_peer(ctx: Context<ClosePeer>, args: ClosePeerArgs) -> Result<()> {
+        instructions::close_peer(ctx, args)
+    }
+
+    pub fn set_precise_queue_release(
+        ctx: Context<SetPreciseQueueRelease>,
+        enabled: bool,
+    ) -> Result<()> {
+        instructions::set_precise_queue_release(ctx, enabled)
+    }
+
+    pub fn set_strict_dust(ctx: Context<SetStrictDust>, enabled: bool) -> Result<()> {
+        instructions::set_strict_dust(ctx, enabled)
+    }
+
+    pub fn set_escrow_timeout(ctx: Context<SetEscrowTimeout>, timeout: i64) -> Result<()> {
+        instructions::set_escrow_timeout(ctx, timeout)
+    }
+
+    pub fn set_denylist_enabled(ctx: Context<SetDenylistEnabled>, enabled: bool) -> Result<()> {
+        instructions::set_denylist_enabled(ctx, enabled)
+    }
+
+    pub fn set_policy_program(
+        ctx: Context<SetPolicyProgram>,
+        policy_program: Option<Pubkey>,
+    ) -> Result<()> {
+        instructions::set_policy_program(ctx, policy_program)
+    }
+
+    pub fn add_to_denylist(ctx: Context<AddToDenylist>, address: [u8; 32]) -> Result<()> {
+        instructions::add_to_denylist(ctx, address)
+    }
+
+    pub fn remove_from_denylist(ctx: Context<RemoveFromDenylist>, address: [u8; 32]) -> Result<()> {
+        instructions::remove_from_denylist(ctx, address)
+    }
+
+    pub fn unquarantine_inbox_item(ctx: Context<UnquarantineInboxItem>) -> Result<()> {
+        instructions::unquarantine_inbox_item(ctx)
+    }
+
+    pub fn set_timelock_delay(ctx: Context<SetTimelockDelay>, delay: i64) -> Result<()> {
+        instructions::set_timelock_delay(ctx, delay)
+    }
+
+    pub fn propose_change(ctx: Context<ProposeChange>, change: ConfigChange) -> Result<()> {
+        instructions::propose_change(ctx, change)
+    }
+
+    pub fn veto_change(ctx: Context<VetoChange>) -> Result<()> {
+        instructions::veto_change(ctx)
+    }
+
+    pub fn execute_change(ctx: Context<ExecuteChange>) -> Result<()> {
+        instructions::execute_change(ctx)
+    }
+
+    pub fn execute_set_peer(ctx: Context<ExecuteSetPeer>, args: SetPeerArgs) -> Result<()> {
+        instructions::execute_set_peer(ctx, args)
+    }
+
+    pub fn execute_register_transceiver(ctx: Context<ExecuteRegisterTransceiver>) -> Result<()> {
+        instructions::execute_register_transceiver(ctx)
+    }
+
+    pub fn execute_set_token_authority(ctx: Context<ExecuteSetTokenAuthority>) -> Result<()> {
+        instructions::execute_set_token_authority(ctx)
+    }
+
+    pub fn execute_deregister_transceiver(
+        ctx: Context<ExecuteDeregisterTransceiver>,
+    ) -> Result<()> {
+        instructions::execute_deregister_transceiver(ctx)
+    }
+
+    pub fn execute_set_outbound_limit(
+        ctx: Context<ExecuteSetOutboundLimit>,
+        args: SetOutboundLimitArgs,
+    ) -> Result<()> {
+        instructions::execute_set_outbound_limit(ctx, args)
+    }
+
+    pub fn execute_set_outbound_chain_limit(
+        ctx: Context<ExecuteSetOutboundChainLimit>,
+        args: SetOutboundChainLimitArgs,
+    ) -> Result<()> {
+        instructions::execute_set_outbound_chain_limit(ctx, args)
+    }
+
+    pub fn execute_set_inbound_limit(
+        ctx: Context<ExecuteSetInboundLimit>,
+        args: SetInboundLimitArgs,
+    ) -> Result<()> {
+        instructions::execute_set_inbound_limit(ctx, args)
+    }
+
+    pub fn execute_set_outbound_rate_limit_duration(
+        ctx: Context<ExecuteSetOutboundRateLimitDuration>,
+        args: SetOutboundRateLimitDurationArgs,
+    ) -> Result<()> {
+        instructions::execute_set_outbound_rate_limit_duration(ctx, args)
+    }
+
+    pub fn execute_set_outbound_chain_rate_limit_duration(
+        ctx: Context<ExecuteSetOutboundChainRateLimitDuration>,
+        args: SetOutboundChainRateLimitDurationArgs,
+    ) -> Result<()> {
+        instructions::execute_set_outbound_chain_rate_limit_duration(ctx, args)
+    }
+
+    pub fn execute_set_inbound_rate_limit_duration(
+        ctx: Context<ExecuteSetInboundRateLimitDuration>,
+        args: SetInboundRateLimitDurationArgs,
+    ) -> Result<()> {
+        instructions::execute_set_inbound_rate_limit_duration(ctx, args)
+    }
+
     pub fn set_peer(ctx: Context<SetPeer>, args: SetPeerArgs) -> Result<()> {
         instructions::set_peer(ctx, args)
     }
@@ -185,6 +484,13 @@ pub mod example_native_token_transfers {
         instructions::set_outbound_limit(ctx, args)
     }
 
+    pub fn set_outbound_chain_limit(
+        ctx: Context<SetOutboundChainLimit>,
+        args: SetOutboundChainLimitArgs,
+    ) -> Result<()> {
+        instructions::set_outbound_chain_limit(ctx, args)
+    }
+
     pub fn set_inbound_limit(
         ctx: Context<SetInboundLimit>,
         args: SetInboundLimitArgs,
@@ -192,6 +498,38 @@ pub mod example_native_token_transfers {
         instructions::set_inbound_limit(ctx, args)
     }
 
+    pub fn set_outbound_rate_limit_duration(
+        ctx: Context<SetOutboundLimit>,
+        args: SetOutboundRateLimitDurationArgs,
+    ) -> Result<()> {
+        instructions::set_outbound_rate_limit_duration(ctx, args)
+    }
+
+    pub fn set_outbound_chain_rate_limit_duration(
+        ctx: Context<SetOutboundChainRateLimitDuration>,
+        args: SetOutboundChainRateLimitDurationArgs,
+    ) -> Result<()> {
+        instructions::set_outbound_chain_rate_limit_duration(ctx, args)
+    }
+
+    pub fn set_inbound_rate_limit_duration(
+        ctx: Context<SetInboundRateLimitDuration>,
+        args: SetInboundRateLimitDurationArgs,
+    ) -> Result<()> {
+        instructions::set_inbound_rate_limit_duration(ctx, args)
+    }
+
+    pub fn migrate(ctx: Context<Migrate>, args: MigrateArgs) -> Result<()> {
+        instructions::migrate(ctx, args)
+    }
+
+    pub fn init_outbox_chain_rate_limit(
+        ctx: Context<InitOutboxChainRateLimit>,
+        args: InitOutboxChainRateLimitArgs,
+    ) -> Result<()> {
+        instructions::init_outbox_chain_rate_limit(ctx, args)
+    }
+
     pub fn mark_outbox_item_as_released(ctx: Context<MarkOutboxItemAsReleased>) -> Result<bool> {
         instructions::mark_outbox_item_as_released(ctx)
     }
@@ -220,6 +558,10 @@ pub mod example_native_token_transfers {
         transceivers::wormhole::instructions::release_outbound(ctx, args)
     }
 
+    pub fn close_wormhole_transceiver_message(ctx: Context<CloseTransceiverMessage>) -> Result<()> {
+        transceivers::wormhole::instructions::close_transceiver_message(ctx)
+    }
+
     pub fn broadcast_wormhole_id(ctx: Context<BroadcastId>) -> Result<()> {
         transceivers::wormhole::instructions::broadcast_id(ctx)
     }
@@ -232,6 +574,19 @@ pub mod example_native_token_transfers {
     }
 }
 
+/// Returned by the `version` instruction.
+///
+/// The program version comes first, so clients that decode the return value
+/// as just a string (as it was before the layout version was added) still get
+/// the program version.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, Debug)]
+pub struct VersionInfo {
+    pub program_version: String,
+    /// The current layout version of the config account (see
+    /// [`config::Config::LAYOUT_VERSION`]).
+    pub config_layout_version: u8,
+}
+
 // The Version struct is just a dummy type because anchor needs every function
 // to have a context. When compiled in CPI mode, anchor generates code that
 // assumes that the struct has a lifetime parameter. So in that mode, we bind a
@@ -5,11 +5,20 @@ use ntt_messages::{
 };
 use std::{collections::HashMap, marker::PhantomData};
 
+use crate::error::NTTError;
+
 #[account]
 #[derive(InitSpace)]
 pub struct ValidatedTransceiverMessage<A: AnchorDeserialize + AnchorSerialize + Space + Clone> {
     pub from_chain: ChainId,
     pub message: TransceiverMessageData<A>,
+    /// The account that paid the rent for this message. The rent is returned
+    /// to this account when the message is closed.
+    pub rent_payer: Pubkey,
+    /// When the message was attested on the source chain (e.g. the timestamp
+    /// of the VAA it was received in). Transfers from a retired peer are only
+    /// accepted if they were attested before it was retired.
+    pub attested_at: i64,
 }
 
 impl<A: AnchorDeserialize + AnchorSerialize + Space + Clone> ValidatedTransceiverMessage<A> {
@@ -39,18 +48,23 @@ impl<A: AnchorDeserialize + AnchorSerialize + Space + Clone> ValidatedTransceive
     }
 
     pub fn from_chain(info: &UncheckedAccount) -> Result<ChainId> {
-        let data: &[u8] = &info.try_borrow_data().unwrap();
+        let data: &[u8] = &info.try_borrow_data()?;
         Self::discriminator_check(data)?;
+        let id = data
+            .get(8..10)
+            .and_then(|id| id.try_into().ok())
+            .ok_or(NTTError::MessageDecodingFailed)?;
         Ok(ChainId {
             // This is LE bytes because we deserialize using Borsh.
             // Not to be confused with the wire format (which is BE bytes)
-            id: u16::from_le_bytes(data[8..10].try_into().unwrap()),
+            id: u16::from_le_bytes(id),
         })
     }
 
     pub fn message(data: &[u8]) -> Result<TransceiverMessageDataBytes<A>> {
         Self::discriminator_check(data)?;
-        Ok(TransceiverMessageDataBytes::parse(&data[10..]))
+        let span = data.get(10..).ok_or(NTTError::MessageDecodingFailed)?;
+        Ok(TransceiverMessageDataBytes::parse(span).map_err(NTTError::from)?)
     }
 }
 
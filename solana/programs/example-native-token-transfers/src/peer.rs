@@ -1,5 +1,7 @@
 use anchor_lang::prelude::*;
 
+use crate::{bitmap::Bitmap, clock::current_timestamp, config::Config};
+
 #[account]
 #[derive(InitSpace)]
 /// A peer on another chain. Stored in a PDA seeded by the chain id.
@@ -7,8 +9,79 @@ pub struct NttManagerPeer {
     pub bump: u8,
     pub address: [u8; 32],
     pub token_decimals: u8,
+    /// Pause transfers to and from this peer. Inbound transfers from a paused
+    /// peer can't be redeemed or released, and outbound transfers to it can't
+    /// be released (but can be once it's unpaused).
+    pub paused: bool,
+    /// When the peer was retired (see [`crate::instructions::remove_peer`]),
+    /// if it was. Outbound transfers to a retired peer are rejected, and
+    /// inbound transfers from it can only be redeemed until `closable_at`.
+    pub retired_at: Option<i64>,
+    /// When a retired peer (and its rate limits) can be closed.
+    pub closable_at: i64,
+    /// Receives the rent of a retired peer when it's closed.
+    pub rent_recipient: Pubkey,
+    /// Previous addresses of the peer that inbound transfers are still
+    /// accepted from for a while, e.g. when the peer's manager was redeployed
+    /// and transfers sent by the old one are still in flight.
+    /// Outbound transfers are only sent to `address`.
+    #[max_len(4)]
+    pub legacy_addresses: Vec<LegacyAddress>,
+    /// Overrides [`Config::threshold`] for inbound transfers from this peer.
+    pub inbound_threshold: Option<u8>,
+    /// Restricts the transceivers whose votes are accepted for inbound
+    /// transfers from this peer to a subset of
+    /// [`Config::enabled_transceivers`]. All enabled transceivers are accepted
+    /// if unset.
+    pub inbound_transceivers: Option<Bitmap>,
+    /// See [`crate::config::Config::version`].
+    pub version: u8,
 }
 
 impl NttManagerPeer {
     pub const SEED_PREFIX: &'static [u8] = b"peer";
+    pub const LAYOUT_VERSION: u8 = 1;
+    /// The `max_len` of `legacy_addresses`.
+    pub const MAX_LEGACY_ADDRESSES: usize = 4;
+
+    /// Whether inbound transfers sent by the manager at `address` are
+    /// accepted, i.e. it's the peer's address, or one of its legacy addresses
+    /// that hasn't expired yet.
+    pub fn accepts_inbound_from(&self, address: &[u8; 32]) -> bool {
+        self.address == *address
+            || self.legacy_addresses.iter().any(|legacy_address| {
+                legacy_address.address == *address
+                    && current_timestamp() < legacy_address.valid_until
+            })
+    }
+
+    /// The transceivers whose votes are accepted for inbound transfers from
+    /// this peer.
+    pub fn enabled_inbound_transceivers(&self, config: &Config) -> Bitmap {
+        match self.inbound_transceivers {
+            Some(transceivers) => config.enabled_transceivers.intersection(transceivers),
+            None => config.enabled_transceivers,
+        }
+    }
+
+    /// The number of votes inbound transfers from this peer need.
+    pub fn effective_inbound_threshold(&self, config: &Config) -> u8 {
+        match (self.inbound_threshold, self.inbound_transceivers) {
+            (None, None) => config.threshold,
+            // like the global threshold when a transceiver is deregistered,
+            // the threshold is lowered if there aren't enough enabled
+            // transceivers left to reach it (but it's at least 1)
+            (threshold, _) => threshold
+                .unwrap_or(config.threshold)
+                .min(self.enabled_inbound_transceivers(config).len())
+                .max(1),
+        }
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, Debug, InitSpace)]
+pub struct LegacyAddress {
+    pub address: [u8; 32],
+    /// Inbound transfers from this address are rejected from then on.
+    pub valid_until: i64,
 }
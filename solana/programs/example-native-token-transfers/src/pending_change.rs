@@ -0,0 +1,101 @@
+use anchor_lang::prelude::*;
+use solana_program::keccak;
+
+use crate::{
+    config::{Config, Role},
+    instructions::{
+        SetInboundLimitArgs, SetInboundRateLimitDurationArgs, SetLegacyPeerAddressesArgs,
+        SetOutboundChainLimitArgs, SetOutboundChainRateLimitDurationArgs, SetOutboundLimitArgs,
+        SetOutboundRateLimitDurationArgs, SetPeerArgs, SetPeerThresholdArgs,
+    },
+};
+
+/// A security-sensitive configuration change. When the timelock is enabled
+/// (see [`Config::timelock_delay`]), these changes can't be made directly, but
+/// have to be proposed first (see [`PendingChange`]).
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, Debug, InitSpace)]
+pub enum ConfigChange {
+    SetPeer(SetPeerArgs),
+    RegisterTransceiver {
+        transceiver: Pubkey,
+    },
+    SetThreshold {
+        threshold: u8,
+    },
+    SetTokenAuthority {
+        new_authority: Pubkey,
+    },
+    /// Lowering the timelock delay (or disabling it) is itself timelocked.
+    /// Raising it is instant.
+    SetTimelockDelay {
+        delay: i64,
+    },
+    SetGuardian {
+        guardian: Option<Pubkey>,
+    },
+    SetLegacyPeerAddresses(SetLegacyPeerAddressesArgs),
+    SetPeerThreshold(SetPeerThresholdArgs),
+    DeregisterTransceiver {
+        transceiver: Pubkey,
+    },
+    /// Raising a rate limit (or shortening its window) is timelocked.
+    /// Lowering it is instant.
+    SetOutboundLimit(SetOutboundLimitArgs),
+    SetOutboundChainLimit(SetOutboundChainLimitArgs),
+    SetInboundLimit(SetInboundLimitArgs),
+    SetOutboundRateLimitDuration(SetOutboundRateLimitDurationArgs),
+    SetOutboundChainRateLimitDuration(SetOutboundChainRateLimitDurationArgs),
+    SetInboundRateLimitDuration(SetInboundRateLimitDurationArgs),
+}
+
+impl ConfigChange {
+    pub fn keccak256(&self) -> Result<keccak::Hash> {
+        Ok(keccak::hash(&self.try_to_vec()?))
+    }
+
+    /// Whether `proposer` can propose this change. The owner can propose any
+    /// change, and role holders can propose the changes they could otherwise
+    /// make directly.
+    pub fn can_propose(&self, config: &Config, proposer: &Pubkey) -> bool {
+        match self {
+            ConfigChange::SetPeer(_) | ConfigChange::SetLegacyPeerAddresses(_) => {
+                config.has_role(proposer, Role::PeerAdmin)
+            }
+            ConfigChange::RegisterTransceiver { .. }
+            | ConfigChange::DeregisterTransceiver { .. }
+            | ConfigChange::SetThreshold { .. }
+            | ConfigChange::SetPeerThreshold(_) => {
+                config.has_role(proposer, Role::TransceiverAdmin)
+            }
+            ConfigChange::SetOutboundLimit(_)
+            | ConfigChange::SetOutboundChainLimit(_)
+            | ConfigChange::SetInboundLimit(_)
+            | ConfigChange::SetOutboundRateLimitDuration(_)
+            | ConfigChange::SetOutboundChainRateLimitDuration(_)
+            | ConfigChange::SetInboundRateLimitDuration(_) => {
+                config.has_role(proposer, Role::RateLimitAdmin)
+            }
+            ConfigChange::SetTokenAuthority { .. }
+            | ConfigChange::SetTimelockDelay { .. }
+            | ConfigChange::SetGuardian { .. } => config.owner == *proposer,
+        }
+    }
+}
+
+#[account]
+#[derive(InitSpace)]
+/// A proposed [`ConfigChange`], which anyone can execute once
+/// `executable_at` is reached. Until then, the owner or the guardian can veto
+/// it.
+/// Stored in a PDA seeded by the hash of the change, so the execute
+/// instructions can check that they apply the proposed change.
+pub struct PendingChange {
+    pub bump: u8,
+    pub change: ConfigChange,
+    pub executable_at: i64,
+    pub rent_payer: Pubkey,
+}
+
+impl PendingChange {
+    pub const SEED_PREFIX: &'static [u8] = b"pending_change";
+}
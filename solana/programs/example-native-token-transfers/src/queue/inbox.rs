@@ -1,6 +1,7 @@
 use std::ops::{Deref, DerefMut};
 
-use anchor_lang::prelude::*;
+use anchor_lang::{prelude::*, Discriminator};
+use ntt_messages::{chain_id::ChainId, ntt::AdditionalPayload};
 
 use crate::{bitmap::Bitmap, clock::current_timestamp, error::NTTError};
 
@@ -18,21 +19,50 @@ pub struct InboxItem {
     pub recipient_address: Pubkey,
     pub votes: Bitmap,
     pub release_status: ReleaseStatus,
+    /// The account that paid the rent for this item. The rent is returned to
+    /// this account when the item is closed.
+    pub rent_payer: Pubkey,
+    pub source_chain: ChainId,
+    pub sender: [u8; 32],
+    /// The additional payload sent along with the tokens, if any. It is kept
+    /// after the transfer is released, so the recipient can read it.
+    pub additional_payload: AdditionalPayload,
+    /// See [`crate::config::Config::version`].
+    pub version: u8,
 }
 
 /// The status of an InboxItem. This determines whether the tokens are minted/unlocked to the recipient. As
 /// such, this must be used as a state machine that moves forward in a linear manner. A state
 /// should never "move backward" to a previous state (e.g. should never move from `Released` to
 /// `ReleaseAfter`).
+/// `Frozen` is the exception, as it's left for the status the item was frozen
+/// in (see [`crate::instructions::freeze_inbox_item`]).
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, Eq, InitSpace)]
 pub enum ReleaseStatus {
     NotApproved,
     ReleaseAfter(i64),
     Released,
+    /// The item was frozen by an admin, and can't be released until it's
+    /// unfrozen. Holds the release timestamp if the item had been approved
+    /// when it was frozen.
+    Frozen(Option<i64>),
+    /// The item was rejected by an admin, and can never be released.
+    Rejected,
+    /// The sender or the recipient was on the denylist when the item was
+    /// released (see [`crate::compliance`]). It can be released again by the
+    /// compliance admin, or rejected.
+    Quarantined,
+    /// The transfer couldn't be delivered, and its tokens were moved to the
+    /// escrow (see [`crate::instructions::escrow_inbox_item`]). Holds the
+    /// amount the escrow received for it. The owner can redirect the tokens
+    /// or return them to the source chain, after which the item is
+    /// [`ReleaseStatus::Released`].
+    Escrowed(u64),
 }
 
 impl InboxItem {
     pub const SEED_PREFIX: &'static [u8] = b"inbox_item";
+    pub const LAYOUT_VERSION: u8 = 1;
 
     /// Attempt to release the transfer.
     ///
@@ -45,6 +75,11 @@ impl InboxItem {
     /// Returns [`NTTError::TransferAlreadyRedeemed`] if the inbox item status is [`ReleaseStatus::Released`].
     /// This is important to prevent a single transfer from being redeemed multiple times, which would
     /// result in minting arbitrary amounts of the token.
+    ///
+    /// Returns [`NTTError::InboxItemFrozen`], [`NTTError::InboxItemRejected`],
+    /// [`NTTError::InboxItemQuarantined`] or [`NTTError::InboxItemEscrowed`] if the inbox item
+    /// status is [`ReleaseStatus::Frozen`], [`ReleaseStatus::Rejected`],
+    /// [`ReleaseStatus::Quarantined`] or [`ReleaseStatus::Escrowed`] respectively.
     pub fn try_release(&mut self) -> Result<bool> {
         let now = current_timestamp();
 
@@ -58,18 +93,62 @@ impl InboxItem {
                 Ok(true)
             }
             ReleaseStatus::Released => Err(NTTError::TransferAlreadyRedeemed.into()),
+            ReleaseStatus::Frozen(_) => Err(NTTError::InboxItemFrozen.into()),
+            ReleaseStatus::Rejected => Err(NTTError::InboxItemRejected.into()),
+            ReleaseStatus::Quarantined => Err(NTTError::InboxItemQuarantined.into()),
+            ReleaseStatus::Escrowed(_) => Err(NTTError::InboxItemEscrowed.into()),
         }
     }
 
+    /// Whether the item can't change anymore, i.e. it was released or
+    /// rejected.
+    pub fn is_final(&self) -> bool {
+        matches!(
+            self.release_status,
+            ReleaseStatus::Released | ReleaseStatus::Rejected
+        )
+    }
+
+    /// Approves the transfer. An item that was frozen before it was approved
+    /// stays frozen, and will be releasable after `release_timestamp` once
+    /// it's unfrozen.
     pub fn release_after(&mut self, release_timestamp: i64) -> Result<()> {
-        if self.release_status != ReleaseStatus::NotApproved {
-            return Err(NTTError::TransferCannotBeRedeemed.into());
+        self.release_status = match self.release_status {
+            ReleaseStatus::NotApproved => ReleaseStatus::ReleaseAfter(release_timestamp),
+            ReleaseStatus::Frozen(None) => ReleaseStatus::Frozen(Some(release_timestamp)),
+            _ => return Err(NTTError::TransferCannotBeRedeemed.into()),
         };
-        self.release_status = ReleaseStatus::ReleaseAfter(release_timestamp);
         Ok(())
     }
+
+    /// Whether the inbox item stored in `info` is final (see
+    /// [`InboxItem::is_final`]). Inbox items that have been closed (see
+    /// [`InboxItemTombstone`]) count as final.
+    pub fn is_done(info: &AccountInfo) -> Result<bool> {
+        if *info.owner != crate::ID {
+            return Err(Error::from(ErrorCode::AccountOwnedByWrongProgram)
+                .with_pubkeys((*info.owner, crate::ID)));
+        }
+        let data: &[u8] = &info.try_borrow_data()?;
+        if data.starts_with(&InboxItemTombstone::DISCRIMINATOR) {
+            return Ok(true);
+        }
+        let inbox_item = InboxItem::try_deserialize(&mut &data[..])?;
+        Ok(inbox_item.is_final())
+    }
 }
 
+/// What remains of an [`InboxItem`] after it has been closed.
+///
+/// Inbox items are content-addressed, so closing them outright would allow the
+/// same message to be redeemed again. Instead, the account is shrunk down to
+/// just the discriminator of this type, and the rest of the rent is returned.
+/// [`crate::instructions::redeem`] fails to deserialize the tombstone as an
+/// [`InboxItem`], so the message can never be redeemed again.
+#[account]
+#[derive(InitSpace)]
+pub struct InboxItemTombstone {}
+
 /// Inbound rate limit per chain.
 /// SECURITY: must check the PDA (since there are multiple PDAs, namely one for each chain.)
 #[account]
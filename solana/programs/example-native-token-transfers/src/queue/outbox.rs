@@ -1,7 +1,7 @@
 use std::ops::{Deref, DerefMut};
 
 use anchor_lang::prelude::*;
-use ntt_messages::{chain_id::ChainId, trimmed_amount::TrimmedAmount};
+use ntt_messages::{chain_id::ChainId, ntt::AdditionalPayload, trimmed_amount::TrimmedAmount};
 
 use crate::{bitmap::*, clock::current_timestamp, error::NTTError};
 
@@ -18,12 +18,47 @@ pub struct OutboxItem {
     pub recipient_address: [u8; 32],
     pub release_timestamp: i64,
     pub released: Bitmap,
+    /// Whether the transfer was queued because it exceeded the outbound rate
+    /// limit. Queued transfers don't consume outbound capacity (and don't
+    /// refill the inbound capacity) when they are inserted, unless they
+    /// reserved it.
+    pub queued: bool,
+    /// Whether the transfer reserved the outbound capacity it was missing
+    /// when it was queued (see [`crate::config::Config::precise_queue_release`]).
+    /// Reserved capacity counts as consumed, like for transfers that weren't
+    /// queued.
+    pub reserved: bool,
+    /// Whether the transfer has been cancelled by the sender. Cancelled
+    /// transfers can never be released.
+    pub cancelled: bool,
+    /// The account that paid the rent for this item. The rent is returned to
+    /// this account when the item is closed.
+    pub rent_payer: Pubkey,
+    /// An additional payload to be delivered to the recipient along with the
+    /// tokens. Empty for plain transfers.
+    pub additional_payload: AdditionalPayload,
+    /// The outbound sequence number of the transfer. The item's address is
+    /// derived from it (see [`OutboxItem::SEED_PREFIX`]).
+    pub sequence: u64,
+    /// See [`crate::config::Config::version`].
+    pub version: u8,
 }
 
 impl OutboxItem {
+    pub const LAYOUT_VERSION: u8 = 1;
+
+    /// Outbox items are PDAs seeded by their sequence number (big endian), so
+    /// the address of a transfer (and the id of the message sent for it) can
+    /// be derived offline from the sequence it was assigned.
+    pub const SEED_PREFIX: &'static [u8] = b"outbox_item";
+
     /// Attempt to release the transfer.
     /// Returns true if the transfer was released, false if it was not yet time to release it.
     pub fn try_release(&mut self, transceiver_index: u8) -> Result<bool> {
+        if self.cancelled {
+            return Err(NTTError::OutboxItemCancelled.into());
+        }
+
         let now = current_timestamp();
 
         if self.release_timestamp > now {
@@ -38,6 +73,29 @@ impl OutboxItem {
 
         Ok(true)
     }
+
+    /// Whether the item is done, i.e. it has been released by every enabled
+    /// transceiver, or it has been cancelled. Done items can be closed.
+    pub fn is_done(&self, enabled_transceivers: Bitmap) -> bool {
+        self.cancelled
+            || self.released.count_enabled_votes(enabled_transceivers) == enabled_transceivers.len()
+    }
+}
+
+#[account]
+#[derive(InitSpace, PartialEq, Eq, Debug)]
+pub struct OutboxSequence {
+    /// The sequence number of the next outbound transfer.
+    pub next: u64,
+}
+
+/// Counter of outbound transfers. Each transfer takes the next sequence
+/// number, and its [`OutboxItem`] is derived from it, like the EVM manager
+/// numbers its messages.
+/// It's created by the first transfer, starting at 0.
+/// NOTE: only one of this account can exist.
+impl OutboxSequence {
+    pub const SEED_PREFIX: &'static [u8] = b"outbox_sequence";
 }
 
 #[account]
@@ -65,3 +123,35 @@ impl DerefMut for OutboxRateLimit {
         &mut self.rate_limit
     }
 }
+
+#[account]
+#[derive(InitSpace, PartialEq, Eq, Debug)]
+pub struct OutboxChainRateLimit {
+    pub bump: u8,
+    pub rate_limit: RateLimitState,
+}
+
+/// Rate limit for outbound transfers to a single chain. This is enforced in
+/// addition to the global [`OutboxRateLimit`], so that a burst of transfers to
+/// one chain can't use up the capacity for every other chain.
+/// It's created by [`crate::instructions::set_peer`] (or, for peers set by the
+/// 3.0.0 release, [`crate::instructions::init_outbox_chain_rate_limit`])
+/// without a limit of its own (i.e. only the global limit applies), and can be
+/// lowered with [`crate::instructions::set_outbound_chain_limit`].
+impl OutboxChainRateLimit {
+    pub const SEED_PREFIX: &'static [u8] = b"outbox_chain_rate_limit";
+}
+
+impl Deref for OutboxChainRateLimit {
+    type Target = RateLimitState;
+
+    fn deref(&self) -> &Self::Target {
+        &self.rate_limit
+    }
+}
+
+impl DerefMut for OutboxChainRateLimit {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.rate_limit
+    }
+}
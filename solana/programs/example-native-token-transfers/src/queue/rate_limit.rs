@@ -14,7 +14,20 @@ pub struct RateLimitState {
     /// The timestamp of the last transaction that counted towards the current
     /// capacity. Transactions that exceeded the capacity do not count, they are
     /// just delayed.
+    ///
+    /// When capacity has been reserved for queued transfers (see
+    /// [`RateLimitState::consume_or_reserve_all`]), this is in the future: the
+    /// rate limiter is empty until then, as everything that refills before
+    /// that is owed to the queued transfers.
     pub last_tx_timestamp: i64,
+    /// The time (in seconds) it takes for the capacity to refill from zero to
+    /// `limit`. This is also how long transfers that exceed the capacity are
+    /// delayed for, unless the missing capacity is reserved (see
+    /// [`RateLimitState::consume_or_reserve_all`]).
+    ///
+    /// NOTE: this field was added after the others, so accounts created
+    /// before it have to be migrated (see [`crate::instructions::migrate`]).
+    pub rate_limit_duration: i64,
 }
 
 /// The result of attempting to consume from a rate limiter.
@@ -26,6 +39,10 @@ pub enum RateLimitResult {
     /// If the rate limit is exceeded, the transfer is delayed until the
     /// given timestamp.
     Delayed(UnixTimestamp),
+    /// If the rate limit is exceeded, but the missing capacity has been
+    /// reserved, the transfer is delayed until the given timestamp, which is
+    /// when the reserved capacity will have refilled.
+    Reserved(UnixTimestamp),
 }
 
 impl RateLimitState {
@@ -34,10 +51,11 @@ impl RateLimitState {
             limit,
             capacity_at_last_tx: limit,
             last_tx_timestamp: 0,
+            rate_limit_duration: Self::DEFAULT_RATE_LIMIT_DURATION,
         }
     }
 
-    pub const RATE_LIMIT_DURATION: i64 = 60 * 60 * 24; // 24 hours
+    pub const DEFAULT_RATE_LIMIT_DURATION: i64 = 60 * 60 * 24; // 24 hours
 
     pub fn capacity(&self) -> u64 {
         self.capacity_at(current_timestamp())
@@ -56,7 +74,10 @@ impl RateLimitState {
     #[allow(clippy::cast_sign_loss)]
     #[allow(clippy::cast_possible_truncation)]
     pub fn capacity_at(&self, now: UnixTimestamp) -> u64 {
-        assert!(self.last_tx_timestamp <= now);
+        // the capacity is reserved until `last_tx_timestamp`
+        if self.last_tx_timestamp > now {
+            return 0;
+        }
 
         let limit = u128::from(self.limit);
 
@@ -76,7 +97,7 @@ impl RateLimitState {
         let calculated_capacity = {
             let time_passed = now - self.last_tx_timestamp;
             u128::from(capacity_at_last_tx)
-                + time_passed as u128 * limit / (Self::RATE_LIMIT_DURATION as u128)
+                + time_passed as u128 * limit / (self.rate_limit_duration as u128)
         };
 
         // The use of `min` here prevents truncation.
@@ -92,43 +113,192 @@ impl RateLimitState {
     /// Otherwise, the timestamp at which the capacity will be available is
     /// returned.
     pub fn consume_or_delay(&mut self, amount: u64) -> RateLimitResult {
+        Self::consume_or_delay_all(&mut [self], amount)
+    }
+
+    /// Same as [`RateLimitState::consume_or_delay`], but for several rate
+    /// limiters at once. The amount is consumed from all of them if it fits
+    /// within each of their capacities. Otherwise none of them are changed,
+    /// and the transfer is delayed.
+    pub fn consume_or_delay_all(rate_limits: &mut [&mut Self], amount: u64) -> RateLimitResult {
         let now = current_timestamp();
-        let capacity = self.capacity_at(now);
-        if capacity >= amount {
-            self.capacity_at_last_tx = capacity - amount;
-            self.last_tx_timestamp = now;
-            RateLimitResult::Consumed(now)
-        } else {
-            RateLimitResult::Delayed(now + Self::RATE_LIMIT_DURATION)
+        // the transfer is delayed until every exceeded rate limiter has had
+        // time to fully refill
+        let delay = rate_limits
+            .iter()
+            .filter(|rate_limit| rate_limit.capacity_at(now) < amount)
+            .map(|rate_limit| rate_limit.rate_limit_duration)
+            .max();
+        if let Some(delay) = delay {
+            return RateLimitResult::Delayed(now + delay);
+        }
+        for rate_limit in rate_limits.iter_mut() {
+            rate_limit.capacity_at_last_tx = rate_limit.capacity_at(now) - amount;
+            rate_limit.last_tx_timestamp = now;
         }
+        RateLimitResult::Consumed(now)
+    }
+
+    /// Same as [`RateLimitState::consume_or_delay_all`], except when the
+    /// amount doesn't fit within the capacity of every rate limiter. Instead of
+    /// delaying the transfer by a full window without consuming anything, the
+    /// amount is consumed from all of them regardless, which reserves the
+    /// capacity that will refill in the future. The transfer is delayed until
+    /// the earliest timestamp at which every rate limiter has refilled enough
+    /// to cover it.
+    ///
+    /// Since the reserved capacity is not available to later transfers, they
+    /// are queued behind the ones that reserved it.
+    ///
+    /// An amount above the limit of any rate limiter (e.g. a zero limit, which
+    /// never refills) would reserve more than one window's worth of capacity,
+    /// and hold up later transfers for longer than that, so it falls back to
+    /// [`RateLimitState::consume_or_delay_all`] instead.
+    pub fn consume_or_reserve_all(rate_limits: &mut [&mut Self], amount: u64) -> RateLimitResult {
+        let now = current_timestamp();
+        if rate_limits
+            .iter()
+            .all(|rate_limit| rate_limit.capacity_at(now) >= amount)
+            || rate_limits
+                .iter()
+                .any(|rate_limit| amount > rate_limit.limit)
+        {
+            return Self::consume_or_delay_all(rate_limits, amount);
+        }
+        let release_timestamp = rate_limits
+            .iter_mut()
+            .map(|rate_limit| {
+                let balance = rate_limit.balance_at(now) - i128::from(amount);
+                rate_limit.set_balance(now, balance);
+                rate_limit.last_tx_timestamp
+            })
+            .fold(now, i64::max);
+        RateLimitResult::Reserved(release_timestamp)
+    }
+
+    /// Same as [`RateLimitState::consume_or_reserve_all`], for a single rate
+    /// limiter.
+    pub fn consume_or_reserve(&mut self, amount: u64) -> RateLimitResult {
+        Self::consume_or_reserve_all(&mut [self], amount)
     }
 
     /// Refills the capacity by the given amount.
     /// This is used to replenish the capacity via backflows.
+    ///
+    /// If capacity has been reserved, the refill is used to pay that back
+    /// first. This doesn't make the transfers that reserved it release
+    /// earlier, but it makes the rate limiter available again sooner.
     pub fn refill(&mut self, now: UnixTimestamp, amount: u64) {
-        self.capacity_at_last_tx = self.capacity_at(now).saturating_add(amount).min(self.limit);
+        let balance = self.balance_at(now) + i128::from(amount);
+        self.set_balance(now, balance);
+    }
+
+    /// Reduces the capacity by the given amount, saturating at zero.
+    /// This is used to take back a backflow that was previously refilled, e.g.
+    /// when a transfer is cancelled.
+    ///
+    /// If capacity has been reserved, there is no capacity to take back, so
+    /// the rate limiter is left unchanged.
+    pub fn drain(&mut self, now: UnixTimestamp, amount: u64) {
+        if self.last_tx_timestamp > now {
+            return;
+        }
+        self.capacity_at_last_tx = self.capacity_at(now).saturating_sub(amount);
         self.last_tx_timestamp = now;
     }
 
+    /// Changes the window over which the capacity refills. The current
+    /// capacity is preserved, only the refill rate from now on changes.
+    /// Reserved capacity is preserved too, so it will take longer (or
+    /// shorter) to pay back.
+    pub fn set_rate_limit_duration(&mut self, rate_limit_duration: i64) {
+        let now = current_timestamp();
+        let balance = self.balance_at(now);
+        self.rate_limit_duration = rate_limit_duration;
+        self.set_balance(now, balance);
+    }
+
+    /// Changes the limit, and shifts the current capacity by the same amount.
+    /// Decreasing the limit never reserves capacity, so the capacity
+    /// saturates at zero. Increasing the limit pays back reserved capacity
+    /// first.
     pub fn set_limit(&mut self, limit: u64) {
         let old_limit = self.limit;
         let now = current_timestamp();
-        let current_capacity = self.capacity_at(now);
+        let balance = self.balance_at(now);
 
         self.limit = limit;
 
-        let new_capacity: u64 = if old_limit > limit {
+        let new_balance = if old_limit > limit {
             // decrease in limit,
-            let diff = old_limit - limit;
-            current_capacity.saturating_sub(diff)
+            let diff = i128::from(old_limit - limit);
+            if balance > 0 {
+                (balance - diff).max(0)
+            } else {
+                balance
+            }
         } else {
             // increase in limit
-            let diff = limit - old_limit;
-            current_capacity.saturating_add(diff)
+            let diff = i128::from(limit - old_limit);
+            balance + diff
         };
 
-        self.capacity_at_last_tx = new_capacity.min(limit);
-        self.last_tx_timestamp = now;
+        self.set_balance(now, new_balance);
+    }
+
+    /// The capacity at `now`, or (if negative) the capacity that has been
+    /// reserved beyond it, i.e. how much has yet to refill before the rate
+    /// limiter is available again.
+    // SECURITY: Sign loss is OK here. `last_tx_timestamp > now` is checked
+    // first, and the duration is always positive.
+    #[allow(clippy::cast_sign_loss)]
+    fn balance_at(&self, now: UnixTimestamp) -> i128 {
+        if self.last_tx_timestamp <= now {
+            return self.capacity_at(now).into();
+        }
+        let time_left = (self.last_tx_timestamp - now) as u128;
+        let reserved = div_ceil(
+            time_left * u128::from(self.limit),
+            self.rate_limit_duration as u128,
+        );
+        -i128::try_from(reserved).unwrap_or(i128::MAX)
+    }
+
+    /// Sets the capacity at `now` to `balance`. If it is negative, the
+    /// rate limiter is marked empty until enough has refilled to pay it back.
+    // SECURITY: Sign loss is OK here. The balance is checked to be positive
+    // first, and the duration is always positive.
+    // SECURITY: Truncation is allowed here. The balance is capped at `limit`,
+    // which is a u64.
+    #[allow(clippy::cast_sign_loss)]
+    #[allow(clippy::cast_possible_truncation)]
+    fn set_balance(&mut self, now: UnixTimestamp, balance: i128) {
+        if balance >= 0 || self.limit == 0 {
+            self.capacity_at_last_tx = balance.clamp(0, self.limit.into()) as u64;
+            self.last_tx_timestamp = now;
+            return;
+        }
+        let time_left = div_ceil(
+            balance
+                .unsigned_abs()
+                .saturating_mul(self.rate_limit_duration as u128),
+            u128::from(self.limit),
+        );
+        self.capacity_at_last_tx = 0;
+        self.last_tx_timestamp = now.saturating_add(i64::try_from(time_left).unwrap_or(i64::MAX));
+    }
+}
+
+// SECURITY: Integer division is OK here, it rounds up. Callers only use it
+// to compute how long it takes to pay back reserved capacity, so rounding up
+// errs on the side of less capacity.
+#[allow(clippy::integer_division)]
+fn div_ceil(a: u128, b: u128) -> u128 {
+    let quotient = a / b;
+    if quotient * b < a {
+        quotient + 1
+    } else {
+        quotient
     }
 }
 
@@ -146,6 +316,7 @@ mod tests {
             limit: 100_000,
             capacity_at_last_tx: 100_000,
             last_tx_timestamp: now,
+            rate_limit_duration: RateLimitState::DEFAULT_RATE_LIMIT_DURATION,
         };
 
         // consume 30k. should be immediate
@@ -157,7 +328,7 @@ mod tests {
         assert_eq!(rate_limit_state.last_tx_timestamp, current_timestamp());
 
         // replenish 1/4 of the limit, i.e. 25k
-        set_test_timestamp(current_timestamp() + RateLimitState::RATE_LIMIT_DURATION / 4);
+        set_test_timestamp(current_timestamp() + RateLimitState::DEFAULT_RATE_LIMIT_DURATION / 4);
         let now = current_timestamp();
 
         assert_eq!(rate_limit_state.capacity(), 70_000 + 25_000);
@@ -166,7 +337,7 @@ mod tests {
         let tomorrow = rate_limit_state.consume_or_delay(150_000);
         assert_eq!(
             tomorrow,
-            RateLimitResult::Delayed(now + RateLimitState::RATE_LIMIT_DURATION)
+            RateLimitResult::Delayed(now + RateLimitState::DEFAULT_RATE_LIMIT_DURATION)
         );
 
         // the limit is not changed, since the tx was delayed
@@ -190,5 +361,238 @@ mod tests {
         // now refill 50k
         rate_limit_state.refill(now, 50_000);
         assert_eq!(rate_limit_state.capacity(), 100_000);
+
+        // now drain 30k
+        rate_limit_state.drain(now, 30_000);
+        assert_eq!(rate_limit_state.capacity(), 70_000);
+
+        // draining more than the capacity saturates at 0
+        rate_limit_state.drain(now, 80_000);
+        assert_eq!(rate_limit_state.capacity(), 0);
+    }
+
+    #[test]
+    fn test_consume_or_delay_all() {
+        let now = current_timestamp();
+        let mut global = RateLimitState {
+            limit: 100_000,
+            capacity_at_last_tx: 100_000,
+            last_tx_timestamp: now,
+            rate_limit_duration: RateLimitState::DEFAULT_RATE_LIMIT_DURATION,
+        };
+        let mut chain = RateLimitState {
+            limit: 50_000,
+            capacity_at_last_tx: 50_000,
+            last_tx_timestamp: now,
+            rate_limit_duration: RateLimitState::DEFAULT_RATE_LIMIT_DURATION,
+        };
+
+        // fits within both, so it's consumed from both
+        assert_eq!(
+            RateLimitState::consume_or_delay_all(&mut [&mut global, &mut chain], 30_000),
+            RateLimitResult::Consumed(now)
+        );
+        assert_eq!(global.capacity(), 70_000);
+        assert_eq!(chain.capacity(), 20_000);
+
+        // fits within the global limit, but not the chain limit, so neither
+        // is consumed
+        assert_eq!(
+            RateLimitState::consume_or_delay_all(&mut [&mut global, &mut chain], 30_000),
+            RateLimitResult::Delayed(now + RateLimitState::DEFAULT_RATE_LIMIT_DURATION)
+        );
+        assert_eq!(global.capacity(), 70_000);
+        assert_eq!(chain.capacity(), 20_000);
+    }
+
+    #[test]
+    fn test_rate_limit_duration() {
+        let now = current_timestamp();
+        let mut global = RateLimitState {
+            limit: 100_000,
+            capacity_at_last_tx: 0,
+            last_tx_timestamp: now,
+            rate_limit_duration: RateLimitState::DEFAULT_RATE_LIMIT_DURATION,
+        };
+        let mut chain = RateLimitState {
+            rate_limit_duration: 60 * 60 * 6, // 6 hours
+            ..global.clone()
+        };
+
+        // the shorter window refills faster
+        set_test_timestamp(now + 60 * 60 * 3);
+        let now = current_timestamp();
+        assert_eq!(global.capacity(), 12_500);
+        assert_eq!(chain.capacity(), 50_000);
+
+        // transfers are delayed by the longest window of the exceeded limits
+        assert_eq!(
+            RateLimitState::consume_or_delay_all(&mut [&mut global, &mut chain], 20_000),
+            RateLimitResult::Delayed(now + RateLimitState::DEFAULT_RATE_LIMIT_DURATION)
+        );
+        assert_eq!(
+            chain.consume_or_delay(60_000),
+            RateLimitResult::Delayed(now + 60 * 60 * 6)
+        );
+
+        // changing the window keeps the current capacity
+        global.set_rate_limit_duration(60 * 60);
+        assert_eq!(global.capacity(), 12_500);
+        set_test_timestamp(now + 60 * 30);
+        assert_eq!(global.capacity(), 62_500);
+    }
+
+    #[test]
+    fn test_consume_or_reserve() {
+        let now = current_timestamp();
+        // refills 1 per second
+        let mut rate_limit_state = RateLimitState {
+            limit: 100_000,
+            capacity_at_last_tx: 10_000,
+            last_tx_timestamp: now,
+            rate_limit_duration: 100_000,
+        };
+
+        // fits, so it's consumed
+        assert_eq!(
+            rate_limit_state.consume_or_reserve(4_000),
+            RateLimitResult::Consumed(now)
+        );
+        assert_eq!(rate_limit_state.capacity(), 6_000);
+
+        // released as soon as the missing 24k have refilled
+        assert_eq!(
+            rate_limit_state.consume_or_reserve(30_000),
+            RateLimitResult::Reserved(now + 24_000)
+        );
+        assert_eq!(rate_limit_state.capacity(), 0);
+
+        // later transfers are queued behind it
+        assert_eq!(
+            rate_limit_state.consume_or_reserve(5_000),
+            RateLimitResult::Reserved(now + 29_000)
+        );
+        assert_eq!(
+            rate_limit_state.consume_or_delay(1),
+            RateLimitResult::Delayed(now + 100_000)
+        );
+
+        // nothing is available until the reserved capacity has refilled
+        set_test_timestamp(now + 28_999);
+        assert_eq!(rate_limit_state.capacity(), 0);
+        set_test_timestamp(now + 29_500);
+        assert_eq!(rate_limit_state.capacity(), 500);
+
+        // backflows pay back the reserved capacity first
+        set_test_timestamp(now + 10_000);
+        let now = current_timestamp();
+        rate_limit_state.refill(now, 10_000);
+        assert_eq!(rate_limit_state.last_tx_timestamp, now + 9_000);
+        assert_eq!(rate_limit_state.capacity(), 0);
+
+        // draining doesn't change the reserved capacity
+        rate_limit_state.drain(now, 10_000);
+        assert_eq!(rate_limit_state.last_tx_timestamp, now + 9_000);
+
+        // neither does decreasing the limit, but it now refills at half the rate
+        rate_limit_state.set_limit(50_000);
+        assert_eq!(rate_limit_state.last_tx_timestamp, now + 18_000);
+
+        // and shortening the window speeds it back up
+        rate_limit_state.set_rate_limit_duration(50_000);
+        assert_eq!(rate_limit_state.last_tx_timestamp, now + 9_000);
+
+        // increasing the limit pays back the reserved capacity first
+        rate_limit_state.set_limit(100_000);
+        assert_eq!(rate_limit_state.last_tx_timestamp, now);
+        assert_eq!(rate_limit_state.capacity(), 41_000);
+
+        // and refilling more than what's reserved leaves the rest as capacity
+        assert_eq!(
+            rate_limit_state.consume_or_reserve(51_000),
+            RateLimitResult::Reserved(now + 5_000)
+        );
+        rate_limit_state.refill(now, 15_000);
+        assert_eq!(rate_limit_state.capacity(), 5_000);
+    }
+
+    #[test]
+    fn test_consume_or_reserve_all() {
+        let now = current_timestamp();
+        let mut global = RateLimitState {
+            limit: 100_000,
+            capacity_at_last_tx: 100_000,
+            last_tx_timestamp: now,
+            rate_limit_duration: 100_000,
+        };
+        let mut chain = RateLimitState {
+            limit: 30_000,
+            capacity_at_last_tx: 10_000,
+            last_tx_timestamp: now,
+            rate_limit_duration: 100_000,
+        };
+
+        // the amount is consumed from both, and the transfer is delayed until
+        // the slowest one has refilled
+        assert_eq!(
+            RateLimitState::consume_or_reserve_all(&mut [&mut global, &mut chain], 20_000),
+            RateLimitResult::Reserved(now + 33_334)
+        );
+        assert_eq!(global.capacity(), 80_000);
+        assert_eq!(chain.capacity(), 0);
+
+        // a zero limit never refills, so nothing is reserved
+        let mut empty = RateLimitState {
+            limit: 0,
+            capacity_at_last_tx: 0,
+            last_tx_timestamp: now,
+            rate_limit_duration: 100_000,
+        };
+        assert_eq!(
+            RateLimitState::consume_or_reserve_all(&mut [&mut global, &mut empty], 1_000),
+            RateLimitResult::Delayed(now + 100_000)
+        );
+        assert_eq!(global.capacity(), 80_000);
+    }
+
+    #[test]
+    fn test_consume_or_reserve_above_limit() {
+        let now = current_timestamp();
+        // refills 1 per second
+        let mut rate_limit_state = RateLimitState {
+            limit: 100_000,
+            capacity_at_last_tx: 100_000,
+            last_tx_timestamp: now,
+            rate_limit_duration: 100_000,
+        };
+
+        // more than the limit, so nothing is reserved, and the transfer is
+        // delayed by a single window
+        assert_eq!(
+            rate_limit_state.consume_or_reserve(250_000),
+            RateLimitResult::Delayed(now + 100_000)
+        );
+        assert_eq!(rate_limit_state.capacity(), 100_000);
+
+        // so it doesn't hold up later transfers
+        assert_eq!(
+            rate_limit_state.consume_or_reserve(1_000),
+            RateLimitResult::Consumed(now)
+        );
+
+        // even once the capacity is used up, a transfer up to the limit is
+        // released within one window
+        assert_eq!(
+            rate_limit_state.consume_or_reserve(99_000),
+            RateLimitResult::Consumed(now)
+        );
+        assert_eq!(
+            rate_limit_state.consume_or_reserve(100_000),
+            RateLimitResult::Reserved(now + 100_000)
+        );
+        assert_eq!(
+            rate_limit_state.consume_or_reserve(100_001),
+            RateLimitResult::Delayed(now + 100_000)
+        );
     }
 }
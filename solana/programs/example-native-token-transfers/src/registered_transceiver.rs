@@ -6,8 +6,11 @@ pub struct RegisteredTransceiver {
     pub bump: u8,
     pub id: u8,
     pub transceiver_address: Pubkey,
+    /// See [`crate::config::Config::version`].
+    pub version: u8,
 }
 
 impl RegisteredTransceiver {
     pub const SEED_PREFIX: &'static [u8] = b"registered_transceiver";
+    pub const LAYOUT_VERSION: u8 = 1;
 }
@@ -3,6 +3,8 @@ use anchor_lang::prelude::*;
 use wormhole_anchor_sdk::wormhole;
 use wormhole_io::TypePrefixedPayload;
 
+use crate::error::NTTError;
+
 cfg_if::cfg_if! {
     if #[cfg(feature = "tilt-devnet2")] {
         const FINALITY: wormhole::Finality = wormhole::Finality::Confirmed;
@@ -75,7 +77,7 @@ pub fn post_message<'info, A: TypePrefixedPayload>(
     wormhole::post_message(
         CpiContext::new_with_signer(wormhole.program.to_account_info(), ix, &seeds.concat()),
         batch_id,
-        TypePrefixedPayload::to_vec_payload(payload),
+        ntt_messages::codec::encode(payload).map_err(NTTError::from)?,
         FINALITY,
     )?;
 
@@ -1,16 +1,22 @@
 use anchor_lang::prelude::*;
 use ntt_messages::chain_id::ChainId;
 
-use crate::{config::Config, transceivers::accounts::peer::TransceiverPeer};
+use crate::{
+    config::{Config, Role},
+    error::NTTError,
+    events::WormholePeerSet,
+    transceivers::accounts::peer::TransceiverPeer,
+};
 
 #[derive(Accounts)]
 #[instruction(args: SetTransceiverPeerArgs)]
 pub struct SetTransceiverPeer<'info> {
     #[account(
-        has_one = owner,
+        constraint = config.has_role(&owner.key(), Role::TransceiverAdmin) @ NTTError::Unauthorized,
     )]
     pub config: Account<'info, Config>,
 
+    /// The owner, or the holder of [`Role::TransceiverAdmin`].
     pub owner: Signer<'info>,
 
     #[account(mut)]
@@ -43,5 +49,10 @@ pub fn set_transceiver_peer(
         address: args.address,
     });
 
+    emit!(WormholePeerSet {
+        chain_id: args.chain_id,
+        address: args.address,
+    });
+
     Ok(())
 }
@@ -0,0 +1,43 @@
+use anchor_lang::prelude::*;
+use ntt_messages::ntt::NativeTokenTransfer;
+
+use crate::{
+    error::NTTError, messages::ValidatedTransceiverMessage, queue::inbox::InboxItem,
+    transfer::Payload,
+};
+
+#[derive(Accounts)]
+pub struct CloseTransceiverMessage<'info> {
+    #[account(
+        mut,
+        has_one = rent_payer @ NTTError::IncorrectRentPayer,
+        close = rent_payer,
+    )]
+    pub transceiver_message:
+        Account<'info, ValidatedTransceiverMessage<NativeTokenTransfer<Payload>>>,
+
+    #[account(
+        seeds = [
+            InboxItem::SEED_PREFIX,
+            transceiver_message.message.ntt_manager_payload.keccak256(transceiver_message.from_chain).as_ref(),
+        ],
+        bump,
+        constraint = InboxItem::is_done(&inbox_item)? @ NTTError::InboxItemNotReleased,
+    )]
+    /// CHECK: the seeds constraint ensures that this is the inbox item of the
+    /// message, and [`InboxItem::is_done`] checks the owner.
+    pub inbox_item: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    /// CHECK: the `transceiver_message` constraint enforces that this is the correct address
+    pub rent_payer: UncheckedAccount<'info>,
+}
+
+/// Closes a transceiver message once the inbox item it voted for has been
+/// released, returning the rent to the original payer.
+///
+/// Receiving the same VAA again after closing re-creates the message, but it
+/// can't be redeemed, since the inbox item is already released.
+pub fn close_transceiver_message(_ctx: Context<CloseTransceiverMessage>) -> Result<()> {
+    Ok(())
+}
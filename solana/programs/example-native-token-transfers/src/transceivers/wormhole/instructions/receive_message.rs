@@ -9,8 +9,9 @@ use ntt_messages::{
 use wormhole_anchor_sdk::wormhole::PostedVaa;
 
 use crate::{
-    config::*, error::NTTError, messages::ValidatedTransceiverMessage,
-    transceivers::accounts::peer::TransceiverPeer, transfer::Payload,
+    config::*, error::NTTError, events::WormholeMessageReceived,
+    messages::ValidatedTransceiverMessage, transceivers::accounts::peer::TransceiverPeer,
+    transfer::Payload,
 };
 
 #[derive(Accounts)]
@@ -65,12 +66,21 @@ pub struct ReceiveMessage<'info> {
 pub fn receive_message(ctx: Context<ReceiveMessage>) -> Result<()> {
     let message = ctx.accounts.vaa.message().message_data.clone();
     let chain_id = ctx.accounts.vaa.emitter_chain();
+    let message_id = message.ntt_manager_payload.id;
     ctx.accounts
         .transceiver_message
         .set_inner(ValidatedTransceiverMessage {
             from_chain: ChainId { id: chain_id },
             message,
+            rent_payer: ctx.accounts.payer.key(),
+            attested_at: ctx.accounts.vaa.timestamp().into(),
         });
 
+    emit!(WormholeMessageReceived {
+        transceiver_message: ctx.accounts.transceiver_message.key(),
+        from_chain: ChainId { id: chain_id },
+        message_id,
+    });
+
     Ok(())
 }
@@ -6,8 +6,14 @@ use ntt_messages::{
 };
 
 use crate::{
-    config::*, error::NTTError, queue::outbox::OutboxItem, registered_transceiver::*,
-    transceivers::wormhole::accounts::*, transfer::Payload,
+    config::*,
+    error::NTTError,
+    events::{OutboundTransferReleased, WormholeMessageSent},
+    peer::NttManagerPeer,
+    queue::outbox::OutboxItem,
+    registered_transceiver::*,
+    transceivers::wormhole::accounts::*,
+    transfer::Payload,
 };
 
 #[derive(Accounts)]
@@ -15,6 +21,9 @@ pub struct ReleaseOutbound<'info> {
     #[account(mut)]
     pub payer: Signer<'info>,
 
+    #[account(
+        constraint = !config.outbound_paused @ NTTError::OutboundPaused,
+    )]
     pub config: NotPausedConfig<'info>,
 
     #[account(
@@ -23,6 +32,13 @@ pub struct ReleaseOutbound<'info> {
     )]
     pub outbox_item: Account<'info, OutboxItem>,
 
+    #[account(
+        seeds = [NttManagerPeer::SEED_PREFIX, outbox_item.recipient_chain.id.to_be_bytes().as_ref()],
+        bump = peer.bump,
+        constraint = !peer.paused @ NTTError::PeerPaused,
+    )]
+    pub peer: Account<'info, NttManagerPeer>,
+
     #[account(
         constraint = transceiver.transceiver_address == crate::ID,
         constraint = config.enabled_transceivers.get(transceiver.id)? @ NTTError::DisabledTransceiver
@@ -66,6 +82,12 @@ pub fn release_outbound(ctx: Context<ReleaseOutbound>, args: ReleaseOutboundArgs
     }
 
     assert!(accs.outbox_item.released.get(accs.transceiver.id)?);
+    emit!(OutboundTransferReleased {
+        outbox_item: accs.outbox_item.key(),
+        transceiver: accs.transceiver.transceiver_address,
+        transceiver_index: accs.transceiver.id,
+    });
+
     let message: TransceiverMessage<WormholeTransceiver, NativeTokenTransfer<Payload>> =
         TransceiverMessage::new(
             // TODO: should we just put the ntt id here statically?
@@ -79,7 +101,7 @@ pub fn release_outbound(ctx: Context<ReleaseOutbound>, args: ReleaseOutboundArgs
                     source_token: accs.config.mint.to_bytes(),
                     to: accs.outbox_item.recipient_address,
                     to_chain: accs.outbox_item.recipient_chain,
-                    additional_payload: Payload {},
+                    additional_payload: accs.outbox_item.additional_payload.clone(),
                 },
             },
             vec![],
@@ -99,5 +121,11 @@ pub fn release_outbound(ctx: Context<ReleaseOutbound>, args: ReleaseOutboundArgs
         ]],
     )?;
 
+    emit!(WormholeMessageSent {
+        outbox_item: accs.outbox_item.key(),
+        wormhole_message: accs.wormhole_message.key(),
+        recipient_chain: accs.outbox_item.recipient_chain,
+    });
+
     Ok(())
 }
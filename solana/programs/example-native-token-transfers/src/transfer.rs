@@ -1,3 +1,56 @@
-use ntt_messages::ntt::EmptyPayload;
+use anchor_lang::prelude::*;
+use ntt_messages::{ntt::AdditionalPayload, trimmed_amount::TrimmedAmount};
+use spl_token_2022::extension::{
+    transfer_fee::TransferFeeConfig, BaseStateWithExtensions, StateWithExtensions,
+};
 
-pub type Payload = EmptyPayload;
+use crate::error::NTTError;
+
+pub type Payload = AdditionalPayload;
+
+/// Returns the transfer fee configuration of `mint`, if it is a Token-2022 mint
+/// with the transfer fee extension.
+///
+/// For mints with a transfer fee, the amount that arrives in custody is less
+/// than the amount transferred, so the transfer instructions can't remove the
+/// dust up front. Instead, the amount passed to them must be such that what
+/// arrives has no dust (see [`amount_before_transfer_fee`]).
+pub fn transfer_fee_config(mint: &AccountInfo) -> Result<Option<TransferFeeConfig>> {
+    if mint.owner != &spl_token_2022::ID {
+        return Ok(None);
+    }
+    let data = mint.try_borrow_data()?;
+    let mint = StateWithExtensions::<spl_token_2022::state::Mint>::unpack(&data)?;
+    Ok(mint.get_extension::<TransferFeeConfig>().ok().copied())
+}
+
+/// Client-side helper for mints with a transfer fee.
+///
+/// Returns the amount to pass to the `transfer_*` instructions so that, after
+/// the fee for `epoch` is taken, the amount that arrives in custody is `amount`
+/// with its dust removed (i.e. it trims cleanly from `mint_decimals` to
+/// `peer_decimals`). The trimmed amount that will be sent is returned too.
+///
+/// The fee can change at an epoch boundary, so the result should be used in the
+/// same epoch.
+pub fn amount_before_transfer_fee(
+    transfer_fee_config: &TransferFeeConfig,
+    epoch: u64,
+    amount: u64,
+    mint_decimals: u8,
+    peer_decimals: u8,
+) -> Result<(u64, TrimmedAmount)> {
+    let mut amount = amount;
+    let trimmed_amount = TrimmedAmount::remove_dust(&mut amount, mint_decimals, peer_decimals)
+        .map_err(NTTError::from)?;
+
+    let transfer_fee = transfer_fee_config.get_epoch_fee(epoch);
+    let pre_fee_amount = transfer_fee
+        .calculate_pre_fee_amount(amount)
+        .filter(|&pre_fee_amount| {
+            transfer_fee.calculate_post_fee_amount(pre_fee_amount) == Some(amount)
+        })
+        .ok_or(NTTError::InvalidTransferFee)?;
+
+    Ok((pre_fee_amount, trimmed_amount))
+}
@@ -1,20 +1,42 @@
 #![cfg(feature = "test-sbf")]
 #![feature(type_changing_struct_update)]
 
-use anchor_lang::{prelude::Pubkey, system_program::System, Id};
+use anchor_lang::{
+    prelude::{ErrorCode, Pubkey},
+    system_program::System,
+    AnchorSerialize, Discriminator, Id, Space,
+};
 use example_native_token_transfers::{
-    config::Config, error::NTTError, registered_transceiver::RegisteredTransceiver,
+    bitmap::Bitmap,
+    config::{Config, Role},
+    error::NTTError,
+    instructions::{InitOutboxChainRateLimitArgs, MigrateArgs},
+    legacy,
+    peer::NttManagerPeer,
+    queue::{
+        inbox::{InboxItem, ReleaseStatus},
+        outbox::{OutboxChainRateLimit, OutboxItem},
+    },
+    registered_transceiver::RegisteredTransceiver,
 };
-use ntt_messages::mode::Mode;
+use ntt_messages::{chain_id::ChainId, mode::Mode, trimmed_amount::TrimmedAmount};
 use solana_program_test::*;
-use solana_sdk::{instruction::InstructionError, signer::Signer, transaction::TransactionError};
+use solana_sdk::{
+    account::Account, instruction::InstructionError, signature::Keypair, signer::Signer,
+    transaction::TransactionError,
+};
 
 use crate::{
-    common::{query::GetAccountDataAnchor, setup::setup, submit::Submittable},
+    common::{
+        query::GetAccountDataAnchor,
+        setup::{setup, OTHER_CHAIN},
+        submit::Submittable,
+    },
     sdk::accounts::{good_ntt, NTTAccounts},
     sdk::instructions::admin::{
-        deregister_transceiver, register_transceiver, set_threshold, DeregisterTransceiver,
-        RegisterTransceiver, SetThreshold,
+        deregister_transceiver, grant_role, init_outbox_chain_rate_limit, migrate,
+        register_transceiver, revoke_role, set_threshold, DeregisterTransceiver,
+        InitOutboxChainRateLimit, Migrate, RegisterTransceiver, SetRole, SetThreshold,
     },
 };
 
@@ -212,3 +234,409 @@ async fn test_threshold_too_high() {
         )
     );
 }
+
+#[tokio::test]
+async fn test_roles() {
+    let (mut ctx, test_data) = setup(Mode::Burning).await;
+    let admin = Keypair::new();
+
+    let grant = |role| {
+        grant_role(
+            &good_ntt,
+            SetRole {
+                owner: test_data.program_owner.pubkey(),
+            },
+            role,
+            admin.pubkey(),
+        )
+    };
+    let set_threshold_as_admin = || {
+        set_threshold(
+            &good_ntt,
+            SetThreshold {
+                owner: admin.pubkey(),
+            },
+            1,
+        )
+    };
+    let unauthorized = TransactionError::InstructionError(
+        0,
+        InstructionError::Custom(NTTError::Unauthorized.into()),
+    );
+
+    let err = set_threshold_as_admin()
+        .submit_with_signers(&[&admin], &mut ctx)
+        .await
+        .unwrap_err();
+    assert_eq!(err.unwrap(), unauthorized);
+
+    // holding a different role doesn't help
+    grant(Role::RateLimitAdmin)
+        .submit_with_signers(&[&test_data.program_owner], &mut ctx)
+        .await
+        .unwrap();
+    ctx.get_new_latest_blockhash().await.unwrap();
+    let err = set_threshold_as_admin()
+        .submit_with_signers(&[&admin], &mut ctx)
+        .await
+        .unwrap_err();
+    assert_eq!(err.unwrap(), unauthorized);
+
+    grant(Role::TransceiverAdmin)
+        .submit_with_signers(&[&test_data.program_owner], &mut ctx)
+        .await
+        .unwrap();
+    let config_account: Config = ctx.get_account_data_anchor(good_ntt.config()).await;
+    assert_eq!(config_account.transceiver_admin, Some(admin.pubkey()));
+    assert_eq!(config_account.rate_limit_admin, Some(admin.pubkey()));
+
+    ctx.get_new_latest_blockhash().await.unwrap();
+    set_threshold_as_admin()
+        .submit_with_signers(&[&admin], &mut ctx)
+        .await
+        .unwrap();
+
+    // only the owner can grant roles
+    let err = grant_role(
+        &good_ntt,
+        SetRole {
+            owner: admin.pubkey(),
+        },
+        Role::PeerAdmin,
+        admin.pubkey(),
+    )
+    .submit_with_signers(&[&admin], &mut ctx)
+    .await
+    .unwrap_err();
+    assert_eq!(
+        err.unwrap(),
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(ErrorCode::ConstraintHasOne.into())
+        )
+    );
+
+    revoke_role(
+        &good_ntt,
+        SetRole {
+            owner: test_data.program_owner.pubkey(),
+        },
+        Role::TransceiverAdmin,
+    )
+    .submit_with_signers(&[&test_data.program_owner], &mut ctx)
+    .await
+    .unwrap();
+    let config_account: Config = ctx.get_account_data_anchor(good_ntt.config()).await;
+    assert_eq!(config_account.transceiver_admin, None);
+
+    ctx.get_new_latest_blockhash().await.unwrap();
+    let err = set_threshold_as_admin()
+        .submit_with_signers(&[&admin], &mut ctx)
+        .await
+        .unwrap_err();
+    assert_eq!(err.unwrap(), unauthorized);
+}
+
+/// Overwrites the account at `address` with `legacy`, as it would have been
+/// stored before accounts were versioned.
+async fn set_legacy_account<T: AnchorSerialize + Space>(
+    ctx: &mut ProgramTestContext,
+    address: Pubkey,
+    discriminator: [u8; 8],
+    legacy: T,
+) {
+    let mut account = ctx
+        .banks_client
+        .get_account(address)
+        .await
+        .unwrap()
+        .unwrap();
+    let len = 8 + T::INIT_SPACE;
+    account.data = discriminator.to_vec();
+    account.data.extend(legacy.try_to_vec().unwrap());
+    account.data.resize(len, 0);
+    account.lamports = ctx
+        .banks_client
+        .get_rent()
+        .await
+        .unwrap()
+        .minimum_balance(len);
+    ctx.set_account(&address, &account.into());
+}
+
+#[tokio::test]
+async fn test_migrate() {
+    let (mut ctx, test_data) = setup(Mode::Locking).await;
+
+    let config: Config = ctx.get_account_data_anchor(good_ntt.config()).await;
+    assert_eq!(config.version, Config::LAYOUT_VERSION);
+    let peer: NttManagerPeer = ctx
+        .get_account_data_anchor(good_ntt.peer(OTHER_CHAIN))
+        .await;
+
+    set_legacy_account(
+        &mut ctx,
+        good_ntt.config(),
+        Config::DISCRIMINATOR,
+        legacy::Config {
+            bump: config.bump,
+            owner: config.owner,
+            pending_owner: config.pending_owner,
+            mint: config.mint,
+            token_program: config.token_program,
+            mode: config.mode,
+            chain_id: config.chain_id,
+            next_transceiver_id: config.next_transceiver_id,
+            threshold: config.threshold,
+            enabled_transceivers: config.enabled_transceivers,
+            paused: config.paused,
+            custody: config.custody,
+        },
+    )
+    .await;
+    set_legacy_account(
+        &mut ctx,
+        good_ntt.peer(OTHER_CHAIN),
+        NttManagerPeer::DISCRIMINATOR,
+        legacy::NttManagerPeer {
+            bump: peer.bump,
+            address: peer.address,
+            token_decimals: peer.token_decimals,
+        },
+    )
+    .await;
+
+    let payer = ctx.payer.pubkey();
+    let migrate_as = |owner: Pubkey, account| {
+        migrate(
+            &good_ntt,
+            Migrate {
+                payer,
+                owner,
+                account,
+            },
+            MigrateArgs { source_chain: None },
+        )
+    };
+
+    let impostor = Keypair::new();
+    let err = migrate_as(impostor.pubkey(), good_ntt.config())
+        .submit_with_signers(&[&impostor], &mut ctx)
+        .await
+        .unwrap_err();
+    assert_eq!(
+        err.unwrap(),
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(ErrorCode::ConstraintHasOne.into())
+        )
+    );
+
+    for account in [good_ntt.config(), good_ntt.peer(OTHER_CHAIN)] {
+        migrate_as(test_data.program_owner.pubkey(), account)
+            .submit_with_signers(&[&test_data.program_owner], &mut ctx)
+            .await
+            .unwrap();
+    }
+
+    let migrated: Config = ctx.get_account_data_anchor(good_ntt.config()).await;
+    assert_eq!(migrated.owner, config.owner);
+    assert_eq!(migrated.mint, config.mint);
+    assert_eq!(migrated.custody, config.custody);
+    assert_eq!(migrated.enabled_transceivers, config.enabled_transceivers);
+    assert_eq!(migrated.version, Config::LAYOUT_VERSION);
+
+    let migrated: NttManagerPeer = ctx
+        .get_account_data_anchor(good_ntt.peer(OTHER_CHAIN))
+        .await;
+    assert_eq!(migrated.address, peer.address);
+    assert_eq!(migrated.token_decimals, peer.token_decimals);
+    assert!(!migrated.paused);
+    assert_eq!(migrated.version, NttManagerPeer::LAYOUT_VERSION);
+
+    ctx.get_new_latest_blockhash().await.unwrap();
+    let err = migrate_as(test_data.program_owner.pubkey(), good_ntt.config())
+        .submit_with_signers(&[&test_data.program_owner], &mut ctx)
+        .await
+        .unwrap_err();
+    assert_eq!(
+        err.unwrap(),
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(NTTError::AccountAlreadyMigrated.into())
+        )
+    );
+}
+
+/// Creates an account owned by the program at a new address, holding `legacy`
+/// padded to `len` bytes (including the discriminator). Unlike
+/// [`set_legacy_account`], the size is given explicitly, so it's checked
+/// against the size of the accounts created by the 3.0.0 release.
+async fn create_legacy_account<T: AnchorSerialize>(
+    ctx: &mut ProgramTestContext,
+    discriminator: [u8; 8],
+    legacy: T,
+    len: usize,
+) -> Pubkey {
+    let mut data = discriminator.to_vec();
+    data.extend(legacy.try_to_vec().unwrap());
+    assert!(data.len() <= len);
+    data.resize(len, 0);
+
+    let lamports = ctx
+        .banks_client
+        .get_rent()
+        .await
+        .unwrap()
+        .minimum_balance(len);
+    let address = Keypair::new().pubkey();
+    ctx.set_account(
+        &address,
+        &Account {
+            lamports,
+            data,
+            owner: example_native_token_transfers::ID,
+            executable: false,
+            rent_epoch: 0,
+        }
+        .into(),
+    );
+    address
+}
+
+#[tokio::test]
+async fn test_migrate_queue_items() {
+    let (mut ctx, test_data) = setup(Mode::Locking).await;
+
+    // sizes of the outbox and inbox items of the 3.0.0 release
+    const OUTBOX_ITEM_LEN: usize = 8 + 131;
+    const INBOX_ITEM_LEN: usize = 8 + 67;
+
+    let mut released = Bitmap::new();
+    released.set(0, true).unwrap();
+    let outbox_item = create_legacy_account(
+        &mut ctx,
+        OutboxItem::DISCRIMINATOR,
+        legacy::OutboxItem {
+            amount: TrimmedAmount::new(1000, 7),
+            sender: test_data.user.pubkey(),
+            recipient_chain: ChainId { id: OTHER_CHAIN },
+            recipient_ntt_manager: [1; 32],
+            recipient_address: [2; 32],
+            release_timestamp: 1234,
+            released,
+        },
+        OUTBOX_ITEM_LEN,
+    )
+    .await;
+
+    let inbox_item = create_legacy_account(
+        &mut ctx,
+        InboxItem::DISCRIMINATOR,
+        legacy::InboxItem {
+            init: true,
+            bump: 255,
+            amount: 1000,
+            recipient_address: test_data.user.pubkey(),
+            votes: released,
+            release_status: legacy::ReleaseStatus::ReleaseAfter(5678),
+        },
+        INBOX_ITEM_LEN,
+    )
+    .await;
+
+    let payer = ctx.payer.pubkey();
+    let migrate_ix = |account, source_chain| {
+        migrate(
+            &good_ntt,
+            Migrate {
+                payer,
+                owner: test_data.program_owner.pubkey(),
+                account,
+            },
+            MigrateArgs { source_chain },
+        )
+    };
+
+    migrate_ix(outbox_item, None)
+        .submit_with_signers(&[&test_data.program_owner], &mut ctx)
+        .await
+        .unwrap();
+
+    // the source chain of inbox items has to be passed in
+    let err = migrate_ix(inbox_item, None)
+        .submit_with_signers(&[&test_data.program_owner], &mut ctx)
+        .await
+        .unwrap_err();
+    assert_eq!(
+        err.unwrap(),
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(NTTError::InvalidChainId.into())
+        )
+    );
+    migrate_ix(inbox_item, Some(ChainId { id: OTHER_CHAIN }))
+        .submit_with_signers(&[&test_data.program_owner], &mut ctx)
+        .await
+        .unwrap();
+
+    let migrated: OutboxItem = ctx.get_account_data_anchor(outbox_item).await;
+    assert_eq!(migrated.amount, TrimmedAmount::new(1000, 7));
+    assert_eq!(migrated.sender, test_data.user.pubkey());
+    assert_eq!(migrated.recipient_chain, ChainId { id: OTHER_CHAIN });
+    assert_eq!(migrated.recipient_address, [2; 32]);
+    assert_eq!(migrated.release_timestamp, 1234);
+    assert_eq!(migrated.released, released);
+    assert_eq!(migrated.rent_payer, ctx.payer.pubkey());
+    assert_eq!(migrated.version, OutboxItem::LAYOUT_VERSION);
+
+    let migrated: InboxItem = ctx.get_account_data_anchor(inbox_item).await;
+    assert!(migrated.init);
+    assert_eq!(migrated.amount, 1000);
+    assert_eq!(migrated.recipient_address, test_data.user.pubkey());
+    assert_eq!(migrated.votes, released);
+    assert_eq!(migrated.release_status, ReleaseStatus::ReleaseAfter(5678));
+    assert_eq!(migrated.source_chain, ChainId { id: OTHER_CHAIN });
+    assert_eq!(migrated.rent_payer, ctx.payer.pubkey());
+    assert_eq!(migrated.version, InboxItem::LAYOUT_VERSION);
+}
+
+#[tokio::test]
+async fn test_init_outbox_chain_rate_limit() {
+    let (mut ctx, _test_data) = setup(Mode::Locking).await;
+
+    let init_ix = |payer| {
+        init_outbox_chain_rate_limit(
+            &good_ntt,
+            InitOutboxChainRateLimit { payer },
+            InitOutboxChainRateLimitArgs {
+                chain_id: ChainId { id: OTHER_CHAIN },
+            },
+        )
+    };
+
+    // it was already created by `set_peer`
+    let err = init_ix(ctx.payer.pubkey())
+        .submit(&mut ctx)
+        .await
+        .unwrap_err();
+    assert_eq!(
+        err.unwrap(),
+        TransactionError::InstructionError(0, InstructionError::Custom(0))
+    );
+
+    // peers set by the 3.0.0 release have no outbound rate limit of their own
+    ctx.set_account(
+        &good_ntt.outbox_chain_rate_limit(OTHER_CHAIN),
+        &Account::default().into(),
+    );
+
+    ctx.get_new_latest_blockhash().await.unwrap();
+    init_ix(ctx.payer.pubkey()).submit(&mut ctx).await.unwrap();
+
+    let rate_limit: OutboxChainRateLimit = ctx
+        .get_account_data_anchor(good_ntt.outbox_chain_rate_limit(OTHER_CHAIN))
+        .await;
+    assert_eq!(rate_limit.rate_limit.limit, u64::MAX);
+    assert_eq!(rate_limit.rate_limit.capacity_at_last_tx, u64::MAX);
+}
@@ -34,7 +34,10 @@ use crate::{
     },
 };
 use crate::{
-    common::{submit::Submittable, utils::post_vaa_helper},
+    common::{
+        submit::Submittable,
+        utils::{next_outbox_item, post_vaa_helper},
+    },
     sdk::instructions::transfer::{approve_token_authority, transfer},
 };
 
@@ -80,6 +83,7 @@ fn init_redeem_accs(
         transceiver_message: good_ntt.transceiver_message(chain_id, ntt_manager_message.id),
         inbox_item: good_ntt.inbox_item(chain_id, ntt_manager_message),
         inbox_rate_limit: good_ntt.inbox_rate_limit(chain_id),
+        outbox_chain_rate_limit: good_ntt.outbox_chain_rate_limit(chain_id),
         mint: test_data.mint,
     }
 }
@@ -173,10 +177,9 @@ async fn test_cancel() {
         inbound_capacity(&mut ctx).await
     );
 
-    let outbox_item = Keypair::new();
+    let outbox_item = next_outbox_item(&good_ntt, &mut ctx).await;
 
-    let (accs, args) =
-        init_transfer_accs_args(&mut ctx, &test_data, outbox_item.pubkey(), 7000, true);
+    let (accs, args) = init_transfer_accs_args(&mut ctx, &test_data, outbox_item, 7000, true);
 
     approve_token_authority(
         &good_ntt,
@@ -188,7 +191,7 @@ async fn test_cancel() {
     .await
     .unwrap();
     transfer(&good_ntt, accs, args, Mode::Locking)
-        .submit_with_signers(&[&outbox_item], &mut ctx)
+        .submit(&mut ctx)
         .await
         .unwrap();
 
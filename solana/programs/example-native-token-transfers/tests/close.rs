@@ -0,0 +1,370 @@
+#![cfg(feature = "test-sbf")]
+#![feature(type_changing_struct_update)]
+
+use anchor_lang::{
+    prelude::{ErrorCode, Pubkey},
+    Id,
+};
+use anchor_spl::token::Token;
+use common::{
+    setup::{TestData, OTHER_CHAIN},
+    utils::make_transfer_message,
+};
+use example_native_token_transfers::{
+    error::NTTError,
+    instructions::{RedeemArgs, ReleaseInboundArgs, TransferArgs},
+    transceivers::wormhole::ReleaseOutboundArgs,
+};
+use ntt_messages::{chain_id::ChainId, mode::Mode};
+use solana_program_test::*;
+use solana_sdk::{
+    instruction::InstructionError, signature::Keypair, signer::Signer,
+    transaction::TransactionError,
+};
+use spl_associated_token_account::get_associated_token_address_with_program_id;
+use wormhole_sdk::Address;
+
+use crate::{
+    common::{
+        setup::{setup, OTHER_TRANSCEIVER},
+        submit::Submittable,
+        utils::{next_outbox_item, post_vaa_helper},
+    },
+    sdk::{
+        accounts::{good_ntt, NTTAccounts},
+        instructions::{
+            close::{close_inbox_item, close_outbox_item, CloseInboxItem, CloseOutboxItem},
+            redeem::{redeem, Redeem},
+            release_inbound::{release_inbound_unlock, ReleaseInbound},
+            transfer::{approve_token_authority, transfer, Transfer},
+        },
+        transceivers::wormhole::instructions::{
+            close_message::{close_transceiver_message, CloseTransceiverMessage},
+            receive_message::{receive_message, ReceiveMessage},
+            release_outbound::{release_outbound, ReleaseOutbound},
+        },
+    },
+};
+
+pub mod common;
+pub mod sdk;
+
+async fn send_transfer(ctx: &mut ProgramTestContext, test_data: &TestData) -> Pubkey {
+    let outbox_item = next_outbox_item(&good_ntt, ctx).await;
+
+    let accs = Transfer {
+        payer: ctx.payer.pubkey(),
+        mint: test_data.mint,
+        from: test_data.user_token_account,
+        from_authority: test_data.user.pubkey(),
+        peer: good_ntt.peer(OTHER_CHAIN),
+        outbox_item,
+    };
+
+    let args = TransferArgs {
+        amount: 100,
+        recipient_chain: ChainId { id: OTHER_CHAIN },
+        recipient_address: [1u8; 32],
+        should_queue: false,
+    };
+
+    approve_token_authority(
+        &good_ntt,
+        &test_data.user_token_account,
+        &test_data.user.pubkey(),
+        &args,
+    )
+    .submit_with_signers(&[&test_data.user], ctx)
+    .await
+    .unwrap();
+    transfer(&good_ntt, accs, args, Mode::Locking)
+        .submit(ctx)
+        .await
+        .unwrap();
+
+    outbox_item
+}
+
+#[tokio::test]
+async fn test_close_outbox_item() {
+    let (mut ctx, test_data) = setup(Mode::Locking).await;
+
+    let outbox_item = send_transfer(&mut ctx, &test_data).await;
+
+    // can't close before the item is released
+    let err = close_outbox_item(
+        &good_ntt,
+        CloseOutboxItem {
+            outbox_item,
+            rent_payer: ctx.payer.pubkey(),
+        },
+    )
+    .submit(&mut ctx)
+    .await
+    .unwrap_err();
+
+    assert_eq!(
+        err.unwrap(),
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(NTTError::OutboxItemNotReleased.into())
+        )
+    );
+
+    release_outbound(
+        &good_ntt,
+        ReleaseOutbound {
+            payer: ctx.payer.pubkey(),
+            outbox_item,
+            peer: good_ntt.peer(OTHER_CHAIN),
+        },
+        ReleaseOutboundArgs {
+            revert_on_delay: true,
+        },
+    )
+    .submit(&mut ctx)
+    .await
+    .unwrap();
+
+    // the rent has to go back to the original payer
+    let err = close_outbox_item(
+        &good_ntt,
+        CloseOutboxItem {
+            outbox_item,
+            rent_payer: Pubkey::new_unique(),
+        },
+    )
+    .submit(&mut ctx)
+    .await
+    .unwrap_err();
+
+    assert_eq!(
+        err.unwrap(),
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(NTTError::IncorrectRentPayer.into())
+        )
+    );
+
+    close_outbox_item(
+        &good_ntt,
+        CloseOutboxItem {
+            outbox_item,
+            rent_payer: ctx.payer.pubkey(),
+        },
+    )
+    .submit(&mut ctx)
+    .await
+    .unwrap();
+
+    assert!(ctx
+        .banks_client
+        .get_account(outbox_item)
+        .await
+        .unwrap()
+        .is_none());
+}
+
+#[tokio::test]
+async fn test_close_inbox_item() {
+    let recipient = Keypair::new();
+    let (mut ctx, test_data) = setup(Mode::Locking).await;
+
+    // transfer tokens to custody account
+    spl_token::instruction::transfer_checked(
+        &Token::id(),
+        &test_data.user_token_account,
+        &test_data.mint,
+        &good_ntt.custody(&test_data.mint),
+        &test_data.user.pubkey(),
+        &[],
+        1000,
+        9,
+    )
+    .unwrap()
+    .submit_with_signers(&[&test_data.user], &mut ctx)
+    .await
+    .unwrap();
+
+    spl_associated_token_account::instruction::create_associated_token_account(
+        &ctx.payer.pubkey(),
+        &recipient.pubkey(),
+        &test_data.mint,
+        &Token::id(),
+    )
+    .submit(&mut ctx)
+    .await
+    .unwrap();
+
+    let recipient_token_account = get_associated_token_address_with_program_id(
+        &recipient.pubkey(),
+        &test_data.mint,
+        &Token::id(),
+    );
+
+    let msg = make_transfer_message(&good_ntt, [0u8; 32], 1000, &recipient.pubkey());
+
+    let vaa0 = post_vaa_helper(
+        &good_ntt,
+        OTHER_CHAIN.into(),
+        Address(OTHER_TRANSCEIVER),
+        msg.clone(),
+        &mut ctx,
+    )
+    .await;
+
+    let receive_message_accs = ReceiveMessage {
+        payer: ctx.payer.pubkey(),
+        peer: good_ntt.transceiver_peer(OTHER_CHAIN),
+        vaa: vaa0,
+        chain_id: OTHER_CHAIN,
+        id: [0u8; 32],
+    };
+
+    let redeem_accs = Redeem {
+        payer: ctx.payer.pubkey(),
+        peer: good_ntt.peer(OTHER_CHAIN),
+        transceiver: good_ntt.program(),
+        transceiver_message: good_ntt.transceiver_message(OTHER_CHAIN, [0u8; 32]),
+        inbox_item: good_ntt.inbox_item(OTHER_CHAIN, msg.ntt_manager_payload.clone()),
+        inbox_rate_limit: good_ntt.inbox_rate_limit(OTHER_CHAIN),
+        outbox_chain_rate_limit: good_ntt.outbox_chain_rate_limit(OTHER_CHAIN),
+        mint: test_data.mint,
+    };
+
+    let inbox_item = redeem_accs.inbox_item;
+
+    receive_message(&good_ntt, receive_message_accs.clone())
+        .submit(&mut ctx)
+        .await
+        .unwrap();
+
+    redeem(&good_ntt, redeem_accs.clone(), RedeemArgs {})
+        .submit(&mut ctx)
+        .await
+        .unwrap();
+
+    // can't close before the item is released
+    let err = close_inbox_item(
+        &good_ntt,
+        CloseInboxItem {
+            inbox_item,
+            rent_payer: ctx.payer.pubkey(),
+        },
+    )
+    .submit(&mut ctx)
+    .await
+    .unwrap_err();
+
+    assert_eq!(
+        err.unwrap(),
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(NTTError::InboxItemNotReleased.into())
+        )
+    );
+
+    let err = close_transceiver_message(
+        &good_ntt,
+        CloseTransceiverMessage {
+            transceiver_message: redeem_accs.transceiver_message,
+            inbox_item,
+            rent_payer: ctx.payer.pubkey(),
+        },
+    )
+    .submit(&mut ctx)
+    .await
+    .unwrap_err();
+
+    assert_eq!(
+        err.unwrap(),
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(NTTError::InboxItemNotReleased.into())
+        )
+    );
+
+    release_inbound_unlock(
+        &good_ntt,
+        ReleaseInbound {
+            payer: ctx.payer.pubkey(),
+            inbox_item,
+            peer: good_ntt.peer(OTHER_CHAIN),
+            mint: test_data.mint,
+            recipient: recipient_token_account,
+        },
+        ReleaseInboundArgs {
+            revert_when_not_ready: true,
+        },
+    )
+    .submit(&mut ctx)
+    .await
+    .unwrap();
+
+    close_transceiver_message(
+        &good_ntt,
+        CloseTransceiverMessage {
+            transceiver_message: redeem_accs.transceiver_message,
+            inbox_item,
+            rent_payer: ctx.payer.pubkey(),
+        },
+    )
+    .submit(&mut ctx)
+    .await
+    .unwrap();
+
+    assert!(ctx
+        .banks_client
+        .get_account(redeem_accs.transceiver_message)
+        .await
+        .unwrap()
+        .is_none());
+
+    let inbox_item_before = ctx
+        .banks_client
+        .get_account(inbox_item)
+        .await
+        .unwrap()
+        .unwrap();
+
+    close_inbox_item(
+        &good_ntt,
+        CloseInboxItem {
+            inbox_item,
+            rent_payer: ctx.payer.pubkey(),
+        },
+    )
+    .submit(&mut ctx)
+    .await
+    .unwrap();
+
+    // the inbox item is replaced with a tombstone
+    let inbox_item_after = ctx
+        .banks_client
+        .get_account(inbox_item)
+        .await
+        .unwrap()
+        .unwrap();
+
+    assert_eq!(inbox_item_after.data.len(), 8);
+    assert!(inbox_item_after.lamports < inbox_item_before.lamports);
+
+    // the same message can be received again, but can't be redeemed
+    receive_message(&good_ntt, receive_message_accs)
+        .submit(&mut ctx)
+        .await
+        .unwrap();
+
+    let err = redeem(&good_ntt, redeem_accs, RedeemArgs {})
+        .submit(&mut ctx)
+        .await
+        .unwrap_err();
+
+    assert_eq!(
+        err.unwrap(),
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(ErrorCode::AccountDiscriminatorMismatch.into())
+        )
+    );
+}
@@ -0,0 +1,48 @@
+use anchor_lang::Event;
+use base64::Engine;
+use solana_program_test::{BanksClientError, ProgramTestContext};
+use solana_sdk::{
+    instruction::Instruction, signer::Signer, signers::Signers, transaction::Transaction,
+};
+
+/// Submits `ix` and returns the log messages of the transaction, so the events
+/// it emitted can be inspected with [`decode_events`].
+pub async fn submit_with_logs<T: Signers + ?Sized>(
+    ix: Instruction,
+    signers: &T,
+    ctx: &mut ProgramTestContext,
+) -> Result<Vec<String>, BanksClientError> {
+    let blockhash = ctx.banks_client.get_latest_blockhash().await.unwrap();
+
+    let mut transaction = Transaction::new_with_payer(&[ix], Some(&ctx.payer.pubkey()));
+    transaction.partial_sign(&[&ctx.payer], blockhash);
+    transaction.partial_sign(signers, blockhash);
+
+    let result = ctx
+        .banks_client
+        .process_transaction_with_metadata(transaction)
+        .await?;
+    result.result?;
+    Ok(result
+        .metadata
+        .map(|metadata| metadata.log_messages)
+        .unwrap_or_default())
+}
+
+/// Decodes all events of type `E` from the `Program data:` lines of `logs`, in
+/// the order they were emitted.
+pub fn decode_events<E: Event>(logs: &[String]) -> Vec<E> {
+    logs.iter()
+        .filter_map(|log| log.strip_prefix("Program data: "))
+        .filter_map(|data| base64::engine::general_purpose::STANDARD.decode(data).ok())
+        .filter(|data| data.starts_with(&E::DISCRIMINATOR))
+        .map(|data| E::try_from_slice(&data[8..]).unwrap())
+        .collect()
+}
+
+/// Like [`decode_events`], but expects exactly one event of type `E`.
+pub fn decode_event<E: Event>(logs: &[String]) -> E {
+    let mut events = decode_events(logs);
+    assert_eq!(events.len(), 1, "expected exactly one event");
+    events.remove(0)
+}
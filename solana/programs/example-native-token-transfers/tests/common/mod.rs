@@ -1,5 +1,7 @@
 #![allow(async_fn_in_trait)]
 pub mod account_json_utils;
+pub mod events;
+pub mod policy;
 pub mod query;
 pub mod setup;
 pub mod submit;
@@ -0,0 +1,59 @@
+//! Instructions of the `dummy-ntt-policy` program, which denies transfers from
+//! or to the addresses it blocked.
+
+use anchor_lang::prelude::Pubkey;
+use example_native_token_transfers::compliance::policy_extra_account_metas;
+use solana_sdk::{
+    instruction::{AccountMeta, Instruction},
+    system_program,
+};
+
+use super::setup::DUMMY_NTT_POLICY;
+
+fn data(name: &str, args: &[u8]) -> Vec<u8> {
+    let preimage = format!("global:{name}");
+    let mut data = solana_program::hash::hash(preimage.as_bytes()).to_bytes()[..8].to_vec();
+    data.extend(args);
+    data
+}
+
+fn blocked(address: &[u8; 32]) -> Pubkey {
+    Pubkey::find_program_address(&[b"blocked", address.as_ref()], &DUMMY_NTT_POLICY).0
+}
+
+pub fn initialize_extra_account_meta_list(payer: Pubkey, mint: Pubkey) -> Instruction {
+    Instruction {
+        program_id: DUMMY_NTT_POLICY,
+        accounts: vec![
+            AccountMeta::new(payer, true),
+            AccountMeta::new(policy_extra_account_metas(&DUMMY_NTT_POLICY, &mint), false),
+            AccountMeta::new_readonly(mint, false),
+            AccountMeta::new_readonly(system_program::ID, false),
+        ],
+        data: data("initialize_extra_account_meta_list", &[]),
+    }
+}
+
+pub fn block(payer: Pubkey, address: [u8; 32]) -> Instruction {
+    Instruction {
+        program_id: DUMMY_NTT_POLICY,
+        accounts: vec![
+            AccountMeta::new(payer, true),
+            AccountMeta::new(blocked(&address), false),
+            AccountMeta::new_readonly(system_program::ID, false),
+        ],
+        data: data("block", &address),
+    }
+}
+
+/// The remaining accounts a transfer needs for the policy program to check
+/// it: the program, its `ExtraAccountMetaList` PDA, and the extra accounts
+/// listed there.
+pub fn policy_accounts(mint: Pubkey, sender: [u8; 32], recipient: [u8; 32]) -> Vec<AccountMeta> {
+    vec![
+        AccountMeta::new_readonly(DUMMY_NTT_POLICY, false),
+        AccountMeta::new_readonly(policy_extra_account_metas(&DUMMY_NTT_POLICY, &mint), false),
+        AccountMeta::new_readonly(blocked(&sender), false),
+        AccountMeta::new_readonly(blocked(&recipient), false),
+    ]
+}
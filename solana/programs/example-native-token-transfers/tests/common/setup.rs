@@ -47,6 +47,18 @@ pub const OTHER_CHAIN: u16 = 2;
 pub const ANOTHER_CHAIN: u16 = 3;
 pub const UNREGISTERED_CHAIN: u16 = u16::MAX;
 
+/// The program id of the `dummy-ntt-recipient` program
+pub const DUMMY_NTT_RECIPIENT: Pubkey =
+    solana_program::pubkey!("fFQtv5LfAJRqLReTXje2hZKvdYYYuLLMMpxSUpEXMG9");
+
+/// The program id of the `dummy-ntt-policy` program
+pub const DUMMY_NTT_POLICY: Pubkey =
+    solana_program::pubkey!("4C3LUwizvxa4HrLqWw6eCTiZZr5AtLkWkm9LjijVpQhz");
+
+/// The program id of the `ntt-transceiver` program
+pub const NTT_TRANSCEIVER: Pubkey =
+    solana_program::pubkey!("Ee6jpX9oq2EsGuqGb6iZZxvtcpmMGZk8SAUbnQy4jcHR");
+
 pub struct TestData {
     pub governance: Governance,
     pub program_owner: Keypair,
@@ -125,6 +137,22 @@ pub async fn setup_programs(program_owner: Pubkey) -> Result<ProgramTest, Error>
         None,
     );
 
+    add_program_upgradeable(
+        &mut program_test,
+        "dummy_ntt_recipient",
+        DUMMY_NTT_RECIPIENT,
+        None,
+    );
+
+    add_program_upgradeable(
+        &mut program_test,
+        "dummy_ntt_policy",
+        DUMMY_NTT_POLICY,
+        None,
+    );
+
+    add_program_upgradeable(&mut program_test, "ntt_transceiver", NTT_TRANSCEIVER, None);
+
     add_program_upgradeable(
         &mut program_test,
         "mainnet_core_bridge",
@@ -1,7 +1,7 @@
 use std::sync::atomic::AtomicU64;
 
-use anchor_lang::AnchorSerialize;
-use example_native_token_transfers::transfer::Payload;
+use anchor_lang::{AccountDeserialize, AnchorSerialize};
+use example_native_token_transfers::{queue::outbox::OutboxSequence, transfer::Payload};
 use ntt_messages::{
     chain_id::ChainId, ntt::NativeTokenTransfer, ntt_manager::NttManagerMessage,
     transceiver::TransceiverMessage, transceivers::wormhole::WormholeTransceiver,
@@ -33,7 +33,7 @@ pub fn make_transfer_message(
             source_token: [3u8; 32],
             to_chain: ChainId { id: THIS_CHAIN },
             to: recipient.to_bytes(),
-            additional_payload: Payload {},
+            additional_payload: Payload::default(),
         },
     };
 
@@ -45,12 +45,42 @@ pub fn make_transfer_message(
     )
 }
 
+/// Returns the address of the outbox item that the next transfer will create.
+pub async fn next_outbox_item(ntt: &NTT, ctx: &mut ProgramTestContext) -> Pubkey {
+    let sequence = match ctx
+        .banks_client
+        .get_account(ntt.outbox_sequence())
+        .await
+        .unwrap()
+    {
+        Some(account) => {
+            OutboxSequence::try_deserialize(&mut account.data.as_ref())
+                .unwrap()
+                .next
+        }
+        None => 0,
+    };
+    ntt.outbox_item(sequence)
+}
+
 pub async fn post_vaa_helper<A: AnchorSerialize + Clone>(
     ntt: &NTT,
     emitter_chain: Chain,
     emitter_address: Address,
     msg: A,
     ctx: &mut ProgramTestContext,
+) -> Pubkey {
+    post_vaa_helper_at(ntt, emitter_chain, emitter_address, msg, 123232, ctx).await
+}
+
+/// Like [`post_vaa_helper`], but the VAA is attested at `timestamp`.
+pub async fn post_vaa_helper_at<A: AnchorSerialize + Clone>(
+    ntt: &NTT,
+    emitter_chain: Chain,
+    emitter_address: Address,
+    msg: A,
+    timestamp: u32,
+    ctx: &mut ProgramTestContext,
 ) -> Pubkey {
     static I: AtomicU64 = AtomicU64::new(0);
 
@@ -60,7 +90,7 @@ pub async fn post_vaa_helper<A: AnchorSerialize + Clone>(
         version: 1,
         guardian_set_index: 0,
         signatures: vec![],
-        timestamp: 123232,
+        timestamp,
         nonce: 0,
         emitter_chain,
         emitter_address,
@@ -1,42 +1,88 @@
 #![cfg(feature = "test-sbf")]
 #![feature(type_changing_struct_update)]
 
-use anchor_lang::prelude::*;
+use anchor_lang::{prelude::*, Discriminator};
 use anchor_spl::token::{Token, TokenAccount};
 use common::{
-    setup::{TestData, ANOTHER_CHAIN, OTHER_CHAIN},
-    utils::make_transfer_message,
+    setup::{TestData, ANOTHER_CHAIN, INBOUND_LIMIT, OTHER_CHAIN, OTHER_MANAGER, OUTBOUND_LIMIT},
+    utils::{make_transfer_message, next_outbox_item},
 };
 use example_native_token_transfers::{
+    bitmap::Bitmap,
+    config::{Config, Role},
     error::NTTError,
-    instructions::{RedeemArgs, ReleaseInboundArgs},
+    events::{
+        InboundQuorumReached, InboundTransferQueued, InboundTransferReleased, InboundVoteRecorded,
+    },
+    instructions::{
+        ClosePeerArgs, MigrateArgs, RedeemArgs, ReleaseInboundArgs, RemovePeerArgs,
+        SetLegacyPeerAddressesArgs, SetOutboundLimitArgs, SetPeerPausedArgs, SetPeerThresholdArgs,
+    },
+    legacy,
+    messages::ValidatedTransceiverMessage,
+    peer::LegacyAddress,
+    queue::{
+        inbox::{InboxItem, InboxRateLimit, ReleaseStatus},
+        outbox::{OutboxChainRateLimit, OutboxItem, OutboxRateLimit},
+    },
+    registered_transceiver::RegisteredTransceiver,
     transfer::Payload,
 };
-use ntt_messages::{mode::Mode, ntt::NativeTokenTransfer, ntt_manager::NttManagerMessage};
+use ntt_messages::{
+    chain_id::ChainId,
+    mode::Mode,
+    ntt::{AdditionalPayload, EmptyPayload, NativeTokenTransfer},
+    ntt_manager::NttManagerMessage,
+    transceiver::TransceiverMessageData,
+};
 use sdk::{
     accounts::NTTAccounts, transceivers::wormhole::instructions::receive_message::ReceiveMessage,
 };
 use solana_program::instruction::InstructionError;
 use solana_program_test::*;
 use solana_sdk::{
-    pubkey::Pubkey, signature::Keypair, signer::Signer, transaction::TransactionError,
+    account::Account, instruction::AccountMeta, pubkey::Pubkey, signature::Keypair, signer::Signer,
+    transaction::TransactionError,
 };
 use spl_associated_token_account::get_associated_token_address_with_program_id;
 use wormhole_sdk::Address;
 
 use crate::{
     common::{
+        events::{decode_event, decode_events, submit_with_logs},
+        policy::{block, initialize_extra_account_meta_list, policy_accounts},
         query::GetAccountDataAnchor,
-        setup::{setup, OTHER_TRANSCEIVER},
+        setup::{setup, DUMMY_NTT_POLICY, OTHER_TRANSCEIVER},
     },
     sdk::{
         accounts::good_ntt,
-        instructions::redeem::{redeem, Redeem},
+        instructions::{
+            admin::{
+                add_to_denylist, close_peer, deregister_transceiver, force_release_inbox_item,
+                freeze_inbox_item, grant_role, migrate, register_transceiver, reject_inbox_item,
+                remove_from_denylist, remove_peer, set_denylist_enabled, set_escrow_timeout,
+                set_inbound_paused, set_legacy_peer_addresses, set_outbound_limit, set_peer_paused,
+                set_peer_threshold, set_policy_program, set_threshold, unfreeze_inbox_item,
+                unquarantine_inbox_item, AddToDenylist, ClosePeer, DeregisterTransceiver,
+                ForceReleaseInboxItem, ManageInboxItem, Migrate, RegisterTransceiver,
+                RemoveFromDenylist, RemovePeer, SetDenylistEnabled, SetEscrowTimeout,
+                SetLegacyPeerAddresses, SetOutboundLimit, SetPaused, SetPeerPaused,
+                SetPeerThreshold, SetPolicyProgram, SetRole, SetThreshold, UnquarantineInboxItem,
+            },
+            escrow::{
+                escrow_inbox_item, redirect_escrowed_inbox_item, return_escrowed_inbox_item,
+                EscrowInboxItem, RedirectEscrowedInboxItem, ReturnEscrowedInboxItem,
+            },
+            redeem::{redeem, reevaluate_inbox_item, Redeem, ReevaluateInboxItem},
+        },
         transceivers::wormhole::instructions::receive_message::receive_message,
     },
 };
 use crate::{
-    common::{submit::Submittable, utils::post_vaa_helper},
+    common::{
+        submit::Submittable,
+        utils::{post_vaa_helper, post_vaa_helper_at},
+    },
     sdk::instructions::release_inbound::{release_inbound_unlock, ReleaseInbound},
 };
 
@@ -56,6 +102,7 @@ fn init_redeem_accs(
         transceiver_message: good_ntt.transceiver_message(chain_id, ntt_manager_message.id),
         inbox_item: good_ntt.inbox_item(chain_id, ntt_manager_message),
         inbox_rate_limit: good_ntt.inbox_rate_limit(chain_id),
+        outbox_chain_rate_limit: good_ntt.outbox_chain_rate_limit(chain_id),
         mint: test_data.mint,
     }
 }
@@ -154,6 +201,7 @@ async fn test_receive() {
         ReleaseInbound {
             payer: ctx.payer.pubkey(),
             inbox_item: good_ntt.inbox_item(OTHER_CHAIN, msg.ntt_manager_payload.clone()),
+            peer: good_ntt.peer(OTHER_CHAIN),
             mint: test_data.mint,
             recipient: recipient_token_account,
         },
@@ -174,6 +222,7 @@ async fn test_receive() {
         ReleaseInbound {
             payer: ctx.payer.pubkey(),
             inbox_item: good_ntt.inbox_item(OTHER_CHAIN, msg.ntt_manager_payload.clone()),
+            peer: good_ntt.peer(OTHER_CHAIN),
             mint: test_data.mint,
             recipient: recipient_token_account,
         },
@@ -194,6 +243,169 @@ async fn test_receive() {
     );
 }
 
+#[tokio::test]
+async fn test_receive_events() {
+    let recipient = Keypair::new();
+    let (mut ctx, test_data) = setup(Mode::Locking).await;
+    let no_signers: &[&Keypair] = &[];
+
+    // transfer tokens to custody account
+    spl_token::instruction::transfer_checked(
+        &Token::id(),
+        &test_data.user_token_account,
+        &test_data.mint,
+        &good_ntt.custody(&test_data.mint),
+        &test_data.user.pubkey(),
+        &[],
+        1000,
+        9,
+    )
+    .unwrap()
+    .submit_with_signers(&[&test_data.user], &mut ctx)
+    .await
+    .unwrap();
+
+    spl_associated_token_account::instruction::create_associated_token_account(
+        &ctx.payer.pubkey(),
+        &recipient.pubkey(),
+        &test_data.mint,
+        &Token::id(),
+    )
+    .submit(&mut ctx)
+    .await
+    .unwrap();
+
+    let recipient_token_account = get_associated_token_address_with_program_id(
+        &recipient.pubkey(),
+        &test_data.mint,
+        &Token::id(),
+    );
+
+    let msg = make_transfer_message(&good_ntt, [0u8; 32], 1000, &recipient.pubkey());
+    let inbox_item = good_ntt.inbox_item(OTHER_CHAIN, msg.ntt_manager_payload.clone());
+
+    let vaa0 = post_vaa_helper(
+        &good_ntt,
+        OTHER_CHAIN.into(),
+        Address(OTHER_TRANSCEIVER),
+        msg.clone(),
+        &mut ctx,
+    )
+    .await;
+
+    receive_message(
+        &good_ntt,
+        init_receive_message_accs(&mut ctx, vaa0, OTHER_CHAIN, [0u8; 32]),
+    )
+    .submit(&mut ctx)
+    .await
+    .unwrap();
+
+    let clock: Clock = ctx.banks_client.get_sysvar().await.unwrap();
+    let logs = submit_with_logs(
+        redeem(
+            &good_ntt,
+            init_redeem_accs(
+                &mut ctx,
+                &test_data,
+                OTHER_CHAIN,
+                msg.ntt_manager_payload.clone(),
+            ),
+            RedeemArgs {},
+        ),
+        no_signers,
+        &mut ctx,
+    )
+    .await
+    .unwrap();
+
+    let vote: InboundVoteRecorded = decode_event(&logs);
+    assert_eq!(vote.inbox_item, inbox_item);
+    assert_eq!(vote.from_chain, ChainId { id: OTHER_CHAIN });
+    assert_eq!(vote.message_id, [0u8; 32]);
+    assert_eq!(vote.transceiver, good_ntt.program());
+    assert_eq!(vote.transceiver_index, 0);
+    assert_eq!(vote.votes, 1);
+    assert_eq!(vote.threshold, 1);
+
+    let quorum: InboundQuorumReached = decode_event(&logs);
+    assert_eq!(quorum.inbox_item, inbox_item);
+    assert_eq!(quorum.release_timestamp, clock.unix_timestamp);
+    // within the rate limit, so not queued
+    assert!(decode_events::<InboundTransferQueued>(&logs).is_empty());
+
+    let logs = submit_with_logs(
+        release_inbound_unlock(
+            &good_ntt,
+            ReleaseInbound {
+                payer: ctx.payer.pubkey(),
+                inbox_item,
+                peer: good_ntt.peer(OTHER_CHAIN),
+                mint: test_data.mint,
+                recipient: recipient_token_account,
+            },
+            ReleaseInboundArgs {
+                revert_when_not_ready: false,
+            },
+        ),
+        no_signers,
+        &mut ctx,
+    )
+    .await
+    .unwrap();
+
+    let released: InboundTransferReleased = decode_event(&logs);
+    assert_eq!(released.inbox_item, inbox_item);
+    assert_eq!(released.recipient, recipient.pubkey());
+    assert_eq!(released.amount, 1000);
+
+    // now a transfer that exceeds the inbound limit, and gets queued
+    let msg = make_transfer_message(&good_ntt, [1u8; 32], INBOUND_LIMIT + 1, &recipient.pubkey());
+    let inbox_item = good_ntt.inbox_item(OTHER_CHAIN, msg.ntt_manager_payload.clone());
+
+    let vaa1 = post_vaa_helper(
+        &good_ntt,
+        OTHER_CHAIN.into(),
+        Address(OTHER_TRANSCEIVER),
+        msg.clone(),
+        &mut ctx,
+    )
+    .await;
+
+    receive_message(
+        &good_ntt,
+        init_receive_message_accs(&mut ctx, vaa1, OTHER_CHAIN, [1u8; 32]),
+    )
+    .submit(&mut ctx)
+    .await
+    .unwrap();
+
+    let logs = submit_with_logs(
+        redeem(
+            &good_ntt,
+            init_redeem_accs(
+                &mut ctx,
+                &test_data,
+                OTHER_CHAIN,
+                msg.ntt_manager_payload.clone(),
+            ),
+            RedeemArgs {},
+        ),
+        no_signers,
+        &mut ctx,
+    )
+    .await
+    .unwrap();
+
+    let queued: InboundTransferQueued = decode_event(&logs);
+    assert_eq!(queued.inbox_item, inbox_item);
+    assert!(queued.release_timestamp > clock.unix_timestamp);
+
+    let quorum: InboundQuorumReached = decode_event(&logs);
+    assert_eq!(quorum.inbox_item, inbox_item);
+    assert_eq!(quorum.release_timestamp, queued.release_timestamp);
+}
+
 #[tokio::test]
 async fn test_double_receive() {
     let recipient = Keypair::new();
@@ -373,12 +585,37 @@ async fn test_wrong_manager_peer() {
 }
 
 #[tokio::test]
-async fn test_wrong_inbox_item() {
+async fn test_legacy_manager_peer() {
     let recipient = Keypair::new();
     let (mut ctx, test_data) = setup(Mode::Locking).await;
 
-    let msg = make_transfer_message(&good_ntt, [0u8; 32], 1000, &recipient.pubkey());
+    let legacy_manager = [7u8; 32];
+    let clock: Clock = ctx.banks_client.get_sysvar().await.unwrap();
+    set_legacy_peer_addresses(
+        &good_ntt,
+        SetLegacyPeerAddresses {
+            owner: test_data.program_owner.pubkey(),
+        },
+        SetLegacyPeerAddressesArgs {
+            chain_id: ChainId { id: OTHER_CHAIN },
+            addresses: vec![LegacyAddress {
+                address: legacy_manager,
+                valid_until: clock.unix_timestamp + 3600,
+            }],
+        },
+    )
+    .submit_with_signers(&[&test_data.program_owner], &mut ctx)
+    .await
+    .unwrap();
+
+    let legacy_message = |id: [u8; 32]| {
+        let mut msg = make_transfer_message(&good_ntt, id, 1000, &recipient.pubkey());
+        msg.source_ntt_manager = legacy_manager;
+        msg
+    };
 
+    // transfers from the legacy manager are accepted until it expires
+    let msg = legacy_message([1u8; 32]);
     let vaa0 = post_vaa_helper(
         &good_ntt,
         OTHER_CHAIN.into(),
@@ -387,34 +624,1797 @@ async fn test_wrong_inbox_item() {
         &mut ctx,
     )
     .await;
-
     receive_message(
         &good_ntt,
-        init_receive_message_accs(&mut ctx, vaa0, OTHER_CHAIN, [0u8; 32]),
+        init_receive_message_accs(&mut ctx, vaa0, OTHER_CHAIN, [1u8; 32]),
+    )
+    .submit(&mut ctx)
+    .await
+    .unwrap();
+    redeem(
+        &good_ntt,
+        init_redeem_accs(
+            &mut ctx,
+            &test_data,
+            OTHER_CHAIN,
+            msg.ntt_manager_payload.clone(),
+        ),
+        RedeemArgs {},
     )
     .submit(&mut ctx)
     .await
     .unwrap();
 
-    // use 'ANOTHER_CHAIN' inbox item account here
-    let mut redeem_accs = init_redeem_accs(
+    let mut clock: Clock = ctx.banks_client.get_sysvar().await.unwrap();
+    clock.unix_timestamp += 3600;
+    ctx.set_sysvar(&clock);
+
+    let msg = legacy_message([2u8; 32]);
+    let vaa1 = post_vaa_helper(
+        &good_ntt,
+        OTHER_CHAIN.into(),
+        Address(OTHER_TRANSCEIVER),
+        msg.clone(),
         &mut ctx,
-        &test_data,
-        OTHER_CHAIN,
-        msg.ntt_manager_payload.clone(),
+    )
+    .await;
+    receive_message(
+        &good_ntt,
+        init_receive_message_accs(&mut ctx, vaa1, OTHER_CHAIN, [2u8; 32]),
+    )
+    .submit(&mut ctx)
+    .await
+    .unwrap();
+    let err = redeem(
+        &good_ntt,
+        init_redeem_accs(
+            &mut ctx,
+            &test_data,
+            OTHER_CHAIN,
+            msg.ntt_manager_payload.clone(),
+        ),
+        RedeemArgs {},
+    )
+    .submit(&mut ctx)
+    .await
+    .unwrap_err();
+    assert_eq!(
+        err.unwrap(),
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(NTTError::InvalidNttManagerPeer.into())
+        )
     );
-    redeem_accs.inbox_item = good_ntt.inbox_item(ANOTHER_CHAIN, msg.ntt_manager_payload.clone());
+}
 
-    let err = redeem(&good_ntt, redeem_accs, RedeemArgs {})
-        .submit(&mut ctx)
+#[tokio::test]
+async fn test_peer_threshold() {
+    let recipient = Keypair::new();
+    let (mut ctx, test_data) = setup(Mode::Locking).await;
+
+    // only one transceiver is enabled
+    let err = set_peer_threshold(
+        &good_ntt,
+        SetPeerThreshold {
+            owner: test_data.program_owner.pubkey(),
+        },
+        SetPeerThresholdArgs {
+            chain_id: ChainId { id: OTHER_CHAIN },
+            threshold: Some(2),
+            transceivers: None,
+        },
+    )
+    .submit_with_signers(&[&test_data.program_owner], &mut ctx)
+    .await
+    .unwrap_err();
+    assert_eq!(
+        err.unwrap(),
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(NTTError::ThresholdTooHigh.into())
+        )
+    );
+
+    // stop trusting the wormhole transceiver (id 0) for this chain
+    let set_transceivers_ix = || {
+        set_peer_threshold(
+            &good_ntt,
+            SetPeerThreshold {
+                owner: test_data.program_owner.pubkey(),
+            },
+            SetPeerThresholdArgs {
+                chain_id: ChainId { id: OTHER_CHAIN },
+                threshold: None,
+                transceivers: Some(Bitmap::from_value(0b10)),
+            },
+        )
+    };
+
+    // no transceiver is left to reach the (global) threshold
+    let err = set_transceivers_ix()
+        .submit_with_signers(&[&test_data.program_owner], &mut ctx)
         .await
         .unwrap_err();
+    assert_eq!(
+        err.unwrap(),
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(NTTError::ThresholdTooHigh.into())
+        )
+    );
+
+    // a second transceiver (id 1) is trusted instead
+    register_transceiver(
+        &good_ntt,
+        RegisterTransceiver {
+            payer: ctx.payer.pubkey(),
+            owner: test_data.program_owner.pubkey(),
+            transceiver: wormhole_anchor_sdk::wormhole::program::ID,
+        },
+    )
+    .submit_with_signers(&[&test_data.program_owner], &mut ctx)
+    .await
+    .unwrap();
+
+    ctx.get_new_latest_blockhash().await.unwrap();
+    set_transceivers_ix()
+        .submit_with_signers(&[&test_data.program_owner], &mut ctx)
+        .await
+        .unwrap();
+
+    let msg = make_transfer_message(&good_ntt, [0u8; 32], 1000, &recipient.pubkey());
+    let vaa0 = post_vaa_helper(
+        &good_ntt,
+        OTHER_CHAIN.into(),
+        Address(OTHER_TRANSCEIVER),
+        msg.clone(),
+        &mut ctx,
+    )
+    .await;
+    receive_message(
+        &good_ntt,
+        init_receive_message_accs(&mut ctx, vaa0, OTHER_CHAIN, [0u8; 32]),
+    )
+    .submit(&mut ctx)
+    .await
+    .unwrap();
 
+    let err = redeem(
+        &good_ntt,
+        init_redeem_accs(
+            &mut ctx,
+            &test_data,
+            OTHER_CHAIN,
+            msg.ntt_manager_payload.clone(),
+        ),
+        RedeemArgs {},
+    )
+    .submit(&mut ctx)
+    .await
+    .unwrap_err();
     assert_eq!(
         err.unwrap(),
         TransactionError::InstructionError(
             0,
-            InstructionError::Custom(ErrorCode::ConstraintSeeds.into())
+            InstructionError::Custom(NTTError::DisabledTransceiver.into())
         )
     );
 }
+
+#[tokio::test]
+async fn test_reevaluate_inbox_item() {
+    let recipient = Keypair::new();
+    let (mut ctx, test_data) = setup(Mode::Locking).await;
+
+    // require a second transceiver's vote
+    register_transceiver(
+        &good_ntt,
+        RegisterTransceiver {
+            payer: ctx.payer.pubkey(),
+            owner: test_data.program_owner.pubkey(),
+            transceiver: wormhole_anchor_sdk::wormhole::program::ID,
+        },
+    )
+    .submit_with_signers(&[&test_data.program_owner], &mut ctx)
+    .await
+    .unwrap();
+    let set_threshold_ix = |threshold| {
+        set_threshold(
+            &good_ntt,
+            SetThreshold {
+                owner: test_data.program_owner.pubkey(),
+            },
+            threshold,
+        )
+    };
+    set_threshold_ix(2)
+        .submit_with_signers(&[&test_data.program_owner], &mut ctx)
+        .await
+        .unwrap();
+
+    let msg = make_transfer_message(&good_ntt, [0u8; 32], 1000, &recipient.pubkey());
+    let vaa0 = post_vaa_helper(
+        &good_ntt,
+        OTHER_CHAIN.into(),
+        Address(OTHER_TRANSCEIVER),
+        msg.clone(),
+        &mut ctx,
+    )
+    .await;
+    receive_message(
+        &good_ntt,
+        init_receive_message_accs(&mut ctx, vaa0, OTHER_CHAIN, [0u8; 32]),
+    )
+    .submit(&mut ctx)
+    .await
+    .unwrap();
+    let redeem_accs = init_redeem_accs(
+        &mut ctx,
+        &test_data,
+        OTHER_CHAIN,
+        msg.ntt_manager_payload.clone(),
+    );
+    redeem(&good_ntt, redeem_accs.clone(), RedeemArgs {})
+        .submit(&mut ctx)
+        .await
+        .unwrap();
+
+    let reevaluate_ix = || {
+        reevaluate_inbox_item(
+            &good_ntt,
+            ReevaluateInboxItem {
+                inbox_item: redeem_accs.inbox_item,
+                source_chain: OTHER_CHAIN,
+            },
+        )
+    };
+
+    let err = reevaluate_ix().submit(&mut ctx).await.unwrap_err();
+    assert_eq!(
+        err.unwrap(),
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(NTTError::ThresholdNotReached.into())
+        )
+    );
+
+    // the single vote is enough once the threshold is lowered
+    set_threshold_ix(1)
+        .submit_with_signers(&[&test_data.program_owner], &mut ctx)
+        .await
+        .unwrap();
+    reevaluate_ix().submit(&mut ctx).await.unwrap();
+
+    let inbox_item: InboxItem = ctx.get_account_data_anchor(redeem_accs.inbox_item).await;
+    assert!(matches!(
+        inbox_item.release_status,
+        ReleaseStatus::ReleaseAfter(_)
+    ));
+
+    // it's only approved once
+    ctx.get_new_latest_blockhash().await.unwrap();
+    let err = reevaluate_ix().submit(&mut ctx).await.unwrap_err();
+    assert_eq!(
+        err.unwrap(),
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(NTTError::TransferCannotBeRedeemed.into())
+        )
+    );
+}
+
+#[tokio::test]
+async fn test_votes_across_transceiver_reregistration() {
+    let recipient = Keypair::new();
+    let (mut ctx, test_data) = setup(Mode::Locking).await;
+
+    // a second transceiver, so that the wormhole transceiver (id 0) can be
+    // deregistered without leaving none
+    let other_transceiver = wormhole_anchor_sdk::wormhole::program::ID;
+    register_transceiver(
+        &good_ntt,
+        RegisterTransceiver {
+            payer: ctx.payer.pubkey(),
+            owner: test_data.program_owner.pubkey(),
+            transceiver: other_transceiver,
+        },
+    )
+    .submit_with_signers(&[&test_data.program_owner], &mut ctx)
+    .await
+    .unwrap();
+    set_threshold(
+        &good_ntt,
+        SetThreshold {
+            owner: test_data.program_owner.pubkey(),
+        },
+        2,
+    )
+    .submit_with_signers(&[&test_data.program_owner], &mut ctx)
+    .await
+    .unwrap();
+
+    let msg = make_transfer_message(&good_ntt, [0u8; 32], 1000, &recipient.pubkey());
+    let vaa0 = post_vaa_helper(
+        &good_ntt,
+        OTHER_CHAIN.into(),
+        Address(OTHER_TRANSCEIVER),
+        msg.clone(),
+        &mut ctx,
+    )
+    .await;
+    receive_message(
+        &good_ntt,
+        init_receive_message_accs(&mut ctx, vaa0, OTHER_CHAIN, [0u8; 32]),
+    )
+    .submit(&mut ctx)
+    .await
+    .unwrap();
+    let redeem_accs = init_redeem_accs(
+        &mut ctx,
+        &test_data,
+        OTHER_CHAIN,
+        msg.ntt_manager_payload.clone(),
+    );
+    redeem(&good_ntt, redeem_accs.clone(), RedeemArgs {})
+        .submit(&mut ctx)
+        .await
+        .unwrap();
+
+    // deregistering the wormhole transceiver lowers the threshold to 1, but
+    // its vote doesn't count while it's disabled
+    deregister_transceiver(
+        &good_ntt,
+        DeregisterTransceiver {
+            owner: test_data.program_owner.pubkey(),
+            transceiver: good_ntt.program(),
+        },
+    )
+    .submit_with_signers(&[&test_data.program_owner], &mut ctx)
+    .await
+    .unwrap();
+
+    let reevaluate_ix = || {
+        reevaluate_inbox_item(
+            &good_ntt,
+            ReevaluateInboxItem {
+                inbox_item: redeem_accs.inbox_item,
+                source_chain: OTHER_CHAIN,
+            },
+        )
+    };
+    let err = reevaluate_ix().submit(&mut ctx).await.unwrap_err();
+    assert_eq!(
+        err.unwrap(),
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(NTTError::ThresholdNotReached.into())
+        )
+    );
+
+    // nor can it vote
+    ctx.get_new_latest_blockhash().await.unwrap();
+    let err = redeem(&good_ntt, redeem_accs.clone(), RedeemArgs {})
+        .submit(&mut ctx)
+        .await
+        .unwrap_err();
+    assert_eq!(
+        err.unwrap(),
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(NTTError::DisabledTransceiver.into())
+        )
+    );
+
+    // re-registering it reuses its id, and its vote counts again
+    register_transceiver(
+        &good_ntt,
+        RegisterTransceiver {
+            payer: ctx.payer.pubkey(),
+            owner: test_data.program_owner.pubkey(),
+            transceiver: good_ntt.program(),
+        },
+    )
+    .submit_with_signers(&[&test_data.program_owner], &mut ctx)
+    .await
+    .unwrap();
+
+    let registered_transceiver: RegisteredTransceiver = ctx
+        .get_account_data_anchor(good_ntt.registered_transceiver(&good_ntt.program()))
+        .await;
+    assert_eq!(registered_transceiver.id, 0);
+    let config: Config = ctx.get_account_data_anchor(good_ntt.config()).await;
+    assert_eq!(config.next_transceiver_id, 2);
+
+    ctx.get_new_latest_blockhash().await.unwrap();
+    reevaluate_ix().submit(&mut ctx).await.unwrap();
+
+    let inbox_item: InboxItem = ctx.get_account_data_anchor(redeem_accs.inbox_item).await;
+    assert!(inbox_item.votes.get(0).unwrap());
+    assert!(matches!(
+        inbox_item.release_status,
+        ReleaseStatus::ReleaseAfter(_)
+    ));
+}
+
+/// Redeems a transfer of `amount` to `recipient`, returning the inbox item and
+/// the recipient's token account.
+async fn redeem_transfer(
+    ctx: &mut ProgramTestContext,
+    test_data: &TestData,
+    recipient: &Pubkey,
+    amount: u64,
+) -> (Pubkey, Pubkey) {
+    spl_associated_token_account::instruction::create_associated_token_account(
+        &ctx.payer.pubkey(),
+        recipient,
+        &test_data.mint,
+        &Token::id(),
+    )
+    .submit(ctx)
+    .await
+    .unwrap();
+
+    let msg = make_transfer_message(&good_ntt, [0u8; 32], amount, recipient);
+    let vaa0 = post_vaa_helper(
+        &good_ntt,
+        OTHER_CHAIN.into(),
+        Address(OTHER_TRANSCEIVER),
+        msg.clone(),
+        ctx,
+    )
+    .await;
+    receive_message(
+        &good_ntt,
+        init_receive_message_accs(ctx, vaa0, OTHER_CHAIN, [0u8; 32]),
+    )
+    .submit(ctx)
+    .await
+    .unwrap();
+    let redeem_accs =
+        init_redeem_accs(ctx, test_data, OTHER_CHAIN, msg.ntt_manager_payload.clone());
+    redeem(&good_ntt, redeem_accs.clone(), RedeemArgs {})
+        .submit(ctx)
+        .await
+        .unwrap();
+
+    let recipient_token_account =
+        get_associated_token_address_with_program_id(recipient, &test_data.mint, &Token::id());
+    (redeem_accs.inbox_item, recipient_token_account)
+}
+
+#[tokio::test]
+async fn test_freeze_and_reject_inbox_item() {
+    let recipient = Keypair::new();
+    let (mut ctx, test_data) = setup(Mode::Locking).await;
+    let (inbox_item, recipient_token_account) =
+        redeem_transfer(&mut ctx, &test_data, &recipient.pubkey(), 1000).await;
+
+    let manage_accs = |authority: &Keypair| ManageInboxItem {
+        authority: authority.pubkey(),
+        inbox_item,
+    };
+    let payer = ctx.payer.pubkey();
+    let release_ix = || {
+        release_inbound_unlock(
+            &good_ntt,
+            ReleaseInbound {
+                payer,
+                inbox_item,
+                peer: good_ntt.peer(OTHER_CHAIN),
+                mint: test_data.mint,
+                recipient: recipient_token_account,
+            },
+            ReleaseInboundArgs {
+                revert_when_not_ready: true,
+            },
+        )
+    };
+    let custom_error = |error: NTTError| {
+        TransactionError::InstructionError(0, InstructionError::Custom(error.into()))
+    };
+
+    let impostor = Keypair::new();
+    let err = freeze_inbox_item(&good_ntt, manage_accs(&impostor))
+        .submit_with_signers(&[&impostor], &mut ctx)
+        .await
+        .unwrap_err();
+    assert_eq!(err.unwrap(), custom_error(NTTError::Unauthorized));
+
+    freeze_inbox_item(&good_ntt, manage_accs(&test_data.program_owner))
+        .submit_with_signers(&[&test_data.program_owner], &mut ctx)
+        .await
+        .unwrap();
+
+    let err = release_ix().submit(&mut ctx).await.unwrap_err();
+    assert_eq!(err.unwrap(), custom_error(NTTError::InboxItemFrozen));
+
+    // unfreezing restores the approval
+    unfreeze_inbox_item(&good_ntt, manage_accs(&test_data.program_owner))
+        .submit_with_signers(&[&test_data.program_owner], &mut ctx)
+        .await
+        .unwrap();
+    let item: InboxItem = ctx.get_account_data_anchor(inbox_item).await;
+    assert!(matches!(
+        item.release_status,
+        ReleaseStatus::ReleaseAfter(_)
+    ));
+
+    reject_inbox_item(&good_ntt, manage_accs(&test_data.program_owner))
+        .submit_with_signers(&[&test_data.program_owner], &mut ctx)
+        .await
+        .unwrap();
+
+    ctx.get_new_latest_blockhash().await.unwrap();
+    let err = release_ix().submit(&mut ctx).await.unwrap_err();
+    assert_eq!(err.unwrap(), custom_error(NTTError::InboxItemRejected));
+
+    // rejecting is final
+    ctx.get_new_latest_blockhash().await.unwrap();
+    let err = unfreeze_inbox_item(&good_ntt, manage_accs(&test_data.program_owner))
+        .submit_with_signers(&[&test_data.program_owner], &mut ctx)
+        .await
+        .unwrap_err();
+    assert_eq!(err.unwrap(), custom_error(NTTError::InboxItemNotFrozen));
+
+    let token_account: TokenAccount = ctx.get_account_data_anchor(recipient_token_account).await;
+    assert_eq!(token_account.amount, 0);
+}
+
+#[tokio::test]
+async fn test_cant_release_inbound_from_paused_peer() {
+    let recipient = Keypair::new();
+    let (mut ctx, test_data) = setup(Mode::Locking).await;
+
+    spl_token::instruction::transfer_checked(
+        &Token::id(),
+        &test_data.user_token_account,
+        &test_data.mint,
+        &good_ntt.custody(&test_data.mint),
+        &test_data.user.pubkey(),
+        &[],
+        1000,
+        9,
+    )
+    .unwrap()
+    .submit_with_signers(&[&test_data.user], &mut ctx)
+    .await
+    .unwrap();
+    let (inbox_item, recipient_token_account) =
+        redeem_transfer(&mut ctx, &test_data, &recipient.pubkey(), 1000).await;
+
+    let set_paused_ix = |paused| {
+        set_peer_paused(
+            &good_ntt,
+            SetPeerPaused {
+                owner: test_data.program_owner.pubkey(),
+            },
+            SetPeerPausedArgs {
+                chain_id: ChainId { id: OTHER_CHAIN },
+                paused,
+            },
+        )
+    };
+
+    let payer = ctx.payer.pubkey();
+    let release_ix = || {
+        release_inbound_unlock(
+            &good_ntt,
+            ReleaseInbound {
+                payer,
+                inbox_item,
+                peer: good_ntt.peer(OTHER_CHAIN),
+                mint: test_data.mint,
+                recipient: recipient_token_account,
+            },
+            ReleaseInboundArgs {
+                revert_when_not_ready: true,
+            },
+        )
+    };
+
+    set_paused_ix(true)
+        .submit_with_signers(&[&test_data.program_owner], &mut ctx)
+        .await
+        .unwrap();
+
+    let err = release_ix().submit(&mut ctx).await.unwrap_err();
+    assert_eq!(
+        err.unwrap(),
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(NTTError::PeerPaused.into())
+        )
+    );
+
+    set_paused_ix(false)
+        .submit_with_signers(&[&test_data.program_owner], &mut ctx)
+        .await
+        .unwrap();
+
+    ctx.get_new_latest_blockhash().await.unwrap();
+    release_ix().submit(&mut ctx).await.unwrap();
+
+    let token_account: TokenAccount = ctx.get_account_data_anchor(recipient_token_account).await;
+    assert_eq!(token_account.amount, 1000);
+}
+
+#[tokio::test]
+async fn test_force_release_inbox_item() {
+    let recipient = Keypair::new();
+    let (mut ctx, test_data) = setup(Mode::Locking).await;
+
+    // exceeds the inbound limit, so the transfer is queued
+    let amount = INBOUND_LIMIT * 2;
+    spl_token::instruction::transfer_checked(
+        &Token::id(),
+        &test_data.user_token_account,
+        &test_data.mint,
+        &good_ntt.custody(&test_data.mint),
+        &test_data.user.pubkey(),
+        &[],
+        amount,
+        9,
+    )
+    .unwrap()
+    .submit_with_signers(&[&test_data.user], &mut ctx)
+    .await
+    .unwrap();
+    let (inbox_item, recipient_token_account) =
+        redeem_transfer(&mut ctx, &test_data, &recipient.pubkey(), amount).await;
+
+    let payer = ctx.payer.pubkey();
+    let release_ix = || {
+        release_inbound_unlock(
+            &good_ntt,
+            ReleaseInbound {
+                payer,
+                inbox_item,
+                peer: good_ntt.peer(OTHER_CHAIN),
+                mint: test_data.mint,
+                recipient: recipient_token_account,
+            },
+            ReleaseInboundArgs {
+                revert_when_not_ready: true,
+            },
+        )
+    };
+
+    let err = release_ix().submit(&mut ctx).await.unwrap_err();
+    assert_eq!(
+        err.unwrap(),
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(NTTError::CantReleaseYet.into())
+        )
+    );
+
+    force_release_inbox_item(
+        &good_ntt,
+        ForceReleaseInboxItem {
+            owner: test_data.program_owner.pubkey(),
+            inbox_item,
+        },
+    )
+    .submit_with_signers(&[&test_data.program_owner], &mut ctx)
+    .await
+    .unwrap();
+
+    ctx.get_new_latest_blockhash().await.unwrap();
+    release_ix().submit(&mut ctx).await.unwrap();
+
+    let token_account: TokenAccount = ctx.get_account_data_anchor(recipient_token_account).await;
+    assert_eq!(token_account.amount, amount);
+}
+
+#[tokio::test]
+async fn test_quarantine_inbox_item() {
+    let recipient = Keypair::new();
+    let (mut ctx, test_data) = setup(Mode::Locking).await;
+    let (inbox_item, recipient_token_account) =
+        redeem_transfer(&mut ctx, &test_data, &recipient.pubkey(), 1000).await;
+
+    let compliance_admin = Keypair::new();
+    grant_role(
+        &good_ntt,
+        SetRole {
+            owner: test_data.program_owner.pubkey(),
+        },
+        Role::ComplianceAdmin,
+        compliance_admin.pubkey(),
+    )
+    .submit_with_signers(&[&test_data.program_owner], &mut ctx)
+    .await
+    .unwrap();
+    set_denylist_enabled(
+        &good_ntt,
+        SetDenylistEnabled {
+            owner: test_data.program_owner.pubkey(),
+        },
+        true,
+    )
+    .submit_with_signers(&[&test_data.program_owner], &mut ctx)
+    .await
+    .unwrap();
+    let denied = recipient.pubkey().to_bytes();
+    add_to_denylist(
+        &good_ntt,
+        AddToDenylist {
+            payer: ctx.payer.pubkey(),
+            authority: compliance_admin.pubkey(),
+        },
+        denied,
+    )
+    .submit_with_signers(&[&compliance_admin], &mut ctx)
+    .await
+    .unwrap();
+
+    let payer = ctx.payer.pubkey();
+    let release_ix = || {
+        let mut ix = release_inbound_unlock(
+            &good_ntt,
+            ReleaseInbound {
+                payer,
+                inbox_item,
+                peer: good_ntt.peer(OTHER_CHAIN),
+                mint: test_data.mint,
+                recipient: recipient_token_account,
+            },
+            ReleaseInboundArgs {
+                revert_when_not_ready: true,
+            },
+        );
+        // the sender of the transfer (see `make_transfer_message`) and the
+        // recipient
+        for address in [[4u8; 32], denied] {
+            ix.accounts.push(AccountMeta::new_readonly(
+                good_ntt.denylist_entry(&address),
+                false,
+            ));
+        }
+        ix
+    };
+    let custom_error = |error: NTTError| {
+        TransactionError::InstructionError(0, InstructionError::Custom(error.into()))
+    };
+    let unquarantine_ix = || {
+        unquarantine_inbox_item(
+            &good_ntt,
+            UnquarantineInboxItem {
+                authority: compliance_admin.pubkey(),
+                inbox_item,
+            },
+        )
+    };
+
+    // the release succeeds, but the transfer is quarantined instead
+    release_ix().submit(&mut ctx).await.unwrap();
+    let item: InboxItem = ctx.get_account_data_anchor(inbox_item).await;
+    assert_eq!(item.release_status, ReleaseStatus::Quarantined);
+
+    ctx.get_new_latest_blockhash().await.unwrap();
+    let err = release_ix().submit(&mut ctx).await.unwrap_err();
+    assert_eq!(err.unwrap(), custom_error(NTTError::InboxItemQuarantined));
+
+    remove_from_denylist(
+        &good_ntt,
+        RemoveFromDenylist {
+            authority: compliance_admin.pubkey(),
+            rent_payer: payer,
+        },
+        denied,
+    )
+    .submit_with_signers(&[&compliance_admin], &mut ctx)
+    .await
+    .unwrap();
+    unquarantine_ix()
+        .submit_with_signers(&[&compliance_admin], &mut ctx)
+        .await
+        .unwrap();
+
+    // only quarantined items can be unquarantined
+    ctx.get_new_latest_blockhash().await.unwrap();
+    let err = unquarantine_ix()
+        .submit_with_signers(&[&compliance_admin], &mut ctx)
+        .await
+        .unwrap_err();
+    assert_eq!(
+        err.unwrap(),
+        custom_error(NTTError::InboxItemNotQuarantined)
+    );
+
+    ctx.get_new_latest_blockhash().await.unwrap();
+    release_ix().submit(&mut ctx).await.unwrap();
+
+    let token_account: TokenAccount = ctx.get_account_data_anchor(recipient_token_account).await;
+    assert_eq!(token_account.amount, 1000);
+}
+
+#[tokio::test]
+async fn test_quarantine_by_policy_program() {
+    let recipient = Keypair::new();
+    let (mut ctx, test_data) = setup(Mode::Locking).await;
+    let (inbox_item, recipient_token_account) =
+        redeem_transfer(&mut ctx, &test_data, &recipient.pubkey(), 1000).await;
+
+    initialize_extra_account_meta_list(ctx.payer.pubkey(), test_data.mint)
+        .submit(&mut ctx)
+        .await
+        .unwrap();
+    let set_policy_program_ix = |policy_program| {
+        set_policy_program(
+            &good_ntt,
+            SetPolicyProgram {
+                owner: test_data.program_owner.pubkey(),
+            },
+            policy_program,
+        )
+    };
+    set_policy_program_ix(Some(DUMMY_NTT_POLICY))
+        .submit_with_signers(&[&test_data.program_owner], &mut ctx)
+        .await
+        .unwrap();
+    let denied = recipient.pubkey().to_bytes();
+    block(ctx.payer.pubkey(), denied)
+        .submit(&mut ctx)
+        .await
+        .unwrap();
+
+    let payer = ctx.payer.pubkey();
+    let release_ix = || {
+        let mut ix = release_inbound_unlock(
+            &good_ntt,
+            ReleaseInbound {
+                payer,
+                inbox_item,
+                peer: good_ntt.peer(OTHER_CHAIN),
+                mint: test_data.mint,
+                recipient: recipient_token_account,
+            },
+            ReleaseInboundArgs {
+                revert_when_not_ready: true,
+            },
+        );
+        // the sender of the transfer (see `make_transfer_message`) and the
+        // recipient
+        ix.accounts
+            .extend(policy_accounts(test_data.mint, [4u8; 32], denied));
+        ix
+    };
+
+    // the release succeeds, but the transfer is quarantined instead
+    release_ix().submit(&mut ctx).await.unwrap();
+    let item: InboxItem = ctx.get_account_data_anchor(inbox_item).await;
+    assert_eq!(item.release_status, ReleaseStatus::Quarantined);
+
+    set_policy_program_ix(None)
+        .submit_with_signers(&[&test_data.program_owner], &mut ctx)
+        .await
+        .unwrap();
+    unquarantine_inbox_item(
+        &good_ntt,
+        UnquarantineInboxItem {
+            authority: test_data.program_owner.pubkey(),
+            inbox_item,
+        },
+    )
+    .submit_with_signers(&[&test_data.program_owner], &mut ctx)
+    .await
+    .unwrap();
+
+    ctx.get_new_latest_blockhash().await.unwrap();
+    release_ix().submit(&mut ctx).await.unwrap();
+
+    let token_account: TokenAccount = ctx.get_account_data_anchor(recipient_token_account).await;
+    assert_eq!(token_account.amount, 1000);
+}
+
+#[tokio::test]
+async fn test_escrow_and_redirect_inbox_item() {
+    let recipient = Keypair::new();
+    let (mut ctx, test_data) = setup(Mode::Locking).await;
+    let (inbox_item, recipient_token_account) =
+        redeem_transfer(&mut ctx, &test_data, &recipient.pubkey(), 1000).await;
+
+    let payer = ctx.payer.pubkey();
+    let escrow_ix = |owner: Option<Pubkey>| {
+        escrow_inbox_item(
+            &good_ntt,
+            EscrowInboxItem {
+                payer,
+                owner,
+                inbox_item,
+                mint: test_data.mint,
+            },
+        )
+    };
+    let custom_error = |error: NTTError| {
+        TransactionError::InstructionError(0, InstructionError::Custom(error.into()))
+    };
+
+    // the escrow timeout is disabled, so only the owner can escrow transfers
+    let err = escrow_ix(None).submit(&mut ctx).await.unwrap_err();
+    assert_eq!(err.unwrap(), custom_error(NTTError::Unauthorized));
+
+    let impostor = Keypair::new();
+    let err = escrow_ix(Some(impostor.pubkey()))
+        .submit_with_signers(&[&impostor], &mut ctx)
+        .await
+        .unwrap_err();
+    assert_eq!(err.unwrap(), custom_error(NTTError::Unauthorized));
+
+    escrow_ix(Some(test_data.program_owner.pubkey()))
+        .submit_with_signers(&[&test_data.program_owner], &mut ctx)
+        .await
+        .unwrap();
+
+    let item: InboxItem = ctx.get_account_data_anchor(inbox_item).await;
+    assert_eq!(item.release_status, ReleaseStatus::Escrowed(1000));
+    let escrow = good_ntt.escrow(&test_data.mint);
+    let escrow_account: TokenAccount = ctx.get_account_data_anchor(escrow).await;
+    assert_eq!(escrow_account.amount, 1000);
+
+    let err = release_inbound_unlock(
+        &good_ntt,
+        ReleaseInbound {
+            payer,
+            inbox_item,
+            peer: good_ntt.peer(OTHER_CHAIN),
+            mint: test_data.mint,
+            recipient: recipient_token_account,
+        },
+        ReleaseInboundArgs {
+            revert_when_not_ready: true,
+        },
+    )
+    .submit(&mut ctx)
+    .await
+    .unwrap_err();
+    assert_eq!(err.unwrap(), custom_error(NTTError::InboxItemEscrowed));
+
+    let new_owner = Keypair::new();
+    spl_associated_token_account::instruction::create_associated_token_account(
+        &payer,
+        &new_owner.pubkey(),
+        &test_data.mint,
+        &Token::id(),
+    )
+    .submit(&mut ctx)
+    .await
+    .unwrap();
+    let new_token_account = get_associated_token_address_with_program_id(
+        &new_owner.pubkey(),
+        &test_data.mint,
+        &Token::id(),
+    );
+
+    redirect_escrowed_inbox_item(
+        &good_ntt,
+        RedirectEscrowedInboxItem {
+            owner: test_data.program_owner.pubkey(),
+            inbox_item,
+            mint: test_data.mint,
+            recipient: new_token_account,
+        },
+    )
+    .submit_with_signers(&[&test_data.program_owner], &mut ctx)
+    .await
+    .unwrap();
+
+    let item: InboxItem = ctx.get_account_data_anchor(inbox_item).await;
+    assert_eq!(item.release_status, ReleaseStatus::Released);
+    let token_account: TokenAccount = ctx.get_account_data_anchor(new_token_account).await;
+    assert_eq!(token_account.amount, 1000);
+    let escrow_account: TokenAccount = ctx.get_account_data_anchor(escrow).await;
+    assert_eq!(escrow_account.amount, 0);
+}
+
+#[tokio::test]
+async fn test_escrow_timeout_and_return_inbox_item() {
+    let recipient = Keypair::new();
+    let (mut ctx, test_data) = setup(Mode::Locking).await;
+    let (inbox_item, _) = redeem_transfer(&mut ctx, &test_data, &recipient.pubkey(), 1000).await;
+
+    set_escrow_timeout(
+        &good_ntt,
+        SetEscrowTimeout {
+            owner: test_data.program_owner.pubkey(),
+        },
+        3600,
+    )
+    .submit_with_signers(&[&test_data.program_owner], &mut ctx)
+    .await
+    .unwrap();
+
+    let payer = ctx.payer.pubkey();
+    let escrow_ix = || {
+        escrow_inbox_item(
+            &good_ntt,
+            EscrowInboxItem {
+                payer,
+                owner: None,
+                inbox_item,
+                mint: test_data.mint,
+            },
+        )
+    };
+
+    let err = escrow_ix().submit(&mut ctx).await.unwrap_err();
+    assert_eq!(
+        err.unwrap(),
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(NTTError::CantEscrowYet.into())
+        )
+    );
+
+    let mut clock: Clock = ctx.banks_client.get_sysvar().await.unwrap();
+    clock.unix_timestamp += 3600;
+    ctx.set_sysvar(&clock);
+
+    // anyone can escrow the transfer once the timeout has passed
+    ctx.get_new_latest_blockhash().await.unwrap();
+    escrow_ix().submit(&mut ctx).await.unwrap();
+
+    let custody = good_ntt.custody(&test_data.mint);
+    let custody_before: TokenAccount = ctx.get_account_data_anchor(custody).await;
+    let outbox_item = next_outbox_item(&good_ntt, &mut ctx).await;
+    return_escrowed_inbox_item(
+        &good_ntt,
+        ReturnEscrowedInboxItem {
+            payer,
+            owner: test_data.program_owner.pubkey(),
+            inbox_item,
+            source_chain: OTHER_CHAIN,
+            mint: test_data.mint,
+            outbox_item,
+        },
+    )
+    .submit_with_signers(&[&test_data.program_owner], &mut ctx)
+    .await
+    .unwrap();
+
+    // the tokens are locked again, and sent back to the sender
+    let custody_after: TokenAccount = ctx.get_account_data_anchor(custody).await;
+    assert_eq!(custody_after.amount, custody_before.amount + 1000);
+    let outbox: OutboxItem = ctx.get_account_data_anchor(outbox_item).await;
+    assert_eq!(outbox.recipient_chain.id, OTHER_CHAIN);
+    assert_eq!(outbox.recipient_ntt_manager, OTHER_MANAGER);
+    // the sender of the transfer (see `make_transfer_message`)
+    assert_eq!(outbox.recipient_address, [4u8; 32]);
+    assert_eq!(outbox.sender, good_ntt.escrow_authority());
+    assert_eq!(outbox.amount.untrim(9).unwrap(), 1000);
+
+    let item: InboxItem = ctx.get_account_data_anchor(inbox_item).await;
+    assert_eq!(item.release_status, ReleaseStatus::Released);
+}
+
+/// Redeems a transfer, has the owner escrow it, and returns it to the sender.
+/// Returns the outbox item of the returned transfer.
+async fn escrow_and_return_transfer(
+    ctx: &mut ProgramTestContext,
+    test_data: &TestData,
+    amount: u64,
+) -> Pubkey {
+    let recipient = Keypair::new();
+    let (inbox_item, _) = redeem_transfer(ctx, test_data, &recipient.pubkey(), amount).await;
+
+    let payer = ctx.payer.pubkey();
+    escrow_inbox_item(
+        &good_ntt,
+        EscrowInboxItem {
+            payer,
+            owner: Some(test_data.program_owner.pubkey()),
+            inbox_item,
+            mint: test_data.mint,
+        },
+    )
+    .submit_with_signers(&[&test_data.program_owner], ctx)
+    .await
+    .unwrap();
+
+    let outbox_item = next_outbox_item(&good_ntt, ctx).await;
+    return_escrowed_inbox_item(
+        &good_ntt,
+        ReturnEscrowedInboxItem {
+            payer,
+            owner: test_data.program_owner.pubkey(),
+            inbox_item,
+            source_chain: OTHER_CHAIN,
+            mint: test_data.mint,
+            outbox_item,
+        },
+    )
+    .submit_with_signers(&[&test_data.program_owner], ctx)
+    .await
+    .unwrap();
+
+    outbox_item
+}
+
+#[tokio::test]
+async fn test_return_escrowed_inbox_item_consumes_rate_limits() {
+    let (mut ctx, test_data) = setup(Mode::Locking).await;
+
+    let outbox_item = escrow_and_return_transfer(&mut ctx, &test_data, 1000).await;
+
+    let clock: Clock = ctx.banks_client.get_sysvar().await.unwrap();
+    let outbound_limit: OutboxRateLimit = ctx
+        .get_account_data_anchor(good_ntt.outbox_rate_limit())
+        .await;
+    let chain_limit: OutboxChainRateLimit = ctx
+        .get_account_data_anchor(good_ntt.outbox_chain_rate_limit(OTHER_CHAIN))
+        .await;
+    let inbound_limit: InboxRateLimit = ctx
+        .get_account_data_anchor(good_ntt.inbox_rate_limit(OTHER_CHAIN))
+        .await;
+
+    // the returned transfer is sent right away, and consumes the outbound
+    // rate limits like any other transfer
+    let outbox: OutboxItem = ctx.get_account_data_anchor(outbox_item).await;
+    assert!(!outbox.queued);
+    assert_eq!(
+        outbound_limit.rate_limit.capacity_at(clock.unix_timestamp),
+        OUTBOUND_LIMIT - 1000
+    );
+    assert_eq!(
+        chain_limit.capacity_at(clock.unix_timestamp),
+        chain_limit.rate_limit.limit - 1000
+    );
+    // the backflow refills what the inbound transfer consumed
+    assert_eq!(
+        inbound_limit.rate_limit.capacity_at(clock.unix_timestamp),
+        INBOUND_LIMIT
+    );
+}
+
+#[tokio::test]
+async fn test_return_escrowed_inbox_item_queued() {
+    let (mut ctx, test_data) = setup(Mode::Locking).await;
+
+    set_outbound_limit(
+        &good_ntt,
+        SetOutboundLimit {
+            owner: test_data.program_owner.pubkey(),
+        },
+        SetOutboundLimitArgs { limit: 500 },
+    )
+    .submit_with_signers(&[&test_data.program_owner], &mut ctx)
+    .await
+    .unwrap();
+
+    let outbox_item = escrow_and_return_transfer(&mut ctx, &test_data, 1000).await;
+
+    // the returned transfer is outside the outbound rate limit, so it's queued
+    // instead of failing, and doesn't refill the inbound rate limit
+    let clock: Clock = ctx.banks_client.get_sysvar().await.unwrap();
+    let outbox: OutboxItem = ctx.get_account_data_anchor(outbox_item).await;
+    assert!(outbox.queued);
+    assert!(outbox.release_timestamp > clock.unix_timestamp);
+
+    let inbound_limit: InboxRateLimit = ctx
+        .get_account_data_anchor(good_ntt.inbox_rate_limit(OTHER_CHAIN))
+        .await;
+    assert_eq!(
+        inbound_limit.rate_limit.capacity_at(clock.unix_timestamp),
+        INBOUND_LIMIT - 1000
+    );
+}
+
+#[tokio::test]
+async fn test_wrong_inbox_item() {
+    let recipient = Keypair::new();
+    let (mut ctx, test_data) = setup(Mode::Locking).await;
+
+    let msg = make_transfer_message(&good_ntt, [0u8; 32], 1000, &recipient.pubkey());
+
+    let vaa0 = post_vaa_helper(
+        &good_ntt,
+        OTHER_CHAIN.into(),
+        Address(OTHER_TRANSCEIVER),
+        msg.clone(),
+        &mut ctx,
+    )
+    .await;
+
+    receive_message(
+        &good_ntt,
+        init_receive_message_accs(&mut ctx, vaa0, OTHER_CHAIN, [0u8; 32]),
+    )
+    .submit(&mut ctx)
+    .await
+    .unwrap();
+
+    // use 'ANOTHER_CHAIN' inbox item account here
+    let mut redeem_accs = init_redeem_accs(
+        &mut ctx,
+        &test_data,
+        OTHER_CHAIN,
+        msg.ntt_manager_payload.clone(),
+    );
+    redeem_accs.inbox_item = good_ntt.inbox_item(ANOTHER_CHAIN, msg.ntt_manager_payload.clone());
+
+    let err = redeem(&good_ntt, redeem_accs, RedeemArgs {})
+        .submit(&mut ctx)
+        .await
+        .unwrap_err();
+
+    assert_eq!(
+        err.unwrap(),
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(ErrorCode::ConstraintSeeds.into())
+        )
+    );
+}
+
+#[tokio::test]
+async fn test_receive_with_payload() {
+    let recipient = Keypair::new();
+    let (mut ctx, test_data) = setup(Mode::Locking).await;
+
+    // transfer tokens to custody account
+    spl_token::instruction::transfer_checked(
+        &Token::id(),
+        &test_data.user_token_account,
+        &test_data.mint,
+        &good_ntt.custody(&test_data.mint),
+        &test_data.user.pubkey(),
+        &[],
+        1000,
+        9,
+    )
+    .unwrap()
+    .submit_with_signers(&[&test_data.user], &mut ctx)
+    .await
+    .unwrap();
+
+    spl_associated_token_account::instruction::create_associated_token_account(
+        &ctx.payer.pubkey(),
+        &recipient.pubkey(),
+        &test_data.mint,
+        &Token::id(),
+    )
+    .submit(&mut ctx)
+    .await
+    .unwrap();
+
+    let recipient_token_account = get_associated_token_address_with_program_id(
+        &recipient.pubkey(),
+        &test_data.mint,
+        &Token::id(),
+    );
+
+    let additional_payload = AdditionalPayload::new(vec![0xDE, 0xAD, 0xBE, 0xEF]).unwrap();
+
+    let mut msg = make_transfer_message(&good_ntt, [0u8; 32], 1000, &recipient.pubkey());
+    msg.ntt_manager_payload.payload.additional_payload = additional_payload.clone();
+
+    let vaa0 = post_vaa_helper(
+        &good_ntt,
+        OTHER_CHAIN.into(),
+        Address(OTHER_TRANSCEIVER),
+        msg.clone(),
+        &mut ctx,
+    )
+    .await;
+
+    receive_message(
+        &good_ntt,
+        init_receive_message_accs(&mut ctx, vaa0, OTHER_CHAIN, [0u8; 32]),
+    )
+    .submit(&mut ctx)
+    .await
+    .unwrap();
+
+    redeem(
+        &good_ntt,
+        init_redeem_accs(
+            &mut ctx,
+            &test_data,
+            OTHER_CHAIN,
+            msg.ntt_manager_payload.clone(),
+        ),
+        RedeemArgs {},
+    )
+    .submit(&mut ctx)
+    .await
+    .unwrap();
+
+    let inbox_item = good_ntt.inbox_item(OTHER_CHAIN, msg.ntt_manager_payload.clone());
+
+    let inbox_item_account: InboxItem = ctx.get_account_data_anchor(inbox_item).await;
+    assert_eq!(inbox_item_account.additional_payload, additional_payload);
+
+    // a transfer with a payload has to be released with a call to the
+    // recipient program
+    let err = release_inbound_unlock(
+        &good_ntt,
+        ReleaseInbound {
+            payer: ctx.payer.pubkey(),
+            inbox_item,
+            peer: good_ntt.peer(OTHER_CHAIN),
+            mint: test_data.mint,
+            recipient: recipient_token_account,
+        },
+        ReleaseInboundArgs {
+            revert_when_not_ready: false,
+        },
+    )
+    .submit(&mut ctx)
+    .await
+    .unwrap_err();
+
+    assert_eq!(
+        err.unwrap(),
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(NTTError::RecipientCallRequired.into())
+        )
+    );
+
+    let token_account: TokenAccount = ctx.get_account_data_anchor(recipient_token_account).await;
+    assert_eq!(token_account.amount, 0);
+}
+
+#[tokio::test]
+async fn test_paused_inbound() {
+    let recipient = Keypair::new();
+    let (mut ctx, test_data) = setup(Mode::Locking).await;
+
+    // transfer tokens to custody account
+    spl_token::instruction::transfer_checked(
+        &Token::id(),
+        &test_data.user_token_account,
+        &test_data.mint,
+        &good_ntt.custody(&test_data.mint),
+        &test_data.user.pubkey(),
+        &[],
+        1000,
+        9,
+    )
+    .unwrap()
+    .submit_with_signers(&[&test_data.user], &mut ctx)
+    .await
+    .unwrap();
+
+    spl_associated_token_account::instruction::create_associated_token_account(
+        &ctx.payer.pubkey(),
+        &recipient.pubkey(),
+        &test_data.mint,
+        &Token::id(),
+    )
+    .submit(&mut ctx)
+    .await
+    .unwrap();
+
+    let recipient_token_account = get_associated_token_address_with_program_id(
+        &recipient.pubkey(),
+        &test_data.mint,
+        &Token::id(),
+    );
+
+    let msg = make_transfer_message(&good_ntt, [0u8; 32], 1000, &recipient.pubkey());
+
+    let vaa0 = post_vaa_helper(
+        &good_ntt,
+        OTHER_CHAIN.into(),
+        Address(OTHER_TRANSCEIVER),
+        msg.clone(),
+        &mut ctx,
+    )
+    .await;
+
+    receive_message(
+        &good_ntt,
+        init_receive_message_accs(&mut ctx, vaa0, OTHER_CHAIN, [0u8; 32]),
+    )
+    .submit(&mut ctx)
+    .await
+    .unwrap();
+
+    // transfers from a paused peer can't be redeemed
+    let set_peer_paused_ix = |paused| {
+        set_peer_paused(
+            &good_ntt,
+            SetPeerPaused {
+                owner: test_data.program_owner.pubkey(),
+            },
+            SetPeerPausedArgs {
+                chain_id: ChainId { id: OTHER_CHAIN },
+                paused,
+            },
+        )
+    };
+    set_peer_paused_ix(true)
+        .submit_with_signers(&[&test_data.program_owner], &mut ctx)
+        .await
+        .unwrap();
+
+    let err = redeem(
+        &good_ntt,
+        init_redeem_accs(
+            &mut ctx,
+            &test_data,
+            OTHER_CHAIN,
+            msg.ntt_manager_payload.clone(),
+        ),
+        RedeemArgs {},
+    )
+    .submit(&mut ctx)
+    .await
+    .unwrap_err();
+    assert_eq!(
+        err.unwrap(),
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(NTTError::PeerPaused.into())
+        )
+    );
+
+    set_peer_paused_ix(false)
+        .submit_with_signers(&[&test_data.program_owner], &mut ctx)
+        .await
+        .unwrap();
+
+    // when inbound is paused, transfers can be redeemed, but not released
+    set_inbound_paused(
+        &good_ntt,
+        SetPaused {
+            owner: test_data.program_owner.pubkey(),
+        },
+        true,
+    )
+    .submit_with_signers(&[&test_data.program_owner], &mut ctx)
+    .await
+    .unwrap();
+
+    redeem(
+        &good_ntt,
+        init_redeem_accs(
+            &mut ctx,
+            &test_data,
+            OTHER_CHAIN,
+            msg.ntt_manager_payload.clone(),
+        ),
+        RedeemArgs {},
+    )
+    .submit(&mut ctx)
+    .await
+    .unwrap();
+
+    let payer = ctx.payer.pubkey();
+    let release_ix = || {
+        release_inbound_unlock(
+            &good_ntt,
+            ReleaseInbound {
+                payer,
+                inbox_item: good_ntt.inbox_item(OTHER_CHAIN, msg.ntt_manager_payload.clone()),
+                peer: good_ntt.peer(OTHER_CHAIN),
+                mint: test_data.mint,
+                recipient: recipient_token_account,
+            },
+            ReleaseInboundArgs {
+                revert_when_not_ready: true,
+            },
+        )
+    };
+
+    let err = release_ix().submit(&mut ctx).await.unwrap_err();
+    assert_eq!(
+        err.unwrap(),
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(NTTError::InboundPaused.into())
+        )
+    );
+
+    set_inbound_paused(
+        &good_ntt,
+        SetPaused {
+            owner: test_data.program_owner.pubkey(),
+        },
+        false,
+    )
+    .submit_with_signers(&[&test_data.program_owner], &mut ctx)
+    .await
+    .unwrap();
+
+    release_ix().submit(&mut ctx).await.unwrap();
+
+    let token_account: TokenAccount = ctx.get_account_data_anchor(recipient_token_account).await;
+    assert_eq!(token_account.amount, 1000);
+}
+
+#[tokio::test]
+async fn test_retired_peer() {
+    let recipient = Keypair::new();
+    let rent_recipient = Keypair::new().pubkey();
+    let (mut ctx, test_data) = setup(Mode::Locking).await;
+
+    // two transfers in flight when the peer is retired
+    let msg = make_transfer_message(&good_ntt, [0u8; 32], 1000, &recipient.pubkey());
+    let late_msg = make_transfer_message(&good_ntt, [1u8; 32], 1000, &recipient.pubkey());
+    for (id, msg) in [([0u8; 32], &msg), ([1u8; 32], &late_msg)] {
+        let vaa = post_vaa_helper(
+            &good_ntt,
+            OTHER_CHAIN.into(),
+            Address(OTHER_TRANSCEIVER),
+            msg.clone(),
+            &mut ctx,
+        )
+        .await;
+        receive_message(
+            &good_ntt,
+            init_receive_message_accs(&mut ctx, vaa, OTHER_CHAIN, id),
+        )
+        .submit(&mut ctx)
+        .await
+        .unwrap();
+    }
+
+    remove_peer(
+        &good_ntt,
+        RemovePeer {
+            owner: test_data.program_owner.pubkey(),
+        },
+        RemovePeerArgs {
+            chain_id: ChainId { id: OTHER_CHAIN },
+            grace_period: 3600,
+            rent_recipient,
+        },
+    )
+    .submit_with_signers(&[&test_data.program_owner], &mut ctx)
+    .await
+    .unwrap();
+
+    // the transfer hadn't been redeemed before the peer was retired, but it's
+    // still within the grace period (and the threshold is 1, so this is the
+    // first and only vote)
+    redeem(
+        &good_ntt,
+        init_redeem_accs(
+            &mut ctx,
+            &test_data,
+            OTHER_CHAIN,
+            msg.ntt_manager_payload.clone(),
+        ),
+        RedeemArgs {},
+    )
+    .submit(&mut ctx)
+    .await
+    .unwrap();
+
+    let inbox_item: InboxItem = ctx
+        .get_account_data_anchor(good_ntt.inbox_item(OTHER_CHAIN, msg.ntt_manager_payload.clone()))
+        .await;
+    assert!(matches!(
+        inbox_item.release_status,
+        ReleaseStatus::ReleaseAfter(_)
+    ));
+
+    // a transfer attested after the peer was retired is rejected, even within
+    // the grace period
+    let clock: Clock = ctx.banks_client.get_sysvar().await.unwrap();
+    let new_msg = make_transfer_message(&good_ntt, [2u8; 32], 1000, &recipient.pubkey());
+    let vaa = post_vaa_helper_at(
+        &good_ntt,
+        OTHER_CHAIN.into(),
+        Address(OTHER_TRANSCEIVER),
+        new_msg.clone(),
+        (clock.unix_timestamp + 1) as u32,
+        &mut ctx,
+    )
+    .await;
+    receive_message(
+        &good_ntt,
+        init_receive_message_accs(&mut ctx, vaa, OTHER_CHAIN, [2u8; 32]),
+    )
+    .submit(&mut ctx)
+    .await
+    .unwrap();
+    let err = redeem(
+        &good_ntt,
+        init_redeem_accs(
+            &mut ctx,
+            &test_data,
+            OTHER_CHAIN,
+            new_msg.ntt_manager_payload.clone(),
+        ),
+        RedeemArgs {},
+    )
+    .submit(&mut ctx)
+    .await
+    .unwrap_err();
+    assert_eq!(
+        err.unwrap(),
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(NTTError::PeerRetired.into())
+        )
+    );
+
+    let close_peer_ix = |owner| {
+        close_peer(
+            &good_ntt,
+            ClosePeer {
+                owner,
+                rent_recipient,
+            },
+            ClosePeerArgs {
+                chain_id: ChainId { id: OTHER_CHAIN },
+            },
+        )
+    };
+    let owner = test_data.program_owner.pubkey();
+
+    let err = close_peer_ix(owner)
+        .submit_with_signers(&[&test_data.program_owner], &mut ctx)
+        .await
+        .unwrap_err();
+    assert_eq!(
+        err.unwrap(),
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(NTTError::GracePeriodNotOver.into())
+        )
+    );
+
+    let mut clock: Clock = ctx.banks_client.get_sysvar().await.unwrap();
+    clock.unix_timestamp += 3600;
+    ctx.set_sysvar(&clock);
+
+    // the grace period is over
+    let err = redeem(
+        &good_ntt,
+        init_redeem_accs(
+            &mut ctx,
+            &test_data,
+            OTHER_CHAIN,
+            late_msg.ntt_manager_payload.clone(),
+        ),
+        RedeemArgs {},
+    )
+    .submit(&mut ctx)
+    .await
+    .unwrap_err();
+    assert_eq!(
+        err.unwrap(),
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(NTTError::PeerRetired.into())
+        )
+    );
+
+    // only the peer admins can close the peer, as in-flight transfers need to
+    // be drained first
+    let err = close_peer_ix(recipient.pubkey())
+        .submit_with_signers(&[&recipient], &mut ctx)
+        .await
+        .unwrap_err();
+    assert_eq!(
+        err.unwrap(),
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(NTTError::Unauthorized.into())
+        )
+    );
+
+    close_peer_ix(owner)
+        .submit_with_signers(&[&test_data.program_owner], &mut ctx)
+        .await
+        .unwrap();
+
+    for account in [
+        good_ntt.peer(OTHER_CHAIN),
+        good_ntt.inbox_rate_limit(OTHER_CHAIN),
+        good_ntt.outbox_chain_rate_limit(OTHER_CHAIN),
+    ] {
+        assert!(ctx
+            .banks_client
+            .get_account(account)
+            .await
+            .unwrap()
+            .is_none());
+    }
+    assert!(ctx.banks_client.get_balance(rent_recipient).await.unwrap() > 0);
+}
+
+#[tokio::test]
+async fn test_redeem_legacy_transceiver_message() {
+    let recipient = Keypair::new();
+    let (mut ctx, test_data) = setup(Mode::Locking).await;
+
+    // a message received by the 3.0.0 release, before transfers had an
+    // additional payload, but not redeemed yet
+    let msg = make_transfer_message(&good_ntt, [0u8; 32], 1000, &recipient.pubkey());
+    let transfer = msg.ntt_manager_payload.payload.clone();
+    let legacy_message = legacy::ValidatedTransceiverMessage {
+        from_chain: ChainId { id: OTHER_CHAIN },
+        message: TransceiverMessageData {
+            source_ntt_manager: msg.source_ntt_manager,
+            recipient_ntt_manager: msg.recipient_ntt_manager,
+            ntt_manager_payload: NttManagerMessage {
+                id: msg.ntt_manager_payload.id,
+                sender: msg.ntt_manager_payload.sender,
+                payload: NativeTokenTransfer {
+                    amount: transfer.amount,
+                    source_token: transfer.source_token,
+                    to_chain: transfer.to_chain,
+                    to: transfer.to,
+                    additional_payload: EmptyPayload {},
+                },
+            },
+        },
+    };
+    let mut data =
+        ValidatedTransceiverMessage::<NativeTokenTransfer<Payload>>::DISCRIMINATOR.to_vec();
+    data.extend(legacy_message.try_to_vec().unwrap());
+    // the size of the transceiver messages of the 3.0.0 release
+    assert_eq!(data.len(), 8 + 205);
+
+    let transceiver_message = good_ntt.transceiver_message(OTHER_CHAIN, msg.ntt_manager_payload.id);
+    let lamports = ctx
+        .banks_client
+        .get_rent()
+        .await
+        .unwrap()
+        .minimum_balance(data.len());
+    ctx.set_account(
+        &transceiver_message,
+        &Account {
+            lamports,
+            data,
+            owner: example_native_token_transfers::ID,
+            executable: false,
+            rent_epoch: 0,
+        }
+        .into(),
+    );
+
+    let redeem_ix = |ctx: &mut ProgramTestContext| {
+        redeem(
+            &good_ntt,
+            init_redeem_accs(
+                ctx,
+                &test_data,
+                OTHER_CHAIN,
+                msg.ntt_manager_payload.clone(),
+            ),
+            RedeemArgs {},
+        )
+    };
+
+    // it can't be decoded before it's migrated
+    assert!(redeem_ix(&mut ctx).submit(&mut ctx).await.is_err());
+
+    migrate(
+        &good_ntt,
+        Migrate {
+            payer: ctx.payer.pubkey(),
+            owner: test_data.program_owner.pubkey(),
+            account: transceiver_message,
+        },
+        MigrateArgs { source_chain: None },
+    )
+    .submit_with_signers(&[&test_data.program_owner], &mut ctx)
+    .await
+    .unwrap();
+
+    let migrated: ValidatedTransceiverMessage<NativeTokenTransfer<Payload>> =
+        ctx.get_account_data_anchor(transceiver_message).await;
+    assert_eq!(migrated.message, msg.message_data);
+    assert_eq!(migrated.rent_payer, ctx.payer.pubkey());
+
+    ctx.get_new_latest_blockhash().await.unwrap();
+    redeem_ix(&mut ctx).submit(&mut ctx).await.unwrap();
+
+    let inbox_item: InboxItem = ctx
+        .get_account_data_anchor(good_ntt.inbox_item(OTHER_CHAIN, msg.ntt_manager_payload.clone()))
+        .await;
+    assert_eq!(inbox_item.amount, 1000);
+    assert_eq!(inbox_item.recipient_address, recipient.pubkey());
+    assert!(matches!(
+        inbox_item.release_status,
+        ReleaseStatus::ReleaseAfter(_)
+    ));
+}
@@ -0,0 +1,356 @@
+#![cfg(feature = "test-sbf")]
+#![feature(type_changing_struct_update)]
+
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Token, TokenAccount};
+use common::{
+    setup::{TestData, DUMMY_NTT_RECIPIENT, OTHER_CHAIN},
+    utils::make_transfer_message,
+};
+use example_native_token_transfers::{
+    error::NTTError,
+    instructions::{RedeemArgs, ReleaseInboundAndCallArgs, ReleaseInboundArgs},
+    transfer::Payload,
+    RECIPIENT_SEED,
+};
+use ntt_messages::{
+    chain_id::ChainId,
+    mode::Mode,
+    ntt::{AdditionalPayload, NativeTokenTransfer},
+    ntt_manager::NttManagerMessage,
+};
+use sdk::accounts::NTTAccounts;
+use solana_program::instruction::InstructionError;
+use solana_program_test::*;
+use solana_sdk::{instruction::AccountMeta, signer::Signer, transaction::TransactionError};
+use spl_associated_token_account::get_associated_token_address_with_program_id;
+use wormhole_sdk::Address;
+
+use crate::{
+    common::{
+        query::GetAccountDataAnchor,
+        setup::{setup, OTHER_TRANSCEIVER},
+        submit::Submittable,
+        utils::post_vaa_helper,
+    },
+    sdk::{
+        accounts::good_ntt,
+        instructions::{
+            redeem::{redeem, Redeem},
+            release_inbound::{
+                release_inbound_unlock, release_inbound_unlock_and_call, ReleaseInbound,
+            },
+        },
+        transceivers::wormhole::instructions::receive_message::{receive_message, ReceiveMessage},
+    },
+};
+
+pub mod common;
+pub mod sdk;
+
+/// Mirrors `dummy_ntt_recipient::Receipt`
+#[derive(AnchorDeserialize, Debug, PartialEq, Eq)]
+struct Receipt {
+    inbox_item: Pubkey,
+    source_chain: ChainId,
+    sender: [u8; 32],
+    amount: u64,
+    additional_payload: Vec<u8>,
+    balance: u64,
+}
+
+fn receipt_address(inbox_item: &Pubkey) -> Pubkey {
+    let (receipt, _) =
+        Pubkey::find_program_address(&[b"receipt", inbox_item.as_ref()], &DUMMY_NTT_RECIPIENT);
+    receipt
+}
+
+/// Redeems a transfer (with payload) to the dummy recipient program, and
+/// returns the inbox item and the recipient token account.
+async fn redeem_to_recipient_program(
+    ctx: &mut ProgramTestContext,
+    test_data: &TestData,
+    additional_payload: AdditionalPayload,
+) -> (
+    NttManagerMessage<NativeTokenTransfer<Payload>>,
+    Pubkey,
+    Pubkey,
+) {
+    let (recipient, _) = Pubkey::find_program_address(&[RECIPIENT_SEED], &DUMMY_NTT_RECIPIENT);
+
+    // transfer tokens to custody account
+    spl_token::instruction::transfer_checked(
+        &Token::id(),
+        &test_data.user_token_account,
+        &test_data.mint,
+        &good_ntt.custody(&test_data.mint),
+        &test_data.user.pubkey(),
+        &[],
+        1000,
+        9,
+    )
+    .unwrap()
+    .submit_with_signers(&[&test_data.user], ctx)
+    .await
+    .unwrap();
+
+    spl_associated_token_account::instruction::create_associated_token_account(
+        &ctx.payer.pubkey(),
+        &recipient,
+        &test_data.mint,
+        &Token::id(),
+    )
+    .submit(ctx)
+    .await
+    .unwrap();
+
+    let recipient_token_account =
+        get_associated_token_address_with_program_id(&recipient, &test_data.mint, &Token::id());
+
+    let mut msg = make_transfer_message(&good_ntt, [0u8; 32], 1000, &recipient);
+    msg.ntt_manager_payload.payload.additional_payload = additional_payload;
+
+    let vaa = post_vaa_helper(
+        &good_ntt,
+        OTHER_CHAIN.into(),
+        Address(OTHER_TRANSCEIVER),
+        msg.clone(),
+        ctx,
+    )
+    .await;
+
+    receive_message(
+        &good_ntt,
+        ReceiveMessage {
+            payer: ctx.payer.pubkey(),
+            peer: good_ntt.transceiver_peer(OTHER_CHAIN),
+            vaa,
+            chain_id: OTHER_CHAIN,
+            id: [0u8; 32],
+        },
+    )
+    .submit(ctx)
+    .await
+    .unwrap();
+
+    let inbox_item = good_ntt.inbox_item(OTHER_CHAIN, msg.ntt_manager_payload.clone());
+
+    redeem(
+        &good_ntt,
+        Redeem {
+            payer: ctx.payer.pubkey(),
+            peer: good_ntt.peer(OTHER_CHAIN),
+            transceiver: good_ntt.program(),
+            transceiver_message: good_ntt.transceiver_message(OTHER_CHAIN, [0u8; 32]),
+            inbox_item,
+            inbox_rate_limit: good_ntt.inbox_rate_limit(OTHER_CHAIN),
+            outbox_chain_rate_limit: good_ntt.outbox_chain_rate_limit(OTHER_CHAIN),
+            mint: test_data.mint,
+        },
+        RedeemArgs {},
+    )
+    .submit(ctx)
+    .await
+    .unwrap();
+
+    (
+        msg.ntt_manager_payload.clone(),
+        inbox_item,
+        recipient_token_account,
+    )
+}
+
+#[tokio::test]
+async fn test_release_inbound_and_call() {
+    let (mut ctx, test_data) = setup(Mode::Locking).await;
+
+    let additional_payload = AdditionalPayload::new(vec![0xDE, 0xAD, 0xBE, 0xEF]).unwrap();
+
+    let (message, inbox_item, recipient_token_account) =
+        redeem_to_recipient_program(&mut ctx, &test_data, additional_payload.clone()).await;
+
+    let mut ix = release_inbound_unlock_and_call(
+        &good_ntt,
+        ReleaseInbound {
+            payer: ctx.payer.pubkey(),
+            inbox_item,
+            peer: good_ntt.peer(OTHER_CHAIN),
+            mint: test_data.mint,
+            recipient: recipient_token_account,
+        },
+        DUMMY_NTT_RECIPIENT,
+        ReleaseInboundAndCallArgs {
+            revert_when_not_ready: true,
+            recipient_accounts: 3,
+        },
+    );
+    // accounts of the recipient program
+    ix.accounts.extend([
+        AccountMeta::new(ctx.payer.pubkey(), true),
+        AccountMeta::new(receipt_address(&inbox_item), false),
+        AccountMeta::new_readonly(solana_sdk::system_program::id(), false),
+    ]);
+    ix.submit(&mut ctx).await.unwrap();
+
+    let receipt = ctx
+        .banks_client
+        .get_account(receipt_address(&inbox_item))
+        .await
+        .unwrap()
+        .unwrap();
+    let receipt = Receipt::deserialize(&mut &receipt.data[8..]).unwrap();
+
+    assert_eq!(
+        receipt,
+        Receipt {
+            inbox_item,
+            source_chain: ChainId { id: OTHER_CHAIN },
+            sender: message.sender,
+            amount: 1000,
+            additional_payload: additional_payload.bytes,
+            // the tokens have landed before the call
+            balance: 1000,
+        }
+    );
+
+    let token_account: TokenAccount = ctx.get_account_data_anchor(recipient_token_account).await;
+    assert_eq!(token_account.amount, 1000);
+}
+
+#[tokio::test]
+async fn test_release_inbound_and_call_wrong_program() {
+    let (mut ctx, test_data) = setup(Mode::Locking).await;
+
+    let (_, inbox_item, recipient_token_account) = redeem_to_recipient_program(
+        &mut ctx,
+        &test_data,
+        AdditionalPayload::new(vec![1, 2, 3]).unwrap(),
+    )
+    .await;
+
+    // the recipient address is not derived from this program
+    let err = release_inbound_unlock_and_call(
+        &good_ntt,
+        ReleaseInbound {
+            payer: ctx.payer.pubkey(),
+            inbox_item,
+            peer: good_ntt.peer(OTHER_CHAIN),
+            mint: test_data.mint,
+            recipient: recipient_token_account,
+        },
+        wormhole_governance::ID,
+        ReleaseInboundAndCallArgs {
+            revert_when_not_ready: true,
+            recipient_accounts: 0,
+        },
+    )
+    .submit(&mut ctx)
+    .await
+    .unwrap_err();
+
+    assert_eq!(
+        err.unwrap(),
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(NTTError::InvalidRecipientProgram.into())
+        )
+    );
+}
+
+#[tokio::test]
+async fn test_release_inbound_without_call() {
+    let (mut ctx, test_data) = setup(Mode::Locking).await;
+
+    let (_, inbox_item, recipient_token_account) = redeem_to_recipient_program(
+        &mut ctx,
+        &test_data,
+        AdditionalPayload::new(vec![1, 2, 3]).unwrap(),
+    )
+    .await;
+
+    // the transfer is meant for the recipient program, so it can't be
+    // released without calling it
+    let err = release_inbound_unlock(
+        &good_ntt,
+        ReleaseInbound {
+            payer: ctx.payer.pubkey(),
+            inbox_item,
+            peer: good_ntt.peer(OTHER_CHAIN),
+            mint: test_data.mint,
+            recipient: recipient_token_account,
+        },
+        ReleaseInboundArgs {
+            revert_when_not_ready: true,
+        },
+    )
+    .submit(&mut ctx)
+    .await
+    .unwrap_err();
+
+    assert_eq!(
+        err.unwrap(),
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(NTTError::RecipientCallRequired.into())
+        )
+    );
+}
+
+#[tokio::test]
+async fn test_release_inbound_and_call_without_payload() {
+    let (mut ctx, test_data) = setup(Mode::Locking).await;
+
+    let (_, inbox_item, recipient_token_account) =
+        redeem_to_recipient_program(&mut ctx, &test_data, AdditionalPayload::default()).await;
+
+    // no payload, but the recipient is a PDA
+    let err = release_inbound_unlock(
+        &good_ntt,
+        ReleaseInbound {
+            payer: ctx.payer.pubkey(),
+            inbox_item,
+            peer: good_ntt.peer(OTHER_CHAIN),
+            mint: test_data.mint,
+            recipient: recipient_token_account,
+        },
+        ReleaseInboundArgs {
+            revert_when_not_ready: true,
+        },
+    )
+    .submit(&mut ctx)
+    .await
+    .unwrap_err();
+
+    assert_eq!(
+        err.unwrap(),
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(NTTError::RecipientCallRequired.into())
+        )
+    );
+
+    let mut ix = release_inbound_unlock_and_call(
+        &good_ntt,
+        ReleaseInbound {
+            payer: ctx.payer.pubkey(),
+            inbox_item,
+            peer: good_ntt.peer(OTHER_CHAIN),
+            mint: test_data.mint,
+            recipient: recipient_token_account,
+        },
+        DUMMY_NTT_RECIPIENT,
+        ReleaseInboundAndCallArgs {
+            revert_when_not_ready: true,
+            recipient_accounts: 3,
+        },
+    );
+    ix.accounts.extend([
+        AccountMeta::new(ctx.payer.pubkey(), true),
+        AccountMeta::new(receipt_address(&inbox_item), false),
+        AccountMeta::new_readonly(solana_sdk::system_program::id(), false),
+    ]);
+    ix.submit(&mut ctx).await.unwrap();
+
+    let token_account: TokenAccount = ctx.get_account_data_anchor(recipient_token_account).await;
+    assert_eq!(token_account.amount, 1000);
+}
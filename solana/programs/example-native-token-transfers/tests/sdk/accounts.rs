@@ -1,14 +1,17 @@
 use anchor_lang::prelude::Pubkey;
 use example_native_token_transfers::{
+    compliance::DenylistEntry,
     config::Config,
-    instructions::TransferArgs,
+    instructions::{TransferArgs, TransferWithPayloadArgs},
+    pending_change::{ConfigChange, PendingChange},
     queue::{
         inbox::{InboxItem, InboxRateLimit},
-        outbox::OutboxRateLimit,
+        outbox::{OutboxChainRateLimit, OutboxItem, OutboxRateLimit, OutboxSequence},
     },
     registered_transceiver::RegisteredTransceiver,
     transfer::Payload,
-    SESSION_AUTHORITY_SEED, TOKEN_AUTHORITY_SEED,
+    ESCROW_AUTHORITY_SEED, RECIPIENT_CALL_AUTHORITY_SEED, SESSION_AUTHORITY_SEED,
+    TOKEN_AUTHORITY_SEED,
 };
 use ntt_messages::{ntt::NativeTokenTransfer, ntt_manager::NttManagerMessage};
 use sha3::{Digest, Keccak256};
@@ -91,6 +94,31 @@ pub trait NTTAccounts {
         outbox_rate_limit
     }
 
+    fn outbox_sequence(&self) -> Pubkey {
+        let (outbox_sequence, _) =
+            Pubkey::find_program_address(&[OutboxSequence::SEED_PREFIX], &self.program());
+        outbox_sequence
+    }
+
+    fn outbox_item(&self, sequence: u64) -> Pubkey {
+        let (outbox_item, _) = Pubkey::find_program_address(
+            &[OutboxItem::SEED_PREFIX, &sequence.to_be_bytes()],
+            &self.program(),
+        );
+        outbox_item
+    }
+
+    fn pending_change(&self, change: &ConfigChange) -> Pubkey {
+        let (pending_change, _) = Pubkey::find_program_address(
+            &[
+                PendingChange::SEED_PREFIX,
+                change.keccak256().unwrap().as_ref(),
+            ],
+            &self.program(),
+        );
+        pending_change
+    }
+
     fn inbox_rate_limit(&self, chain: u16) -> Pubkey {
         let (inbox_rate_limit, _) = Pubkey::find_program_address(
             &[InboxRateLimit::SEED_PREFIX, &chain.to_be_bytes()],
@@ -99,6 +127,14 @@ pub trait NTTAccounts {
         inbox_rate_limit
     }
 
+    fn outbox_chain_rate_limit(&self, chain: u16) -> Pubkey {
+        let (outbox_chain_rate_limit, _) = Pubkey::find_program_address(
+            &[OutboxChainRateLimit::SEED_PREFIX, &chain.to_be_bytes()],
+            &self.program(),
+        );
+        outbox_chain_rate_limit
+    }
+
     fn session_authority(&self, sender: &Pubkey, args: &TransferArgs) -> Pubkey {
         let TransferArgs {
             amount,
@@ -120,6 +156,33 @@ pub trait NTTAccounts {
         session_authority
     }
 
+    fn session_authority_with_payload(
+        &self,
+        sender: &Pubkey,
+        args: &TransferWithPayloadArgs,
+    ) -> Pubkey {
+        let TransferWithPayloadArgs {
+            amount,
+            recipient_chain,
+            recipient_address,
+            should_queue,
+            additional_payload,
+        } = args;
+        let mut hasher = Keccak256::new();
+
+        hasher.update(amount.to_be_bytes());
+        hasher.update(recipient_chain.id.to_be_bytes());
+        hasher.update(recipient_address);
+        hasher.update([*should_queue as u8]);
+        hasher.update(additional_payload);
+
+        let (session_authority, _) = Pubkey::find_program_address(
+            &[SESSION_AUTHORITY_SEED, sender.as_ref(), &hasher.finalize()],
+            &self.program(),
+        );
+        session_authority
+    }
+
     fn inbox_item(
         &self,
         chain: u16,
@@ -142,6 +205,26 @@ pub trait NTTAccounts {
         token_authority
     }
 
+    fn escrow_authority(&self) -> Pubkey {
+        let (escrow_authority, _) =
+            Pubkey::find_program_address(&[ESCROW_AUTHORITY_SEED], &self.program());
+        escrow_authority
+    }
+
+    fn escrow(&self, mint: &Pubkey) -> Pubkey {
+        anchor_spl::associated_token::get_associated_token_address_with_program_id(
+            &self.escrow_authority(),
+            mint,
+            &spl_token::ID,
+        )
+    }
+
+    fn recipient_call_authority(&self) -> Pubkey {
+        let (recipient_call_authority, _) =
+            Pubkey::find_program_address(&[RECIPIENT_CALL_AUTHORITY_SEED], &self.program());
+        recipient_call_authority
+    }
+
     fn registered_transceiver(&self, transceiver: &Pubkey) -> Pubkey {
         let (registered_transceiver, _) = Pubkey::find_program_address(
             &[RegisteredTransceiver::SEED_PREFIX, transceiver.as_ref()],
@@ -175,6 +258,14 @@ pub trait NTTAccounts {
         peer
     }
 
+    fn denylist_entry(&self, address: &[u8; 32]) -> Pubkey {
+        let (denylist_entry, _) = Pubkey::find_program_address(
+            &[DenylistEntry::SEED_PREFIX, address.as_ref()],
+            &self.program(),
+        );
+        denylist_entry
+    }
+
     fn transceiver_peer(&self, chain: u16) -> Pubkey {
         let (peer, _) = Pubkey::find_program_address(
             &[b"transceiver_peer".as_ref(), &chain.to_be_bytes()],
@@ -1,5 +1,14 @@
 use anchor_lang::{prelude::Pubkey, system_program::System, Id, InstructionData, ToAccountMetas};
-use example_native_token_transfers::instructions::{SetOutboundLimitArgs, SetPeerArgs};
+use example_native_token_transfers::{
+    config::Role,
+    instructions::{
+        ClosePeerArgs, InitOutboxChainRateLimitArgs, MigrateArgs, PausePeerArgs, RemovePeerArgs,
+        SetInboundRateLimitDurationArgs, SetLegacyPeerAddressesArgs, SetOutboundChainLimitArgs,
+        SetOutboundChainRateLimitDurationArgs, SetOutboundLimitArgs,
+        SetOutboundRateLimitDurationArgs, SetPeerArgs, SetPeerPausedArgs, SetPeerThresholdArgs,
+    },
+    pending_change::ConfigChange,
+};
 use solana_sdk::instruction::Instruction;
 
 use crate::sdk::accounts::NTT;
@@ -19,6 +28,7 @@ pub fn set_peer(ntt: &NTT, accounts: SetPeer, args: SetPeerArgs) -> Instruction
         payer: accounts.payer,
         peer: ntt.peer(chain_id),
         inbox_rate_limit: ntt.inbox_rate_limit(chain_id),
+        outbox_chain_rate_limit: ntt.outbox_chain_rate_limit(chain_id),
         system_program: System::id(),
     };
 
@@ -48,6 +58,314 @@ pub fn set_paused(ntt: &NTT, accounts: SetPaused, pause: bool) -> Instruction {
     }
 }
 
+pub struct Pause {
+    pub pauser: Pubkey,
+}
+
+pub fn pause(ntt: &NTT, accounts: Pause) -> Instruction {
+    let data = example_native_token_transfers::instruction::Pause {};
+
+    let accounts = example_native_token_transfers::accounts::Pause {
+        pauser: accounts.pauser,
+        config: ntt.config(),
+    };
+
+    Instruction {
+        program_id: ntt.program(),
+        accounts: accounts.to_account_metas(None),
+        data: data.data(),
+    }
+}
+
+pub struct SetRole {
+    pub owner: Pubkey,
+}
+
+pub fn grant_role(ntt: &NTT, accounts: SetRole, role: Role, holder: Pubkey) -> Instruction {
+    let data = example_native_token_transfers::instruction::GrantRole { role, holder };
+
+    let accounts = example_native_token_transfers::accounts::SetRole {
+        owner: accounts.owner,
+        config: ntt.config(),
+    };
+
+    Instruction {
+        program_id: ntt.program(),
+        accounts: accounts.to_account_metas(None),
+        data: data.data(),
+    }
+}
+
+pub fn revoke_role(ntt: &NTT, accounts: SetRole, role: Role) -> Instruction {
+    let data = example_native_token_transfers::instruction::RevokeRole { role };
+
+    let accounts = example_native_token_transfers::accounts::SetRole {
+        owner: accounts.owner,
+        config: ntt.config(),
+    };
+
+    Instruction {
+        program_id: ntt.program(),
+        accounts: accounts.to_account_metas(None),
+        data: data.data(),
+    }
+}
+
+pub fn pause_outbound(ntt: &NTT, accounts: Pause) -> Instruction {
+    let data = example_native_token_transfers::instruction::PauseOutbound {};
+
+    let accounts = example_native_token_transfers::accounts::Pause {
+        pauser: accounts.pauser,
+        config: ntt.config(),
+    };
+
+    Instruction {
+        program_id: ntt.program(),
+        accounts: accounts.to_account_metas(None),
+        data: data.data(),
+    }
+}
+
+pub fn pause_inbound(ntt: &NTT, accounts: Pause) -> Instruction {
+    let data = example_native_token_transfers::instruction::PauseInbound {};
+
+    let accounts = example_native_token_transfers::accounts::Pause {
+        pauser: accounts.pauser,
+        config: ntt.config(),
+    };
+
+    Instruction {
+        program_id: ntt.program(),
+        accounts: accounts.to_account_metas(None),
+        data: data.data(),
+    }
+}
+
+pub fn set_outbound_paused(ntt: &NTT, accounts: SetPaused, paused: bool) -> Instruction {
+    let data = example_native_token_transfers::instruction::SetOutboundPaused { paused };
+
+    let accounts = example_native_token_transfers::accounts::SetPaused {
+        owner: accounts.owner,
+        config: ntt.config(),
+    };
+
+    Instruction {
+        program_id: ntt.program(),
+        accounts: accounts.to_account_metas(None),
+        data: data.data(),
+    }
+}
+
+pub fn set_inbound_paused(ntt: &NTT, accounts: SetPaused, paused: bool) -> Instruction {
+    let data = example_native_token_transfers::instruction::SetInboundPaused { paused };
+
+    let accounts = example_native_token_transfers::accounts::SetPaused {
+        owner: accounts.owner,
+        config: ntt.config(),
+    };
+
+    Instruction {
+        program_id: ntt.program(),
+        accounts: accounts.to_account_metas(None),
+        data: data.data(),
+    }
+}
+
+pub struct SetPeerPaused {
+    pub owner: Pubkey,
+}
+
+pub fn set_peer_paused(ntt: &NTT, accounts: SetPeerPaused, args: SetPeerPausedArgs) -> Instruction {
+    let chain_id = args.chain_id.id;
+    let data = example_native_token_transfers::instruction::SetPeerPaused { args };
+
+    let accounts = example_native_token_transfers::accounts::SetPeerPaused {
+        owner: accounts.owner,
+        config: ntt.config(),
+        peer: ntt.peer(chain_id),
+    };
+
+    Instruction {
+        program_id: ntt.program(),
+        accounts: accounts.to_account_metas(None),
+        data: data.data(),
+    }
+}
+
+pub fn pause_peer(ntt: &NTT, accounts: Pause, args: PausePeerArgs) -> Instruction {
+    let chain_id = args.chain_id.id;
+    let data = example_native_token_transfers::instruction::PausePeer { args };
+
+    let accounts = example_native_token_transfers::accounts::PausePeer {
+        pauser: accounts.pauser,
+        config: ntt.config(),
+        peer: ntt.peer(chain_id),
+    };
+
+    Instruction {
+        program_id: ntt.program(),
+        accounts: accounts.to_account_metas(None),
+        data: data.data(),
+    }
+}
+
+pub struct SetPeerThreshold {
+    pub owner: Pubkey,
+}
+
+pub fn set_peer_threshold(
+    ntt: &NTT,
+    accounts: SetPeerThreshold,
+    args: SetPeerThresholdArgs,
+) -> Instruction {
+    let chain_id = args.chain_id.id;
+    let data = example_native_token_transfers::instruction::SetPeerThreshold { args };
+
+    let accounts = example_native_token_transfers::accounts::SetPeerThreshold {
+        owner: accounts.owner,
+        config: ntt.config(),
+        peer: ntt.peer(chain_id),
+    };
+
+    Instruction {
+        program_id: ntt.program(),
+        accounts: accounts.to_account_metas(None),
+        data: data.data(),
+    }
+}
+
+pub struct SetLegacyPeerAddresses {
+    pub owner: Pubkey,
+}
+
+pub fn set_legacy_peer_addresses(
+    ntt: &NTT,
+    accounts: SetLegacyPeerAddresses,
+    args: SetLegacyPeerAddressesArgs,
+) -> Instruction {
+    let chain_id = args.chain_id.id;
+    let data = example_native_token_transfers::instruction::SetLegacyPeerAddresses { args };
+
+    let accounts = example_native_token_transfers::accounts::SetLegacyPeerAddresses {
+        owner: accounts.owner,
+        config: ntt.config(),
+        peer: ntt.peer(chain_id),
+    };
+
+    Instruction {
+        program_id: ntt.program(),
+        accounts: accounts.to_account_metas(None),
+        data: data.data(),
+    }
+}
+
+pub struct RemovePeer {
+    pub owner: Pubkey,
+}
+
+pub fn remove_peer(ntt: &NTT, accounts: RemovePeer, args: RemovePeerArgs) -> Instruction {
+    let chain_id = args.chain_id.id;
+    let data = example_native_token_transfers::instruction::RemovePeer { args };
+
+    let accounts = example_native_token_transfers::accounts::RemovePeer {
+        owner: accounts.owner,
+        config: ntt.config(),
+        peer: ntt.peer(chain_id),
+    };
+
+    Instruction {
+        program_id: ntt.program(),
+        accounts: accounts.to_account_metas(None),
+        data: data.data(),
+    }
+}
+
+pub struct ClosePeer {
+    pub owner: Pubkey,
+    pub rent_recipient: Pubkey,
+}
+
+pub fn close_peer(ntt: &NTT, accounts: ClosePeer, args: ClosePeerArgs) -> Instruction {
+    let chain_id = args.chain_id.id;
+    let data = example_native_token_transfers::instruction::ClosePeer { args };
+
+    let accounts = example_native_token_transfers::accounts::ClosePeer {
+        owner: accounts.owner,
+        config: ntt.config(),
+        peer: ntt.peer(chain_id),
+        inbox_rate_limit: ntt.inbox_rate_limit(chain_id),
+        outbox_chain_rate_limit: ntt.outbox_chain_rate_limit(chain_id),
+        rent_recipient: accounts.rent_recipient,
+    };
+
+    Instruction {
+        program_id: ntt.program(),
+        accounts: accounts.to_account_metas(None),
+        data: data.data(),
+    }
+}
+
+pub struct SetPreciseQueueRelease {
+    pub owner: Pubkey,
+}
+
+pub fn set_precise_queue_release(
+    ntt: &NTT,
+    accounts: SetPreciseQueueRelease,
+    enabled: bool,
+) -> Instruction {
+    let data = example_native_token_transfers::instruction::SetPreciseQueueRelease { enabled };
+
+    let accounts = example_native_token_transfers::accounts::SetPreciseQueueRelease {
+        owner: accounts.owner,
+        config: ntt.config(),
+    };
+
+    Instruction {
+        program_id: ntt.program(),
+        accounts: accounts.to_account_metas(None),
+        data: data.data(),
+    }
+}
+
+pub struct SetStrictDust {
+    pub owner: Pubkey,
+}
+
+pub fn set_strict_dust(ntt: &NTT, accounts: SetStrictDust, enabled: bool) -> Instruction {
+    let data = example_native_token_transfers::instruction::SetStrictDust { enabled };
+
+    let accounts = example_native_token_transfers::accounts::SetStrictDust {
+        owner: accounts.owner,
+        config: ntt.config(),
+    };
+
+    Instruction {
+        program_id: ntt.program(),
+        accounts: accounts.to_account_metas(None),
+        data: data.data(),
+    }
+}
+
+pub struct SetEscrowTimeout {
+    pub owner: Pubkey,
+}
+
+pub fn set_escrow_timeout(ntt: &NTT, accounts: SetEscrowTimeout, timeout: i64) -> Instruction {
+    let data = example_native_token_transfers::instruction::SetEscrowTimeout { timeout };
+
+    let accounts = example_native_token_transfers::accounts::SetEscrowTimeout {
+        owner: accounts.owner,
+        config: ntt.config(),
+    };
+
+    Instruction {
+        program_id: ntt.program(),
+        accounts: accounts.to_account_metas(None),
+        data: data.data(),
+    }
+}
+
 pub struct RegisterTransceiver {
     pub payer: Pubkey,
     pub owner: Pubkey,
@@ -136,3 +454,509 @@ pub fn set_outbound_limit(
         data: data.data(),
     }
 }
+
+pub fn set_outbound_chain_limit(
+    ntt: &NTT,
+    accounts: SetOutboundLimit,
+    args: SetOutboundChainLimitArgs,
+) -> Instruction {
+    let chain_id = args.chain_id.id;
+    let data = example_native_token_transfers::instruction::SetOutboundChainLimit { args };
+
+    let accounts = example_native_token_transfers::accounts::SetOutboundChainLimit {
+        config: ntt.config(),
+        owner: accounts.owner,
+        rate_limit: ntt.outbox_chain_rate_limit(chain_id),
+    };
+
+    Instruction {
+        program_id: ntt.program(),
+        accounts: accounts.to_account_metas(None),
+        data: data.data(),
+    }
+}
+
+pub fn set_outbound_rate_limit_duration(
+    ntt: &NTT,
+    accounts: SetOutboundLimit,
+    args: SetOutboundRateLimitDurationArgs,
+) -> Instruction {
+    let data = example_native_token_transfers::instruction::SetOutboundRateLimitDuration { args };
+
+    let accounts = example_native_token_transfers::accounts::SetOutboundLimit {
+        config: ntt.config(),
+        owner: accounts.owner,
+        rate_limit: ntt.outbox_rate_limit(),
+    };
+
+    Instruction {
+        program_id: ntt.program(),
+        accounts: accounts.to_account_metas(None),
+        data: data.data(),
+    }
+}
+
+pub fn set_outbound_chain_rate_limit_duration(
+    ntt: &NTT,
+    accounts: SetOutboundLimit,
+    args: SetOutboundChainRateLimitDurationArgs,
+) -> Instruction {
+    let chain_id = args.chain_id.id;
+    let data =
+        example_native_token_transfers::instruction::SetOutboundChainRateLimitDuration { args };
+
+    let accounts = example_native_token_transfers::accounts::SetOutboundChainRateLimitDuration {
+        config: ntt.config(),
+        owner: accounts.owner,
+        rate_limit: ntt.outbox_chain_rate_limit(chain_id),
+    };
+
+    Instruction {
+        program_id: ntt.program(),
+        accounts: accounts.to_account_metas(None),
+        data: data.data(),
+    }
+}
+
+pub fn set_inbound_rate_limit_duration(
+    ntt: &NTT,
+    accounts: SetOutboundLimit,
+    args: SetInboundRateLimitDurationArgs,
+) -> Instruction {
+    let chain_id = args.chain_id.id;
+    let data = example_native_token_transfers::instruction::SetInboundRateLimitDuration { args };
+
+    let accounts = example_native_token_transfers::accounts::SetInboundRateLimitDuration {
+        config: ntt.config(),
+        owner: accounts.owner,
+        rate_limit: ntt.inbox_rate_limit(chain_id),
+    };
+
+    Instruction {
+        program_id: ntt.program(),
+        accounts: accounts.to_account_metas(None),
+        data: data.data(),
+    }
+}
+
+pub struct Migrate {
+    pub payer: Pubkey,
+    pub owner: Pubkey,
+    pub account: Pubkey,
+}
+
+pub fn migrate(ntt: &NTT, accounts: Migrate, args: MigrateArgs) -> Instruction {
+    let data = example_native_token_transfers::instruction::Migrate { args };
+
+    let accounts = example_native_token_transfers::accounts::Migrate {
+        payer: accounts.payer,
+        owner: accounts.owner,
+        config: ntt.config(),
+        account: accounts.account,
+        system_program: System::id(),
+    };
+
+    Instruction {
+        program_id: ntt.program(),
+        accounts: accounts.to_account_metas(None),
+        data: data.data(),
+    }
+}
+
+pub struct InitOutboxChainRateLimit {
+    pub payer: Pubkey,
+}
+
+pub fn init_outbox_chain_rate_limit(
+    ntt: &NTT,
+    accounts: InitOutboxChainRateLimit,
+    args: InitOutboxChainRateLimitArgs,
+) -> Instruction {
+    let chain_id = args.chain_id.id;
+    let data = example_native_token_transfers::instruction::InitOutboxChainRateLimit { args };
+
+    let accounts = example_native_token_transfers::accounts::InitOutboxChainRateLimit {
+        payer: accounts.payer,
+        peer: ntt.peer(chain_id),
+        outbox_chain_rate_limit: ntt.outbox_chain_rate_limit(chain_id),
+        system_program: System::id(),
+    };
+
+    Instruction {
+        program_id: ntt.program(),
+        accounts: accounts.to_account_metas(None),
+        data: data.data(),
+    }
+}
+
+pub struct SetTimelockDelay {
+    pub owner: Pubkey,
+}
+
+pub fn set_timelock_delay(ntt: &NTT, accounts: SetTimelockDelay, delay: i64) -> Instruction {
+    let data = example_native_token_transfers::instruction::SetTimelockDelay { delay };
+
+    let accounts = example_native_token_transfers::accounts::SetTimelockDelay {
+        owner: accounts.owner,
+        config: ntt.config(),
+    };
+
+    Instruction {
+        program_id: ntt.program(),
+        accounts: accounts.to_account_metas(None),
+        data: data.data(),
+    }
+}
+
+pub struct ProposeChange {
+    pub payer: Pubkey,
+    pub proposer: Pubkey,
+}
+
+pub fn propose_change(ntt: &NTT, accounts: ProposeChange, change: ConfigChange) -> Instruction {
+    let pending_change = ntt.pending_change(&change);
+    let data = example_native_token_transfers::instruction::ProposeChange { change };
+
+    let accounts = example_native_token_transfers::accounts::ProposeChange {
+        payer: accounts.payer,
+        proposer: accounts.proposer,
+        config: ntt.config(),
+        pending_change,
+        system_program: System::id(),
+    };
+
+    Instruction {
+        program_id: ntt.program(),
+        accounts: accounts.to_account_metas(None),
+        data: data.data(),
+    }
+}
+
+pub struct VetoChange {
+    pub authority: Pubkey,
+    pub rent_payer: Pubkey,
+}
+
+pub fn veto_change(ntt: &NTT, accounts: VetoChange, change: &ConfigChange) -> Instruction {
+    let data = example_native_token_transfers::instruction::VetoChange {};
+
+    let accounts = example_native_token_transfers::accounts::VetoChange {
+        authority: accounts.authority,
+        config: ntt.config(),
+        rent_payer: accounts.rent_payer,
+        pending_change: ntt.pending_change(change),
+    };
+
+    Instruction {
+        program_id: ntt.program(),
+        accounts: accounts.to_account_metas(None),
+        data: data.data(),
+    }
+}
+
+pub struct ExecuteChange {
+    pub rent_payer: Pubkey,
+}
+
+pub fn execute_change(ntt: &NTT, accounts: ExecuteChange, change: &ConfigChange) -> Instruction {
+    let data = example_native_token_transfers::instruction::ExecuteChange {};
+
+    let accounts = example_native_token_transfers::accounts::ExecuteChange {
+        config: ntt.config(),
+        rent_payer: accounts.rent_payer,
+        pending_change: ntt.pending_change(change),
+    };
+
+    Instruction {
+        program_id: ntt.program(),
+        accounts: accounts.to_account_metas(None),
+        data: data.data(),
+    }
+}
+
+pub struct ExecuteSetPeer {
+    pub payer: Pubkey,
+    pub rent_payer: Pubkey,
+}
+
+pub fn execute_set_peer(ntt: &NTT, accounts: ExecuteSetPeer, args: SetPeerArgs) -> Instruction {
+    let chain_id = args.chain_id.id;
+    let pending_change = ntt.pending_change(&ConfigChange::SetPeer(args.clone()));
+    let data = example_native_token_transfers::instruction::ExecuteSetPeer { args };
+
+    let accounts = example_native_token_transfers::accounts::ExecuteSetPeer {
+        payer: accounts.payer,
+        config: ntt.config(),
+        rent_payer: accounts.rent_payer,
+        pending_change,
+        peer: ntt.peer(chain_id),
+        inbox_rate_limit: ntt.inbox_rate_limit(chain_id),
+        outbox_chain_rate_limit: ntt.outbox_chain_rate_limit(chain_id),
+        system_program: System::id(),
+    };
+
+    Instruction {
+        program_id: ntt.program(),
+        accounts: accounts.to_account_metas(None),
+        data: data.data(),
+    }
+}
+
+pub struct ExecuteRegisterTransceiver {
+    pub payer: Pubkey,
+    pub rent_payer: Pubkey,
+    pub transceiver: Pubkey,
+}
+
+pub fn execute_register_transceiver(
+    ntt: &NTT,
+    accounts: ExecuteRegisterTransceiver,
+) -> Instruction {
+    let data = example_native_token_transfers::instruction::ExecuteRegisterTransceiver {};
+
+    let accounts = example_native_token_transfers::accounts::ExecuteRegisterTransceiver {
+        payer: accounts.payer,
+        config: ntt.config(),
+        transceiver: accounts.transceiver,
+        rent_payer: accounts.rent_payer,
+        pending_change: ntt.pending_change(&ConfigChange::RegisterTransceiver {
+            transceiver: accounts.transceiver,
+        }),
+        registered_transceiver: ntt.registered_transceiver(&accounts.transceiver),
+        system_program: System::id(),
+    };
+
+    Instruction {
+        program_id: ntt.program(),
+        accounts: accounts.to_account_metas(None),
+        data: data.data(),
+    }
+}
+
+pub struct ExecuteDeregisterTransceiver {
+    pub rent_payer: Pubkey,
+    pub transceiver: Pubkey,
+}
+
+pub fn execute_deregister_transceiver(
+    ntt: &NTT,
+    accounts: ExecuteDeregisterTransceiver,
+) -> Instruction {
+    let data = example_native_token_transfers::instruction::ExecuteDeregisterTransceiver {};
+
+    let accounts = example_native_token_transfers::accounts::ExecuteDeregisterTransceiver {
+        config: ntt.config(),
+        registered_transceiver: ntt.registered_transceiver(&accounts.transceiver),
+        rent_payer: accounts.rent_payer,
+        pending_change: ntt.pending_change(&ConfigChange::DeregisterTransceiver {
+            transceiver: accounts.transceiver,
+        }),
+    };
+
+    Instruction {
+        program_id: ntt.program(),
+        accounts: accounts.to_account_metas(None),
+        data: data.data(),
+    }
+}
+
+pub fn execute_set_outbound_limit(
+    ntt: &NTT,
+    accounts: ExecuteChange,
+    args: SetOutboundLimitArgs,
+) -> Instruction {
+    let pending_change = ntt.pending_change(&ConfigChange::SetOutboundLimit(args.clone()));
+    let data = example_native_token_transfers::instruction::ExecuteSetOutboundLimit { args };
+
+    let accounts = example_native_token_transfers::accounts::ExecuteSetOutboundLimit {
+        rent_payer: accounts.rent_payer,
+        pending_change,
+        rate_limit: ntt.outbox_rate_limit(),
+    };
+
+    Instruction {
+        program_id: ntt.program(),
+        accounts: accounts.to_account_metas(None),
+        data: data.data(),
+    }
+}
+
+pub struct ManageInboxItem {
+    pub authority: Pubkey,
+    pub inbox_item: Pubkey,
+}
+
+fn manage_inbox_item(
+    ntt: &NTT,
+    accounts: ManageInboxItem,
+    data: impl InstructionData,
+) -> Instruction {
+    let accounts = example_native_token_transfers::accounts::ManageInboxItem {
+        authority: accounts.authority,
+        config: ntt.config(),
+        inbox_item: accounts.inbox_item,
+    };
+
+    Instruction {
+        program_id: ntt.program(),
+        accounts: accounts.to_account_metas(None),
+        data: data.data(),
+    }
+}
+
+pub fn freeze_inbox_item(ntt: &NTT, accounts: ManageInboxItem) -> Instruction {
+    manage_inbox_item(
+        ntt,
+        accounts,
+        example_native_token_transfers::instruction::FreezeInboxItem {},
+    )
+}
+
+pub fn unfreeze_inbox_item(ntt: &NTT, accounts: ManageInboxItem) -> Instruction {
+    manage_inbox_item(
+        ntt,
+        accounts,
+        example_native_token_transfers::instruction::UnfreezeInboxItem {},
+    )
+}
+
+pub fn reject_inbox_item(ntt: &NTT, accounts: ManageInboxItem) -> Instruction {
+    manage_inbox_item(
+        ntt,
+        accounts,
+        example_native_token_transfers::instruction::RejectInboxItem {},
+    )
+}
+
+pub struct ForceReleaseInboxItem {
+    pub owner: Pubkey,
+    pub inbox_item: Pubkey,
+}
+
+pub fn force_release_inbox_item(ntt: &NTT, accounts: ForceReleaseInboxItem) -> Instruction {
+    let data = example_native_token_transfers::instruction::ForceReleaseInboxItem {};
+
+    let accounts = example_native_token_transfers::accounts::ForceReleaseInboxItem {
+        owner: accounts.owner,
+        config: ntt.config(),
+        inbox_item: accounts.inbox_item,
+    };
+
+    Instruction {
+        program_id: ntt.program(),
+        accounts: accounts.to_account_metas(None),
+        data: data.data(),
+    }
+}
+
+pub struct SetDenylistEnabled {
+    pub owner: Pubkey,
+}
+
+pub fn set_denylist_enabled(ntt: &NTT, accounts: SetDenylistEnabled, enabled: bool) -> Instruction {
+    let data = example_native_token_transfers::instruction::SetDenylistEnabled { enabled };
+
+    let accounts = example_native_token_transfers::accounts::SetDenylistEnabled {
+        owner: accounts.owner,
+        config: ntt.config(),
+    };
+
+    Instruction {
+        program_id: ntt.program(),
+        accounts: accounts.to_account_metas(None),
+        data: data.data(),
+    }
+}
+
+pub struct SetPolicyProgram {
+    pub owner: Pubkey,
+}
+
+pub fn set_policy_program(
+    ntt: &NTT,
+    accounts: SetPolicyProgram,
+    policy_program: Option<Pubkey>,
+) -> Instruction {
+    let data = example_native_token_transfers::instruction::SetPolicyProgram { policy_program };
+
+    let accounts = example_native_token_transfers::accounts::SetPolicyProgram {
+        owner: accounts.owner,
+        config: ntt.config(),
+    };
+
+    Instruction {
+        program_id: ntt.program(),
+        accounts: accounts.to_account_metas(None),
+        data: data.data(),
+    }
+}
+
+pub struct AddToDenylist {
+    pub payer: Pubkey,
+    pub authority: Pubkey,
+}
+
+pub fn add_to_denylist(ntt: &NTT, accounts: AddToDenylist, address: [u8; 32]) -> Instruction {
+    let data = example_native_token_transfers::instruction::AddToDenylist { address };
+
+    let accounts = example_native_token_transfers::accounts::AddToDenylist {
+        payer: accounts.payer,
+        authority: accounts.authority,
+        config: ntt.config(),
+        entry: ntt.denylist_entry(&address),
+        system_program: System::id(),
+    };
+
+    Instruction {
+        program_id: ntt.program(),
+        accounts: accounts.to_account_metas(None),
+        data: data.data(),
+    }
+}
+
+pub struct RemoveFromDenylist {
+    pub authority: Pubkey,
+    pub rent_payer: Pubkey,
+}
+
+pub fn remove_from_denylist(
+    ntt: &NTT,
+    accounts: RemoveFromDenylist,
+    address: [u8; 32],
+) -> Instruction {
+    let data = example_native_token_transfers::instruction::RemoveFromDenylist { address };
+
+    let accounts = example_native_token_transfers::accounts::RemoveFromDenylist {
+        authority: accounts.authority,
+        config: ntt.config(),
+        entry: ntt.denylist_entry(&address),
+        rent_payer: accounts.rent_payer,
+    };
+
+    Instruction {
+        program_id: ntt.program(),
+        accounts: accounts.to_account_metas(None),
+        data: data.data(),
+    }
+}
+
+pub struct UnquarantineInboxItem {
+    pub authority: Pubkey,
+    pub inbox_item: Pubkey,
+}
+
+pub fn unquarantine_inbox_item(ntt: &NTT, accounts: UnquarantineInboxItem) -> Instruction {
+    let data = example_native_token_transfers::instruction::UnquarantineInboxItem {};
+
+    let accounts = example_native_token_transfers::accounts::UnquarantineInboxItem {
+        authority: accounts.authority,
+        config: ntt.config(),
+        inbox_item: accounts.inbox_item,
+    };
+
+    Instruction {
+        program_id: ntt.program(),
+        accounts: accounts.to_account_metas(None),
+        data: data.data(),
+    }
+}
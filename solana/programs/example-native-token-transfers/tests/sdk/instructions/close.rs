@@ -0,0 +1,45 @@
+use anchor_lang::{prelude::Pubkey, InstructionData, ToAccountMetas};
+use solana_sdk::instruction::Instruction;
+
+use crate::sdk::accounts::NTT;
+
+pub struct CloseOutboxItem {
+    pub outbox_item: Pubkey,
+    pub rent_payer: Pubkey,
+}
+
+pub fn close_outbox_item(ntt: &NTT, accounts: CloseOutboxItem) -> Instruction {
+    let data = example_native_token_transfers::instruction::CloseOutboxItem {};
+
+    let accounts = example_native_token_transfers::accounts::CloseOutboxItem {
+        config: ntt.config(),
+        outbox_item: accounts.outbox_item,
+        rent_payer: accounts.rent_payer,
+    };
+
+    Instruction {
+        program_id: ntt.program(),
+        accounts: accounts.to_account_metas(None),
+        data: data.data(),
+    }
+}
+
+pub struct CloseInboxItem {
+    pub inbox_item: Pubkey,
+    pub rent_payer: Pubkey,
+}
+
+pub fn close_inbox_item(ntt: &NTT, accounts: CloseInboxItem) -> Instruction {
+    let data = example_native_token_transfers::instruction::CloseInboxItem {};
+
+    let accounts = example_native_token_transfers::accounts::CloseInboxItem {
+        inbox_item: accounts.inbox_item,
+        rent_payer: accounts.rent_payer,
+    };
+
+    Instruction {
+        program_id: ntt.program(),
+        accounts: accounts.to_account_metas(None),
+        data: data.data(),
+    }
+}
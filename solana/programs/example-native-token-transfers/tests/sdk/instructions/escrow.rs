@@ -0,0 +1,108 @@
+use anchor_lang::{prelude::*, InstructionData};
+use anchor_spl::{associated_token::AssociatedToken, token::Token};
+use example_native_token_transfers::accounts::NotPausedConfig;
+use solana_sdk::instruction::Instruction;
+
+use crate::sdk::accounts::NTT;
+
+pub struct EscrowInboxItem {
+    pub payer: Pubkey,
+    /// Only needed before the escrow timeout has passed.
+    pub owner: Option<Pubkey>,
+    pub inbox_item: Pubkey,
+    pub mint: Pubkey,
+}
+
+pub fn escrow_inbox_item(ntt: &NTT, accounts: EscrowInboxItem) -> Instruction {
+    let data = example_native_token_transfers::instruction::EscrowInboxItem {};
+
+    let accounts = example_native_token_transfers::accounts::EscrowInboxItem {
+        payer: accounts.payer,
+        owner: accounts.owner,
+        config: NotPausedConfig {
+            config: ntt.config(),
+        },
+        inbox_item: accounts.inbox_item,
+        token_authority: ntt.token_authority(),
+        multisig_token_authority: None,
+        escrow_authority: ntt.escrow_authority(),
+        escrow: ntt.escrow(&accounts.mint),
+        mint: accounts.mint,
+        custody: ntt.custody(&accounts.mint),
+        token_program: Token::id(),
+        associated_token_program: AssociatedToken::id(),
+        system_program: System::id(),
+    };
+
+    Instruction {
+        program_id: ntt.program(),
+        accounts: accounts.to_account_metas(None),
+        data: data.data(),
+    }
+}
+
+pub struct RedirectEscrowedInboxItem {
+    pub owner: Pubkey,
+    pub inbox_item: Pubkey,
+    pub mint: Pubkey,
+    pub recipient: Pubkey,
+}
+
+pub fn redirect_escrowed_inbox_item(ntt: &NTT, accounts: RedirectEscrowedInboxItem) -> Instruction {
+    let data = example_native_token_transfers::instruction::RedirectEscrowedInboxItem {};
+
+    let accounts = example_native_token_transfers::accounts::RedirectEscrowedInboxItem {
+        owner: accounts.owner,
+        config: ntt.config(),
+        inbox_item: accounts.inbox_item,
+        escrow_authority: ntt.escrow_authority(),
+        escrow: ntt.escrow(&accounts.mint),
+        recipient: accounts.recipient,
+        mint: accounts.mint,
+        token_program: Token::id(),
+    };
+
+    Instruction {
+        program_id: ntt.program(),
+        accounts: accounts.to_account_metas(None),
+        data: data.data(),
+    }
+}
+
+pub struct ReturnEscrowedInboxItem {
+    pub payer: Pubkey,
+    pub owner: Pubkey,
+    pub inbox_item: Pubkey,
+    pub source_chain: u16,
+    pub mint: Pubkey,
+    pub outbox_item: Pubkey,
+}
+
+pub fn return_escrowed_inbox_item(ntt: &NTT, accounts: ReturnEscrowedInboxItem) -> Instruction {
+    let data = example_native_token_transfers::instruction::ReturnEscrowedInboxItem {};
+
+    let accounts = example_native_token_transfers::accounts::ReturnEscrowedInboxItem {
+        payer: accounts.payer,
+        owner: accounts.owner,
+        config: ntt.config(),
+        inbox_item: accounts.inbox_item,
+        peer: ntt.peer(accounts.source_chain),
+        escrow_authority: ntt.escrow_authority(),
+        escrow: ntt.escrow(&accounts.mint),
+        mint: accounts.mint,
+        custody: ntt.custody(&accounts.mint),
+        token_program: Token::id(),
+        outbox_sequence: ntt.outbox_sequence(),
+        outbox_item: accounts.outbox_item,
+        outbox_rate_limit: ntt.outbox_rate_limit(),
+        inbox_rate_limit: ntt.inbox_rate_limit(accounts.source_chain),
+        outbox_chain_rate_limit: ntt.outbox_chain_rate_limit(accounts.source_chain),
+        system_program: System::id(),
+    };
+
+    Instruction {
+        program_id: ntt.program(),
+        accounts: accounts.to_account_metas(None),
+        data: data.data(),
+    }
+}
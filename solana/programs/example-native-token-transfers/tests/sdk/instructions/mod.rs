@@ -1,4 +1,6 @@
 pub mod admin;
+pub mod close;
+pub mod escrow;
 pub mod initialize;
 pub mod post_vaa;
 pub mod redeem;
@@ -13,6 +13,7 @@ pub struct Redeem {
     pub mint: Pubkey,
     pub inbox_item: Pubkey,
     pub inbox_rate_limit: Pubkey,
+    pub outbox_chain_rate_limit: Pubkey,
 }
 
 pub fn redeem(ntt: &NTT, accs: Redeem, args: RedeemArgs) -> Instruction {
@@ -28,6 +29,7 @@ pub fn redeem(ntt: &NTT, accs: Redeem, args: RedeemArgs) -> Instruction {
         inbox_item: accs.inbox_item,
         inbox_rate_limit: accs.inbox_rate_limit,
         outbox_rate_limit: ntt.outbox_rate_limit(),
+        outbox_chain_rate_limit: accs.outbox_chain_rate_limit,
         system_program: System::id(),
     };
 
@@ -37,3 +39,28 @@ pub fn redeem(ntt: &NTT, accs: Redeem, args: RedeemArgs) -> Instruction {
         data: data.data(),
     }
 }
+
+#[derive(Debug, Clone)]
+pub struct ReevaluateInboxItem {
+    pub inbox_item: Pubkey,
+    pub source_chain: u16,
+}
+
+pub fn reevaluate_inbox_item(ntt: &NTT, accs: ReevaluateInboxItem) -> Instruction {
+    let data = example_native_token_transfers::instruction::ReevaluateInboxItem {};
+
+    let accounts = example_native_token_transfers::accounts::ReevaluateInboxItem {
+        config: ntt.config(),
+        peer: ntt.peer(accs.source_chain),
+        inbox_item: accs.inbox_item,
+        inbox_rate_limit: ntt.inbox_rate_limit(accs.source_chain),
+        outbox_rate_limit: ntt.outbox_rate_limit(),
+        outbox_chain_rate_limit: ntt.outbox_chain_rate_limit(accs.source_chain),
+    };
+
+    Instruction {
+        program_id: example_native_token_transfers::ID,
+        accounts: accounts.to_account_metas(None),
+        data: data.data(),
+    }
+}
@@ -1,6 +1,9 @@
 use anchor_lang::{prelude::*, InstructionData};
 use anchor_spl::token::Token;
-use example_native_token_transfers::{accounts::NotPausedConfig, instructions::ReleaseInboundArgs};
+use example_native_token_transfers::{
+    accounts::NotPausedConfig,
+    instructions::{ReleaseInboundAndCallArgs, ReleaseInboundArgs},
+};
 use solana_sdk::instruction::Instruction;
 
 use crate::sdk::accounts::NTT;
@@ -8,6 +11,7 @@ use crate::sdk::accounts::NTT;
 pub struct ReleaseInbound {
     pub payer: Pubkey,
     pub inbox_item: Pubkey,
+    pub peer: Pubkey,
     pub mint: Pubkey,
     pub recipient: Pubkey,
 }
@@ -18,23 +22,55 @@ pub fn release_inbound_unlock(
     args: ReleaseInboundArgs,
 ) -> Instruction {
     let data = example_native_token_transfers::instruction::ReleaseInboundUnlock { args };
-    let accounts = example_native_token_transfers::accounts::ReleaseInboundUnlock {
+    let accounts = release_inbound_unlock_accounts(ntt, release_inbound);
+    Instruction {
+        program_id: example_native_token_transfers::ID,
+        accounts: accounts.to_account_metas(None),
+        data: data.data(),
+    }
+}
+
+/// The accounts expected by the recipient program (besides the ones passed by
+/// the manager) have to be appended to the instruction's accounts, and counted
+/// in [`ReleaseInboundAndCallArgs::recipient_accounts`].
+pub fn release_inbound_unlock_and_call(
+    ntt: &NTT,
+    release_inbound: ReleaseInbound,
+    recipient_program: Pubkey,
+    args: ReleaseInboundAndCallArgs,
+) -> Instruction {
+    let data = example_native_token_transfers::instruction::ReleaseInboundUnlockAndCall { args };
+    let accounts = example_native_token_transfers::accounts::ReleaseInboundUnlockAndCall {
+        release: release_inbound_unlock_accounts(ntt, release_inbound),
+        call: example_native_token_transfers::accounts::RecipientCall {
+            call_authority: ntt.recipient_call_authority(),
+            recipient_program,
+        },
+    };
+    Instruction {
+        program_id: example_native_token_transfers::ID,
+        accounts: accounts.to_account_metas(None),
+        data: data.data(),
+    }
+}
+
+fn release_inbound_unlock_accounts(
+    ntt: &NTT,
+    release_inbound: ReleaseInbound,
+) -> example_native_token_transfers::accounts::ReleaseInboundUnlock {
+    example_native_token_transfers::accounts::ReleaseInboundUnlock {
         common: example_native_token_transfers::accounts::ReleaseInbound {
             payer: release_inbound.payer,
             config: NotPausedConfig {
                 config: ntt.config(),
             },
             inbox_item: release_inbound.inbox_item,
+            peer: release_inbound.peer,
             recipient: release_inbound.recipient,
             token_authority: ntt.token_authority(),
             mint: release_inbound.mint,
             token_program: Token::id(),
             custody: ntt.custody(&release_inbound.mint),
         },
-    };
-    Instruction {
-        program_id: example_native_token_transfers::ID,
-        accounts: accounts.to_account_metas(None),
-        data: data.data(),
     }
 }
@@ -1,6 +1,9 @@
 use anchor_lang::{prelude::Pubkey, system_program::System, Id, InstructionData, ToAccountMetas};
 use anchor_spl::{token::Token, token_2022::spl_token_2022};
-use example_native_token_transfers::{accounts::NotPausedConfig, instructions::TransferArgs};
+use example_native_token_transfers::{
+    accounts::NotPausedConfig,
+    instructions::{TransferArgs, TransferWithPayloadArgs},
+};
 use ntt_messages::mode::Mode;
 use solana_sdk::instruction::Instruction;
 
@@ -50,6 +53,7 @@ pub fn transfer_burn_with_token_program_id(
     let accounts = example_native_token_transfers::accounts::TransferBurn {
         common: common_with_token_program_id(ntt, &transfer, token_program_id),
         inbox_rate_limit: ntt.inbox_rate_limit(chain_id),
+        outbox_chain_rate_limit: ntt.outbox_chain_rate_limit(chain_id),
         peer: transfer.peer,
         session_authority,
         token_authority: ntt.token_authority(),
@@ -79,6 +83,7 @@ pub fn transfer_lock_with_token_program_id(
     let accounts = example_native_token_transfers::accounts::TransferLock {
         common: common_with_token_program_id(ntt, &transfer, token_program_id),
         inbox_rate_limit: ntt.inbox_rate_limit(chain_id),
+        outbox_chain_rate_limit: ntt.outbox_chain_rate_limit(chain_id),
         peer: transfer.peer,
         session_authority,
     };
@@ -89,6 +94,106 @@ pub fn transfer_lock_with_token_program_id(
     }
 }
 
+pub fn transfer_with_payload(
+    ntt: &NTT,
+    transfer: Transfer,
+    args: TransferWithPayloadArgs,
+    mode: Mode,
+) -> Instruction {
+    match mode {
+        Mode::Burning => transfer_burn_with_payload(ntt, transfer, args),
+        Mode::Locking => transfer_lock_with_payload(ntt, transfer, args),
+    }
+}
+
+pub fn transfer_burn_with_payload(
+    ntt: &NTT,
+    transfer: Transfer,
+    args: TransferWithPayloadArgs,
+) -> Instruction {
+    let chain_id = args.recipient_chain.id;
+    let session_authority = ntt.session_authority_with_payload(&transfer.from_authority, &args);
+    let data = example_native_token_transfers::instruction::TransferBurnWithPayload { args };
+
+    let accounts = example_native_token_transfers::accounts::TransferBurnWithPayload {
+        common: common_with_token_program_id(ntt, &transfer, &Token::id()),
+        inbox_rate_limit: ntt.inbox_rate_limit(chain_id),
+        outbox_chain_rate_limit: ntt.outbox_chain_rate_limit(chain_id),
+        peer: transfer.peer,
+        session_authority,
+        token_authority: ntt.token_authority(),
+    };
+
+    Instruction {
+        program_id: example_native_token_transfers::ID,
+        accounts: accounts.to_account_metas(None),
+        data: data.data(),
+    }
+}
+
+pub fn transfer_lock_with_payload(
+    ntt: &NTT,
+    transfer: Transfer,
+    args: TransferWithPayloadArgs,
+) -> Instruction {
+    let chain_id = args.recipient_chain.id;
+    let session_authority = ntt.session_authority_with_payload(&transfer.from_authority, &args);
+    let data = example_native_token_transfers::instruction::TransferLockWithPayload { args };
+
+    let accounts = example_native_token_transfers::accounts::TransferLockWithPayload {
+        common: common_with_token_program_id(ntt, &transfer, &Token::id()),
+        inbox_rate_limit: ntt.inbox_rate_limit(chain_id),
+        outbox_chain_rate_limit: ntt.outbox_chain_rate_limit(chain_id),
+        peer: transfer.peer,
+        session_authority,
+    };
+
+    Instruction {
+        program_id: example_native_token_transfers::ID,
+        accounts: accounts.to_account_metas(None),
+        data: data.data(),
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct CancelOutboundQueuedTransfer {
+    pub sender: Pubkey,
+    pub mint: Pubkey,
+    pub sender_token_account: Pubkey,
+    pub outbox_item: Pubkey,
+    pub recipient_chain: u16,
+}
+
+pub fn cancel_outbound_queued_transfer(
+    ntt: &NTT,
+    cancel: CancelOutboundQueuedTransfer,
+) -> Instruction {
+    let data = example_native_token_transfers::instruction::CancelOutboundQueuedTransfer {};
+
+    let accounts = example_native_token_transfers::accounts::CancelOutboundQueuedTransfer {
+        sender: cancel.sender,
+        config: NotPausedConfig {
+            config: ntt.config(),
+        },
+        outbox_item: cancel.outbox_item,
+        mint: cancel.mint,
+        sender_token_account: cancel.sender_token_account,
+        token_authority: ntt.token_authority(),
+        token_program: Token::id(),
+        custody: ntt.custody(&cancel.mint),
+        outbox_rate_limit: ntt.outbox_rate_limit(),
+        outbox_chain_rate_limit: ntt.outbox_chain_rate_limit(cancel.recipient_chain),
+        inbox_rate_limit: ntt.inbox_rate_limit(cancel.recipient_chain),
+        multisig_token_authority: None,
+    };
+
+    Instruction {
+        program_id: example_native_token_transfers::ID,
+        accounts: accounts.to_account_metas(None),
+        data: data.data(),
+    }
+}
+
 pub fn approve_token_authority(
     ntt: &NTT,
     user_token_account: &Pubkey,
@@ -116,6 +221,23 @@ pub fn approve_token_authority_with_token_program_id(
     .unwrap()
 }
 
+pub fn approve_token_authority_with_payload(
+    ntt: &NTT,
+    user_token_account: &Pubkey,
+    user: &Pubkey,
+    args: &TransferWithPayloadArgs,
+) -> Instruction {
+    spl_token_2022::instruction::approve(
+        &Token::id(),
+        user_token_account,
+        &ntt.session_authority_with_payload(user, args),
+        user,
+        &[user],
+        args.amount,
+    )
+    .unwrap()
+}
+
 fn common_with_token_program_id(
     ntt: &NTT,
     transfer: &Transfer,
@@ -129,6 +251,7 @@ fn common_with_token_program_id(
         mint: transfer.mint,
         from: transfer.from,
         token_program: *token_program_id,
+        outbox_sequence: ntt.outbox_sequence(),
         outbox_item: transfer.outbox_item,
         outbox_rate_limit: ntt.outbox_rate_limit(),
         system_program: System::id(),
@@ -0,0 +1,26 @@
+use anchor_lang::{prelude::Pubkey, InstructionData, ToAccountMetas};
+use solana_sdk::instruction::Instruction;
+
+use crate::sdk::accounts::NTT;
+
+pub struct CloseTransceiverMessage {
+    pub transceiver_message: Pubkey,
+    pub inbox_item: Pubkey,
+    pub rent_payer: Pubkey,
+}
+
+pub fn close_transceiver_message(ntt: &NTT, accs: CloseTransceiverMessage) -> Instruction {
+    let data = example_native_token_transfers::instruction::CloseWormholeTransceiverMessage {};
+
+    let accounts = example_native_token_transfers::accounts::CloseTransceiverMessage {
+        transceiver_message: accs.transceiver_message,
+        inbox_item: accs.inbox_item,
+        rent_payer: accs.rent_payer,
+    };
+
+    Instruction {
+        program_id: ntt.program(),
+        accounts: accounts.to_account_metas(None),
+        data: data.data(),
+    }
+}
@@ -1,5 +1,6 @@
 pub mod admin;
 pub mod broadcast_id;
 pub mod broadcast_peer;
+pub mod close_message;
 pub mod receive_message;
 pub mod release_outbound;
@@ -9,6 +9,7 @@ use crate::sdk::{accounts::NTT, transceivers::wormhole::accounts::wormhole::worm
 pub struct ReleaseOutbound {
     pub payer: Pubkey,
     pub outbox_item: Pubkey,
+    pub peer: Pubkey,
 }
 
 pub fn release_outbound(
@@ -23,6 +24,7 @@ pub fn release_outbound(
             config: ntt.config(),
         },
         outbox_item: release_outbound.outbox_item,
+        peer: release_outbound.peer,
         wormhole_message: ntt.wormhole_message(&release_outbound.outbox_item),
         emitter: ntt.emitter(),
         transceiver: ntt.registered_transceiver(&ntt.program()),
@@ -0,0 +1,416 @@
+#![cfg(feature = "test-sbf")]
+#![feature(type_changing_struct_update)]
+
+use anchor_lang::prelude::{Clock, ErrorCode};
+use example_native_token_transfers::{
+    config::{Config, Role},
+    error::NTTError,
+    instructions::{SetOutboundLimitArgs, SetOutboundRateLimitDurationArgs, SetPeerArgs},
+    peer::NttManagerPeer,
+    pending_change::{ConfigChange, PendingChange},
+    queue::{inbox::InboxRateLimit, outbox::OutboxRateLimit},
+};
+use ntt_messages::{chain_id::ChainId, mode::Mode};
+use solana_program_test::*;
+use solana_sdk::{
+    instruction::InstructionError, signature::Keypair, signer::Signer,
+    transaction::TransactionError,
+};
+
+use crate::{
+    common::{
+        query::GetAccountDataAnchor,
+        setup::{setup, TestData, ANOTHER_MANAGER, OTHER_CHAIN, OUTBOUND_LIMIT},
+        submit::Submittable,
+    },
+    sdk::accounts::{good_ntt, NTTAccounts},
+    sdk::instructions::admin::{
+        deregister_transceiver, execute_change, execute_deregister_transceiver,
+        execute_set_outbound_limit, execute_set_peer, grant_role, propose_change,
+        set_outbound_limit, set_outbound_rate_limit_duration, set_threshold, set_timelock_delay,
+        veto_change, DeregisterTransceiver, ExecuteChange, ExecuteDeregisterTransceiver,
+        ExecuteSetPeer, ProposeChange, SetOutboundLimit, SetRole, SetThreshold, SetTimelockDelay,
+        VetoChange,
+    },
+};
+
+pub mod common;
+pub mod sdk;
+
+const DELAY: i64 = 3600;
+
+fn custom_error(error: NTTError) -> TransactionError {
+    TransactionError::InstructionError(0, InstructionError::Custom(error.into()))
+}
+
+async fn enable_timelock(ctx: &mut ProgramTestContext, test_data: &TestData) {
+    set_timelock_delay(
+        &good_ntt,
+        SetTimelockDelay {
+            owner: test_data.program_owner.pubkey(),
+        },
+        DELAY,
+    )
+    .submit_with_signers(&[&test_data.program_owner], ctx)
+    .await
+    .unwrap();
+}
+
+async fn propose(ctx: &mut ProgramTestContext, test_data: &TestData, change: ConfigChange) {
+    propose_change(
+        &good_ntt,
+        ProposeChange {
+            payer: ctx.payer.pubkey(),
+            proposer: test_data.program_owner.pubkey(),
+        },
+        change,
+    )
+    .submit_with_signers(&[&test_data.program_owner], ctx)
+    .await
+    .unwrap();
+}
+
+async fn warp_forward(ctx: &mut ProgramTestContext, seconds: i64) {
+    let mut clock: Clock = ctx.banks_client.get_sysvar().await.unwrap();
+    clock.unix_timestamp += seconds;
+    ctx.set_sysvar(&clock);
+}
+
+#[tokio::test]
+async fn test_timelock_blocks_direct_changes() {
+    let (mut ctx, test_data) = setup(Mode::Locking).await;
+    enable_timelock(&mut ctx, &test_data).await;
+
+    let err = set_threshold(
+        &good_ntt,
+        SetThreshold {
+            owner: test_data.program_owner.pubkey(),
+        },
+        1,
+    )
+    .submit_with_signers(&[&test_data.program_owner], &mut ctx)
+    .await
+    .unwrap_err();
+    assert_eq!(err.unwrap(), custom_error(NTTError::TimelockRequired));
+
+    let err = grant_role(
+        &good_ntt,
+        SetRole {
+            owner: test_data.program_owner.pubkey(),
+        },
+        Role::Guardian,
+        Keypair::new().pubkey(),
+    )
+    .submit_with_signers(&[&test_data.program_owner], &mut ctx)
+    .await
+    .unwrap_err();
+    assert_eq!(err.unwrap(), custom_error(NTTError::TimelockRequired));
+
+    // raising the delay is instant, lowering it isn't
+    set_timelock_delay(
+        &good_ntt,
+        SetTimelockDelay {
+            owner: test_data.program_owner.pubkey(),
+        },
+        2 * DELAY,
+    )
+    .submit_with_signers(&[&test_data.program_owner], &mut ctx)
+    .await
+    .unwrap();
+
+    let err = set_timelock_delay(
+        &good_ntt,
+        SetTimelockDelay {
+            owner: test_data.program_owner.pubkey(),
+        },
+        0,
+    )
+    .submit_with_signers(&[&test_data.program_owner], &mut ctx)
+    .await
+    .unwrap_err();
+    assert_eq!(err.unwrap(), custom_error(NTTError::TimelockRequired));
+}
+
+#[tokio::test]
+async fn test_propose_and_execute_change() {
+    let (mut ctx, test_data) = setup(Mode::Locking).await;
+    enable_timelock(&mut ctx, &test_data).await;
+
+    let args = SetPeerArgs {
+        chain_id: ChainId { id: OTHER_CHAIN },
+        address: ANOTHER_MANAGER,
+        limit: 1234,
+        token_decimals: 8,
+    };
+    let change = ConfigChange::SetPeer(args.clone());
+    propose(&mut ctx, &test_data, change.clone()).await;
+
+    let pending_change: PendingChange = ctx
+        .get_account_data_anchor(good_ntt.pending_change(&change))
+        .await;
+    assert_eq!(pending_change.change, change);
+    assert_eq!(pending_change.rent_payer, ctx.payer.pubkey());
+
+    let execute = |args: SetPeerArgs, payer| {
+        execute_set_peer(
+            &good_ntt,
+            ExecuteSetPeer {
+                payer,
+                rent_payer: payer,
+            },
+            args,
+        )
+    };
+    let payer = ctx.payer.pubkey();
+
+    let err = execute(args.clone(), payer)
+        .submit(&mut ctx)
+        .await
+        .unwrap_err();
+    assert_eq!(err.unwrap(), custom_error(NTTError::TimelockNotExpired));
+
+    warp_forward(&mut ctx, DELAY).await;
+
+    // only the proposed change can be executed
+    let err = execute(
+        SetPeerArgs {
+            limit: u64::MAX,
+            ..args.clone()
+        },
+        payer,
+    )
+    .submit(&mut ctx)
+    .await
+    .unwrap_err();
+    assert_eq!(
+        err.unwrap(),
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(ErrorCode::AccountNotInitialized.into())
+        )
+    );
+
+    execute(args.clone(), payer).submit(&mut ctx).await.unwrap();
+
+    let peer: NttManagerPeer = ctx
+        .get_account_data_anchor(good_ntt.peer(OTHER_CHAIN))
+        .await;
+    assert_eq!(peer.address, ANOTHER_MANAGER);
+    assert_eq!(peer.token_decimals, 8);
+    let inbox_rate_limit: InboxRateLimit = ctx
+        .get_account_data_anchor(good_ntt.inbox_rate_limit(OTHER_CHAIN))
+        .await;
+    assert_eq!(inbox_rate_limit.rate_limit.limit, 1234);
+
+    // the pending change is closed, so it can't be executed twice
+    assert!(ctx
+        .banks_client
+        .get_account(good_ntt.pending_change(&change))
+        .await
+        .unwrap()
+        .is_none());
+
+    // lowering the delay goes through the timelock too
+    let change = ConfigChange::SetTimelockDelay { delay: 0 };
+    propose(&mut ctx, &test_data, change.clone()).await;
+    warp_forward(&mut ctx, DELAY).await;
+    execute_change(&good_ntt, ExecuteChange { rent_payer: payer }, &change)
+        .submit(&mut ctx)
+        .await
+        .unwrap();
+
+    let config: Config = ctx.get_account_data_anchor(good_ntt.config()).await;
+    assert_eq!(config.timelock_delay, 0);
+}
+
+#[tokio::test]
+async fn test_veto_change() {
+    let (mut ctx, test_data) = setup(Mode::Locking).await;
+    let guardian = Keypair::new();
+    grant_role(
+        &good_ntt,
+        SetRole {
+            owner: test_data.program_owner.pubkey(),
+        },
+        Role::Guardian,
+        guardian.pubkey(),
+    )
+    .submit_with_signers(&[&test_data.program_owner], &mut ctx)
+    .await
+    .unwrap();
+    enable_timelock(&mut ctx, &test_data).await;
+
+    let new_guardian = Keypair::new().pubkey();
+    let change = ConfigChange::SetGuardian {
+        guardian: Some(new_guardian),
+    };
+
+    // only the owner can propose changes to the guardian
+    let err = propose_change(
+        &good_ntt,
+        ProposeChange {
+            payer: ctx.payer.pubkey(),
+            proposer: guardian.pubkey(),
+        },
+        change.clone(),
+    )
+    .submit_with_signers(&[&guardian], &mut ctx)
+    .await
+    .unwrap_err();
+    assert_eq!(err.unwrap(), custom_error(NTTError::Unauthorized));
+
+    propose(&mut ctx, &test_data, change.clone()).await;
+
+    let impostor = Keypair::new();
+    let err = veto_change(
+        &good_ntt,
+        VetoChange {
+            authority: impostor.pubkey(),
+            rent_payer: ctx.payer.pubkey(),
+        },
+        &change,
+    )
+    .submit_with_signers(&[&impostor], &mut ctx)
+    .await
+    .unwrap_err();
+    assert_eq!(err.unwrap(), custom_error(NTTError::Unauthorized));
+
+    veto_change(
+        &good_ntt,
+        VetoChange {
+            authority: guardian.pubkey(),
+            rent_payer: ctx.payer.pubkey(),
+        },
+        &change,
+    )
+    .submit_with_signers(&[&guardian], &mut ctx)
+    .await
+    .unwrap();
+
+    warp_forward(&mut ctx, DELAY).await;
+    let err = execute_change(
+        &good_ntt,
+        ExecuteChange {
+            rent_payer: ctx.payer.pubkey(),
+        },
+        &change,
+    )
+    .submit(&mut ctx)
+    .await
+    .unwrap_err();
+    assert_eq!(
+        err.unwrap(),
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(ErrorCode::AccountNotInitialized.into())
+        )
+    );
+
+    let config: Config = ctx.get_account_data_anchor(good_ntt.config()).await;
+    assert_eq!(config.guardian, Some(guardian.pubkey()));
+}
+
+#[tokio::test]
+async fn test_timelock_deregister_transceiver() {
+    let (mut ctx, test_data) = setup(Mode::Locking).await;
+    enable_timelock(&mut ctx, &test_data).await;
+
+    let transceiver = example_native_token_transfers::ID;
+    let err = deregister_transceiver(
+        &good_ntt,
+        DeregisterTransceiver {
+            owner: test_data.program_owner.pubkey(),
+            transceiver,
+        },
+    )
+    .submit_with_signers(&[&test_data.program_owner], &mut ctx)
+    .await
+    .unwrap_err();
+    assert_eq!(err.unwrap(), custom_error(NTTError::TimelockRequired));
+
+    propose(
+        &mut ctx,
+        &test_data,
+        ConfigChange::DeregisterTransceiver { transceiver },
+    )
+    .await;
+    warp_forward(&mut ctx, DELAY).await;
+
+    let rent_payer = ctx.payer.pubkey();
+    execute_deregister_transceiver(
+        &good_ntt,
+        ExecuteDeregisterTransceiver {
+            rent_payer,
+            transceiver,
+        },
+    )
+    .submit(&mut ctx)
+    .await
+    .unwrap();
+
+    let config: Config = ctx.get_account_data_anchor(good_ntt.config()).await;
+    assert!(!config.enabled_transceivers.get(0).unwrap());
+}
+
+#[tokio::test]
+async fn test_timelock_rate_limits() {
+    let (mut ctx, test_data) = setup(Mode::Locking).await;
+    enable_timelock(&mut ctx, &test_data).await;
+
+    let set_limit = |limit| {
+        set_outbound_limit(
+            &good_ntt,
+            SetOutboundLimit {
+                owner: test_data.program_owner.pubkey(),
+            },
+            SetOutboundLimitArgs { limit },
+        )
+    };
+
+    // lowering the limit is instant, raising it isn't
+    set_limit(OUTBOUND_LIMIT / 2)
+        .submit_with_signers(&[&test_data.program_owner], &mut ctx)
+        .await
+        .unwrap();
+
+    let err = set_limit(OUTBOUND_LIMIT)
+        .submit_with_signers(&[&test_data.program_owner], &mut ctx)
+        .await
+        .unwrap_err();
+    assert_eq!(err.unwrap(), custom_error(NTTError::TimelockRequired));
+
+    // neither is shortening the window, which makes it refill faster
+    let err = set_outbound_rate_limit_duration(
+        &good_ntt,
+        SetOutboundLimit {
+            owner: test_data.program_owner.pubkey(),
+        },
+        SetOutboundRateLimitDurationArgs { duration: 1 },
+    )
+    .submit_with_signers(&[&test_data.program_owner], &mut ctx)
+    .await
+    .unwrap_err();
+    assert_eq!(err.unwrap(), custom_error(NTTError::TimelockRequired));
+
+    let args = SetOutboundLimitArgs {
+        limit: OUTBOUND_LIMIT,
+    };
+    propose(
+        &mut ctx,
+        &test_data,
+        ConfigChange::SetOutboundLimit(args.clone()),
+    )
+    .await;
+    warp_forward(&mut ctx, DELAY).await;
+
+    let rent_payer = ctx.payer.pubkey();
+    execute_set_outbound_limit(&good_ntt, ExecuteChange { rent_payer }, args)
+        .submit(&mut ctx)
+        .await
+        .unwrap();
+
+    let rate_limit: OutboxRateLimit = ctx
+        .get_account_data_anchor(good_ntt.outbox_rate_limit())
+        .await;
+    assert_eq!(rate_limit.rate_limit.limit, OUTBOUND_LIMIT);
+}
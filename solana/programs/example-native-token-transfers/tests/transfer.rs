@@ -1,34 +1,61 @@
 #![cfg(feature = "test-sbf")]
 #![feature(type_changing_struct_update)]
 
-use anchor_lang::prelude::{Clock, ErrorCode, Pubkey};
+use anchor_lang::{
+    prelude::{Clock, ErrorCode, Pubkey},
+    AnchorSerialize,
+};
 use anchor_spl::token::{Mint, TokenAccount};
 use common::setup::{TestData, OTHER_CHAIN};
 use example_native_token_transfers::{
     bitmap::Bitmap,
+    config::{Config, Role},
     error::NTTError,
-    instructions::{SetOutboundLimitArgs, TransferArgs},
-    queue::outbox::{OutboxItem, OutboxRateLimit},
+    events::{OutboundTransferQueued, OutboundTransferReleased, TransferSent},
+    instructions::{
+        MigrateArgs, PausePeerArgs, RemovePeerArgs, SetOutboundChainLimitArgs,
+        SetOutboundLimitArgs, SetOutboundRateLimitDurationArgs, SetPeerPausedArgs, TransferArgs,
+        TransferWithPayloadArgs,
+    },
+    peer::NttManagerPeer,
+    queue::{
+        outbox::{OutboxChainRateLimit, OutboxItem, OutboxRateLimit, OutboxSequence},
+        rate_limit::RateLimitState,
+    },
     transceivers::wormhole::ReleaseOutboundArgs,
-    transfer::Payload,
+    transfer::{amount_before_transfer_fee, Payload},
 };
 use ntt_messages::{
-    chain_id::ChainId, mode::Mode, ntt::NativeTokenTransfer, ntt_manager::NttManagerMessage,
-    transceiver::TransceiverMessage, transceivers::wormhole::WormholeTransceiver,
+    chain_id::ChainId,
+    mode::Mode,
+    ntt::{AdditionalPayload, NativeTokenTransfer, MAX_ADDITIONAL_PAYLOAD_LEN},
+    ntt_manager::NttManagerMessage,
+    transceiver::TransceiverMessage,
+    transceivers::wormhole::WormholeTransceiver,
     trimmed_amount::TrimmedAmount,
 };
 use sdk::accounts::NTT;
 use solana_program_test::*;
 use solana_sdk::{
-    instruction::InstructionError, signature::Keypair, signer::Signer,
+    instruction::{AccountMeta, Instruction, InstructionError},
+    signature::Keypair,
+    signer::Signer,
     transaction::TransactionError,
 };
+use spl_token_2022::extension::{
+    transfer_fee::TransferFeeConfig, BaseStateWithExtensions, StateWithExtensions,
+};
 use wormhole_anchor_sdk::wormhole::PostedVaa;
 
 use crate::{
     common::{
+        events::{decode_event, decode_events, submit_with_logs},
+        policy::{block, initialize_extra_account_meta_list, policy_accounts},
         query::GetAccountDataAnchor,
-        setup::{ANOTHER_CHAIN, OUTBOUND_LIMIT, UNREGISTERED_CHAIN},
+        setup::{
+            ANOTHER_CHAIN, DUMMY_NTT_POLICY, NTT_TRANSCEIVER, OUTBOUND_LIMIT, UNREGISTERED_CHAIN,
+        },
+        utils::next_outbox_item,
     },
     sdk::{
         accounts::{good_ntt, NTTAccounts},
@@ -39,10 +66,21 @@ use crate::{
     common::{setup::OTHER_MANAGER, submit::Submittable},
     sdk::{
         instructions::{
-            admin::{set_outbound_limit, set_paused, SetOutboundLimit, SetPaused},
+            admin::{
+                add_to_denylist, grant_role, migrate, pause, pause_inbound, pause_outbound,
+                pause_peer, register_transceiver, remove_from_denylist, remove_peer,
+                set_denylist_enabled, set_outbound_chain_limit, set_outbound_limit,
+                set_outbound_paused, set_outbound_rate_limit_duration, set_paused, set_peer_paused,
+                set_policy_program, set_precise_queue_release, set_strict_dust, AddToDenylist,
+                Migrate, Pause, RegisterTransceiver, RemoveFromDenylist, RemovePeer,
+                SetDenylistEnabled, SetOutboundLimit, SetPaused, SetPeerPaused, SetPolicyProgram,
+                SetPreciseQueueRelease, SetRole, SetStrictDust,
+            },
             transfer::{
-                approve_token_authority, approve_token_authority_with_token_program_id, transfer,
-                transfer_with_token_program_id,
+                approve_token_authority, approve_token_authority_with_payload,
+                approve_token_authority_with_token_program_id, cancel_outbound_queued_transfer,
+                transfer, transfer_with_payload, transfer_with_token_program_id,
+                CancelOutboundQueuedTransfer,
             },
         },
         transceivers::wormhole::instructions::release_outbound::{
@@ -105,7 +143,7 @@ pub async fn test_transfer_locking_with_transfer_fee() {
         &mut ctx,
         &test_data,
         Mode::Locking,
-        NTTError::BadAmountAfterTransfer.into(),
+        NTTError::AmountAfterFeeHasDust.into(),
     )
     .await;
 }
@@ -117,19 +155,31 @@ pub async fn test_transfer_burning_with_transfer_fee() {
         &mut ctx,
         &test_data,
         Mode::Burning,
-        spl_token::error::TokenError::InsufficientFunds as u32,
+        NTTError::AmountAfterFeeHasDust.into(),
     )
     .await;
 }
 
+#[tokio::test]
+pub async fn test_transfer_locking_with_transfer_fee_amount() {
+    let (mut ctx, test_data) = setup_with_transfer_fee(Mode::Locking).await;
+    test_transfer_with_transfer_fee_amount(&mut ctx, &test_data, Mode::Locking).await;
+}
+
+#[tokio::test]
+pub async fn test_transfer_burning_with_transfer_fee_amount() {
+    let (mut ctx, test_data) = setup_with_transfer_fee(Mode::Burning).await;
+    test_transfer_with_transfer_fee_amount(&mut ctx, &test_data, Mode::Burning).await;
+}
+
 /// This tests the happy path of a transfer, with all the relevant account checks.
 /// Written as a helper function so both modes can be tested.
 async fn test_transfer(ctx: &mut ProgramTestContext, test_data: &TestData, mode: Mode) {
-    let outbox_item = Keypair::new();
+    let outbox_item = next_outbox_item(&good_ntt, ctx).await;
 
     let clock: Clock = ctx.banks_client.get_sysvar().await.unwrap();
 
-    let (accs, args) = init_accs_args(&good_ntt, ctx, test_data, outbox_item.pubkey(), 154, false);
+    let (accs, args) = init_accs_args(&good_ntt, ctx, test_data, outbox_item, 154, false);
 
     approve_token_authority(
         &good_ntt,
@@ -141,11 +191,11 @@ async fn test_transfer(ctx: &mut ProgramTestContext, test_data: &TestData, mode:
     .await
     .unwrap();
     transfer(&good_ntt, accs, args, mode)
-        .submit_with_signers(&[&outbox_item], ctx)
+        .submit(ctx)
         .await
         .unwrap();
 
-    let outbox_item_account: OutboxItem = ctx.get_account_data_anchor(outbox_item.pubkey()).await;
+    let outbox_item_account: OutboxItem = ctx.get_account_data_anchor(outbox_item).await;
 
     assert_eq!(
         outbox_item_account,
@@ -160,6 +210,13 @@ async fn test_transfer(ctx: &mut ProgramTestContext, test_data: &TestData, mode:
             recipient_address: [1u8; 32],
             release_timestamp: clock.unix_timestamp,
             released: Bitmap::new(),
+            queued: false,
+            reserved: false,
+            cancelled: false,
+            rent_payer: ctx.payer.pubkey(),
+            additional_payload: Payload::default(),
+            sequence: 0,
+            version: OutboxItem::LAYOUT_VERSION,
         }
     );
 
@@ -167,7 +224,8 @@ async fn test_transfer(ctx: &mut ProgramTestContext, test_data: &TestData, mode:
         &good_ntt,
         ReleaseOutbound {
             payer: ctx.payer.pubkey(),
-            outbox_item: outbox_item.pubkey(),
+            outbox_item,
+            peer: good_ntt.peer(OTHER_CHAIN),
         },
         ReleaseOutboundArgs {
             revert_on_delay: true,
@@ -177,8 +235,7 @@ async fn test_transfer(ctx: &mut ProgramTestContext, test_data: &TestData, mode:
     .await
     .unwrap();
 
-    let outbox_item_account_after: OutboxItem =
-        ctx.get_account_data_anchor(outbox_item.pubkey()).await;
+    let outbox_item_account_after: OutboxItem = ctx.get_account_data_anchor(outbox_item).await;
 
     // make sure the outbox item is now released, but nothing else has changed
     assert_eq!(
@@ -189,7 +246,7 @@ async fn test_transfer(ctx: &mut ProgramTestContext, test_data: &TestData, mode:
         outbox_item_account_after,
     );
 
-    let wh_message = good_ntt.wormhole_message(&outbox_item.pubkey());
+    let wh_message = good_ntt.wormhole_message(&outbox_item);
 
     // NOTE: technically this is not a PostedVAA but a PostedMessage, but the
     // sdk does not export that type, so we parse it as a PostedVAA instead.
@@ -207,7 +264,7 @@ async fn test_transfer(ctx: &mut ProgramTestContext, test_data: &TestData, mode:
             example_native_token_transfers::ID.to_bytes(),
             OTHER_MANAGER,
             NttManagerMessage {
-                id: outbox_item.pubkey().to_bytes(),
+                id: outbox_item.to_bytes(),
                 sender: test_data.user.pubkey().to_bytes(),
                 payload: NativeTokenTransfer {
                     amount: TrimmedAmount {
@@ -217,7 +274,7 @@ async fn test_transfer(ctx: &mut ProgramTestContext, test_data: &TestData, mode:
                     source_token: test_data.mint.to_bytes(),
                     to: [1u8; 32],
                     to_chain: ChainId { id: 2 },
-                    additional_payload: Payload {}
+                    additional_payload: Payload::default()
                 }
             },
             vec![]
@@ -225,15 +282,208 @@ async fn test_transfer(ctx: &mut ProgramTestContext, test_data: &TestData, mode:
     );
 }
 
+#[tokio::test]
+async fn test_transfer_events() {
+    let (mut ctx, test_data) = setup(Mode::Locking).await;
+
+    let outbox_item = next_outbox_item(&good_ntt, &mut ctx).await;
+    let clock: Clock = ctx.banks_client.get_sysvar().await.unwrap();
+    let (accs, args) = init_accs_args(&good_ntt, &mut ctx, &test_data, outbox_item, 154, false);
+
+    approve_token_authority(
+        &good_ntt,
+        &test_data.user_token_account,
+        &test_data.user.pubkey(),
+        &args,
+    )
+    .submit_with_signers(&[&test_data.user], &mut ctx)
+    .await
+    .unwrap();
+    let no_signers: &[&Keypair] = &[];
+    let logs = submit_with_logs(
+        transfer(&good_ntt, accs, args, Mode::Locking),
+        no_signers,
+        &mut ctx,
+    )
+    .await
+    .unwrap();
+
+    let sent: TransferSent = decode_event(&logs);
+    assert_eq!(sent.outbox_item, outbox_item);
+    assert_eq!(sent.sender, test_data.user.pubkey());
+    assert_eq!(
+        sent.amount,
+        TrimmedAmount {
+            amount: 1,
+            decimals: 7
+        }
+    );
+    assert_eq!(sent.recipient_chain, ChainId { id: OTHER_CHAIN });
+    assert_eq!(sent.recipient_ntt_manager, OTHER_MANAGER);
+    assert_eq!(sent.recipient_address, [1u8; 32]);
+    assert_eq!(sent.release_timestamp, clock.unix_timestamp);
+    // not rate limited, so not queued
+    assert!(decode_events::<OutboundTransferQueued>(&logs).is_empty());
+
+    let logs = submit_with_logs(
+        release_outbound(
+            &good_ntt,
+            ReleaseOutbound {
+                payer: ctx.payer.pubkey(),
+                outbox_item,
+                peer: good_ntt.peer(OTHER_CHAIN),
+            },
+            ReleaseOutboundArgs {
+                revert_on_delay: true,
+            },
+        ),
+        no_signers,
+        &mut ctx,
+    )
+    .await
+    .unwrap();
+
+    let released: OutboundTransferReleased = decode_event(&logs);
+    assert_eq!(released.outbox_item, outbox_item);
+    assert_eq!(released.transceiver, good_ntt.program());
+    assert_eq!(released.transceiver_index, 0);
+
+    // now a transfer that exceeds the outbound limit, and gets queued
+    let outbox_item = next_outbox_item(&good_ntt, &mut ctx).await;
+    let (accs, args) = init_accs_args(
+        &good_ntt,
+        &mut ctx,
+        &test_data,
+        outbox_item,
+        OUTBOUND_LIMIT + 1000,
+        true,
+    );
+
+    approve_token_authority(
+        &good_ntt,
+        &test_data.user_token_account,
+        &test_data.user.pubkey(),
+        &args,
+    )
+    .submit_with_signers(&[&test_data.user], &mut ctx)
+    .await
+    .unwrap();
+    let logs = submit_with_logs(
+        transfer(&good_ntt, accs, args, Mode::Locking),
+        no_signers,
+        &mut ctx,
+    )
+    .await
+    .unwrap();
+
+    let sent: TransferSent = decode_event(&logs);
+    let queued: OutboundTransferQueued = decode_event(&logs);
+    assert_eq!(queued.outbox_item, outbox_item);
+    assert!(queued.release_timestamp > clock.unix_timestamp);
+    assert_eq!(queued.release_timestamp, sent.release_timestamp);
+}
+
+async fn get_mint_2022(
+    ctx: &mut ProgramTestContext,
+    mint: Pubkey,
+) -> (spl_token_2022::state::Mint, TransferFeeConfig) {
+    let account = ctx.banks_client.get_account(mint).await.unwrap().unwrap();
+    let mint = StateWithExtensions::<spl_token_2022::state::Mint>::unpack(&account.data).unwrap();
+    (
+        mint.base,
+        *mint.get_extension::<TransferFeeConfig>().unwrap(),
+    )
+}
+
+async fn get_token_account_2022_amount(ctx: &mut ProgramTestContext, token_account: Pubkey) -> u64 {
+    let account = ctx
+        .banks_client
+        .get_account(token_account)
+        .await
+        .unwrap()
+        .unwrap();
+    StateWithExtensions::<spl_token_2022::state::Account>::unpack(&account.data)
+        .unwrap()
+        .base
+        .amount
+}
+
+/// The amount after fees determines the dust, so the amount before fees
+/// (computed with [`amount_before_transfer_fee`]) doesn't need to trim cleanly.
+async fn test_transfer_with_transfer_fee_amount(
+    ctx: &mut ProgramTestContext,
+    test_data: &TestData,
+    mode: Mode,
+) {
+    let custody = good_ntt.custody_with_token_program_id(&test_data.mint, &spl_token_2022::id());
+
+    let clock: Clock = ctx.banks_client.get_sysvar().await.unwrap();
+    let (mint_before, transfer_fee_config) = get_mint_2022(ctx, test_data.mint).await;
+    let (amount, trimmed_amount) = amount_before_transfer_fee(
+        &transfer_fee_config,
+        clock.epoch,
+        10_000,
+        mint_before.decimals,
+        7,
+    )
+    .unwrap();
+    // 5% fee, rounded up
+    assert_eq!(amount, 10_527);
+    assert_eq!(trimmed_amount, TrimmedAmount::new(100, 7));
+
+    let user_before = get_token_account_2022_amount(ctx, test_data.user_token_account).await;
+    let custody_before = get_token_account_2022_amount(ctx, custody).await;
+
+    let outbox_item = next_outbox_item(&good_ntt, ctx).await;
+    let (accs, args) = init_accs_args(&good_ntt, ctx, test_data, outbox_item, amount, false);
+
+    approve_token_authority_with_token_program_id(
+        &good_ntt,
+        &test_data.user_token_account,
+        &test_data.user.pubkey(),
+        &args,
+        &spl_token_2022::id(),
+    )
+    .submit_with_signers(&[&test_data.user], ctx)
+    .await
+    .unwrap();
+    transfer_with_token_program_id(&good_ntt, accs, args, mode, &spl_token_2022::id())
+        .submit(ctx)
+        .await
+        .unwrap();
+
+    let outbox_item_account: OutboxItem = ctx.get_account_data_anchor(outbox_item).await;
+    assert_eq!(outbox_item_account.amount, trimmed_amount);
+
+    let user_after = get_token_account_2022_amount(ctx, test_data.user_token_account).await;
+    let custody_after = get_token_account_2022_amount(ctx, custody).await;
+    let (mint_after, _) = get_mint_2022(ctx, test_data.mint).await;
+
+    assert_eq!(user_before - amount, user_after);
+    match mode {
+        // only what arrived after the fee is locked
+        Mode::Locking => {
+            assert_eq!(custody_before + 10_000, custody_after);
+            assert_eq!(mint_before.supply, mint_after.supply);
+        }
+        // only what arrived after the fee is burned
+        Mode::Burning => {
+            assert_eq!(custody_before, custody_after);
+            assert_eq!(mint_before.supply - 10_000, mint_after.supply);
+        }
+    }
+}
+
 async fn test_transfer_with_transfer_fee(
     ctx: &mut ProgramTestContext,
     test_data: &TestData,
     mode: Mode,
     error_code: u32,
 ) {
-    let outbox_item = Keypair::new();
+    let outbox_item = next_outbox_item(&good_ntt, ctx).await;
 
-    let (accs, args) = init_accs_args(&good_ntt, ctx, test_data, outbox_item.pubkey(), 154, false);
+    // 154 - 8 (fee) = 146, which has dust
+    let (accs, args) = init_accs_args(&good_ntt, ctx, test_data, outbox_item, 154, false);
 
     approve_token_authority_with_token_program_id(
         &good_ntt,
@@ -246,7 +496,7 @@ async fn test_transfer_with_transfer_fee(
     .await
     .unwrap();
     let err = transfer_with_token_program_id(&good_ntt, accs, args, mode, &spl_token_2022::id())
-        .submit_with_signers(&[&outbox_item], ctx)
+        .submit(ctx)
         .await
         .unwrap_err();
     assert_eq!(
@@ -259,16 +509,9 @@ async fn test_transfer_with_transfer_fee(
 async fn test_burn_mode_burns_tokens() {
     let (mut ctx, test_data) = setup(Mode::Burning).await;
 
-    let outbox_item = Keypair::new();
+    let outbox_item = next_outbox_item(&good_ntt, &mut ctx).await;
 
-    let (accs, args) = init_accs_args(
-        &good_ntt,
-        &mut ctx,
-        &test_data,
-        outbox_item.pubkey(),
-        105,
-        false,
-    );
+    let (accs, args) = init_accs_args(&good_ntt, &mut ctx, &test_data, outbox_item, 105, false);
 
     let mint_before: Mint = ctx.get_account_data_anchor(test_data.mint).await;
 
@@ -286,7 +529,7 @@ async fn test_burn_mode_burns_tokens() {
     .await
     .unwrap();
     transfer(&good_ntt, accs, args, Mode::Burning)
-        .submit_with_signers(&[&outbox_item], &mut ctx)
+        .submit(&mut ctx)
         .await
         .unwrap();
 
@@ -298,7 +541,7 @@ async fn test_burn_mode_burns_tokens() {
 
     // NOTE: we transfer 105, but only 100 gets burned (token is 9 decimals, and
     // gets trimmed to 8)
-    // TODO: should we just revert if there's dust?
+    // See [`test_strict_dust`] for reverting instead
     assert_eq!(mint_before.supply - 100, mint_after.supply);
     assert_eq!(
         token_account_before.amount - 100,
@@ -310,16 +553,9 @@ async fn test_burn_mode_burns_tokens() {
 async fn locking_mode_locks_tokens() {
     let (mut ctx, test_data) = setup(Mode::Locking).await;
 
-    let outbox_item = Keypair::new();
+    let outbox_item = next_outbox_item(&good_ntt, &mut ctx).await;
 
-    let (accs, args) = init_accs_args(
-        &good_ntt,
-        &mut ctx,
-        &test_data,
-        outbox_item.pubkey(),
-        1050,
-        false,
-    );
+    let (accs, args) = init_accs_args(&good_ntt, &mut ctx, &test_data, outbox_item, 1050, false);
 
     let token_account_before: TokenAccount = ctx
         .get_account_data_anchor(test_data.user_token_account)
@@ -341,7 +577,7 @@ async fn locking_mode_locks_tokens() {
     .await
     .unwrap();
     transfer(&good_ntt, accs, args, Mode::Locking)
-        .submit_with_signers(&[&outbox_item], &mut ctx)
+        .submit(&mut ctx)
         .await
         .unwrap();
 
@@ -375,16 +611,10 @@ async fn locking_mode_locks_tokens() {
 async fn test_bad_mint() {
     let (mut ctx, test_data) = setup(Mode::Locking).await;
 
-    let outbox_item = Keypair::new();
+    let outbox_item = next_outbox_item(&good_ntt, &mut ctx).await;
 
-    let (mut accs, args) = init_accs_args(
-        &good_ntt,
-        &mut ctx,
-        &test_data,
-        outbox_item.pubkey(),
-        1050,
-        false,
-    );
+    let (mut accs, args) =
+        init_accs_args(&good_ntt, &mut ctx, &test_data, outbox_item, 1050, false);
 
     approve_token_authority(
         &good_ntt,
@@ -411,10 +641,7 @@ async fn test_bad_mint() {
         }
     });
 
-    let err = instruction
-        .submit_with_signers(&[&outbox_item], &mut ctx)
-        .await
-        .unwrap_err();
+    let err = instruction.submit(&mut ctx).await.unwrap_err();
 
     assert_eq!(
         err.unwrap(),
@@ -440,16 +667,9 @@ async fn test_invalid_peer() {
 
     let (mut ctx, test_data) = setup(Mode::Locking).await;
 
-    let outbox_item = Keypair::new();
+    let outbox_item = next_outbox_item(&good_ntt, &mut ctx).await;
 
-    let (accs, args) = init_accs_args(
-        &BadNTT {},
-        &mut ctx,
-        &test_data,
-        outbox_item.pubkey(),
-        1050,
-        false,
-    );
+    let (accs, args) = init_accs_args(&BadNTT {}, &mut ctx, &test_data, outbox_item, 1050, false);
 
     approve_token_authority(
         &good_ntt,
@@ -462,7 +682,7 @@ async fn test_invalid_peer() {
     .unwrap();
 
     let err = transfer(&BadNTT {}, accs, args, Mode::Locking)
-        .submit_with_signers(&[&outbox_item], &mut ctx)
+        .submit(&mut ctx)
         .await
         .unwrap_err();
 
@@ -489,16 +709,9 @@ async fn test_unregistered_peer_cant_transfer() {
 
     let (mut ctx, test_data) = setup(Mode::Locking).await;
 
-    let outbox_item = Keypair::new();
+    let outbox_item = next_outbox_item(&good_ntt, &mut ctx).await;
 
-    let (accs, args) = init_accs_args(
-        &BadNTT {},
-        &mut ctx,
-        &test_data,
-        outbox_item.pubkey(),
-        1050,
-        false,
-    );
+    let (accs, args) = init_accs_args(&BadNTT {}, &mut ctx, &test_data, outbox_item, 1050, false);
 
     approve_token_authority(
         &good_ntt,
@@ -511,7 +724,7 @@ async fn test_unregistered_peer_cant_transfer() {
     .unwrap();
 
     let err = transfer(&BadNTT {}, accs, args, Mode::Locking)
-        .submit_with_signers(&[&outbox_item], &mut ctx)
+        .submit(&mut ctx)
         .await
         .unwrap_err();
 
@@ -529,16 +742,9 @@ async fn test_unregistered_peer_cant_transfer() {
 async fn test_cant_transfer_to_unregistered_peer() {
     let (mut ctx, test_data) = setup(Mode::Locking).await;
 
-    let outbox_item = Keypair::new();
+    let outbox_item = next_outbox_item(&good_ntt, &mut ctx).await;
 
-    let (accs, args) = init_accs_args(
-        &good_ntt,
-        &mut ctx,
-        &test_data,
-        outbox_item.pubkey(),
-        1050,
-        false,
-    );
+    let (accs, args) = init_accs_args(&good_ntt, &mut ctx, &test_data, outbox_item, 1050, false);
 
     // use unregistered peer chain id here
     let bad_args = TransferArgs {
@@ -559,7 +765,7 @@ async fn test_cant_transfer_to_unregistered_peer() {
     .unwrap();
 
     let err = transfer(&good_ntt, accs, bad_args, Mode::Locking)
-        .submit_with_signers(&[&outbox_item], &mut ctx)
+        .submit(&mut ctx)
         .await
         .unwrap_err();
 
@@ -577,17 +783,10 @@ async fn test_cant_transfer_to_unregistered_peer() {
 async fn test_rate_limit() {
     let (mut ctx, test_data) = setup(Mode::Locking).await;
 
-    let outbox_item = Keypair::new();
+    let outbox_item = next_outbox_item(&good_ntt, &mut ctx).await;
     let clock: Clock = ctx.banks_client.get_sysvar().await.unwrap();
 
-    let (accs, args) = init_accs_args(
-        &good_ntt,
-        &mut ctx,
-        &test_data,
-        outbox_item.pubkey(),
-        100,
-        false,
-    );
+    let (accs, args) = init_accs_args(&good_ntt, &mut ctx, &test_data, outbox_item, 100, false);
 
     let outbound_limit_before: OutboxRateLimit = ctx
         .get_account_data_anchor(good_ntt.outbox_rate_limit())
@@ -603,7 +802,7 @@ async fn test_rate_limit() {
     .await
     .unwrap();
     transfer(&good_ntt, accs, args, Mode::Locking)
-        .submit_with_signers(&[&outbox_item], &mut ctx)
+        .submit(&mut ctx)
         .await
         .unwrap();
 
@@ -618,19 +817,27 @@ async fn test_rate_limit() {
 }
 
 #[tokio::test]
-async fn test_transfer_wrong_mode() {
+async fn test_strict_dust() {
     let (mut ctx, test_data) = setup(Mode::Burning).await;
-    let outbox_item = Keypair::new();
 
-    let (accs, args) = init_accs_args(
+    set_strict_dust(
         &good_ntt,
-        &mut ctx,
-        &test_data,
-        outbox_item.pubkey(),
-        100,
-        false,
-    );
+        SetStrictDust {
+            owner: test_data.program_owner.pubkey(),
+        },
+        true,
+    )
+    .submit_with_signers(&[&test_data.program_owner], &mut ctx)
+    .await
+    .unwrap();
+
+    let token_account_before: TokenAccount = ctx
+        .get_account_data_anchor(test_data.user_token_account)
+        .await;
 
+    // 105 has dust, so the transfer is rejected instead of being trimmed to 100
+    let outbox_item = next_outbox_item(&good_ntt, &mut ctx).await;
+    let (accs, args) = init_accs_args(&good_ntt, &mut ctx, &test_data, outbox_item, 105, false);
     approve_token_authority(
         &good_ntt,
         &test_data.user_token_account,
@@ -640,56 +847,74 @@ async fn test_transfer_wrong_mode() {
     .submit_with_signers(&[&test_data.user], &mut ctx)
     .await
     .unwrap();
-    // make sure we can't transfer in the wrong mode
-    let err = transfer(&good_ntt, accs.clone(), args.clone(), Mode::Locking)
-        .submit_with_signers(&[&outbox_item], &mut ctx)
+    let err = transfer(&good_ntt, accs, args, Mode::Burning)
+        .submit(&mut ctx)
         .await
         .unwrap_err();
-
     assert_eq!(
         err.unwrap(),
         TransactionError::InstructionError(
             0,
-            InstructionError::Custom(NTTError::InvalidMode.into())
+            InstructionError::Custom(NTTError::TransferAmountHasDust.into())
         )
     );
-}
-
-#[tokio::test]
-async fn test_cant_transfer_more_than_balance() {
-    let (mut ctx, test_data) = setup(Mode::Locking).await;
 
-    let outbox_item = Keypair::new();
+    // amounts without dust are unaffected
+    let outbox_item = next_outbox_item(&good_ntt, &mut ctx).await;
+    let (accs, args) = init_accs_args(&good_ntt, &mut ctx, &test_data, outbox_item, 100, false);
+    approve_token_authority(
+        &good_ntt,
+        &test_data.user_token_account,
+        &test_data.user.pubkey(),
+        &args,
+    )
+    .submit_with_signers(&[&test_data.user], &mut ctx)
+    .await
+    .unwrap();
+    transfer(&good_ntt, accs, args, Mode::Burning)
+        .submit(&mut ctx)
+        .await
+        .unwrap();
 
-    let token_account: TokenAccount = ctx
+    let token_account_after: TokenAccount = ctx
         .get_account_data_anchor(test_data.user_token_account)
         .await;
-    let more_than_balance = token_account.amount.checked_add(100).unwrap();
+    assert_eq!(
+        token_account_before.amount - 100,
+        token_account_after.amount
+    );
+}
 
-    // extend outbound limit to avoid TransferExceedsRateLimit error
-    set_outbound_limit(
+#[tokio::test]
+async fn test_denylist() {
+    let (mut ctx, test_data) = setup(Mode::Locking).await;
+    let compliance_admin = Keypair::new();
+    grant_role(
         &good_ntt,
-        SetOutboundLimit {
+        SetRole {
             owner: test_data.program_owner.pubkey(),
         },
-        SetOutboundLimitArgs {
-            limit: more_than_balance,
-        },
+        Role::ComplianceAdmin,
+        compliance_admin.pubkey(),
     )
     .submit_with_signers(&[&test_data.program_owner], &mut ctx)
     .await
     .unwrap();
-
-    // try to transfer more than balance
-    let (accs, args) = init_accs_args(
+    set_denylist_enabled(
         &good_ntt,
-        &mut ctx,
-        &test_data,
-        outbox_item.pubkey(),
-        more_than_balance,
-        false,
-    );
+        SetDenylistEnabled {
+            owner: test_data.program_owner.pubkey(),
+        },
+        true,
+    )
+    .submit_with_signers(&[&test_data.program_owner], &mut ctx)
+    .await
+    .unwrap();
 
+    let sender = test_data.user.pubkey().to_bytes();
+    let recipient = [1u8; 32];
+    let outbox_item = next_outbox_item(&good_ntt, &mut ctx).await;
+    let (accs, args) = init_accs_args(&good_ntt, &mut ctx, &test_data, outbox_item, 100, false);
     approve_token_authority(
         &good_ntt,
         &test_data.user_token_account,
@@ -699,92 +924,1534 @@ async fn test_cant_transfer_more_than_balance() {
     .submit_with_signers(&[&test_data.user], &mut ctx)
     .await
     .unwrap();
-    let err = transfer(&good_ntt, accs, args, Mode::Locking)
-        .submit_with_signers(&[&outbox_item], &mut ctx)
-        .await
-        .unwrap_err();
 
-    assert_eq!(
-        err.unwrap(),
-        TransactionError::InstructionError(
-            0,
-            InstructionError::Custom(spl_token::error::TokenError::InsufficientFunds as u32,)
-        )
-    );
-}
+    let transfer_ix = |with_entries: bool| {
+        let mut ix = transfer(&good_ntt, accs.clone(), args.clone(), Mode::Locking);
+        if with_entries {
+            for address in [sender, recipient] {
+                ix.accounts.push(AccountMeta::new_readonly(
+                    good_ntt.denylist_entry(&address),
+                    false,
+                ));
+            }
+        }
+        ix
+    };
+    let custom_error = |error: NTTError| {
+        TransactionError::InstructionError(0, InstructionError::Custom(error.into()))
+    };
 
-async fn assert_queued(ctx: &mut ProgramTestContext, outbox_item: Pubkey) {
-    let outbox_item_account: OutboxItem = ctx.get_account_data_anchor(outbox_item).await;
+    // the entries have to be passed, even if they don't exist
+    let err = transfer_ix(false).submit(&mut ctx).await.unwrap_err();
+    assert_eq!(err.unwrap(), custom_error(NTTError::MissingDenylistEntry));
 
-    let clock: Clock = ctx.banks_client.get_sysvar().await.unwrap();
+    add_to_denylist(
+        &good_ntt,
+        AddToDenylist {
+            payer: ctx.payer.pubkey(),
+            authority: compliance_admin.pubkey(),
+        },
+        recipient,
+    )
+    .submit_with_signers(&[&compliance_admin], &mut ctx)
+    .await
+    .unwrap();
 
-    assert!(!outbox_item_account.released.get(0).unwrap());
-    assert!(outbox_item_account.release_timestamp > clock.unix_timestamp);
+    let err = transfer_ix(true).submit(&mut ctx).await.unwrap_err();
+    assert_eq!(err.unwrap(), custom_error(NTTError::TransferDenied));
+
+    remove_from_denylist(
+        &good_ntt,
+        RemoveFromDenylist {
+            authority: compliance_admin.pubkey(),
+            rent_payer: ctx.payer.pubkey(),
+        },
+        recipient,
+    )
+    .submit_with_signers(&[&compliance_admin], &mut ctx)
+    .await
+    .unwrap();
+
+    ctx.get_new_latest_blockhash().await.unwrap();
+    transfer_ix(true).submit(&mut ctx).await.unwrap();
 }
 
 #[tokio::test]
-async fn test_large_tx_queue() {
+async fn test_policy_program() {
     let (mut ctx, test_data) = setup(Mode::Locking).await;
-
-    let outbox_item = Keypair::new();
-
-    let too_much = OUTBOUND_LIMIT + 1000;
+    initialize_extra_account_meta_list(ctx.payer.pubkey(), test_data.mint)
+        .submit(&mut ctx)
+        .await
+        .unwrap();
+    let set_policy_program_ix = |policy_program| {
+        set_policy_program(
+            &good_ntt,
+            SetPolicyProgram {
+                owner: test_data.program_owner.pubkey(),
+            },
+            policy_program,
+        )
+    };
+    set_policy_program_ix(Some(DUMMY_NTT_POLICY))
+        .submit_with_signers(&[&test_data.program_owner], &mut ctx)
+        .await
+        .unwrap();
+
+    let sender = test_data.user.pubkey().to_bytes();
+    let recipient = [1u8; 32];
+    let outbox_item = next_outbox_item(&good_ntt, &mut ctx).await;
+    let (accs, args) = init_accs_args(&good_ntt, &mut ctx, &test_data, outbox_item, 100, false);
+    approve_token_authority(
+        &good_ntt,
+        &test_data.user_token_account,
+        &test_data.user.pubkey(),
+        &args,
+    )
+    .submit_with_signers(&[&test_data.user], &mut ctx)
+    .await
+    .unwrap();
+
+    let transfer_ix = |with_policy_accounts: bool| {
+        let mut ix = transfer(&good_ntt, accs.clone(), args.clone(), Mode::Locking);
+        if with_policy_accounts {
+            ix.accounts
+                .extend(policy_accounts(test_data.mint, sender, recipient));
+        }
+        ix
+    };
+    let custom_error = |error: NTTError| {
+        TransactionError::InstructionError(0, InstructionError::Custom(error.into()))
+    };
+
+    // the policy program and its accounts have to be passed
+    let err = transfer_ix(false).submit(&mut ctx).await.unwrap_err();
+    assert_eq!(err.unwrap(), custom_error(NTTError::MissingPolicyAccount));
+
+    block(ctx.payer.pubkey(), recipient)
+        .submit(&mut ctx)
+        .await
+        .unwrap();
+
+    let err = transfer_ix(true).submit(&mut ctx).await.unwrap_err();
+    assert_eq!(err.unwrap(), custom_error(NTTError::TransferDenied));
+
+    set_policy_program_ix(None)
+        .submit_with_signers(&[&test_data.program_owner], &mut ctx)
+        .await
+        .unwrap();
+
+    transfer_ix(false).submit(&mut ctx).await.unwrap();
+}
+
+#[tokio::test]
+async fn test_outbox_item_sequence() {
+    let (mut ctx, test_data) = setup(Mode::Locking).await;
+
+    for sequence in 0..2 {
+        let outbox_item = good_ntt.outbox_item(sequence);
+        assert_eq!(next_outbox_item(&good_ntt, &mut ctx).await, outbox_item);
+
+        let (accs, args) = init_accs_args(&good_ntt, &mut ctx, &test_data, outbox_item, 100, false);
+        approve_token_authority(
+            &good_ntt,
+            &test_data.user_token_account,
+            &test_data.user.pubkey(),
+            &args,
+        )
+        .submit_with_signers(&[&test_data.user], &mut ctx)
+        .await
+        .unwrap();
+        transfer(&good_ntt, accs, args, Mode::Locking)
+            .submit(&mut ctx)
+            .await
+            .unwrap();
+
+        let outbox_item_account: OutboxItem = ctx.get_account_data_anchor(outbox_item).await;
+        assert_eq!(outbox_item_account.sequence, sequence);
+
+        let outbox_sequence: OutboxSequence = ctx
+            .get_account_data_anchor(good_ntt.outbox_sequence())
+            .await;
+        assert_eq!(outbox_sequence.next, sequence + 1);
+    }
+
+    // an outbox item that isn't derived from the next sequence is rejected
+    for sequence in [0, 3] {
+        let (accs, args) = init_accs_args(
+            &good_ntt,
+            &mut ctx,
+            &test_data,
+            good_ntt.outbox_item(sequence),
+            100,
+            false,
+        );
+        approve_token_authority(
+            &good_ntt,
+            &test_data.user_token_account,
+            &test_data.user.pubkey(),
+            &args,
+        )
+        .submit_with_signers(&[&test_data.user], &mut ctx)
+        .await
+        .unwrap();
+        let err = transfer(&good_ntt, accs, args, Mode::Locking)
+            .submit(&mut ctx)
+            .await
+            .unwrap_err();
+        assert_eq!(
+            err.unwrap(),
+            TransactionError::InstructionError(
+                0,
+                InstructionError::Custom(ErrorCode::ConstraintSeeds.into())
+            )
+        );
+    }
+}
+
+#[tokio::test]
+async fn test_transfer_wrong_mode() {
+    let (mut ctx, test_data) = setup(Mode::Burning).await;
+    let outbox_item = next_outbox_item(&good_ntt, &mut ctx).await;
+
+    let (accs, args) = init_accs_args(&good_ntt, &mut ctx, &test_data, outbox_item, 100, false);
+
+    approve_token_authority(
+        &good_ntt,
+        &test_data.user_token_account,
+        &test_data.user.pubkey(),
+        &args,
+    )
+    .submit_with_signers(&[&test_data.user], &mut ctx)
+    .await
+    .unwrap();
+    // make sure we can't transfer in the wrong mode
+    let err = transfer(&good_ntt, accs.clone(), args.clone(), Mode::Locking)
+        .submit(&mut ctx)
+        .await
+        .unwrap_err();
+
+    assert_eq!(
+        err.unwrap(),
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(NTTError::InvalidMode.into())
+        )
+    );
+}
+
+#[tokio::test]
+async fn test_cant_transfer_more_than_balance() {
+    let (mut ctx, test_data) = setup(Mode::Locking).await;
+
+    let outbox_item = next_outbox_item(&good_ntt, &mut ctx).await;
+
+    let token_account: TokenAccount = ctx
+        .get_account_data_anchor(test_data.user_token_account)
+        .await;
+    let more_than_balance = token_account.amount.checked_add(100).unwrap();
+
+    // extend outbound limit to avoid TransferExceedsRateLimit error
+    set_outbound_limit(
+        &good_ntt,
+        SetOutboundLimit {
+            owner: test_data.program_owner.pubkey(),
+        },
+        SetOutboundLimitArgs {
+            limit: more_than_balance,
+        },
+    )
+    .submit_with_signers(&[&test_data.program_owner], &mut ctx)
+    .await
+    .unwrap();
+
+    // try to transfer more than balance
+    let (accs, args) = init_accs_args(
+        &good_ntt,
+        &mut ctx,
+        &test_data,
+        outbox_item,
+        more_than_balance,
+        false,
+    );
+
+    approve_token_authority(
+        &good_ntt,
+        &test_data.user_token_account,
+        &test_data.user.pubkey(),
+        &args,
+    )
+    .submit_with_signers(&[&test_data.user], &mut ctx)
+    .await
+    .unwrap();
+    let err = transfer(&good_ntt, accs, args, Mode::Locking)
+        .submit(&mut ctx)
+        .await
+        .unwrap_err();
+
+    assert_eq!(
+        err.unwrap(),
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(spl_token::error::TokenError::InsufficientFunds as u32,)
+        )
+    );
+}
+
+async fn assert_queued(ctx: &mut ProgramTestContext, outbox_item: Pubkey) {
+    let outbox_item_account: OutboxItem = ctx.get_account_data_anchor(outbox_item).await;
+
+    let clock: Clock = ctx.banks_client.get_sysvar().await.unwrap();
+
+    assert!(!outbox_item_account.released.get(0).unwrap());
+    assert!(outbox_item_account.release_timestamp > clock.unix_timestamp);
+}
+
+#[tokio::test]
+async fn test_large_tx_queue() {
+    let (mut ctx, test_data) = setup(Mode::Locking).await;
+
+    let outbox_item = next_outbox_item(&good_ntt, &mut ctx).await;
+
+    let too_much = OUTBOUND_LIMIT + 1000;
     let should_queue = true;
     let (accs, args) = init_accs_args(
         &good_ntt,
-        &mut ctx,
-        &test_data,
-        outbox_item.pubkey(),
-        too_much,
-        should_queue,
+        &mut ctx,
+        &test_data,
+        outbox_item,
+        too_much,
+        should_queue,
+    );
+
+    let outbound_limit_before: OutboxRateLimit = ctx
+        .get_account_data_anchor(good_ntt.outbox_rate_limit())
+        .await;
+
+    approve_token_authority(
+        &good_ntt,
+        &test_data.user_token_account,
+        &test_data.user.pubkey(),
+        &args,
+    )
+    .submit_with_signers(&[&test_data.user], &mut ctx)
+    .await
+    .unwrap();
+    transfer(&good_ntt, accs, args, Mode::Locking)
+        .submit(&mut ctx)
+        .await
+        .unwrap();
+
+    let outbound_limit_after: OutboxRateLimit = ctx
+        .get_account_data_anchor(good_ntt.outbox_rate_limit())
+        .await;
+
+    assert_queued(&mut ctx, outbox_item).await;
+
+    // queued transfers don't change the rate limit
+    assert_eq!(outbound_limit_before, outbound_limit_after);
+}
+
+#[tokio::test]
+async fn test_cant_transfer_when_paused() {
+    let (mut ctx, test_data) = setup(Mode::Locking).await;
+
+    let outbox_item = next_outbox_item(&good_ntt, &mut ctx).await;
+
+    let (accs, args) = init_accs_args(&good_ntt, &mut ctx, &test_data, outbox_item, 100, false);
+
+    set_paused(
+        &good_ntt,
+        SetPaused {
+            owner: test_data.program_owner.pubkey(),
+        },
+        true,
+    )
+    .submit_with_signers(&[&test_data.program_owner], &mut ctx)
+    .await
+    .unwrap();
+
+    approve_token_authority(
+        &good_ntt,
+        &test_data.user_token_account,
+        &test_data.user.pubkey(),
+        &args,
+    )
+    .submit_with_signers(&[&test_data.user], &mut ctx)
+    .await
+    .unwrap();
+    let err = transfer(&good_ntt, accs.clone(), args.clone(), Mode::Locking)
+        .submit(&mut ctx)
+        .await
+        .unwrap_err();
+
+    assert_eq!(
+        err.unwrap(),
+        TransactionError::InstructionError(0, InstructionError::Custom(NTTError::Paused.into()))
+    );
+
+    // make sure we can unpause
+    set_paused(
+        &good_ntt,
+        SetPaused {
+            owner: test_data.program_owner.pubkey(),
+        },
+        false,
+    )
+    .submit_with_signers(&[&test_data.program_owner], &mut ctx)
+    .await
+    .unwrap();
+
+    approve_token_authority(
+        &good_ntt,
+        &test_data.user_token_account,
+        &test_data.user.pubkey(),
+        &args,
+    )
+    .submit_with_signers(&[&test_data.user], &mut ctx)
+    .await
+    .unwrap();
+    transfer(&good_ntt, accs, args, Mode::Locking)
+        .submit(&mut ctx)
+        .await
+        .unwrap();
+}
+
+/// Attempts a transfer of `amount`, returning the error if it failed.
+async fn try_transfer(
+    ctx: &mut ProgramTestContext,
+    test_data: &TestData,
+    amount: u64,
+) -> Option<TransactionError> {
+    let outbox_item = next_outbox_item(&good_ntt, ctx).await;
+    let (accs, args) = init_accs_args(&good_ntt, ctx, test_data, outbox_item, amount, false);
+    approve_token_authority(
+        &good_ntt,
+        &test_data.user_token_account,
+        &test_data.user.pubkey(),
+        &args,
+    )
+    .submit_with_signers(&[&test_data.user], ctx)
+    .await
+    .unwrap();
+    transfer(&good_ntt, accs, args, Mode::Locking)
+        .submit(ctx)
+        .await
+        .err()
+        .map(|err| err.unwrap())
+}
+
+#[tokio::test]
+async fn test_pauser() {
+    let (mut ctx, test_data) = setup(Mode::Locking).await;
+    let pauser = Keypair::new();
+
+    grant_role(
+        &good_ntt,
+        SetRole {
+            owner: test_data.program_owner.pubkey(),
+        },
+        Role::Pauser,
+        pauser.pubkey(),
+    )
+    .submit_with_signers(&[&test_data.program_owner], &mut ctx)
+    .await
+    .unwrap();
+
+    // only the pauser (or the owner) can pause
+    let err = pause(
+        &good_ntt,
+        Pause {
+            pauser: test_data.user.pubkey(),
+        },
+    )
+    .submit_with_signers(&[&test_data.user], &mut ctx)
+    .await
+    .unwrap_err();
+    assert_eq!(
+        err.unwrap(),
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(NTTError::InvalidPauser.into())
+        )
+    );
+
+    pause(
+        &good_ntt,
+        Pause {
+            pauser: pauser.pubkey(),
+        },
+    )
+    .submit_with_signers(&[&pauser], &mut ctx)
+    .await
+    .unwrap();
+
+    assert_eq!(
+        try_transfer(&mut ctx, &test_data, 100).await,
+        Some(TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(NTTError::Paused.into())
+        ))
+    );
+
+    // the pauser can't unpause
+    let err = set_paused(
+        &good_ntt,
+        SetPaused {
+            owner: pauser.pubkey(),
+        },
+        false,
+    )
+    .submit_with_signers(&[&pauser], &mut ctx)
+    .await
+    .unwrap_err();
+    assert_eq!(
+        err.unwrap(),
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(ErrorCode::ConstraintHasOne.into())
+        )
+    );
+
+    set_paused(
+        &good_ntt,
+        SetPaused {
+            owner: test_data.program_owner.pubkey(),
+        },
+        false,
+    )
+    .submit_with_signers(&[&test_data.program_owner], &mut ctx)
+    .await
+    .unwrap();
+
+    assert_eq!(try_transfer(&mut ctx, &test_data, 100).await, None);
+}
+
+#[tokio::test]
+async fn test_pauser_granular() {
+    let (mut ctx, test_data) = setup(Mode::Locking).await;
+    let pauser = Keypair::new();
+
+    grant_role(
+        &good_ntt,
+        SetRole {
+            owner: test_data.program_owner.pubkey(),
+        },
+        Role::Pauser,
+        pauser.pubkey(),
+    )
+    .submit_with_signers(&[&test_data.program_owner], &mut ctx)
+    .await
+    .unwrap();
+
+    // only the pauser (or the owner) can pause
+    let err = pause_peer(
+        &good_ntt,
+        Pause {
+            pauser: test_data.user.pubkey(),
+        },
+        PausePeerArgs {
+            chain_id: ChainId { id: OTHER_CHAIN },
+        },
+    )
+    .submit_with_signers(&[&test_data.user], &mut ctx)
+    .await
+    .unwrap_err();
+    assert_eq!(
+        err.unwrap(),
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(NTTError::InvalidPauser.into())
+        )
+    );
+
+    let pauser_accounts = || Pause {
+        pauser: pauser.pubkey(),
+    };
+    pause_peer(
+        &good_ntt,
+        pauser_accounts(),
+        PausePeerArgs {
+            chain_id: ChainId { id: OTHER_CHAIN },
+        },
+    )
+    .submit_with_signers(&[&pauser], &mut ctx)
+    .await
+    .unwrap();
+    pause_inbound(&good_ntt, pauser_accounts())
+        .submit_with_signers(&[&pauser], &mut ctx)
+        .await
+        .unwrap();
+    pause_outbound(&good_ntt, pauser_accounts())
+        .submit_with_signers(&[&pauser], &mut ctx)
+        .await
+        .unwrap();
+
+    let peer: NttManagerPeer = ctx
+        .get_account_data_anchor(good_ntt.peer(OTHER_CHAIN))
+        .await;
+    assert!(peer.paused);
+    let config: Config = ctx.get_account_data_anchor(good_ntt.config()).await;
+    assert!(config.inbound_paused);
+    assert!(config.outbound_paused);
+
+    assert_eq!(
+        try_transfer(&mut ctx, &test_data, 100).await,
+        Some(TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(NTTError::OutboundPaused.into())
+        ))
+    );
+
+    // the pauser can't unpause
+    let err = set_outbound_paused(
+        &good_ntt,
+        SetPaused {
+            owner: pauser.pubkey(),
+        },
+        false,
+    )
+    .submit_with_signers(&[&pauser], &mut ctx)
+    .await
+    .unwrap_err();
+    assert_eq!(
+        err.unwrap(),
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(ErrorCode::ConstraintHasOne.into())
+        )
+    );
+
+    let err = set_peer_paused(
+        &good_ntt,
+        SetPeerPaused {
+            owner: pauser.pubkey(),
+        },
+        SetPeerPausedArgs {
+            chain_id: ChainId { id: OTHER_CHAIN },
+            paused: false,
+        },
+    )
+    .submit_with_signers(&[&pauser], &mut ctx)
+    .await
+    .unwrap_err();
+    assert_eq!(
+        err.unwrap(),
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(NTTError::Unauthorized.into())
+        )
+    );
+}
+
+#[tokio::test]
+async fn test_cant_transfer_when_outbound_paused() {
+    let (mut ctx, test_data) = setup(Mode::Locking).await;
+
+    set_outbound_paused(
+        &good_ntt,
+        SetPaused {
+            owner: test_data.program_owner.pubkey(),
+        },
+        true,
+    )
+    .submit_with_signers(&[&test_data.program_owner], &mut ctx)
+    .await
+    .unwrap();
+
+    assert_eq!(
+        try_transfer(&mut ctx, &test_data, 100).await,
+        Some(TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(NTTError::OutboundPaused.into())
+        ))
+    );
+
+    set_outbound_paused(
+        &good_ntt,
+        SetPaused {
+            owner: test_data.program_owner.pubkey(),
+        },
+        false,
+    )
+    .submit_with_signers(&[&test_data.program_owner], &mut ctx)
+    .await
+    .unwrap();
+
+    assert_eq!(try_transfer(&mut ctx, &test_data, 100).await, None);
+}
+
+#[tokio::test]
+async fn test_cant_transfer_to_paused_peer() {
+    let (mut ctx, test_data) = setup(Mode::Locking).await;
+
+    set_peer_paused(
+        &good_ntt,
+        SetPeerPaused {
+            owner: test_data.program_owner.pubkey(),
+        },
+        SetPeerPausedArgs {
+            chain_id: ChainId { id: OTHER_CHAIN },
+            paused: true,
+        },
+    )
+    .submit_with_signers(&[&test_data.program_owner], &mut ctx)
+    .await
+    .unwrap();
+
+    assert_eq!(
+        try_transfer(&mut ctx, &test_data, 100).await,
+        Some(TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(NTTError::PeerPaused.into())
+        ))
+    );
+
+    set_peer_paused(
+        &good_ntt,
+        SetPeerPaused {
+            owner: test_data.program_owner.pubkey(),
+        },
+        SetPeerPausedArgs {
+            chain_id: ChainId { id: OTHER_CHAIN },
+            paused: false,
+        },
+    )
+    .submit_with_signers(&[&test_data.program_owner], &mut ctx)
+    .await
+    .unwrap();
+
+    assert_eq!(try_transfer(&mut ctx, &test_data, 100).await, None);
+}
+
+/// Sets whether the peer of `OTHER_CHAIN` is paused.
+async fn set_other_peer_paused(ctx: &mut ProgramTestContext, test_data: &TestData, paused: bool) {
+    set_peer_paused(
+        &good_ntt,
+        SetPeerPaused {
+            owner: test_data.program_owner.pubkey(),
+        },
+        SetPeerPausedArgs {
+            chain_id: ChainId { id: OTHER_CHAIN },
+            paused,
+        },
+    )
+    .submit_with_signers(&[&test_data.program_owner], ctx)
+    .await
+    .unwrap();
+}
+
+/// Transfers a small amount to `OTHER_CHAIN`, and returns the outbox item.
+async fn transfer_to_other_chain(ctx: &mut ProgramTestContext, test_data: &TestData) -> Pubkey {
+    let outbox_item = next_outbox_item(&good_ntt, ctx).await;
+    let (accs, args) = init_accs_args(&good_ntt, ctx, test_data, outbox_item, 154, false);
+
+    approve_token_authority(
+        &good_ntt,
+        &test_data.user_token_account,
+        &test_data.user.pubkey(),
+        &args,
+    )
+    .submit_with_signers(&[&test_data.user], ctx)
+    .await
+    .unwrap();
+    transfer(&good_ntt, accs, args, Mode::Locking)
+        .submit(ctx)
+        .await
+        .unwrap();
+
+    outbox_item
+}
+
+#[tokio::test]
+async fn test_cant_release_outbound_to_paused_peer() {
+    let (mut ctx, test_data) = setup(Mode::Locking).await;
+
+    let outbox_item = transfer_to_other_chain(&mut ctx, &test_data).await;
+
+    set_other_peer_paused(&mut ctx, &test_data, true).await;
+
+    let payer = ctx.payer.pubkey();
+    let release_outbound_ix = || {
+        release_outbound(
+            &good_ntt,
+            ReleaseOutbound {
+                payer,
+                outbox_item,
+                peer: good_ntt.peer(OTHER_CHAIN),
+            },
+            ReleaseOutboundArgs {
+                revert_on_delay: true,
+            },
+        )
+    };
+
+    let err = release_outbound_ix().submit(&mut ctx).await.unwrap_err();
+    assert_eq!(
+        err.unwrap(),
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(NTTError::PeerPaused.into())
+        )
+    );
+
+    set_other_peer_paused(&mut ctx, &test_data, false).await;
+
+    release_outbound_ix().submit(&mut ctx).await.unwrap();
+
+    let outbox_item_account: OutboxItem = ctx.get_account_data_anchor(outbox_item).await;
+    assert_eq!(outbox_item_account.released, Bitmap::from_value(1));
+}
+
+#[tokio::test]
+async fn test_cant_mark_outbox_item_as_released_to_paused_peer() {
+    let (mut ctx, test_data) = setup(Mode::Locking).await;
+
+    // the standalone transceiver program releases outbox items through
+    // `mark_outbox_item_as_released`
+    register_transceiver(
+        &good_ntt,
+        RegisterTransceiver {
+            payer: ctx.payer.pubkey(),
+            owner: test_data.program_owner.pubkey(),
+            transceiver: NTT_TRANSCEIVER,
+        },
+    )
+    .submit_with_signers(&[&test_data.program_owner], &mut ctx)
+    .await
+    .unwrap();
+
+    let outbox_item = transfer_to_other_chain(&mut ctx, &test_data).await;
+
+    set_other_peer_paused(&mut ctx, &test_data, true).await;
+
+    let transceiver_pda = |seeds: &[&[u8]]| Pubkey::find_program_address(seeds, &NTT_TRANSCEIVER).0;
+    let emitter = transceiver_pda(&[b"emitter"]);
+    let mut data =
+        solana_program::hash::hash(b"global:release_wormhole_outbound").to_bytes()[..8].to_vec();
+    data.extend(
+        ReleaseOutboundArgs {
+            revert_on_delay: true,
+        }
+        .try_to_vec()
+        .unwrap(),
+    );
+    let release_outbound_ix = Instruction {
+        program_id: NTT_TRANSCEIVER,
+        accounts: vec![
+            AccountMeta::new(ctx.payer.pubkey(), true),
+            AccountMeta::new_readonly(good_ntt.config(), false),
+            AccountMeta::new(outbox_item, false),
+            AccountMeta::new_readonly(good_ntt.registered_transceiver(&NTT_TRANSCEIVER), false),
+            AccountMeta::new(transceiver_pda(&[b"message", outbox_item.as_ref()]), false),
+            AccountMeta::new_readonly(emitter, false),
+            AccountMeta::new(good_ntt.wormhole().bridge(), false),
+            AccountMeta::new(good_ntt.wormhole().fee_collector(), false),
+            AccountMeta::new(good_ntt.wormhole().sequence(&emitter), false),
+            AccountMeta::new_readonly(good_ntt.wormhole().program, false),
+            AccountMeta::new_readonly(solana_sdk::system_program::id(), false),
+            AccountMeta::new_readonly(solana_sdk::sysvar::clock::id(), false),
+            AccountMeta::new_readonly(solana_sdk::sysvar::rent::id(), false),
+            AccountMeta::new_readonly(good_ntt.program(), false),
+            AccountMeta::new_readonly(transceiver_pda(&[b"outbox_item_signer"]), false),
+            AccountMeta::new_readonly(good_ntt.peer(OTHER_CHAIN), false),
+        ],
+        data,
+    };
+
+    let err = release_outbound_ix.submit(&mut ctx).await.unwrap_err();
+    assert_eq!(
+        err.unwrap(),
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(NTTError::PeerPaused.into())
+        )
+    );
+
+    let outbox_item_account: OutboxItem = ctx.get_account_data_anchor(outbox_item).await;
+    assert_eq!(outbox_item_account.released, Bitmap::new());
+}
+
+#[tokio::test]
+async fn test_cant_transfer_to_retired_peer() {
+    let (mut ctx, test_data) = setup(Mode::Locking).await;
+
+    remove_peer(
+        &good_ntt,
+        RemovePeer {
+            owner: test_data.program_owner.pubkey(),
+        },
+        RemovePeerArgs {
+            chain_id: ChainId { id: OTHER_CHAIN },
+            grace_period: 3600,
+            rent_recipient: test_data.program_owner.pubkey(),
+        },
+    )
+    .submit_with_signers(&[&test_data.program_owner], &mut ctx)
+    .await
+    .unwrap();
+
+    // rejected immediately, even though the peer isn't closed yet
+    assert_eq!(
+        try_transfer(&mut ctx, &test_data, 100).await,
+        Some(TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(NTTError::PeerRetired.into())
+        ))
+    );
+}
+
+#[tokio::test]
+async fn test_large_tx_no_queue() {
+    let (mut ctx, test_data) = setup(Mode::Locking).await;
+
+    let outbox_item = next_outbox_item(&good_ntt, &mut ctx).await;
+
+    let too_much = OUTBOUND_LIMIT + 1000;
+    let should_queue = false;
+    let (accs, args) = init_accs_args(
+        &good_ntt,
+        &mut ctx,
+        &test_data,
+        outbox_item,
+        too_much,
+        should_queue,
+    );
+
+    approve_token_authority(
+        &good_ntt,
+        &test_data.user_token_account,
+        &test_data.user.pubkey(),
+        &args,
+    )
+    .submit_with_signers(&[&test_data.user], &mut ctx)
+    .await
+    .unwrap();
+    let err = transfer(&good_ntt, accs, args, Mode::Locking)
+        .submit(&mut ctx)
+        .await
+        .unwrap_err();
+
+    assert_eq!(
+        err.unwrap(),
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(NTTError::TransferExceedsRateLimit.into())
+        )
+    );
+}
+
+async fn set_chain_limit(
+    ctx: &mut ProgramTestContext,
+    test_data: &TestData,
+    chain: u16,
+    limit: u64,
+) {
+    set_outbound_chain_limit(
+        &good_ntt,
+        SetOutboundLimit {
+            owner: test_data.program_owner.pubkey(),
+        },
+        SetOutboundChainLimitArgs {
+            limit,
+            chain_id: ChainId { id: chain },
+        },
+    )
+    .submit_with_signers(&[&test_data.program_owner], ctx)
+    .await
+    .unwrap();
+}
+
+#[tokio::test]
+async fn test_chain_rate_limit() {
+    let (mut ctx, test_data) = setup(Mode::Locking).await;
+
+    let chain_limit = OUTBOUND_LIMIT / 2;
+    set_chain_limit(&mut ctx, &test_data, OTHER_CHAIN, chain_limit).await;
+
+    let outbound_limit_before: OutboxRateLimit = ctx
+        .get_account_data_anchor(good_ntt.outbox_rate_limit())
+        .await;
+
+    // within both limits
+    let outbox_item = next_outbox_item(&good_ntt, &mut ctx).await;
+    let (accs, args) = init_accs_args(
+        &good_ntt,
+        &mut ctx,
+        &test_data,
+        outbox_item,
+        chain_limit - 1000,
+        false,
+    );
+    approve_token_authority(
+        &good_ntt,
+        &test_data.user_token_account,
+        &test_data.user.pubkey(),
+        &args,
+    )
+    .submit_with_signers(&[&test_data.user], &mut ctx)
+    .await
+    .unwrap();
+    transfer(&good_ntt, accs, args, Mode::Locking)
+        .submit(&mut ctx)
+        .await
+        .unwrap();
+
+    let clock: Clock = ctx.banks_client.get_sysvar().await.unwrap();
+    let outbound_limit: OutboxRateLimit = ctx
+        .get_account_data_anchor(good_ntt.outbox_rate_limit())
+        .await;
+    let chain_limit_after: OutboxChainRateLimit = ctx
+        .get_account_data_anchor(good_ntt.outbox_chain_rate_limit(OTHER_CHAIN))
+        .await;
+    assert_eq!(
+        outbound_limit_before.capacity_at(clock.unix_timestamp) - (chain_limit - 1000),
+        outbound_limit.capacity_at(clock.unix_timestamp)
+    );
+    assert_eq!(chain_limit_after.capacity_at(clock.unix_timestamp), 1000);
+
+    // within the global limit, but not the chain limit
+    let outbox_item = next_outbox_item(&good_ntt, &mut ctx).await;
+    let (accs, args) = init_accs_args(&good_ntt, &mut ctx, &test_data, outbox_item, 2000, false);
+    approve_token_authority(
+        &good_ntt,
+        &test_data.user_token_account,
+        &test_data.user.pubkey(),
+        &args,
+    )
+    .submit_with_signers(&[&test_data.user], &mut ctx)
+    .await
+    .unwrap();
+    let err = transfer(&good_ntt, accs.clone(), args, Mode::Locking)
+        .submit(&mut ctx)
+        .await
+        .unwrap_err();
+    assert_eq!(
+        err.unwrap(),
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(NTTError::TransferExceedsRateLimit.into())
+        )
+    );
+
+    // transfers to other chains are unaffected
+    let args = TransferArgs {
+        amount: 2000,
+        recipient_chain: ChainId { id: ANOTHER_CHAIN },
+        recipient_address: [1u8; 32],
+        should_queue: false,
+    };
+    approve_token_authority(
+        &good_ntt,
+        &test_data.user_token_account,
+        &test_data.user.pubkey(),
+        &args,
+    )
+    .submit_with_signers(&[&test_data.user], &mut ctx)
+    .await
+    .unwrap();
+    transfer(
+        &good_ntt,
+        Transfer {
+            peer: good_ntt.peer(ANOTHER_CHAIN),
+            ..accs
+        },
+        args,
+        Mode::Locking,
+    )
+    .submit(&mut ctx)
+    .await
+    .unwrap();
+}
+
+#[tokio::test]
+async fn test_chain_rate_limit_queue() {
+    let (mut ctx, test_data) = setup(Mode::Locking).await;
+
+    set_chain_limit(&mut ctx, &test_data, OTHER_CHAIN, 1000).await;
+
+    let outbox_item = next_outbox_item(&good_ntt, &mut ctx).await;
+    let (accs, args) = init_accs_args(&good_ntt, &mut ctx, &test_data, outbox_item, 2000, true);
+
+    let outbound_limit_before: OutboxRateLimit = ctx
+        .get_account_data_anchor(good_ntt.outbox_rate_limit())
+        .await;
+    let chain_limit_before: OutboxChainRateLimit = ctx
+        .get_account_data_anchor(good_ntt.outbox_chain_rate_limit(OTHER_CHAIN))
+        .await;
+
+    approve_token_authority(
+        &good_ntt,
+        &test_data.user_token_account,
+        &test_data.user.pubkey(),
+        &args,
+    )
+    .submit_with_signers(&[&test_data.user], &mut ctx)
+    .await
+    .unwrap();
+    transfer(&good_ntt, accs, args, Mode::Locking)
+        .submit(&mut ctx)
+        .await
+        .unwrap();
+
+    assert_queued(&mut ctx, outbox_item).await;
+
+    // neither limit is consumed when the transfer is queued
+    let outbound_limit_after: OutboxRateLimit = ctx
+        .get_account_data_anchor(good_ntt.outbox_rate_limit())
+        .await;
+    let chain_limit_after: OutboxChainRateLimit = ctx
+        .get_account_data_anchor(good_ntt.outbox_chain_rate_limit(OTHER_CHAIN))
+        .await;
+    assert_eq!(outbound_limit_before, outbound_limit_after);
+    assert_eq!(chain_limit_before, chain_limit_after);
+}
+
+#[tokio::test]
+async fn test_rate_limit_duration() {
+    let (mut ctx, test_data) = setup(Mode::Locking).await;
+
+    set_outbound_rate_limit_duration(
+        &good_ntt,
+        SetOutboundLimit {
+            owner: test_data.program_owner.pubkey(),
+        },
+        SetOutboundRateLimitDurationArgs { duration: 3600 },
+    )
+    .submit_with_signers(&[&test_data.program_owner], &mut ctx)
+    .await
+    .unwrap();
+
+    let outbound_limit: OutboxRateLimit = ctx
+        .get_account_data_anchor(good_ntt.outbox_rate_limit())
+        .await;
+    assert_eq!(outbound_limit.rate_limit_duration, 3600);
+
+    // transfers exceeding the limit are delayed by the new window
+    let outbox_item = next_outbox_item(&good_ntt, &mut ctx).await;
+    let (accs, args) = init_accs_args(
+        &good_ntt,
+        &mut ctx,
+        &test_data,
+        outbox_item,
+        OUTBOUND_LIMIT + 1000,
+        true,
+    );
+    approve_token_authority(
+        &good_ntt,
+        &test_data.user_token_account,
+        &test_data.user.pubkey(),
+        &args,
+    )
+    .submit_with_signers(&[&test_data.user], &mut ctx)
+    .await
+    .unwrap();
+    transfer(&good_ntt, accs, args, Mode::Locking)
+        .submit(&mut ctx)
+        .await
+        .unwrap();
+
+    let clock: Clock = ctx.banks_client.get_sysvar().await.unwrap();
+    let outbox_item_account: OutboxItem = ctx.get_account_data_anchor(outbox_item).await;
+    assert_eq!(
+        outbox_item_account.release_timestamp,
+        clock.unix_timestamp + 3600
+    );
+}
+
+#[tokio::test]
+async fn test_invalid_rate_limit_duration() {
+    let (mut ctx, test_data) = setup(Mode::Locking).await;
+
+    for duration in [0, -1] {
+        let err = set_outbound_rate_limit_duration(
+            &good_ntt,
+            SetOutboundLimit {
+                owner: test_data.program_owner.pubkey(),
+            },
+            SetOutboundRateLimitDurationArgs { duration },
+        )
+        .submit_with_signers(&[&test_data.program_owner], &mut ctx)
+        .await
+        .unwrap_err();
+        assert_eq!(
+            err.unwrap(),
+            TransactionError::InstructionError(
+                0,
+                InstructionError::Custom(NTTError::InvalidRateLimitDuration.into())
+            )
+        );
+    }
+}
+
+#[tokio::test]
+async fn test_migrate_rate_limit() {
+    let (mut ctx, test_data) = setup(Mode::Locking).await;
+
+    let rate_limit = good_ntt.outbox_rate_limit();
+    let payer = ctx.payer.pubkey();
+    let owner = &test_data.program_owner;
+    let migrate_ix = || {
+        migrate(
+            &good_ntt,
+            Migrate {
+                payer,
+                owner: owner.pubkey(),
+                account: rate_limit,
+            },
+            MigrateArgs { source_chain: None },
+        )
+    };
+
+    // already up to date
+    let err = migrate_ix()
+        .submit_with_signers(&[owner], &mut ctx)
+        .await
+        .unwrap_err();
+    assert_eq!(
+        err.unwrap(),
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(NTTError::AccountAlreadyMigrated.into())
+        )
+    );
+
+    let expected: OutboxRateLimit = ctx.get_account_data_anchor(rate_limit).await;
+
+    // emulate an account created before the rate limit duration was added
+    let mut account = ctx
+        .banks_client
+        .get_account(rate_limit)
+        .await
+        .unwrap()
+        .unwrap();
+    let old_len = account.data.len() - 8;
+    account.data.truncate(old_len);
+    account.lamports = ctx
+        .banks_client
+        .get_rent()
+        .await
+        .unwrap()
+        .minimum_balance(old_len);
+    ctx.set_account(&rate_limit, &account.into());
+
+    // the same transaction was just rejected, so make sure it's signed
+    // differently this time
+    ctx.get_new_latest_blockhash().await.unwrap();
+    migrate_ix()
+        .submit_with_signers(&[owner], &mut ctx)
+        .await
+        .unwrap();
+
+    let migrated: OutboxRateLimit = ctx.get_account_data_anchor(rate_limit).await;
+    assert_eq!(migrated, expected);
+    assert_eq!(
+        migrated.rate_limit_duration,
+        RateLimitState::DEFAULT_RATE_LIMIT_DURATION
+    );
+
+    // the migrated account is usable again
+    let outbox_item = next_outbox_item(&good_ntt, &mut ctx).await;
+    let (accs, args) = init_accs_args(&good_ntt, &mut ctx, &test_data, outbox_item, 100, false);
+    approve_token_authority(
+        &good_ntt,
+        &test_data.user_token_account,
+        &test_data.user.pubkey(),
+        &args,
+    )
+    .submit_with_signers(&[&test_data.user], &mut ctx)
+    .await
+    .unwrap();
+    transfer(&good_ntt, accs, args, Mode::Locking)
+        .submit(&mut ctx)
+        .await
+        .unwrap();
+}
+
+#[tokio::test]
+async fn test_cant_release_queued() {
+    let (mut ctx, test_data) = setup(Mode::Locking).await;
+
+    let outbox_item = next_outbox_item(&good_ntt, &mut ctx).await;
+
+    let too_much = OUTBOUND_LIMIT + 1000;
+    let (accs, args) = init_accs_args(&good_ntt, &mut ctx, &test_data, outbox_item, too_much, true);
+
+    approve_token_authority(
+        &good_ntt,
+        &test_data.user_token_account,
+        &test_data.user.pubkey(),
+        &args,
+    )
+    .submit_with_signers(&[&test_data.user], &mut ctx)
+    .await
+    .unwrap();
+    transfer(&good_ntt, accs, args, Mode::Locking)
+        .submit(&mut ctx)
+        .await
+        .unwrap();
+
+    assert_queued(&mut ctx, outbox_item).await;
+
+    // check that 'revert_on_delay = true' returns correct error
+    let err = release_outbound(
+        &good_ntt,
+        ReleaseOutbound {
+            payer: ctx.payer.pubkey(),
+            outbox_item,
+            peer: good_ntt.peer(OTHER_CHAIN),
+        },
+        ReleaseOutboundArgs {
+            revert_on_delay: true,
+        },
+    )
+    .submit(&mut ctx)
+    .await
+    .unwrap_err();
+
+    assert_eq!(
+        err.unwrap(),
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(NTTError::CantReleaseYet.into())
+        )
+    );
+
+    // check that 'revert_on_delay = false' succeeds but does not release
+    release_outbound(
+        &good_ntt,
+        ReleaseOutbound {
+            payer: ctx.payer.pubkey(),
+            outbox_item,
+            peer: good_ntt.peer(OTHER_CHAIN),
+        },
+        ReleaseOutboundArgs {
+            revert_on_delay: false,
+        },
+    )
+    .submit(&mut ctx)
+    .await
+    .unwrap();
+
+    assert_queued(&mut ctx, outbox_item).await;
+
+    // just to be safe, let's make sure the wormhole message account wasn't initialised
+    let wh_message = good_ntt.wormhole_message(&outbox_item);
+    assert!(ctx
+        .banks_client
+        .get_account(wh_message)
+        .await
+        .unwrap()
+        .is_none());
+}
+
+#[tokio::test]
+async fn test_cant_release_twice() {
+    let (mut ctx, test_data) = setup(Mode::Locking).await;
+
+    let outbox_item = next_outbox_item(&good_ntt, &mut ctx).await;
+
+    let (accs, args) = init_accs_args(&good_ntt, &mut ctx, &test_data, outbox_item, 100, false);
+
+    approve_token_authority(
+        &good_ntt,
+        &test_data.user_token_account,
+        &test_data.user.pubkey(),
+        &args,
+    )
+    .submit_with_signers(&[&test_data.user], &mut ctx)
+    .await
+    .unwrap();
+    transfer(&good_ntt, accs, args, Mode::Locking)
+        .submit(&mut ctx)
+        .await
+        .unwrap();
+
+    release_outbound(
+        &good_ntt,
+        ReleaseOutbound {
+            payer: ctx.payer.pubkey(),
+            outbox_item,
+            peer: good_ntt.peer(OTHER_CHAIN),
+        },
+        ReleaseOutboundArgs {
+            revert_on_delay: true,
+        },
+    )
+    .submit(&mut ctx)
+    .await
+    .unwrap();
+
+    // make sure we can't release again
+    let err = release_outbound(
+        &good_ntt,
+        ReleaseOutbound {
+            payer: ctx.payer.pubkey(),
+            outbox_item,
+            peer: good_ntt.peer(OTHER_CHAIN),
+        },
+        ReleaseOutboundArgs {
+            revert_on_delay: true,
+        },
+    )
+    .submit(&mut ctx)
+    .await
+    .unwrap_err();
+
+    assert_eq!(
+        err.unwrap(),
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(NTTError::MessageAlreadySent.into())
+        )
+    );
+}
+
+#[tokio::test]
+async fn test_cancel_queued_transfer_locking() {
+    test_cancel_queued_transfer(Mode::Locking).await;
+}
+
+#[tokio::test]
+async fn test_cancel_queued_transfer_burning() {
+    test_cancel_queued_transfer(Mode::Burning).await;
+}
+
+async fn test_cancel_queued_transfer(mode: Mode) {
+    let (mut ctx, test_data) = setup(mode).await;
+
+    let outbox_item = next_outbox_item(&good_ntt, &mut ctx).await;
+
+    let too_much = OUTBOUND_LIMIT + 1000;
+    let (accs, args) = init_accs_args(&good_ntt, &mut ctx, &test_data, outbox_item, too_much, true);
+
+    let token_account_before: TokenAccount = ctx
+        .get_account_data_anchor(test_data.user_token_account)
+        .await;
+
+    approve_token_authority(
+        &good_ntt,
+        &test_data.user_token_account,
+        &test_data.user.pubkey(),
+        &args,
+    )
+    .submit_with_signers(&[&test_data.user], &mut ctx)
+    .await
+    .unwrap();
+    transfer(&good_ntt, accs, args, mode)
+        .submit(&mut ctx)
+        .await
+        .unwrap();
+
+    assert_queued(&mut ctx, outbox_item).await;
+
+    let outbound_limit_before: OutboxRateLimit = ctx
+        .get_account_data_anchor(good_ntt.outbox_rate_limit())
+        .await;
+
+    cancel_outbound_queued_transfer(
+        &good_ntt,
+        CancelOutboundQueuedTransfer {
+            sender: test_data.user.pubkey(),
+            mint: test_data.mint,
+            sender_token_account: test_data.user_token_account,
+            outbox_item,
+            recipient_chain: OTHER_CHAIN,
+        },
+    )
+    .submit_with_signers(&[&test_data.user], &mut ctx)
+    .await
+    .unwrap();
+
+    let token_account_after: TokenAccount = ctx
+        .get_account_data_anchor(test_data.user_token_account)
+        .await;
+
+    let outbound_limit_after: OutboxRateLimit = ctx
+        .get_account_data_anchor(good_ntt.outbox_rate_limit())
+        .await;
+
+    let outbox_item_account: OutboxItem = ctx.get_account_data_anchor(outbox_item).await;
+
+    // the tokens are returned to the sender
+    assert_eq!(token_account_before.amount, token_account_after.amount);
+    // queued transfers don't consume the rate limit, so there's nothing to undo
+    assert_eq!(outbound_limit_before, outbound_limit_after);
+    assert!(outbox_item_account.cancelled);
+
+    // the cancelled transfer can't be released
+    let err = release_outbound(
+        &good_ntt,
+        ReleaseOutbound {
+            payer: ctx.payer.pubkey(),
+            outbox_item,
+            peer: good_ntt.peer(OTHER_CHAIN),
+        },
+        ReleaseOutboundArgs {
+            revert_on_delay: false,
+        },
+    )
+    .submit(&mut ctx)
+    .await
+    .unwrap_err();
+
+    assert_eq!(
+        err.unwrap(),
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(NTTError::OutboxItemCancelled.into())
+        )
     );
 
-    let outbound_limit_before: OutboxRateLimit = ctx
-        .get_account_data_anchor(good_ntt.outbox_rate_limit())
-        .await;
+    // and can't be cancelled twice
+    let err = cancel_outbound_queued_transfer(
+        &good_ntt,
+        CancelOutboundQueuedTransfer {
+            sender: test_data.user.pubkey(),
+            mint: test_data.mint,
+            sender_token_account: test_data.user_token_account,
+            outbox_item,
+            recipient_chain: OTHER_CHAIN,
+        },
+    )
+    .submit_with_signers(&[&test_data.user], &mut ctx)
+    .await
+    .unwrap_err();
+
+    assert_eq!(
+        err.unwrap(),
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(NTTError::OutboxItemCancelled.into())
+        )
+    );
+}
 
+async fn queue_transfer(ctx: &mut ProgramTestContext, test_data: &TestData, amount: u64) -> Pubkey {
+    let outbox_item = next_outbox_item(&good_ntt, ctx).await;
+    let (accs, args) = init_accs_args(&good_ntt, ctx, test_data, outbox_item, amount, true);
     approve_token_authority(
         &good_ntt,
         &test_data.user_token_account,
         &test_data.user.pubkey(),
         &args,
     )
-    .submit_with_signers(&[&test_data.user], &mut ctx)
+    .submit_with_signers(&[&test_data.user], ctx)
     .await
     .unwrap();
     transfer(&good_ntt, accs, args, Mode::Locking)
-        .submit_with_signers(&[&outbox_item], &mut ctx)
+        .submit(ctx)
         .await
         .unwrap();
-
-    let outbound_limit_after: OutboxRateLimit = ctx
-        .get_account_data_anchor(good_ntt.outbox_rate_limit())
-        .await;
-
-    assert_queued(&mut ctx, outbox_item.pubkey()).await;
-
-    // queued transfers don't change the rate limit
-    assert_eq!(outbound_limit_before, outbound_limit_after);
+    outbox_item
 }
 
 #[tokio::test]
-async fn test_cant_transfer_when_paused() {
+async fn test_precise_queue_release() {
     let (mut ctx, test_data) = setup(Mode::Locking).await;
 
-    let outbox_item = Keypair::new();
-
-    let (accs, args) = init_accs_args(
-        &good_ntt,
-        &mut ctx,
-        &test_data,
-        outbox_item.pubkey(),
-        100,
-        false,
-    );
-
-    set_paused(
+    set_precise_queue_release(
         &good_ntt,
-        SetPaused {
+        SetPreciseQueueRelease {
             owner: test_data.program_owner.pubkey(),
         },
         true,
@@ -793,6 +2460,51 @@ async fn test_cant_transfer_when_paused() {
     .await
     .unwrap();
 
+    // more than the limit can't be reserved, so it's delayed by a full window
+    // instead (and doesn't hold up later transfers)
+    let oversized = queue_transfer(&mut ctx, &test_data, OUTBOUND_LIMIT + 1000).await;
+    let clock: Clock = ctx.banks_client.get_sysvar().await.unwrap();
+    let oversized_account: OutboxItem = ctx.get_account_data_anchor(oversized).await;
+    assert!(oversized_account.queued);
+    assert!(!oversized_account.reserved);
+    assert_eq!(
+        oversized_account.release_timestamp,
+        clock.unix_timestamp + RateLimitState::DEFAULT_RATE_LIMIT_DURATION
+    );
+
+    // this fits, so it's not queued
+    let consumed = queue_transfer(&mut ctx, &test_data, OUTBOUND_LIMIT - 1000).await;
+    let consumed_account: OutboxItem = ctx.get_account_data_anchor(consumed).await;
+    assert!(!consumed_account.queued);
+
+    // the missing 1000 refill in 1000 / OUTBOUND_LIMIT of the (24 hour) window
+    let refill_time = 8640;
+
+    let first = queue_transfer(&mut ctx, &test_data, 2000).await;
+    let first_account: OutboxItem = ctx.get_account_data_anchor(first).await;
+    assert!(first_account.queued);
+    assert!(first_account.reserved);
+    assert_eq!(
+        first_account.release_timestamp,
+        clock.unix_timestamp + refill_time
+    );
+
+    // the next transfer is queued behind the first one
+    let second = queue_transfer(&mut ctx, &test_data, 1000).await;
+    let second_account: OutboxItem = ctx.get_account_data_anchor(second).await;
+    assert_eq!(
+        second_account.release_timestamp,
+        clock.unix_timestamp + 2 * refill_time
+    );
+
+    let outbound_limit: OutboxRateLimit = ctx
+        .get_account_data_anchor(good_ntt.outbox_rate_limit())
+        .await;
+    assert_eq!(outbound_limit.capacity_at(clock.unix_timestamp), 0);
+
+    // so nothing can go through without being queued
+    let outbox_item = next_outbox_item(&good_ntt, &mut ctx).await;
+    let (accs, args) = init_accs_args(&good_ntt, &mut ctx, &test_data, outbox_item, 1, false);
     approve_token_authority(
         &good_ntt,
         &test_data.user_token_account,
@@ -802,28 +2514,53 @@ async fn test_cant_transfer_when_paused() {
     .submit_with_signers(&[&test_data.user], &mut ctx)
     .await
     .unwrap();
-    let err = transfer(&good_ntt, accs.clone(), args.clone(), Mode::Locking)
-        .submit_with_signers(&[&outbox_item], &mut ctx)
+    let err = transfer(&good_ntt, accs, args, Mode::Locking)
+        .submit(&mut ctx)
         .await
         .unwrap_err();
-
     assert_eq!(
         err.unwrap(),
-        TransactionError::InstructionError(0, InstructionError::Custom(NTTError::Paused.into()))
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(NTTError::TransferExceedsRateLimit.into())
+        )
     );
 
-    // make sure we can unpause
-    set_paused(
+    // cancelling a transfer returns the capacity it reserved
+    cancel_outbound_queued_transfer(
         &good_ntt,
-        SetPaused {
-            owner: test_data.program_owner.pubkey(),
+        CancelOutboundQueuedTransfer {
+            sender: test_data.user.pubkey(),
+            mint: test_data.mint,
+            sender_token_account: test_data.user_token_account,
+            outbox_item: first,
+            recipient_chain: OTHER_CHAIN,
         },
-        false,
     )
-    .submit_with_signers(&[&test_data.program_owner], &mut ctx)
+    .submit_with_signers(&[&test_data.user], &mut ctx)
     .await
     .unwrap();
 
+    // the second transfer's reservation is paid back, and it refills from
+    // there
+    let outbound_limit: OutboxRateLimit = ctx
+        .get_account_data_anchor(good_ntt.outbox_rate_limit())
+        .await;
+    assert_eq!(outbound_limit.capacity_at(clock.unix_timestamp), 0);
+    assert_eq!(
+        outbound_limit.capacity_at(clock.unix_timestamp + refill_time),
+        1000
+    );
+}
+
+#[tokio::test]
+async fn test_cant_cancel_unqueued_transfer() {
+    let (mut ctx, test_data) = setup(Mode::Locking).await;
+
+    let outbox_item = next_outbox_item(&good_ntt, &mut ctx).await;
+
+    let (accs, args) = init_accs_args(&good_ntt, &mut ctx, &test_data, outbox_item, 1000, false);
+
     approve_token_authority(
         &good_ntt,
         &test_data.user_token_account,
@@ -834,27 +2571,42 @@ async fn test_cant_transfer_when_paused() {
     .await
     .unwrap();
     transfer(&good_ntt, accs, args, Mode::Locking)
-        .submit_with_signers(&[&outbox_item], &mut ctx)
+        .submit(&mut ctx)
         .await
         .unwrap();
+
+    // the transfer can be released right away, so it can't be cancelled
+    let err = cancel_outbound_queued_transfer(
+        &good_ntt,
+        CancelOutboundQueuedTransfer {
+            sender: test_data.user.pubkey(),
+            mint: test_data.mint,
+            sender_token_account: test_data.user_token_account,
+            outbox_item,
+            recipient_chain: OTHER_CHAIN,
+        },
+    )
+    .submit_with_signers(&[&test_data.user], &mut ctx)
+    .await
+    .unwrap_err();
+
+    assert_eq!(
+        err.unwrap(),
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(NTTError::OutboxItemNotQueued.into())
+        )
+    );
 }
 
 #[tokio::test]
-async fn test_large_tx_no_queue() {
+async fn test_only_sender_can_cancel() {
     let (mut ctx, test_data) = setup(Mode::Locking).await;
 
-    let outbox_item = Keypair::new();
+    let outbox_item = next_outbox_item(&good_ntt, &mut ctx).await;
 
     let too_much = OUTBOUND_LIMIT + 1000;
-    let should_queue = false;
-    let (accs, args) = init_accs_args(
-        &good_ntt,
-        &mut ctx,
-        &test_data,
-        outbox_item.pubkey(),
-        too_much,
-        should_queue,
-    );
+    let (accs, args) = init_accs_args(&good_ntt, &mut ctx, &test_data, outbox_item, too_much, true);
 
     approve_token_authority(
         &good_ntt,
@@ -865,35 +2617,41 @@ async fn test_large_tx_no_queue() {
     .submit_with_signers(&[&test_data.user], &mut ctx)
     .await
     .unwrap();
-    let err = transfer(&good_ntt, accs, args, Mode::Locking)
-        .submit_with_signers(&[&outbox_item], &mut ctx)
+    transfer(&good_ntt, accs, args, Mode::Locking)
+        .submit(&mut ctx)
         .await
-        .unwrap_err();
+        .unwrap();
+
+    let err = cancel_outbound_queued_transfer(
+        &good_ntt,
+        CancelOutboundQueuedTransfer {
+            sender: test_data.program_owner.pubkey(),
+            mint: test_data.mint,
+            sender_token_account: test_data.user_token_account,
+            outbox_item,
+            recipient_chain: OTHER_CHAIN,
+        },
+    )
+    .submit_with_signers(&[&test_data.program_owner], &mut ctx)
+    .await
+    .unwrap_err();
 
     assert_eq!(
         err.unwrap(),
         TransactionError::InstructionError(
             0,
-            InstructionError::Custom(NTTError::TransferExceedsRateLimit.into())
+            InstructionError::Custom(ErrorCode::ConstraintHasOne.into())
         )
     );
 }
 
 #[tokio::test]
-async fn test_cant_release_queued() {
+async fn test_cant_cancel_released_transfer() {
     let (mut ctx, test_data) = setup(Mode::Locking).await;
 
-    let outbox_item = Keypair::new();
+    let outbox_item = next_outbox_item(&good_ntt, &mut ctx).await;
 
-    let too_much = OUTBOUND_LIMIT + 1000;
-    let (accs, args) = init_accs_args(
-        &good_ntt,
-        &mut ctx,
-        &test_data,
-        outbox_item.pubkey(),
-        too_much,
-        true,
-    );
+    let (accs, args) = init_accs_args(&good_ntt, &mut ctx, &test_data, outbox_item, 100, false);
 
     approve_token_authority(
         &good_ntt,
@@ -905,18 +2663,16 @@ async fn test_cant_release_queued() {
     .await
     .unwrap();
     transfer(&good_ntt, accs, args, Mode::Locking)
-        .submit_with_signers(&[&outbox_item], &mut ctx)
+        .submit(&mut ctx)
         .await
         .unwrap();
 
-    assert_queued(&mut ctx, outbox_item.pubkey()).await;
-
-    // check that 'revert_on_delay = true' returns correct error
-    let err = release_outbound(
+    release_outbound(
         &good_ntt,
         ReleaseOutbound {
             payer: ctx.payer.pubkey(),
-            outbox_item: outbox_item.pubkey(),
+            outbox_item,
+            peer: good_ntt.peer(OTHER_CHAIN),
         },
         ReleaseOutboundArgs {
             revert_on_delay: true,
@@ -924,59 +2680,58 @@ async fn test_cant_release_queued() {
     )
     .submit(&mut ctx)
     .await
+    .unwrap();
+
+    let err = cancel_outbound_queued_transfer(
+        &good_ntt,
+        CancelOutboundQueuedTransfer {
+            sender: test_data.user.pubkey(),
+            mint: test_data.mint,
+            sender_token_account: test_data.user_token_account,
+            outbox_item,
+            recipient_chain: OTHER_CHAIN,
+        },
+    )
+    .submit_with_signers(&[&test_data.user], &mut ctx)
+    .await
     .unwrap_err();
 
     assert_eq!(
         err.unwrap(),
         TransactionError::InstructionError(
             0,
-            InstructionError::Custom(NTTError::CantReleaseYet.into())
+            InstructionError::Custom(NTTError::OutboxItemAlreadyReleased.into())
         )
     );
+}
 
-    // check that 'revert_on_delay = false' succeeds but does not release
-    release_outbound(
-        &good_ntt,
-        ReleaseOutbound {
-            payer: ctx.payer.pubkey(),
-            outbox_item: outbox_item.pubkey(),
-        },
-        ReleaseOutboundArgs {
-            revert_on_delay: false,
-        },
-    )
-    .submit(&mut ctx)
-    .await
-    .unwrap();
-
-    assert_queued(&mut ctx, outbox_item.pubkey()).await;
+fn with_payload(args: TransferArgs, additional_payload: Vec<u8>) -> TransferWithPayloadArgs {
+    let args: TransferWithPayloadArgs = args.into();
+    TransferWithPayloadArgs {
+        additional_payload,
+        ..args
+    }
+}
 
-    // just to be safe, let's make sure the wormhole message account wasn't initialised
-    let wh_message = good_ntt.wormhole_message(&outbox_item.pubkey());
-    assert!(ctx
-        .banks_client
-        .get_account(wh_message)
-        .await
-        .unwrap()
-        .is_none());
+#[tokio::test]
+async fn test_transfer_with_payload_locking() {
+    test_transfer_with_payload(Mode::Locking).await;
 }
 
 #[tokio::test]
-async fn test_cant_release_twice() {
-    let (mut ctx, test_data) = setup(Mode::Locking).await;
+async fn test_transfer_with_payload_burning() {
+    test_transfer_with_payload(Mode::Burning).await;
+}
 
-    let outbox_item = Keypair::new();
+async fn test_transfer_with_payload(mode: Mode) {
+    let (mut ctx, test_data) = setup(mode).await;
 
-    let (accs, args) = init_accs_args(
-        &good_ntt,
-        &mut ctx,
-        &test_data,
-        outbox_item.pubkey(),
-        100,
-        false,
-    );
+    let outbox_item = next_outbox_item(&good_ntt, &mut ctx).await;
 
-    approve_token_authority(
+    let (accs, args) = init_accs_args(&good_ntt, &mut ctx, &test_data, outbox_item, 154, false);
+    let args = with_payload(args, vec![0xDE, 0xAD, 0xBE, 0xEF]);
+
+    approve_token_authority_with_payload(
         &good_ntt,
         &test_data.user_token_account,
         &test_data.user.pubkey(),
@@ -985,16 +2740,22 @@ async fn test_cant_release_twice() {
     .submit_with_signers(&[&test_data.user], &mut ctx)
     .await
     .unwrap();
-    transfer(&good_ntt, accs, args, Mode::Locking)
-        .submit_with_signers(&[&outbox_item], &mut ctx)
+    transfer_with_payload(&good_ntt, accs, args, mode)
+        .submit(&mut ctx)
         .await
         .unwrap();
 
+    let additional_payload = AdditionalPayload::new(vec![0xDE, 0xAD, 0xBE, 0xEF]).unwrap();
+
+    let outbox_item_account: OutboxItem = ctx.get_account_data_anchor(outbox_item).await;
+    assert_eq!(outbox_item_account.additional_payload, additional_payload);
+
     release_outbound(
         &good_ntt,
         ReleaseOutbound {
             payer: ctx.payer.pubkey(),
-            outbox_item: outbox_item.pubkey(),
+            outbox_item,
+            peer: good_ntt.peer(OTHER_CHAIN),
         },
         ReleaseOutboundArgs {
             revert_on_delay: true,
@@ -1004,26 +2765,78 @@ async fn test_cant_release_twice() {
     .await
     .unwrap();
 
-    // make sure we can't release again
-    let err = release_outbound(
+    let wh_message = good_ntt.wormhole_message(&outbox_item);
+    let msg: PostedVaa<TransceiverMessage<WormholeTransceiver, NativeTokenTransfer<Payload>>> =
+        ctx.get_account_data_anchor_unchecked(wh_message).await;
+
+    assert_eq!(
+        msg.data().ntt_manager_payload.payload.additional_payload,
+        additional_payload
+    );
+}
+
+#[tokio::test]
+async fn test_transfer_with_payload_needs_matching_approval() {
+    let (mut ctx, test_data) = setup(Mode::Locking).await;
+
+    let outbox_item = next_outbox_item(&good_ntt, &mut ctx).await;
+
+    let (accs, args) = init_accs_args(&good_ntt, &mut ctx, &test_data, outbox_item, 154, false);
+
+    // approve a different payload
+    approve_token_authority_with_payload(
         &good_ntt,
-        ReleaseOutbound {
-            payer: ctx.payer.pubkey(),
-            outbox_item: outbox_item.pubkey(),
-        },
-        ReleaseOutboundArgs {
-            revert_on_delay: true,
-        },
+        &test_data.user_token_account,
+        &test_data.user.pubkey(),
+        &with_payload(args.clone(), vec![1]),
     )
-    .submit(&mut ctx)
+    .submit_with_signers(&[&test_data.user], &mut ctx)
     .await
-    .unwrap_err();
+    .unwrap();
+
+    let err = transfer_with_payload(&good_ntt, accs, with_payload(args, vec![2]), Mode::Locking)
+        .submit(&mut ctx)
+        .await
+        .unwrap_err();
 
     assert_eq!(
         err.unwrap(),
         TransactionError::InstructionError(
             0,
-            InstructionError::Custom(NTTError::MessageAlreadySent.into())
+            InstructionError::Custom(spl_token::error::TokenError::OwnerMismatch as u32)
+        )
+    );
+}
+
+#[tokio::test]
+async fn test_transfer_with_payload_too_long() {
+    let (mut ctx, test_data) = setup(Mode::Locking).await;
+
+    let outbox_item = next_outbox_item(&good_ntt, &mut ctx).await;
+
+    let (accs, args) = init_accs_args(&good_ntt, &mut ctx, &test_data, outbox_item, 154, false);
+    let args = with_payload(args, vec![0; MAX_ADDITIONAL_PAYLOAD_LEN + 1]);
+
+    approve_token_authority_with_payload(
+        &good_ntt,
+        &test_data.user_token_account,
+        &test_data.user.pubkey(),
+        &args,
+    )
+    .submit_with_signers(&[&test_data.user], &mut ctx)
+    .await
+    .unwrap();
+
+    let err = transfer_with_payload(&good_ntt, accs, args, Mode::Locking)
+        .submit(&mut ctx)
+        .await
+        .unwrap_err();
+
+    assert_eq!(
+        err.unwrap(),
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(NTTError::AdditionalPayloadTooLong.into())
         )
     );
 }
@@ -0,0 +1,29 @@
+//! Events emitted by the transceiver. The manager emits its own events for the
+//! outbox and inbox state transitions (see
+//! [`example_native_token_transfers::events`]).
+
+use anchor_lang::prelude::*;
+use ntt_messages::chain_id::ChainId;
+
+#[event]
+pub struct WormholePeerSet {
+    pub chain_id: ChainId,
+    pub address: [u8; 32],
+}
+
+/// A wormhole VAA was verified and stored as a transceiver message, ready to
+/// be redeemed.
+#[event]
+pub struct WormholeMessageReceived {
+    pub transceiver_message: Pubkey,
+    pub from_chain: ChainId,
+    pub message_id: [u8; 32],
+}
+
+/// An outbox item was posted to wormhole.
+#[event]
+pub struct WormholeMessageSent {
+    pub outbox_item: Pubkey,
+    pub wormhole_message: Pubkey,
+    pub recipient_chain: ChainId,
+}
@@ -1,4 +1,5 @@
 use anchor_lang::prelude::*;
+pub mod events;
 pub mod messages;
 pub mod peer;
 pub mod wormhole;
@@ -36,6 +37,16 @@ pub mod ntt_transceiver {
         wormhole::instructions::release_outbound(ctx, args)
     }
 
+    pub fn close_wormhole_transceiver_message(ctx: Context<CloseTransceiverMessage>) -> Result<()> {
+        wormhole::instructions::close_transceiver_message(ctx)
+    }
+
+    pub fn migrate_wormhole_transceiver_message(
+        ctx: Context<MigrateTransceiverMessage>,
+    ) -> Result<()> {
+        wormhole::instructions::migrate_transceiver_message(ctx)
+    }
+
     pub fn broadcast_wormhole_id(ctx: Context<BroadcastId>) -> Result<()> {
         wormhole::instructions::broadcast_id(ctx)
     }
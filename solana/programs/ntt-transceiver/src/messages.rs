@@ -7,6 +7,13 @@ use std::{collections::HashMap, marker::PhantomData};
 pub struct ValidatedTransceiverMessage<A: AnchorDeserialize + AnchorSerialize + Space + Clone> {
     pub from_chain: ChainId,
     pub message: TransceiverMessageData<A>,
+    /// The account that paid the rent for this message. The rent is returned
+    /// to this account when the message is closed.
+    pub rent_payer: Pubkey,
+    /// When the message was attested on the source chain (e.g. the timestamp
+    /// of the VAA it was received in). Transfers from a retired peer are only
+    /// accepted if they were attested before it was retired.
+    pub attested_at: i64,
 }
 
 impl<A: AnchorDeserialize + AnchorSerialize + Space + Clone> ValidatedTransceiverMessage<A> {
@@ -1,4 +1,5 @@
 use anchor_lang::prelude::*;
+use example_native_token_transfers::error::NTTError;
 use wormhole_anchor_sdk::wormhole;
 use wormhole_io::TypePrefixedPayload;
 
@@ -64,7 +65,7 @@ pub fn post_message<'info, A: TypePrefixedPayload>(
     wormhole::post_message(
         CpiContext::new_with_signer(wormhole.program.to_account_info(), ix, &seeds.concat()),
         batch_id,
-        TypePrefixedPayload::to_vec_payload(payload),
+        ntt_messages::codec::encode(payload).map_err(NTTError::from)?,
         wormhole::Finality::Finalized,
     )?;
 
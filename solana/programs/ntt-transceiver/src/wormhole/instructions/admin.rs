@@ -1,4 +1,4 @@
-use crate::peer::TransceiverPeer;
+use crate::{events::WormholePeerSet, peer::TransceiverPeer};
 use anchor_lang::prelude::*;
 use example_native_token_transfers::config::Config;
 use ntt_messages::chain_id::ChainId;
@@ -43,5 +43,10 @@ pub fn set_transceiver_peer(
         address: args.address,
     });
 
+    emit!(WormholePeerSet {
+        chain_id: args.chain_id,
+        address: args.address,
+    });
+
     Ok(())
 }
@@ -0,0 +1,38 @@
+use crate::messages::ValidatedTransceiverMessage;
+use anchor_lang::prelude::*;
+use example_native_token_transfers::{error::NTTError, queue::inbox::InboxItem, transfer::Payload};
+use ntt_messages::ntt::NativeTokenTransfer;
+
+#[derive(Accounts)]
+pub struct CloseTransceiverMessage<'info> {
+    #[account(
+        mut,
+        has_one = rent_payer @ NTTError::IncorrectRentPayer,
+        close = rent_payer,
+    )]
+    pub transceiver_message:
+        Account<'info, ValidatedTransceiverMessage<NativeTokenTransfer<Payload>>>,
+
+    #[account(
+        seeds = [
+            InboxItem::SEED_PREFIX,
+            transceiver_message.message.ntt_manager_payload.keccak256(transceiver_message.from_chain).as_ref(),
+        ],
+        bump,
+        seeds::program = example_native_token_transfers::ID,
+        constraint = InboxItem::is_done(&inbox_item)? @ NTTError::InboxItemNotReleased,
+    )]
+    /// CHECK: the seeds constraint ensures that this is the inbox item of the
+    /// message, and [`InboxItem::is_done`] checks the owner.
+    pub inbox_item: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    /// CHECK: the `transceiver_message` constraint enforces that this is the correct address
+    pub rent_payer: UncheckedAccount<'info>,
+}
+
+/// Closes a transceiver message once the inbox item it voted for has been
+/// released by the manager, returning the rent to the original payer.
+pub fn close_transceiver_message(_ctx: Context<CloseTransceiverMessage>) -> Result<()> {
+    Ok(())
+}
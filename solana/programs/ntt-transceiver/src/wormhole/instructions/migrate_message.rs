@@ -0,0 +1,82 @@
+use crate::messages::ValidatedTransceiverMessage;
+use anchor_lang::{prelude::*, system_program, Discriminator};
+use example_native_token_transfers::{config::Config, error::NTTError, legacy, transfer::Payload};
+use ntt_messages::ntt::NativeTokenTransfer;
+
+#[derive(Accounts)]
+pub struct MigrateTransceiverMessage<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    #[account(
+        has_one = owner,
+    )]
+    pub config: Account<'info, Config>,
+
+    pub owner: Signer<'info>,
+
+    #[account(
+        mut,
+        owner = crate::ID,
+    )]
+    /// CHECK: the message can't be deserialized before the migration. The
+    /// discriminator is checked in [`migrate_transceiver_message`].
+    pub transceiver_message: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Migrates a message received (but not redeemed yet) by the 3.0.0 release to
+/// the current layout, like [`example_native_token_transfers::instructions::migrate`]
+/// does for the manager's accounts. The rent is returned to `payer` (who pays
+/// for growing the message) when it's closed. The message was received before
+/// any peer could be retired, so its attestation time is left as zero.
+pub fn migrate_transceiver_message(ctx: Context<MigrateTransceiverMessage>) -> Result<()> {
+    type Message = ValidatedTransceiverMessage<NativeTokenTransfer<Payload>>;
+
+    let message = &ctx.accounts.transceiver_message;
+    if !message
+        .try_borrow_data()?
+        .starts_with(&Message::DISCRIMINATOR)
+    {
+        return Err(ErrorCode::AccountDiscriminatorMismatch.into());
+    }
+
+    let old_len = 8 + legacy::ValidatedTransceiverMessage::INIT_SPACE;
+    let new_len = 8 + Message::INIT_SPACE;
+    match message.data_len() {
+        len if len == new_len => return Err(NTTError::AccountAlreadyMigrated.into()),
+        len if len != old_len => return Err(ErrorCode::AccountDidNotDeserialize.into()),
+        _ => {}
+    }
+
+    let old =
+        legacy::ValidatedTransceiverMessage::deserialize(&mut &message.try_borrow_data()?[8..])?;
+
+    let missing_rent = Rent::get()?
+        .minimum_balance(new_len)
+        .saturating_sub(message.lamports());
+    if missing_rent > 0 {
+        system_program::transfer(
+            CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                system_program::Transfer {
+                    from: ctx.accounts.payer.to_account_info(),
+                    to: message.to_account_info(),
+                },
+            ),
+            missing_rent,
+        )?;
+    }
+
+    message.realloc(new_len, true)?;
+    Message {
+        from_chain: old.from_chain,
+        message: old.into_message_data(),
+        rent_payer: ctx.accounts.payer.key(),
+        attested_at: 0,
+    }
+    .try_serialize(&mut &mut message.try_borrow_mut_data()?[..])?;
+
+    Ok(())
+}
@@ -1,4 +1,6 @@
-use crate::{messages::ValidatedTransceiverMessage, peer::TransceiverPeer};
+use crate::{
+    events::WormholeMessageReceived, messages::ValidatedTransceiverMessage, peer::TransceiverPeer,
+};
 use anchor_lang::prelude::*;
 use example_native_token_transfers::{
     config::{anchor_reexports::*, *},
@@ -65,12 +67,21 @@ pub struct ReceiveMessage<'info> {
 pub fn receive_message(ctx: Context<ReceiveMessage>) -> Result<()> {
     let message = ctx.accounts.vaa.message().message_data.clone();
     let chain_id = ctx.accounts.vaa.emitter_chain();
+    let message_id = message.ntt_manager_payload.id;
     ctx.accounts
         .transceiver_message
         .set_inner(ValidatedTransceiverMessage {
             from_chain: ChainId { id: chain_id },
             message,
+            rent_payer: ctx.accounts.payer.key(),
+            attested_at: ctx.accounts.vaa.timestamp().into(),
         });
 
+    emit!(WormholeMessageReceived {
+        transceiver_message: ctx.accounts.transceiver_message.key(),
+        from_chain: ChainId { id: chain_id },
+        message_id,
+    });
+
     Ok(())
 }
@@ -1,4 +1,4 @@
-use crate::wormhole::accounts::*;
+use crate::{events::WormholeMessageSent, wormhole::accounts::*};
 use anchor_lang::prelude::*;
 use example_native_token_transfers::{
     config::{anchor_reexports::*, *},
@@ -61,6 +61,10 @@ pub struct ReleaseOutbound<'info> {
     )]
     /// CHECK: this PDA is used to sign the CPI into NTT manager program
     pub outbox_item_signer: UncheckedAccount<'info>,
+
+    /// CHECK: the peer of the recipient chain, checked (and required not to be
+    /// paused) by the NTT manager program
+    pub peer: UncheckedAccount<'info>,
 }
 
 impl<'info> ReleaseOutbound<'info> {
@@ -74,6 +78,7 @@ impl<'info> ReleaseOutbound<'info> {
                         config: self.config.config.to_account_info(),
                     },
                     outbox_item: self.outbox_item.to_account_info(),
+                    peer: self.peer.to_account_info(),
                     transceiver: self.transceiver.to_account_info(),
                 },
                 // signer seeds
@@ -117,7 +122,7 @@ pub fn release_outbound(ctx: Context<ReleaseOutbound>, args: ReleaseOutboundArgs
                     source_token: accs.config.mint.to_bytes(),
                     to: accs.outbox_item.recipient_address,
                     to_chain: accs.outbox_item.recipient_chain,
-                    additional_payload: Payload {},
+                    additional_payload: accs.outbox_item.additional_payload.clone(),
                 },
             },
             vec![],
@@ -137,5 +142,11 @@ pub fn release_outbound(ctx: Context<ReleaseOutbound>, args: ReleaseOutboundArgs
         ]],
     )?;
 
+    emit!(WormholeMessageSent {
+        outbox_item: accs.outbox_item.key(),
+        wormhole_message: accs.wormhole_message.key(),
+        recipient_chain: accs.outbox_item.recipient_chain,
+    });
+
     Ok(())
 }
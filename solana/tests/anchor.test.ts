@@ -36,7 +36,7 @@ import {
  * Test Config Constants
  */
 const SOLANA_ROOT_DIR = `${__dirname}/../`;
-const VERSION: IdlVersion = "3.0.0";
+const VERSION: IdlVersion = "4.0.0";
 const TOKEN_PROGRAM = spl.TOKEN_2022_PROGRAM_ID;
 const GUARDIAN_KEY =
   "cfb12303a19cde580bb4dd771639b0d26bc68353645571a8cff516ab2ee113a0";
@@ -208,9 +208,9 @@ describe("example-native-token-transfers", () => {
       const amount = 100_000n;
       const receiver = testing.utils.makeUniversalChainAddress("Ethereum");
 
-      // TODO: keep or remove the `outboxItem` param?
-      // added as a way to keep tests the same but it technically breaks the Ntt interface
-      const outboxItem = $.keypair.generate();
+      // the first transfer takes the first outbound sequence number
+      const outboxItem = await ntt.createOutboxItem();
+      expect(outboxItem).toEqual(ntt.pdas.outboxItemAccount(0));
       const xferTxs = ntt.transfer(
         sender,
         amount,
@@ -222,7 +222,7 @@ describe("example-native-token-transfers", () => {
 
       // assert that released bitmap has transceiver bits set
       const outboxItemInfo = await ntt.program.account.outboxItem.fetch(
-        outboxItem.publicKey
+        outboxItem as anchor.web3.PublicKey
       );
       expect(outboxItemInfo.released.map.bitLength()).toBe(
         Object.keys(nttTransceivers).length
@@ -231,7 +231,7 @@ describe("example-native-token-transfers", () => {
       const wormholeXcvr = await ntt.getWormholeTransceiver();
       expect(wormholeXcvr).toBeTruthy();
       const wormholeMessage = wormholeXcvr!.pdas.wormholeMessageAccount(
-        outboxItem.publicKey
+        outboxItem as anchor.web3.PublicKey
       );
       const unsignedVaa = await coreBridge.parsePostMessageAccount(
         wormholeMessage
@@ -563,7 +563,7 @@ describe("example-native-token-transfers", () => {
           { ntt: overrides["Solana"] },
           payerAddress
         );
-        expect(version).toBe("3.0.0");
+        expect(version).toBe("4.0.0");
       });
 
       test("It initializes using `emitterAccount` as transceiver address", async () => {
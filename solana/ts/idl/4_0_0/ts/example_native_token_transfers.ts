@@ -0,0 +1,13761 @@
+export type ExampleNativeTokenTransfers = {
+  "version": "4.0.0",
+  "name": "example_native_token_transfers",
+  "instructions": [
+    {
+      "name": "initialize",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "deployer",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "programData",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "config",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "mint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rateLimit",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenAuthority",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "In any case, this function is used to set the Config and initialize the program so we",
+            "assume the caller of this function will have total control over the program.",
+            "",
+            "TODO: Using `UncheckedAccount` here leads to \"Access violation in stack frame ...\".",
+            "Could refactor code to use `Box<_>` to reduce stack size."
+          ]
+        },
+        {
+          "name": "multisigTokenAuthority",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "custody",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "The custody account that holds tokens in locking mode and temporarily",
+            "holds tokens in burning mode.",
+            "function if the token account has already been created."
+          ]
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "associated token account for the given mint."
+          ]
+        },
+        {
+          "name": "associatedTokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "bpfLoaderUpgradeableProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "InitializeArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "initializeLut",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "owner",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "lutAddress",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "lut",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "lutProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "entries",
+          "accounts": [
+            {
+              "name": "config",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "custody",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "tokenProgram",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "mint",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "tokenAuthority",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "outboxRateLimit",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "wormhole",
+              "accounts": [
+                {
+                  "name": "bridge",
+                  "isMut": true,
+                  "isSigner": false
+                },
+                {
+                  "name": "feeCollector",
+                  "isMut": true,
+                  "isSigner": false
+                },
+                {
+                  "name": "sequence",
+                  "isMut": true,
+                  "isSigner": false
+                },
+                {
+                  "name": "program",
+                  "isMut": false,
+                  "isSigner": false
+                },
+                {
+                  "name": "systemProgram",
+                  "isMut": false,
+                  "isSigner": false
+                },
+                {
+                  "name": "clock",
+                  "isMut": false,
+                  "isSigner": false
+                },
+                {
+                  "name": "rent",
+                  "isMut": false,
+                  "isSigner": false
+                }
+              ]
+            }
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "recentSlot",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "version",
+      "accounts": [],
+      "args": [],
+      "returns": {
+        "defined": "VersionInfo"
+      }
+    },
+    {
+      "name": "transferBurn",
+      "accounts": [
+        {
+          "name": "common",
+          "accounts": [
+            {
+              "name": "payer",
+              "isMut": true,
+              "isSigner": true
+            },
+            {
+              "name": "config",
+              "accounts": [
+                {
+                  "name": "config",
+                  "isMut": false,
+                  "isSigner": false
+                }
+              ]
+            },
+            {
+              "name": "mint",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "from",
+              "isMut": true,
+              "isSigner": false,
+              "docs": [
+                "account can spend these tokens."
+              ]
+            },
+            {
+              "name": "tokenProgram",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "outboxSequence",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "outboxItem",
+              "isMut": true,
+              "isSigner": false,
+              "docs": [
+                "NOTE: the item is derived from the next sequence number, so if another",
+                "transfer takes it first, this transfer fails instead of being sent",
+                "twice. Clients can then retry with the new sequence number."
+              ]
+            },
+            {
+              "name": "outboxRateLimit",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "custody",
+              "isMut": true,
+              "isSigner": false,
+              "docs": [
+                "Tokens are always transferred to the custody account first regardless of",
+                "the mode.",
+                "For an explanation, see the note in [`transfer_burn`]."
+              ]
+            },
+            {
+              "name": "systemProgram",
+              "isMut": false,
+              "isSigner": false
+            }
+          ]
+        },
+        {
+          "name": "inboxRateLimit",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "outboxChainRateLimit",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "peer",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "sessionAuthority",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "See [`crate::SESSION_AUTHORITY_SEED`] for an explanation of the flow."
+          ]
+        },
+        {
+          "name": "tokenAuthority",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "TransferArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "transferLock",
+      "accounts": [
+        {
+          "name": "common",
+          "accounts": [
+            {
+              "name": "payer",
+              "isMut": true,
+              "isSigner": true
+            },
+            {
+              "name": "config",
+              "accounts": [
+                {
+                  "name": "config",
+                  "isMut": false,
+                  "isSigner": false
+                }
+              ]
+            },
+            {
+              "name": "mint",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "from",
+              "isMut": true,
+              "isSigner": false,
+              "docs": [
+                "account can spend these tokens."
+              ]
+            },
+            {
+              "name": "tokenProgram",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "outboxSequence",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "outboxItem",
+              "isMut": true,
+              "isSigner": false,
+              "docs": [
+                "NOTE: the item is derived from the next sequence number, so if another",
+                "transfer takes it first, this transfer fails instead of being sent",
+                "twice. Clients can then retry with the new sequence number."
+              ]
+            },
+            {
+              "name": "outboxRateLimit",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "custody",
+              "isMut": true,
+              "isSigner": false,
+              "docs": [
+                "Tokens are always transferred to the custody account first regardless of",
+                "the mode.",
+                "For an explanation, see the note in [`transfer_burn`]."
+              ]
+            },
+            {
+              "name": "systemProgram",
+              "isMut": false,
+              "isSigner": false
+            }
+          ]
+        },
+        {
+          "name": "inboxRateLimit",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "outboxChainRateLimit",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "peer",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "sessionAuthority",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "See [`crate::SESSION_AUTHORITY_SEED`] for an explanation of the flow."
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "TransferArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "transferBurnWithPayload",
+      "accounts": [
+        {
+          "name": "common",
+          "accounts": [
+            {
+              "name": "payer",
+              "isMut": true,
+              "isSigner": true
+            },
+            {
+              "name": "config",
+              "accounts": [
+                {
+                  "name": "config",
+                  "isMut": false,
+                  "isSigner": false
+                }
+              ]
+            },
+            {
+              "name": "mint",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "from",
+              "isMut": true,
+              "isSigner": false,
+              "docs": [
+                "account can spend these tokens."
+              ]
+            },
+            {
+              "name": "tokenProgram",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "outboxSequence",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "outboxItem",
+              "isMut": true,
+              "isSigner": false,
+              "docs": [
+                "NOTE: the item is derived from the next sequence number, so if another",
+                "transfer takes it first, this transfer fails instead of being sent",
+                "twice. Clients can then retry with the new sequence number."
+              ]
+            },
+            {
+              "name": "outboxRateLimit",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "custody",
+              "isMut": true,
+              "isSigner": false,
+              "docs": [
+                "Tokens are always transferred to the custody account first regardless of",
+                "the mode.",
+                "For an explanation, see the note in [`transfer_burn`]."
+              ]
+            },
+            {
+              "name": "systemProgram",
+              "isMut": false,
+              "isSigner": false
+            }
+          ]
+        },
+        {
+          "name": "inboxRateLimit",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "outboxChainRateLimit",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "peer",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "sessionAuthority",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "See [`crate::SESSION_AUTHORITY_SEED`] for an explanation of the flow."
+          ]
+        },
+        {
+          "name": "tokenAuthority",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "TransferWithPayloadArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "transferLockWithPayload",
+      "accounts": [
+        {
+          "name": "common",
+          "accounts": [
+            {
+              "name": "payer",
+              "isMut": true,
+              "isSigner": true
+            },
+            {
+              "name": "config",
+              "accounts": [
+                {
+                  "name": "config",
+                  "isMut": false,
+                  "isSigner": false
+                }
+              ]
+            },
+            {
+              "name": "mint",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "from",
+              "isMut": true,
+              "isSigner": false,
+              "docs": [
+                "account can spend these tokens."
+              ]
+            },
+            {
+              "name": "tokenProgram",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "outboxSequence",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "outboxItem",
+              "isMut": true,
+              "isSigner": false,
+              "docs": [
+                "NOTE: the item is derived from the next sequence number, so if another",
+                "transfer takes it first, this transfer fails instead of being sent",
+                "twice. Clients can then retry with the new sequence number."
+              ]
+            },
+            {
+              "name": "outboxRateLimit",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "custody",
+              "isMut": true,
+              "isSigner": false,
+              "docs": [
+                "Tokens are always transferred to the custody account first regardless of",
+                "the mode.",
+                "For an explanation, see the note in [`transfer_burn`]."
+              ]
+            },
+            {
+              "name": "systemProgram",
+              "isMut": false,
+              "isSigner": false
+            }
+          ]
+        },
+        {
+          "name": "inboxRateLimit",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "outboxChainRateLimit",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "peer",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "sessionAuthority",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "See [`crate::SESSION_AUTHORITY_SEED`] for an explanation of the flow."
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "TransferWithPayloadArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "cancelOutboundQueuedTransfer",
+      "accounts": [
+        {
+          "name": "sender",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "config",
+          "accounts": [
+            {
+              "name": "config",
+              "isMut": false,
+              "isSigner": false
+            }
+          ]
+        },
+        {
+          "name": "outboxItem",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "mint",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "senderTokenAccount",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "The token account the tokens are returned to. Must be owned by the",
+            "original sender."
+          ]
+        },
+        {
+          "name": "tokenAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "custody",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "outboxRateLimit",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "outboxChainRateLimit",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "inboxRateLimit",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "multisigTokenAuthority",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Only used in burning mode, when the mint authority is a multisig."
+          ]
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "redeem",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "peer",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "transceiverMessage",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "`Account<T>` and `owner` constraints are mutually-exclusive"
+          ]
+        },
+        {
+          "name": "transceiver",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "mint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "inboxItem",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "NOTE: This account is content-addressed (PDA seeded by the message hash).",
+            "This is because in a multi-transceiver configuration, the different",
+            "transceivers \"vote\" on messages (by delivering them). By making the inbox",
+            "items content-addressed, we can ensure that disagreeing votes don't",
+            "interfere with each other.",
+            "On the first call to [`redeem()`], [`InboxItem`] will be allocated and initialized with",
+            "default values.",
+            "On subsequent calls, we want to modify the `InboxItem` by \"voting\" on it. Therefore the",
+            "program should not fail which would occur when using the `init` constraint.",
+            "The [`InboxItem::init`] field is used to guard against malicious or accidental modification",
+            "InboxItem fields that should remain constant."
+          ]
+        },
+        {
+          "name": "inboxRateLimit",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "outboxRateLimit",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "outboxChainRateLimit",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "RedeemArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "reevaluateInboxItem",
+      "accounts": [
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "peer",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "inboxItem",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "inboxRateLimit",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "outboxRateLimit",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "outboxChainRateLimit",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "freezeInboxItem",
+      "accounts": [
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": true,
+          "docs": [
+            "The owner, or the holder of [`Role::Guardian`]."
+          ]
+        },
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "inboxItem",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "unfreezeInboxItem",
+      "accounts": [
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": true,
+          "docs": [
+            "The owner, or the holder of [`Role::Guardian`]."
+          ]
+        },
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "inboxItem",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "rejectInboxItem",
+      "accounts": [
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": true,
+          "docs": [
+            "The owner, or the holder of [`Role::Guardian`]."
+          ]
+        },
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "inboxItem",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "forceReleaseInboxItem",
+      "accounts": [
+        {
+          "name": "owner",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "inboxItem",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "releaseInboundMint",
+      "accounts": [
+        {
+          "name": "common",
+          "accounts": [
+            {
+              "name": "payer",
+              "isMut": true,
+              "isSigner": true
+            },
+            {
+              "name": "config",
+              "accounts": [
+                {
+                  "name": "config",
+                  "isMut": false,
+                  "isSigner": false
+                }
+              ]
+            },
+            {
+              "name": "inboxItem",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "peer",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "recipient",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "tokenAuthority",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "mint",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "tokenProgram",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "custody",
+              "isMut": true,
+              "isSigner": false
+            }
+          ]
+        },
+        {
+          "name": "multisigTokenAuthority",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "ReleaseInboundArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "releaseInboundUnlock",
+      "accounts": [
+        {
+          "name": "common",
+          "accounts": [
+            {
+              "name": "payer",
+              "isMut": true,
+              "isSigner": true
+            },
+            {
+              "name": "config",
+              "accounts": [
+                {
+                  "name": "config",
+                  "isMut": false,
+                  "isSigner": false
+                }
+              ]
+            },
+            {
+              "name": "inboxItem",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "peer",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "recipient",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "tokenAuthority",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "mint",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "tokenProgram",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "custody",
+              "isMut": true,
+              "isSigner": false
+            }
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "ReleaseInboundArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "releaseInboundMintAndCall",
+      "accounts": [
+        {
+          "name": "release",
+          "accounts": [
+            {
+              "name": "common",
+              "accounts": [
+                {
+                  "name": "payer",
+                  "isMut": true,
+                  "isSigner": true
+                },
+                {
+                  "name": "config",
+                  "accounts": [
+                    {
+                      "name": "config",
+                      "isMut": false,
+                      "isSigner": false
+                    }
+                  ]
+                },
+                {
+                  "name": "inboxItem",
+                  "isMut": true,
+                  "isSigner": false
+                },
+                {
+                  "name": "peer",
+                  "isMut": false,
+                  "isSigner": false
+                },
+                {
+                  "name": "recipient",
+                  "isMut": true,
+                  "isSigner": false
+                },
+                {
+                  "name": "tokenAuthority",
+                  "isMut": false,
+                  "isSigner": false
+                },
+                {
+                  "name": "mint",
+                  "isMut": true,
+                  "isSigner": false
+                },
+                {
+                  "name": "tokenProgram",
+                  "isMut": false,
+                  "isSigner": false
+                },
+                {
+                  "name": "custody",
+                  "isMut": true,
+                  "isSigner": false
+                }
+              ]
+            },
+            {
+              "name": "multisigTokenAuthority",
+              "isMut": false,
+              "isSigner": false,
+              "isOptional": true
+            }
+          ]
+        },
+        {
+          "name": "call",
+          "accounts": [
+            {
+              "name": "callAuthority",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "recipientProgram",
+              "isMut": false,
+              "isSigner": false
+            }
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "ReleaseInboundAndCallArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "releaseInboundUnlockAndCall",
+      "accounts": [
+        {
+          "name": "release",
+          "accounts": [
+            {
+              "name": "common",
+              "accounts": [
+                {
+                  "name": "payer",
+                  "isMut": true,
+                  "isSigner": true
+                },
+                {
+                  "name": "config",
+                  "accounts": [
+                    {
+                      "name": "config",
+                      "isMut": false,
+                      "isSigner": false
+                    }
+                  ]
+                },
+                {
+                  "name": "inboxItem",
+                  "isMut": true,
+                  "isSigner": false
+                },
+                {
+                  "name": "peer",
+                  "isMut": false,
+                  "isSigner": false
+                },
+                {
+                  "name": "recipient",
+                  "isMut": true,
+                  "isSigner": false
+                },
+                {
+                  "name": "tokenAuthority",
+                  "isMut": false,
+                  "isSigner": false
+                },
+                {
+                  "name": "mint",
+                  "isMut": true,
+                  "isSigner": false
+                },
+                {
+                  "name": "tokenProgram",
+                  "isMut": false,
+                  "isSigner": false
+                },
+                {
+                  "name": "custody",
+                  "isMut": true,
+                  "isSigner": false
+                }
+              ]
+            }
+          ]
+        },
+        {
+          "name": "call",
+          "accounts": [
+            {
+              "name": "callAuthority",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "recipientProgram",
+              "isMut": false,
+              "isSigner": false
+            }
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "ReleaseInboundAndCallArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "closeOutboxItem",
+      "accounts": [
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "outboxItem",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "rentPayer",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "closeInboxItem",
+      "accounts": [
+        {
+          "name": "inboxItem",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "`Account<InboxItem>` here, because anchor would serialize the item back",
+            "into the account at the end of the instruction, overwriting the",
+            "tombstone."
+          ]
+        },
+        {
+          "name": "rentPayer",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "escrowInboxItem",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "owner",
+          "isMut": false,
+          "isSigner": true,
+          "isOptional": true,
+          "docs": [
+            "The owner, to escrow the transfer before [`Config::escrow_timeout`] has",
+            "passed (or when it's disabled)."
+          ]
+        },
+        {
+          "name": "config",
+          "accounts": [
+            {
+              "name": "config",
+              "isMut": false,
+              "isSigner": false
+            }
+          ]
+        },
+        {
+          "name": "inboxItem",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "multisigTokenAuthority",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "escrowAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "escrow",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "mint",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "custody",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "associatedTokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "redirectEscrowedInboxItem",
+      "accounts": [
+        {
+          "name": "owner",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "inboxItem",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "escrowAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "escrow",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "recipient",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "The token account the tokens are sent to. It can be owned by anyone."
+          ]
+        },
+        {
+          "name": "mint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "returnEscrowedInboxItem",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "owner",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "inboxItem",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "peer",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "escrowAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "escrow",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "mint",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "custody",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "outboxSequence",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "outboxItem",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "outboxRateLimit",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "inboxRateLimit",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "outboxChainRateLimit",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "transferOwnership",
+      "accounts": [
+        {
+          "name": "config",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "owner",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "newOwner",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "upgradeLock",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "programData",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "bpfLoaderUpgradeableProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "transferOwnershipOneStepUnchecked",
+      "accounts": [
+        {
+          "name": "config",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "owner",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "newOwner",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "upgradeLock",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "programData",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "bpfLoaderUpgradeableProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "claimOwnership",
+      "accounts": [
+        {
+          "name": "config",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "upgradeLock",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "newOwner",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "programData",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "bpfLoaderUpgradeableProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "acceptTokenAuthority",
+      "accounts": [
+        {
+          "name": "common",
+          "accounts": [
+            {
+              "name": "config",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "mint",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "tokenAuthority",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "multisigTokenAuthority",
+              "isMut": false,
+              "isSigner": false,
+              "isOptional": true
+            },
+            {
+              "name": "tokenProgram",
+              "isMut": false,
+              "isSigner": false
+            }
+          ]
+        },
+        {
+          "name": "currentAuthority",
+          "isMut": false,
+          "isSigner": true
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "acceptTokenAuthorityFromMultisig",
+      "accounts": [
+        {
+          "name": "common",
+          "accounts": [
+            {
+              "name": "config",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "mint",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "tokenAuthority",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "multisigTokenAuthority",
+              "isMut": false,
+              "isSigner": false,
+              "isOptional": true
+            },
+            {
+              "name": "tokenProgram",
+              "isMut": false,
+              "isSigner": false
+            }
+          ]
+        },
+        {
+          "name": "currentMultisigAuthority",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "setTokenAuthorityOneStepUnchecked",
+      "accounts": [
+        {
+          "name": "common",
+          "accounts": [
+            {
+              "name": "config",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "owner",
+              "isMut": false,
+              "isSigner": true
+            },
+            {
+              "name": "mint",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "tokenAuthority",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "multisigTokenAuthority",
+              "isMut": false,
+              "isSigner": false,
+              "isOptional": true
+            },
+            {
+              "name": "newAuthority",
+              "isMut": false,
+              "isSigner": false
+            }
+          ]
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "setTokenAuthority",
+      "accounts": [
+        {
+          "name": "common",
+          "accounts": [
+            {
+              "name": "config",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "owner",
+              "isMut": false,
+              "isSigner": true
+            },
+            {
+              "name": "mint",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "tokenAuthority",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "multisigTokenAuthority",
+              "isMut": false,
+              "isSigner": false,
+              "isOptional": true
+            },
+            {
+              "name": "newAuthority",
+              "isMut": false,
+              "isSigner": false
+            }
+          ]
+        },
+        {
+          "name": "rentPayer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "pendingTokenAuthority",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "revertTokenAuthority",
+      "accounts": [
+        {
+          "name": "common",
+          "accounts": [
+            {
+              "name": "config",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "mint",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "tokenAuthority",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "multisigTokenAuthority",
+              "isMut": false,
+              "isSigner": false,
+              "isOptional": true
+            },
+            {
+              "name": "rentPayer",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "pendingTokenAuthority",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "tokenProgram",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "systemProgram",
+              "isMut": false,
+              "isSigner": false
+            }
+          ]
+        },
+        {
+          "name": "owner",
+          "isMut": false,
+          "isSigner": true
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "claimTokenAuthority",
+      "accounts": [
+        {
+          "name": "common",
+          "accounts": [
+            {
+              "name": "config",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "mint",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "tokenAuthority",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "multisigTokenAuthority",
+              "isMut": false,
+              "isSigner": false,
+              "isOptional": true
+            },
+            {
+              "name": "rentPayer",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "pendingTokenAuthority",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "tokenProgram",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "systemProgram",
+              "isMut": false,
+              "isSigner": false
+            }
+          ]
+        },
+        {
+          "name": "newAuthority",
+          "isMut": false,
+          "isSigner": true
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "claimTokenAuthorityToMultisig",
+      "accounts": [
+        {
+          "name": "common",
+          "accounts": [
+            {
+              "name": "config",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "mint",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "tokenAuthority",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "multisigTokenAuthority",
+              "isMut": false,
+              "isSigner": false,
+              "isOptional": true
+            },
+            {
+              "name": "rentPayer",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "pendingTokenAuthority",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "tokenProgram",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "systemProgram",
+              "isMut": false,
+              "isSigner": false
+            }
+          ]
+        },
+        {
+          "name": "newMultisigAuthority",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "setPaused",
+      "accounts": [
+        {
+          "name": "owner",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "config",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "pause",
+          "type": "bool"
+        }
+      ]
+    },
+    {
+      "name": "pause",
+      "accounts": [
+        {
+          "name": "pauser",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "config",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "grantRole",
+      "accounts": [
+        {
+          "name": "owner",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "config",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "role",
+          "type": {
+            "defined": "Role"
+          }
+        },
+        {
+          "name": "holder",
+          "type": "publicKey"
+        }
+      ]
+    },
+    {
+      "name": "revokeRole",
+      "accounts": [
+        {
+          "name": "owner",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "config",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "role",
+          "type": {
+            "defined": "Role"
+          }
+        }
+      ]
+    },
+    {
+      "name": "setOutboundPaused",
+      "accounts": [
+        {
+          "name": "owner",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "config",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "paused",
+          "type": "bool"
+        }
+      ]
+    },
+    {
+      "name": "setInboundPaused",
+      "accounts": [
+        {
+          "name": "owner",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "config",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "paused",
+          "type": "bool"
+        }
+      ]
+    },
+    {
+      "name": "setPeerPaused",
+      "accounts": [
+        {
+          "name": "owner",
+          "isMut": false,
+          "isSigner": true,
+          "docs": [
+            "The owner, or the holder of [`Role::PeerAdmin`]."
+          ]
+        },
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "peer",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "SetPeerPausedArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "pauseOutbound",
+      "accounts": [
+        {
+          "name": "pauser",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "config",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "pauseInbound",
+      "accounts": [
+        {
+          "name": "pauser",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "config",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "pausePeer",
+      "accounts": [
+        {
+          "name": "pauser",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "peer",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "PausePeerArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "setPeerThreshold",
+      "accounts": [
+        {
+          "name": "owner",
+          "isMut": false,
+          "isSigner": true,
+          "docs": [
+            "The owner, or the holder of [`Role::TransceiverAdmin`]."
+          ]
+        },
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "peer",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "SetPeerThresholdArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "executeSetPeerThreshold",
+      "accounts": [
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rentPayer",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "pendingChange",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "peer",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "SetPeerThresholdArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "setLegacyPeerAddresses",
+      "accounts": [
+        {
+          "name": "owner",
+          "isMut": false,
+          "isSigner": true,
+          "docs": [
+            "The owner, or the holder of [`Role::PeerAdmin`]."
+          ]
+        },
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "peer",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "SetLegacyPeerAddressesArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "executeSetLegacyPeerAddresses",
+      "accounts": [
+        {
+          "name": "rentPayer",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "pendingChange",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "peer",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "SetLegacyPeerAddressesArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "removePeer",
+      "accounts": [
+        {
+          "name": "owner",
+          "isMut": false,
+          "isSigner": true,
+          "docs": [
+            "The owner, or the holder of [`Role::PeerAdmin`]."
+          ]
+        },
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "peer",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "RemovePeerArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "closePeer",
+      "accounts": [
+        {
+          "name": "owner",
+          "isMut": false,
+          "isSigner": true,
+          "docs": [
+            "The owner, or the holder of [`Role::PeerAdmin`]."
+          ]
+        },
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "peer",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "inboxRateLimit",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "outboxChainRateLimit",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "rentRecipient",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "ClosePeerArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "setPreciseQueueRelease",
+      "accounts": [
+        {
+          "name": "owner",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "config",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "enabled",
+          "type": "bool"
+        }
+      ]
+    },
+    {
+      "name": "setStrictDust",
+      "accounts": [
+        {
+          "name": "owner",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "config",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "enabled",
+          "type": "bool"
+        }
+      ]
+    },
+    {
+      "name": "setEscrowTimeout",
+      "accounts": [
+        {
+          "name": "owner",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "config",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "timeout",
+          "type": "i64"
+        }
+      ]
+    },
+    {
+      "name": "setDenylistEnabled",
+      "accounts": [
+        {
+          "name": "owner",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "config",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "enabled",
+          "type": "bool"
+        }
+      ]
+    },
+    {
+      "name": "setPolicyProgram",
+      "accounts": [
+        {
+          "name": "owner",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "config",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "policyProgram",
+          "type": {
+            "option": "publicKey"
+          }
+        }
+      ]
+    },
+    {
+      "name": "addToDenylist",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": true,
+          "docs": [
+            "The owner, or the holder of [`Role::ComplianceAdmin`]."
+          ]
+        },
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "entry",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "address",
+          "type": {
+            "array": [
+              "u8",
+              32
+            ]
+          }
+        }
+      ]
+    },
+    {
+      "name": "removeFromDenylist",
+      "accounts": [
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": true,
+          "docs": [
+            "The owner, or the holder of [`Role::ComplianceAdmin`]."
+          ]
+        },
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "entry",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "rentPayer",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "address",
+          "type": {
+            "array": [
+              "u8",
+              32
+            ]
+          }
+        }
+      ]
+    },
+    {
+      "name": "unquarantineInboxItem",
+      "accounts": [
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": true,
+          "docs": [
+            "The owner, or the holder of [`Role::ComplianceAdmin`]."
+          ]
+        },
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "inboxItem",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "setTimelockDelay",
+      "accounts": [
+        {
+          "name": "owner",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "config",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "delay",
+          "type": "i64"
+        }
+      ]
+    },
+    {
+      "name": "proposeChange",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "proposer",
+          "isMut": false,
+          "isSigner": true,
+          "docs": [
+            "The owner, or the holder of the role that can make `change` directly",
+            "(see [`ConfigChange::can_propose`])."
+          ]
+        },
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "pendingChange",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "change",
+          "type": {
+            "defined": "ConfigChange"
+          }
+        }
+      ]
+    },
+    {
+      "name": "vetoChange",
+      "accounts": [
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": true,
+          "docs": [
+            "The owner, or the holder of [`Role::Guardian`]."
+          ]
+        },
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rentPayer",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "pendingChange",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "executeChange",
+      "accounts": [
+        {
+          "name": "config",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "rentPayer",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "pendingChange",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "executeSetPeer",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rentPayer",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "pendingChange",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "peer",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "inboxRateLimit",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "outboxChainRateLimit",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "SetPeerArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "executeRegisterTransceiver",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "config",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "transceiver",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rentPayer",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "pendingChange",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "registeredTransceiver",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "executeSetTokenAuthority",
+      "accounts": [
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "mint",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "multisigTokenAuthority",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "newAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rentPayer",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "pendingChange",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "executeDeregisterTransceiver",
+      "accounts": [
+        {
+          "name": "config",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "registeredTransceiver",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rentPayer",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "pendingChange",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "executeSetOutboundLimit",
+      "accounts": [
+        {
+          "name": "rentPayer",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "pendingChange",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "rateLimit",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "SetOutboundLimitArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "executeSetOutboundChainLimit",
+      "accounts": [
+        {
+          "name": "rentPayer",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "pendingChange",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "rateLimit",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "SetOutboundChainLimitArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "executeSetInboundLimit",
+      "accounts": [
+        {
+          "name": "rentPayer",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "pendingChange",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "rateLimit",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "SetInboundLimitArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "executeSetOutboundRateLimitDuration",
+      "accounts": [
+        {
+          "name": "rentPayer",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "pendingChange",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "rateLimit",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "SetOutboundRateLimitDurationArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "executeSetOutboundChainRateLimitDuration",
+      "accounts": [
+        {
+          "name": "rentPayer",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "pendingChange",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "rateLimit",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "SetOutboundChainRateLimitDurationArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "executeSetInboundRateLimitDuration",
+      "accounts": [
+        {
+          "name": "rentPayer",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "pendingChange",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "rateLimit",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "SetInboundRateLimitDurationArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "setPeer",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "owner",
+          "isMut": false,
+          "isSigner": true,
+          "docs": [
+            "The owner, or the holder of [`Role::PeerAdmin`]."
+          ]
+        },
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "peer",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "inboxRateLimit",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "outboxChainRateLimit",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "SetPeerArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "registerTransceiver",
+      "accounts": [
+        {
+          "name": "config",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "owner",
+          "isMut": false,
+          "isSigner": true,
+          "docs": [
+            "The owner, or the holder of [`Role::TransceiverAdmin`]."
+          ]
+        },
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "transceiver",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "used here that wraps the Transceiver account type."
+          ]
+        },
+        {
+          "name": "registeredTransceiver",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "deregisterTransceiver",
+      "accounts": [
+        {
+          "name": "config",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "owner",
+          "isMut": false,
+          "isSigner": true,
+          "docs": [
+            "The owner, or the holder of [`Role::TransceiverAdmin`]."
+          ]
+        },
+        {
+          "name": "registeredTransceiver",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "setOutboundLimit",
+      "accounts": [
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "owner",
+          "isMut": false,
+          "isSigner": true,
+          "docs": [
+            "The owner, or the holder of [`Role::RateLimitAdmin`]."
+          ]
+        },
+        {
+          "name": "rateLimit",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "SetOutboundLimitArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "setOutboundChainLimit",
+      "accounts": [
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "owner",
+          "isMut": false,
+          "isSigner": true,
+          "docs": [
+            "The owner, or the holder of [`Role::RateLimitAdmin`]."
+          ]
+        },
+        {
+          "name": "rateLimit",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "SetOutboundChainLimitArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "setInboundLimit",
+      "accounts": [
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "owner",
+          "isMut": false,
+          "isSigner": true,
+          "docs": [
+            "The owner, or the holder of [`Role::RateLimitAdmin`]."
+          ]
+        },
+        {
+          "name": "rateLimit",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "SetInboundLimitArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "setOutboundRateLimitDuration",
+      "accounts": [
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "owner",
+          "isMut": false,
+          "isSigner": true,
+          "docs": [
+            "The owner, or the holder of [`Role::RateLimitAdmin`]."
+          ]
+        },
+        {
+          "name": "rateLimit",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "SetOutboundRateLimitDurationArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "setOutboundChainRateLimitDuration",
+      "accounts": [
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "owner",
+          "isMut": false,
+          "isSigner": true,
+          "docs": [
+            "The owner, or the holder of [`Role::RateLimitAdmin`]."
+          ]
+        },
+        {
+          "name": "rateLimit",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "SetOutboundChainRateLimitDurationArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "setInboundRateLimitDuration",
+      "accounts": [
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "owner",
+          "isMut": false,
+          "isSigner": true,
+          "docs": [
+            "The owner, or the holder of [`Role::RateLimitAdmin`]."
+          ]
+        },
+        {
+          "name": "rateLimit",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "SetInboundRateLimitDurationArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "migrate",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "owner",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "so only its owner is read in [`migrate`]. It comes before the fields",
+            "added since, so its offset is the same in every layout."
+          ]
+        },
+        {
+          "name": "account",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "discriminator is checked in [`migrate`]."
+          ]
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "MigrateArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "initOutboxChainRateLimit",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "peer",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "outboxChainRateLimit",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "InitOutboxChainRateLimitArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "markOutboxItemAsReleased",
+      "accounts": [
+        {
+          "name": "signer",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "config",
+          "accounts": [
+            {
+              "name": "config",
+              "isMut": false,
+              "isSigner": false
+            }
+          ]
+        },
+        {
+          "name": "outboxItem",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "peer",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "transceiver",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [],
+      "returns": "bool"
+    },
+    {
+      "name": "setThreshold",
+      "accounts": [
+        {
+          "name": "owner",
+          "isMut": false,
+          "isSigner": true,
+          "docs": [
+            "The owner, or the holder of [`Role::TransceiverAdmin`]."
+          ]
+        },
+        {
+          "name": "config",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "threshold",
+          "type": "u8"
+        }
+      ]
+    },
+    {
+      "name": "setWormholePeer",
+      "accounts": [
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "owner",
+          "isMut": false,
+          "isSigner": true,
+          "docs": [
+            "The owner, or the holder of [`Role::TransceiverAdmin`]."
+          ]
+        },
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "peer",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "SetTransceiverPeerArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "receiveWormholeMessage",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "config",
+          "accounts": [
+            {
+              "name": "config",
+              "isMut": false,
+              "isSigner": false
+            }
+          ]
+        },
+        {
+          "name": "peer",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "vaa",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "transceiverMessage",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "releaseWormholeOutbound",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "config",
+          "accounts": [
+            {
+              "name": "config",
+              "isMut": false,
+              "isSigner": false
+            }
+          ]
+        },
+        {
+          "name": "outboxItem",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "peer",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "transceiver",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "wormholeMessage",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "emitter",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "wormhole",
+          "accounts": [
+            {
+              "name": "bridge",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "feeCollector",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "sequence",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "program",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "systemProgram",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "clock",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "rent",
+              "isMut": false,
+              "isSigner": false
+            }
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "ReleaseOutboundArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "closeWormholeTransceiverMessage",
+      "accounts": [
+        {
+          "name": "transceiverMessage",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "inboxItem",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "message, and [`InboxItem::is_done`] checks the owner."
+          ]
+        },
+        {
+          "name": "rentPayer",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "broadcastWormholeId",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "mint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "wormholeMessage",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "emitter",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "enforced by the [`CpiContext`] call in [`post_message`].",
+            "The seeds constraint ensures that this is the correct address"
+          ]
+        },
+        {
+          "name": "wormhole",
+          "accounts": [
+            {
+              "name": "bridge",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "feeCollector",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "sequence",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "program",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "systemProgram",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "clock",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "rent",
+              "isMut": false,
+              "isSigner": false
+            }
+          ]
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "broadcastWormholePeer",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "peer",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "wormholeMessage",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "emitter",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "wormhole",
+          "accounts": [
+            {
+              "name": "bridge",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "feeCollector",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "sequence",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "program",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "systemProgram",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "clock",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "rent",
+              "isMut": false,
+              "isSigner": false
+            }
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "BroadcastPeerArgs"
+          }
+        }
+      ]
+    }
+  ],
+  "accounts": [
+    {
+      "name": "denylistEntry",
+      "docs": [
+        "Marks an address as denied. Stored in a PDA seeded by the address."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "rentPayer",
+            "docs": [
+              "The account that paid the rent for this entry. The rent is returned to",
+              "this account when the entry is removed."
+            ],
+            "type": "publicKey"
+          }
+        ]
+      }
+    },
+    {
+      "name": "config",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "owner",
+            "docs": [
+              "Owner of the program."
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "pendingOwner",
+            "docs": [
+              "Pending next owner (before claiming ownership)."
+            ],
+            "type": {
+              "option": "publicKey"
+            }
+          },
+          {
+            "name": "mint",
+            "docs": [
+              "Mint address of the token managed by this program."
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "tokenProgram",
+            "docs": [
+              "Address of the token program (token or token22). This could always be queried",
+              "from the [`mint`] account's owner, but storing it here avoids an indirection",
+              "on the client side."
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "mode",
+            "docs": [
+              "The mode that this program is running in. This is used to determine",
+              "whether the program is burning tokens or locking tokens."
+            ],
+            "type": {
+              "defined": "Mode"
+            }
+          },
+          {
+            "name": "chainId",
+            "docs": [
+              "The chain id of the chain that this program is running on. We don't",
+              "hardcode this so that the program is deployable on any potential SVM",
+              "forks."
+            ],
+            "type": {
+              "defined": "ChainId"
+            }
+          },
+          {
+            "name": "nextTransceiverId",
+            "docs": [
+              "The next transceiver id to use when registering an transceiver."
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "threshold",
+            "docs": [
+              "The number of transceivers that must attest to a transfer before it is",
+              "accepted."
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "enabledTransceivers",
+            "docs": [
+              "Bitmap of enabled transceivers.",
+              "The maximum number of transceivers is equal to [`Bitmap::BITS`]."
+            ],
+            "type": {
+              "defined": "Bitmap"
+            }
+          },
+          {
+            "name": "paused",
+            "docs": [
+              "Pause the program. This is useful for upgrades and other maintenance."
+            ],
+            "type": "bool"
+          },
+          {
+            "name": "custody",
+            "docs": [
+              "The custody account that holds tokens in locking mode."
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "preciseQueueRelease",
+            "docs": [
+              "When a transfer exceeds the rate limit, reserve the missing capacity",
+              "and queue the transfer only until it has refilled, instead of delaying",
+              "it by a full rate limit window.",
+              "See [`crate::queue::rate_limit::RateLimitState::consume_or_reserve_all`]."
+            ],
+            "type": "bool"
+          },
+          {
+            "name": "strictDust",
+            "docs": [
+              "Reject outbound transfers whose amount has dust (i.e. it doesn't trim",
+              "cleanly to the recipient chain's decimals), instead of silently",
+              "transferring the amount with the dust removed."
+            ],
+            "type": "bool"
+          },
+          {
+            "name": "pauser",
+            "docs": [
+              "Holder of [`Role::Pauser`]."
+            ],
+            "type": {
+              "option": "publicKey"
+            }
+          },
+          {
+            "name": "outboundPaused",
+            "docs": [
+              "Pause outbound transfers (and releasing queued outbound transfers),",
+              "while inbound transfers keep working."
+            ],
+            "type": "bool"
+          },
+          {
+            "name": "inboundPaused",
+            "docs": [
+              "Pause releasing inbound transfers, while they can still be redeemed",
+              "(and queued)."
+            ],
+            "type": "bool"
+          },
+          {
+            "name": "rateLimitAdmin",
+            "docs": [
+              "Holder of [`Role::RateLimitAdmin`]."
+            ],
+            "type": {
+              "option": "publicKey"
+            }
+          },
+          {
+            "name": "peerAdmin",
+            "docs": [
+              "Holder of [`Role::PeerAdmin`]."
+            ],
+            "type": {
+              "option": "publicKey"
+            }
+          },
+          {
+            "name": "transceiverAdmin",
+            "docs": [
+              "Holder of [`Role::TransceiverAdmin`]."
+            ],
+            "type": {
+              "option": "publicKey"
+            }
+          },
+          {
+            "name": "timelockDelay",
+            "docs": [
+              "Delay (in seconds) between proposing a security-sensitive change and",
+              "executing it (see [`crate::pending_change::ConfigChange`]). Zero",
+              "disables the timelock, in which case these changes are made directly."
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "guardian",
+            "docs": [
+              "Holder of [`Role::Guardian`]."
+            ],
+            "type": {
+              "option": "publicKey"
+            }
+          },
+          {
+            "name": "complianceAdmin",
+            "docs": [
+              "Holder of [`Role::ComplianceAdmin`]."
+            ],
+            "type": {
+              "option": "publicKey"
+            }
+          },
+          {
+            "name": "denylistEnabled",
+            "docs": [
+              "Block transfers from and to the addresses on the denylist (see",
+              "[`crate::compliance`])."
+            ],
+            "type": "bool"
+          },
+          {
+            "name": "policyProgram",
+            "docs": [
+              "A program that is asked whether to allow each transfer, in addition to",
+              "the denylist (see [`crate::compliance`]). `None` disables this check."
+            ],
+            "type": {
+              "option": "publicKey"
+            }
+          },
+          {
+            "name": "escrowTimeout",
+            "docs": [
+              "How long (in seconds) after it became releasable an inbound transfer",
+              "that is still not released can be moved to the escrow by anyone (see",
+              "[`crate::instructions::escrow_inbox_item`]). Zero disables this, so",
+              "only the owner can escrow transfers."
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "version",
+            "docs": [
+              "The layout version of this account (see [`Config::LAYOUT_VERSION`]).",
+              "Fields added in later releases go after this one, and existing",
+              "accounts are upgraded with [`crate::instructions::migrate`]."
+            ],
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "LUT",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "address",
+            "type": "publicKey"
+          }
+        ]
+      }
+    },
+    {
+      "name": "nttManagerPeer",
+      "docs": [
+        "A peer on another chain. Stored in a PDA seeded by the chain id."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "address",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "tokenDecimals",
+            "type": "u8"
+          },
+          {
+            "name": "paused",
+            "docs": [
+              "Pause transfers to and from this peer. Inbound transfers from a paused",
+              "peer can't be redeemed or released, and outbound transfers to it can't",
+              "be released (but can be once it's unpaused)."
+            ],
+            "type": "bool"
+          },
+          {
+            "name": "retiredAt",
+            "docs": [
+              "When the peer was retired (see [`crate::instructions::remove_peer`]),",
+              "if it was. Outbound transfers to a retired peer are rejected, and",
+              "inbound transfers from it can only be redeemed until `closable_at`."
+            ],
+            "type": {
+              "option": "i64"
+            }
+          },
+          {
+            "name": "closableAt",
+            "docs": [
+              "When a retired peer (and its rate limits) can be closed."
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "rentRecipient",
+            "docs": [
+              "Receives the rent of a retired peer when it's closed."
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "legacyAddresses",
+            "docs": [
+              "Previous addresses of the peer that inbound transfers are still",
+              "accepted from for a while, e.g. when the peer's manager was redeployed",
+              "and transfers sent by the old one are still in flight.",
+              "Outbound transfers are only sent to `address`."
+            ],
+            "type": {
+              "vec": {
+                "defined": "LegacyAddress"
+              }
+            }
+          },
+          {
+            "name": "inboundThreshold",
+            "docs": [
+              "Overrides [`Config::threshold`] for inbound transfers from this peer."
+            ],
+            "type": {
+              "option": "u8"
+            }
+          },
+          {
+            "name": "inboundTransceivers",
+            "docs": [
+              "Restricts the transceivers whose votes are accepted for inbound",
+              "transfers from this peer to a subset of",
+              "[`Config::enabled_transceivers`]. All enabled transceivers are accepted",
+              "if unset."
+            ],
+            "type": {
+              "option": {
+                "defined": "Bitmap"
+              }
+            }
+          },
+          {
+            "name": "version",
+            "docs": [
+              "See [`crate::config::Config::version`]."
+            ],
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "pendingChange",
+      "docs": [
+        "A proposed [`ConfigChange`], which anyone can execute once",
+        "`executable_at` is reached. Until then, the owner or the guardian can veto",
+        "it.",
+        "Stored in a PDA seeded by the hash of the change, so the execute",
+        "instructions can check that they apply the proposed change."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "change",
+            "type": {
+              "defined": "ConfigChange"
+            }
+          },
+          {
+            "name": "executableAt",
+            "type": "i64"
+          },
+          {
+            "name": "rentPayer",
+            "type": "publicKey"
+          }
+        ]
+      }
+    },
+    {
+      "name": "pendingTokenAuthority",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "pendingAuthority",
+            "type": "publicKey"
+          },
+          {
+            "name": "rentPayer",
+            "type": "publicKey"
+          }
+        ]
+      }
+    },
+    {
+      "name": "inboxItem",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "init",
+            "type": "bool"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "amount",
+            "type": "u64"
+          },
+          {
+            "name": "recipientAddress",
+            "type": "publicKey"
+          },
+          {
+            "name": "votes",
+            "type": {
+              "defined": "Bitmap"
+            }
+          },
+          {
+            "name": "releaseStatus",
+            "type": {
+              "defined": "ReleaseStatus"
+            }
+          },
+          {
+            "name": "rentPayer",
+            "docs": [
+              "The account that paid the rent for this item. The rent is returned to",
+              "this account when the item is closed."
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "sourceChain",
+            "type": {
+              "defined": "ChainId"
+            }
+          },
+          {
+            "name": "sender",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "additionalPayload",
+            "docs": [
+              "The additional payload sent along with the tokens, if any. It is kept",
+              "after the transfer is released, so the recipient can read it."
+            ],
+            "type": {
+              "defined": "AdditionalPayload"
+            }
+          },
+          {
+            "name": "version",
+            "docs": [
+              "See [`crate::config::Config::version`]."
+            ],
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "inboxItemTombstone",
+      "docs": [
+        "What remains of an [`InboxItem`] after it has been closed.",
+        "",
+        "Inbox items are content-addressed, so closing them outright would allow the",
+        "same message to be redeemed again. Instead, the account is shrunk down to",
+        "just the discriminator of this type, and the rest of the rent is returned.",
+        "[`crate::instructions::redeem`] fails to deserialize the tombstone as an",
+        "[`InboxItem`], so the message can never be redeemed again."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": []
+      }
+    },
+    {
+      "name": "inboxRateLimit",
+      "docs": [
+        "Inbound rate limit per chain.",
+        "SECURITY: must check the PDA (since there are multiple PDAs, namely one for each chain.)"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "rateLimit",
+            "type": {
+              "defined": "RateLimitState"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "outboxItem",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "amount",
+            "type": {
+              "defined": "TrimmedAmount"
+            }
+          },
+          {
+            "name": "sender",
+            "type": "publicKey"
+          },
+          {
+            "name": "recipientChain",
+            "type": {
+              "defined": "ChainId"
+            }
+          },
+          {
+            "name": "recipientNttManager",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "recipientAddress",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "releaseTimestamp",
+            "type": "i64"
+          },
+          {
+            "name": "released",
+            "type": {
+              "defined": "Bitmap"
+            }
+          },
+          {
+            "name": "queued",
+            "docs": [
+              "Whether the transfer was queued because it exceeded the outbound rate",
+              "limit. Queued transfers don't consume outbound capacity (and don't",
+              "refill the inbound capacity) when they are inserted, unless they",
+              "reserved it."
+            ],
+            "type": "bool"
+          },
+          {
+            "name": "reserved",
+            "docs": [
+              "Whether the transfer reserved the outbound capacity it was missing",
+              "when it was queued (see [`crate::config::Config::precise_queue_release`]).",
+              "Reserved capacity counts as consumed, like for transfers that weren't",
+              "queued."
+            ],
+            "type": "bool"
+          },
+          {
+            "name": "cancelled",
+            "docs": [
+              "Whether the transfer has been cancelled by the sender. Cancelled",
+              "transfers can never be released."
+            ],
+            "type": "bool"
+          },
+          {
+            "name": "rentPayer",
+            "docs": [
+              "The account that paid the rent for this item. The rent is returned to",
+              "this account when the item is closed."
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "additionalPayload",
+            "docs": [
+              "An additional payload to be delivered to the recipient along with the",
+              "tokens. Empty for plain transfers."
+            ],
+            "type": {
+              "defined": "AdditionalPayload"
+            }
+          },
+          {
+            "name": "sequence",
+            "docs": [
+              "The outbound sequence number of the transfer. The item's address is",
+              "derived from it (see [`OutboxItem::SEED_PREFIX`])."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "version",
+            "docs": [
+              "See [`crate::config::Config::version`]."
+            ],
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "outboxSequence",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "next",
+            "docs": [
+              "The sequence number of the next outbound transfer."
+            ],
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "outboxRateLimit",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "rateLimit",
+            "type": {
+              "defined": "RateLimitState"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "outboxChainRateLimit",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "rateLimit",
+            "type": {
+              "defined": "RateLimitState"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "registeredTransceiver",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "id",
+            "type": "u8"
+          },
+          {
+            "name": "transceiverAddress",
+            "type": "publicKey"
+          },
+          {
+            "name": "version",
+            "docs": [
+              "See [`crate::config::Config::version`]."
+            ],
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "transceiverPeer",
+      "docs": [
+        "A peer on another chain. Stored in a PDA seeded by the chain id."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "address",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "bridgeData",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "guardianSetIndex",
+            "docs": [
+              "The current guardian set index, used to decide which signature sets to accept."
+            ],
+            "type": "u32"
+          },
+          {
+            "name": "lastLamports",
+            "docs": [
+              "Lamports in the collection account"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "config",
+            "docs": [
+              "Bridge configuration, which is set once upon initialization."
+            ],
+            "type": {
+              "defined": "BridgeConfig"
+            }
+          }
+        ]
+      }
+    }
+  ],
+  "types": [
+    {
+      "name": "Bitmap",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "map",
+            "type": "u128"
+          }
+        ]
+      }
+    },
+    {
+      "name": "SetPeerArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "chainId",
+            "type": {
+              "defined": "ChainId"
+            }
+          },
+          {
+            "name": "address",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "limit",
+            "type": "u64"
+          },
+          {
+            "name": "tokenDecimals",
+            "docs": [
+              "The token decimals on the peer chain."
+            ],
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "SetOutboundLimitArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "limit",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "SetOutboundChainLimitArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "limit",
+            "type": "u64"
+          },
+          {
+            "name": "chainId",
+            "type": {
+              "defined": "ChainId"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "SetInboundLimitArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "limit",
+            "type": "u64"
+          },
+          {
+            "name": "chainId",
+            "type": {
+              "defined": "ChainId"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "SetOutboundRateLimitDurationArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "duration",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "SetOutboundChainRateLimitDurationArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "duration",
+            "type": "i64"
+          },
+          {
+            "name": "chainId",
+            "type": {
+              "defined": "ChainId"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "SetInboundRateLimitDurationArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "duration",
+            "type": "i64"
+          },
+          {
+            "name": "chainId",
+            "type": {
+              "defined": "ChainId"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "SetPeerPausedArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "chainId",
+            "type": {
+              "defined": "ChainId"
+            }
+          },
+          {
+            "name": "paused",
+            "type": "bool"
+          }
+        ]
+      }
+    },
+    {
+      "name": "PausePeerArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "chainId",
+            "type": {
+              "defined": "ChainId"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "SetLegacyPeerAddressesArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "chainId",
+            "type": {
+              "defined": "ChainId"
+            }
+          },
+          {
+            "name": "addresses",
+            "type": {
+              "vec": {
+                "defined": "LegacyAddress"
+              }
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "RemovePeerArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "chainId",
+            "type": {
+              "defined": "ChainId"
+            }
+          },
+          {
+            "name": "gracePeriod",
+            "docs": [
+              "How long (in seconds) inbound transfers from the peer can still be",
+              "redeemed for, before the peer can be closed."
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "rentRecipient",
+            "docs": [
+              "Receives the rent of the peer and its rate limits when they are closed."
+            ],
+            "type": "publicKey"
+          }
+        ]
+      }
+    },
+    {
+      "name": "ClosePeerArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "chainId",
+            "type": {
+              "defined": "ChainId"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "SetPeerThresholdArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "chainId",
+            "type": {
+              "defined": "ChainId"
+            }
+          },
+          {
+            "name": "threshold",
+            "docs": [
+              "See [`NttManagerPeer::inbound_threshold`]."
+            ],
+            "type": {
+              "option": "u8"
+            }
+          },
+          {
+            "name": "transceivers",
+            "docs": [
+              "See [`NttManagerPeer::inbound_transceivers`]."
+            ],
+            "type": {
+              "option": {
+                "defined": "Bitmap"
+              }
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "InitializeArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "chainId",
+            "type": "u16"
+          },
+          {
+            "name": "limit",
+            "type": "u64"
+          },
+          {
+            "name": "mode",
+            "type": {
+              "defined": "Mode"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "MigrateArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "sourceChain",
+            "docs": [
+              "The chain the transfer of an [`InboxItem`] came from, which the 3.0.0",
+              "release didn't store. Required when migrating an inbox item, and",
+              "ignored for other accounts."
+            ],
+            "type": {
+              "option": {
+                "defined": "ChainId"
+              }
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "InitOutboxChainRateLimitArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "chainId",
+            "type": {
+              "defined": "ChainId"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "RedeemArgs",
+      "type": {
+        "kind": "struct",
+        "fields": []
+      }
+    },
+    {
+      "name": "ReleaseInboundArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "revertWhenNotReady",
+            "type": "bool"
+          }
+        ]
+      }
+    },
+    {
+      "name": "ReleaseInboundAndCallArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "revertWhenNotReady",
+            "type": "bool"
+          },
+          {
+            "name": "recipientAccounts",
+            "docs": [
+              "The number of remaining accounts (at the end) that are passed to the",
+              "recipient program. The ones before them are used by the release itself",
+              "(e.g. the transfer hook and denylist accounts)."
+            ],
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "TransferArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "amount",
+            "type": "u64"
+          },
+          {
+            "name": "recipientChain",
+            "type": {
+              "defined": "ChainId"
+            }
+          },
+          {
+            "name": "recipientAddress",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "shouldQueue",
+            "type": "bool"
+          }
+        ]
+      }
+    },
+    {
+      "name": "TransferWithPayloadArgs",
+      "docs": [
+        "Same as [`TransferArgs`], with an additional payload attached to the",
+        "transfer, to be interpreted by the recipient."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "amount",
+            "type": "u64"
+          },
+          {
+            "name": "recipientChain",
+            "type": {
+              "defined": "ChainId"
+            }
+          },
+          {
+            "name": "recipientAddress",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "shouldQueue",
+            "type": "bool"
+          },
+          {
+            "name": "additionalPayload",
+            "type": "bytes"
+          }
+        ]
+      }
+    },
+    {
+      "name": "LegacyAddress",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "address",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "validUntil",
+            "docs": [
+              "Inbound transfers from this address are rejected from then on."
+            ],
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "RateLimitState",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "limit",
+            "docs": [
+              "The maximum capacity of the rate limiter."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "capacityAtLastTx",
+            "docs": [
+              "The capacity of the rate limiter at `last_tx_timestamp`.",
+              "The actual current capacity is calculated in `capacity_at`, by",
+              "accounting for the time that has passed since `last_tx_timestamp` and",
+              "the refill rate."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "lastTxTimestamp",
+            "docs": [
+              "The timestamp of the last transaction that counted towards the current",
+              "capacity. Transactions that exceeded the capacity do not count, they are",
+              "just delayed.",
+              "",
+              "When capacity has been reserved for queued transfers (see",
+              "[`RateLimitState::consume_or_reserve_all`]), this is in the future: the",
+              "rate limiter is empty until then, as everything that refills before",
+              "that is owed to the queued transfers."
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "rateLimitDuration",
+            "docs": [
+              "The time (in seconds) it takes for the capacity to refill from zero to",
+              "`limit`. This is also how long transfers that exceed the capacity are",
+              "delayed for, unless the missing capacity is reserved (see",
+              "[`RateLimitState::consume_or_reserve_all`]).",
+              "",
+              "NOTE: this field was added after the others, so accounts created",
+              "before it have to be migrated (see [`crate::instructions::migrate`])."
+            ],
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "SetTransceiverPeerArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "chainId",
+            "type": {
+              "defined": "ChainId"
+            }
+          },
+          {
+            "name": "address",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "BroadcastPeerArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "chainId",
+            "type": "u16"
+          }
+        ]
+      }
+    },
+    {
+      "name": "ReleaseOutboundArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "revertOnDelay",
+            "type": "bool"
+          }
+        ]
+      }
+    },
+    {
+      "name": "VersionInfo",
+      "docs": [
+        "Returned by the `version` instruction.",
+        "",
+        "The program version comes first, so clients that decode the return value",
+        "as just a string (as it was before the layout version was added) still get",
+        "the program version."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "programVersion",
+            "type": "string"
+          },
+          {
+            "name": "configLayoutVersion",
+            "docs": [
+              "The current layout version of the config account (see",
+              "[`config::Config::LAYOUT_VERSION`])."
+            ],
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "Role",
+      "docs": [
+        "Roles that the owner can delegate to other accounts (one holder each), so",
+        "that routine operations don't need to go through the owner (which is",
+        "typically a governance program or a multisig).",
+        "The owner can always perform these operations too."
+      ],
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "RateLimitAdmin"
+          },
+          {
+            "name": "PeerAdmin"
+          },
+          {
+            "name": "TransceiverAdmin"
+          },
+          {
+            "name": "Pauser"
+          },
+          {
+            "name": "Guardian"
+          },
+          {
+            "name": "ComplianceAdmin"
+          }
+        ]
+      }
+    },
+    {
+      "name": "ConfigChange",
+      "docs": [
+        "A security-sensitive configuration change. When the timelock is enabled",
+        "(see [`Config::timelock_delay`]), these changes can't be made directly, but",
+        "have to be proposed first (see [`PendingChange`])."
+      ],
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "SetPeer",
+            "fields": [
+              {
+                "defined": "SetPeerArgs"
+              }
+            ]
+          },
+          {
+            "name": "RegisterTransceiver",
+            "fields": [
+              {
+                "name": "transceiver",
+                "type": "publicKey"
+              }
+            ]
+          },
+          {
+            "name": "SetThreshold",
+            "fields": [
+              {
+                "name": "threshold",
+                "type": "u8"
+              }
+            ]
+          },
+          {
+            "name": "SetTokenAuthority",
+            "fields": [
+              {
+                "name": "newAuthority",
+                "type": "publicKey"
+              }
+            ]
+          },
+          {
+            "name": "SetTimelockDelay",
+            "fields": [
+              {
+                "name": "delay",
+                "type": "i64"
+              }
+            ]
+          },
+          {
+            "name": "SetGuardian",
+            "fields": [
+              {
+                "name": "guardian",
+                "type": {
+                  "option": "publicKey"
+                }
+              }
+            ]
+          },
+          {
+            "name": "SetLegacyPeerAddresses",
+            "fields": [
+              {
+                "defined": "SetLegacyPeerAddressesArgs"
+              }
+            ]
+          },
+          {
+            "name": "SetPeerThreshold",
+            "fields": [
+              {
+                "defined": "SetPeerThresholdArgs"
+              }
+            ]
+          },
+          {
+            "name": "DeregisterTransceiver",
+            "fields": [
+              {
+                "name": "transceiver",
+                "type": "publicKey"
+              }
+            ]
+          },
+          {
+            "name": "SetOutboundLimit",
+            "fields": [
+              {
+                "defined": "SetOutboundLimitArgs"
+              }
+            ]
+          },
+          {
+            "name": "SetOutboundChainLimit",
+            "fields": [
+              {
+                "defined": "SetOutboundChainLimitArgs"
+              }
+            ]
+          },
+          {
+            "name": "SetInboundLimit",
+            "fields": [
+              {
+                "defined": "SetInboundLimitArgs"
+              }
+            ]
+          },
+          {
+            "name": "SetOutboundRateLimitDuration",
+            "fields": [
+              {
+                "defined": "SetOutboundRateLimitDurationArgs"
+              }
+            ]
+          },
+          {
+            "name": "SetOutboundChainRateLimitDuration",
+            "fields": [
+              {
+                "defined": "SetOutboundChainRateLimitDurationArgs"
+              }
+            ]
+          },
+          {
+            "name": "SetInboundRateLimitDuration",
+            "fields": [
+              {
+                "defined": "SetInboundRateLimitDurationArgs"
+              }
+            ]
+          }
+        ]
+      }
+    },
+    {
+      "name": "ReleaseStatus",
+      "docs": [
+        "The status of an InboxItem. This determines whether the tokens are minted/unlocked to the recipient. As",
+        "such, this must be used as a state machine that moves forward in a linear manner. A state",
+        "should never \"move backward\" to a previous state (e.g. should never move from `Released` to",
+        "`ReleaseAfter`).",
+        "`Frozen` is the exception, as it's left for the status the item was frozen",
+        "in (see [`crate::instructions::freeze_inbox_item`])."
+      ],
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "NotApproved"
+          },
+          {
+            "name": "ReleaseAfter",
+            "fields": [
+              "i64"
+            ]
+          },
+          {
+            "name": "Released"
+          },
+          {
+            "name": "Frozen",
+            "fields": [
+              {
+                "option": "i64"
+              }
+            ]
+          },
+          {
+            "name": "Rejected"
+          },
+          {
+            "name": "Quarantined"
+          },
+          {
+            "name": "Escrowed",
+            "fields": [
+              "u64"
+            ]
+          }
+        ]
+      }
+    },
+    {
+      "name": "ChainId",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "id",
+            "type": "u16"
+          }
+        ]
+      }
+    },
+    {
+      "name": "Mode",
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "Locking"
+          },
+          {
+            "name": "Burning"
+          }
+        ]
+      }
+    },
+    {
+      "name": "TrimmedAmount",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "amount",
+            "type": "u64"
+          },
+          {
+            "name": "decimals",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "AdditionalPayload",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bytes",
+            "type": "bytes"
+          }
+        ]
+      }
+    },
+    {
+      "name": "BridgeConfig",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "guardianSetExpirationTime",
+            "docs": [
+              "Period for how long a guardian set is valid after it has been replaced by a new one.  This",
+              "guarantees that VAAs issued by that set can still be submitted for a certain period.  In",
+              "this period we still trust the old guardian set."
+            ],
+            "type": "u32"
+          },
+          {
+            "name": "fee",
+            "docs": [
+              "Amount of lamports that needs to be paid to the protocol to post a message"
+            ],
+            "type": "u64"
+          }
+        ]
+      }
+    }
+  ],
+  "events": [
+    {
+      "name": "TransferSent",
+      "fields": [
+        {
+          "name": "outboxItem",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "sender",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "amount",
+          "type": {
+            "defined": "TrimmedAmount"
+          },
+          "index": false
+        },
+        {
+          "name": "recipientChain",
+          "type": {
+            "defined": "ChainId"
+          },
+          "index": false
+        },
+        {
+          "name": "recipientNttManager",
+          "type": {
+            "array": [
+              "u8",
+              32
+            ]
+          },
+          "index": false
+        },
+        {
+          "name": "recipientAddress",
+          "type": {
+            "array": [
+              "u8",
+              32
+            ]
+          },
+          "index": false
+        },
+        {
+          "name": "releaseTimestamp",
+          "type": "i64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "OutboundTransferQueued",
+      "fields": [
+        {
+          "name": "outboxItem",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "releaseTimestamp",
+          "type": "i64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "OutboundTransferReleased",
+      "fields": [
+        {
+          "name": "outboxItem",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "transceiver",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "transceiverIndex",
+          "type": "u8",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "OutboundTransferCancelled",
+      "fields": [
+        {
+          "name": "outboxItem",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "sender",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "amount",
+          "type": "u64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "InboundVoteRecorded",
+      "fields": [
+        {
+          "name": "inboxItem",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "fromChain",
+          "type": {
+            "defined": "ChainId"
+          },
+          "index": false
+        },
+        {
+          "name": "messageId",
+          "type": {
+            "array": [
+              "u8",
+              32
+            ]
+          },
+          "index": false
+        },
+        {
+          "name": "transceiver",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "transceiverIndex",
+          "type": "u8",
+          "index": false
+        },
+        {
+          "name": "votes",
+          "type": "u8",
+          "index": false
+        },
+        {
+          "name": "threshold",
+          "type": "u8",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "InboundQuorumReached",
+      "fields": [
+        {
+          "name": "inboxItem",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "releaseTimestamp",
+          "type": "i64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "InboundTransferQueued",
+      "fields": [
+        {
+          "name": "inboxItem",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "releaseTimestamp",
+          "type": "i64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "InboundTransferReleased",
+      "fields": [
+        {
+          "name": "inboxItem",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "recipient",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "amount",
+          "type": "u64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "InboundTransferFrozen",
+      "fields": [
+        {
+          "name": "inboxItem",
+          "type": "publicKey",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "InboundTransferUnfrozen",
+      "fields": [
+        {
+          "name": "inboxItem",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "releaseStatus",
+          "type": {
+            "defined": "ReleaseStatus"
+          },
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "InboundTransferRejected",
+      "fields": [
+        {
+          "name": "inboxItem",
+          "type": "publicKey",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "InboundTransferForceReleased",
+      "fields": [
+        {
+          "name": "inboxItem",
+          "type": "publicKey",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "InboundTransferQuarantined",
+      "fields": [
+        {
+          "name": "inboxItem",
+          "type": "publicKey",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "InboundTransferUnquarantined",
+      "fields": [
+        {
+          "name": "inboxItem",
+          "type": "publicKey",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "InboundTransferEscrowed",
+      "fields": [
+        {
+          "name": "inboxItem",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "amount",
+          "type": "u64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "EscrowedTransferRedirected",
+      "fields": [
+        {
+          "name": "inboxItem",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "recipient",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "amount",
+          "type": "u64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "EscrowedTransferReturned",
+      "fields": [
+        {
+          "name": "inboxItem",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "outboxItem",
+          "type": "publicKey",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "PeerSet",
+      "fields": [
+        {
+          "name": "chainId",
+          "type": {
+            "defined": "ChainId"
+          },
+          "index": false
+        },
+        {
+          "name": "address",
+          "type": {
+            "array": [
+              "u8",
+              32
+            ]
+          },
+          "index": false
+        },
+        {
+          "name": "tokenDecimals",
+          "type": "u8",
+          "index": false
+        },
+        {
+          "name": "inboundLimit",
+          "type": "u64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "OutboundLimitSet",
+      "fields": [
+        {
+          "name": "limit",
+          "type": "u64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "OutboundChainLimitSet",
+      "fields": [
+        {
+          "name": "chainId",
+          "type": {
+            "defined": "ChainId"
+          },
+          "index": false
+        },
+        {
+          "name": "limit",
+          "type": "u64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "InboundLimitSet",
+      "fields": [
+        {
+          "name": "chainId",
+          "type": {
+            "defined": "ChainId"
+          },
+          "index": false
+        },
+        {
+          "name": "limit",
+          "type": "u64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "OutboundRateLimitDurationSet",
+      "fields": [
+        {
+          "name": "duration",
+          "type": "i64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "OutboundChainRateLimitDurationSet",
+      "fields": [
+        {
+          "name": "chainId",
+          "type": {
+            "defined": "ChainId"
+          },
+          "index": false
+        },
+        {
+          "name": "duration",
+          "type": "i64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "InboundRateLimitDurationSet",
+      "fields": [
+        {
+          "name": "chainId",
+          "type": {
+            "defined": "ChainId"
+          },
+          "index": false
+        },
+        {
+          "name": "duration",
+          "type": "i64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "ThresholdSet",
+      "fields": [
+        {
+          "name": "oldThreshold",
+          "type": "u8",
+          "index": false
+        },
+        {
+          "name": "threshold",
+          "type": "u8",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "PausedSet",
+      "fields": [
+        {
+          "name": "paused",
+          "type": "bool",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "RoleGranted",
+      "fields": [
+        {
+          "name": "role",
+          "type": {
+            "defined": "Role"
+          },
+          "index": false
+        },
+        {
+          "name": "holder",
+          "type": "publicKey",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "RoleRevoked",
+      "fields": [
+        {
+          "name": "role",
+          "type": {
+            "defined": "Role"
+          },
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "OutboundPausedSet",
+      "fields": [
+        {
+          "name": "paused",
+          "type": "bool",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "InboundPausedSet",
+      "fields": [
+        {
+          "name": "paused",
+          "type": "bool",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "PeerPausedSet",
+      "fields": [
+        {
+          "name": "chainId",
+          "type": {
+            "defined": "ChainId"
+          },
+          "index": false
+        },
+        {
+          "name": "paused",
+          "type": "bool",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "PeerThresholdSet",
+      "fields": [
+        {
+          "name": "chainId",
+          "type": {
+            "defined": "ChainId"
+          },
+          "index": false
+        },
+        {
+          "name": "threshold",
+          "type": {
+            "option": "u8"
+          },
+          "index": false
+        },
+        {
+          "name": "transceivers",
+          "type": {
+            "option": {
+              "defined": "Bitmap"
+            }
+          },
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "LegacyPeerAddressesSet",
+      "fields": [
+        {
+          "name": "chainId",
+          "type": {
+            "defined": "ChainId"
+          },
+          "index": false
+        },
+        {
+          "name": "addresses",
+          "type": {
+            "vec": {
+              "defined": "LegacyAddress"
+            }
+          },
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "PeerRemoved",
+      "fields": [
+        {
+          "name": "chainId",
+          "type": {
+            "defined": "ChainId"
+          },
+          "index": false
+        },
+        {
+          "name": "closableAt",
+          "type": "i64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "PeerClosed",
+      "fields": [
+        {
+          "name": "chainId",
+          "type": {
+            "defined": "ChainId"
+          },
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "PreciseQueueReleaseSet",
+      "fields": [
+        {
+          "name": "enabled",
+          "type": "bool",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "StrictDustSet",
+      "fields": [
+        {
+          "name": "enabled",
+          "type": "bool",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "EscrowTimeoutSet",
+      "fields": [
+        {
+          "name": "timeout",
+          "type": "i64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "TimelockDelaySet",
+      "fields": [
+        {
+          "name": "delay",
+          "type": "i64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "ChangeProposed",
+      "fields": [
+        {
+          "name": "pendingChange",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "change",
+          "type": {
+            "defined": "ConfigChange"
+          },
+          "index": false
+        },
+        {
+          "name": "executableAt",
+          "type": "i64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "ChangeVetoed",
+      "fields": [
+        {
+          "name": "pendingChange",
+          "type": "publicKey",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "ChangeExecuted",
+      "fields": [
+        {
+          "name": "pendingChange",
+          "type": "publicKey",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "TransceiverRegistered",
+      "fields": [
+        {
+          "name": "transceiver",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "transceiverIndex",
+          "type": "u8",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "TransceiverDeregistered",
+      "fields": [
+        {
+          "name": "transceiver",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "transceiverIndex",
+          "type": "u8",
+          "index": false
+        },
+        {
+          "name": "threshold",
+          "type": "u8",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "OwnershipTransferInitiated",
+      "fields": [
+        {
+          "name": "owner",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "pendingOwner",
+          "type": "publicKey",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "OwnershipTransferred",
+      "fields": [
+        {
+          "name": "previousOwner",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "owner",
+          "type": "publicKey",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "TokenAuthorityAccepted",
+      "fields": [
+        {
+          "name": "mint",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "authority",
+          "type": "publicKey",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "TokenAuthorityTransferInitiated",
+      "fields": [
+        {
+          "name": "mint",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "pendingAuthority",
+          "type": "publicKey",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "TokenAuthorityTransferReverted",
+      "fields": [
+        {
+          "name": "mint",
+          "type": "publicKey",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "TokenAuthorityTransferred",
+      "fields": [
+        {
+          "name": "mint",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "newAuthority",
+          "type": "publicKey",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "DenylistEnabledSet",
+      "fields": [
+        {
+          "name": "enabled",
+          "type": "bool",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "PolicyProgramSet",
+      "fields": [
+        {
+          "name": "policyProgram",
+          "type": {
+            "option": "publicKey"
+          },
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "AddressDenylisted",
+      "fields": [
+        {
+          "name": "address",
+          "type": {
+            "array": [
+              "u8",
+              32
+            ]
+          },
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "AddressRemovedFromDenylist",
+      "fields": [
+        {
+          "name": "address",
+          "type": {
+            "array": [
+              "u8",
+              32
+            ]
+          },
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "WormholePeerSet",
+      "fields": [
+        {
+          "name": "chainId",
+          "type": {
+            "defined": "ChainId"
+          },
+          "index": false
+        },
+        {
+          "name": "address",
+          "type": {
+            "array": [
+              "u8",
+              32
+            ]
+          },
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "WormholeMessageReceived",
+      "fields": [
+        {
+          "name": "transceiverMessage",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "fromChain",
+          "type": {
+            "defined": "ChainId"
+          },
+          "index": false
+        },
+        {
+          "name": "messageId",
+          "type": {
+            "array": [
+              "u8",
+              32
+            ]
+          },
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "WormholeMessageSent",
+      "fields": [
+        {
+          "name": "outboxItem",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "wormholeMessage",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "recipientChain",
+          "type": {
+            "defined": "ChainId"
+          },
+          "index": false
+        }
+      ]
+    }
+  ],
+  "errors": [
+    {
+      "code": 6000,
+      "name": "CantReleaseYet",
+      "msg": "CantReleaseYet"
+    },
+    {
+      "code": 6001,
+      "name": "InvalidPendingOwner",
+      "msg": "InvalidPendingOwner"
+    },
+    {
+      "code": 6002,
+      "name": "InvalidChainId",
+      "msg": "InvalidChainId"
+    },
+    {
+      "code": 6003,
+      "name": "InvalidRecipientAddress",
+      "msg": "InvalidRecipientAddress"
+    },
+    {
+      "code": 6004,
+      "name": "InvalidTransceiverPeer",
+      "msg": "InvalidTransceiverPeer"
+    },
+    {
+      "code": 6005,
+      "name": "InvalidNttManagerPeer",
+      "msg": "InvalidNttManagerPeer"
+    },
+    {
+      "code": 6006,
+      "name": "InvalidRecipientNttManager",
+      "msg": "InvalidRecipientNttManager"
+    },
+    {
+      "code": 6007,
+      "name": "TransferAlreadyRedeemed",
+      "msg": "TransferAlreadyRedeemed"
+    },
+    {
+      "code": 6008,
+      "name": "TransferCannotBeRedeemed",
+      "msg": "TransferCannotBeRedeemed"
+    },
+    {
+      "code": 6009,
+      "name": "TransferNotApproved",
+      "msg": "TransferNotApproved"
+    },
+    {
+      "code": 6010,
+      "name": "MessageAlreadySent",
+      "msg": "MessageAlreadySent"
+    },
+    {
+      "code": 6011,
+      "name": "InvalidMode",
+      "msg": "InvalidMode"
+    },
+    {
+      "code": 6012,
+      "name": "InvalidMintAuthority",
+      "msg": "InvalidMintAuthority"
+    },
+    {
+      "code": 6013,
+      "name": "TransferExceedsRateLimit",
+      "msg": "TransferExceedsRateLimit"
+    },
+    {
+      "code": 6014,
+      "name": "Paused",
+      "msg": "Paused"
+    },
+    {
+      "code": 6015,
+      "name": "DisabledTransceiver",
+      "msg": "DisabledTransceiver"
+    },
+    {
+      "code": 6016,
+      "name": "InvalidDeployer",
+      "msg": "InvalidDeployer"
+    },
+    {
+      "code": 6017,
+      "name": "BadAmountAfterTransfer",
+      "msg": "BadAmountAfterTransfer"
+    },
+    {
+      "code": 6018,
+      "name": "BadAmountAfterBurn",
+      "msg": "BadAmountAfterBurn"
+    },
+    {
+      "code": 6019,
+      "name": "ZeroThreshold",
+      "msg": "ZeroThreshold"
+    },
+    {
+      "code": 6020,
+      "name": "OverflowExponent",
+      "msg": "OverflowExponent"
+    },
+    {
+      "code": 6021,
+      "name": "OverflowScaledAmount",
+      "msg": "OverflowScaledAmount"
+    },
+    {
+      "code": 6022,
+      "name": "BitmapIndexOutOfBounds",
+      "msg": "BitmapIndexOutOfBounds"
+    },
+    {
+      "code": 6023,
+      "name": "NoRegisteredTransceivers",
+      "msg": "NoRegisteredTransceivers"
+    },
+    {
+      "code": 6024,
+      "name": "NotPaused",
+      "msg": "NotPaused"
+    },
+    {
+      "code": 6025,
+      "name": "InvalidPendingTokenAuthority",
+      "msg": "InvalidPendingTokenAuthority"
+    },
+    {
+      "code": 6026,
+      "name": "IncorrectRentPayer",
+      "msg": "IncorrectRentPayer"
+    },
+    {
+      "code": 6027,
+      "name": "InvalidMultisig",
+      "msg": "InvalidMultisig"
+    },
+    {
+      "code": 6028,
+      "name": "ThresholdTooHigh",
+      "msg": "ThresholdTooHigh"
+    },
+    {
+      "code": 6029,
+      "name": "InvalidTransceiverProgram",
+      "msg": "InvalidTransceiverProgram"
+    },
+    {
+      "code": 6030,
+      "name": "OutboxItemCancelled",
+      "msg": "OutboxItemCancelled"
+    },
+    {
+      "code": 6031,
+      "name": "OutboxItemAlreadyReleased",
+      "msg": "OutboxItemAlreadyReleased"
+    },
+    {
+      "code": 6032,
+      "name": "OutboxItemNotReleased",
+      "msg": "OutboxItemNotReleased"
+    },
+    {
+      "code": 6033,
+      "name": "InboxItemNotReleased",
+      "msg": "InboxItemNotReleased"
+    },
+    {
+      "code": 6034,
+      "name": "AdditionalPayloadTooLong",
+      "msg": "AdditionalPayloadTooLong"
+    },
+    {
+      "code": 6035,
+      "name": "InvalidRecipientProgram",
+      "msg": "InvalidRecipientProgram"
+    },
+    {
+      "code": 6036,
+      "name": "MessageEncodingFailed",
+      "msg": "MessageEncodingFailed"
+    },
+    {
+      "code": 6037,
+      "name": "MessageDecodingFailed",
+      "msg": "MessageDecodingFailed"
+    },
+    {
+      "code": 6038,
+      "name": "InvalidRateLimitDuration",
+      "msg": "InvalidRateLimitDuration"
+    },
+    {
+      "code": 6039,
+      "name": "AmountAfterFeeHasDust",
+      "msg": "AmountAfterFeeHasDust"
+    },
+    {
+      "code": 6040,
+      "name": "InvalidTransferFee",
+      "msg": "InvalidTransferFee"
+    },
+    {
+      "code": 6041,
+      "name": "TransferAmountHasDust",
+      "msg": "TransferAmountHasDust"
+    },
+    {
+      "code": 6042,
+      "name": "InvalidPauser",
+      "msg": "InvalidPauser"
+    },
+    {
+      "code": 6043,
+      "name": "OutboundPaused",
+      "msg": "OutboundPaused"
+    },
+    {
+      "code": 6044,
+      "name": "InboundPaused",
+      "msg": "InboundPaused"
+    },
+    {
+      "code": 6045,
+      "name": "PeerPaused",
+      "msg": "PeerPaused"
+    },
+    {
+      "code": 6046,
+      "name": "Unauthorized",
+      "msg": "Unauthorized"
+    },
+    {
+      "code": 6047,
+      "name": "TimelockRequired",
+      "msg": "TimelockRequired"
+    },
+    {
+      "code": 6048,
+      "name": "TimelockNotExpired",
+      "msg": "TimelockNotExpired"
+    },
+    {
+      "code": 6049,
+      "name": "InvalidTimelockDelay",
+      "msg": "InvalidTimelockDelay"
+    },
+    {
+      "code": 6050,
+      "name": "InvalidConfigChange",
+      "msg": "InvalidConfigChange"
+    },
+    {
+      "code": 6051,
+      "name": "AccountAlreadyMigrated",
+      "msg": "AccountAlreadyMigrated"
+    },
+    {
+      "code": 6052,
+      "name": "PeerRetired",
+      "msg": "PeerRetired"
+    },
+    {
+      "code": 6053,
+      "name": "PeerNotRetired",
+      "msg": "PeerNotRetired"
+    },
+    {
+      "code": 6054,
+      "name": "InvalidGracePeriod",
+      "msg": "InvalidGracePeriod"
+    },
+    {
+      "code": 6055,
+      "name": "GracePeriodNotOver",
+      "msg": "GracePeriodNotOver"
+    },
+    {
+      "code": 6056,
+      "name": "TooManyLegacyAddresses",
+      "msg": "TooManyLegacyAddresses"
+    },
+    {
+      "code": 6057,
+      "name": "ThresholdNotReached",
+      "msg": "ThresholdNotReached"
+    },
+    {
+      "code": 6058,
+      "name": "InboxItemFrozen",
+      "msg": "InboxItemFrozen"
+    },
+    {
+      "code": 6059,
+      "name": "InboxItemNotFrozen",
+      "msg": "InboxItemNotFrozen"
+    },
+    {
+      "code": 6060,
+      "name": "InboxItemRejected",
+      "msg": "InboxItemRejected"
+    },
+    {
+      "code": 6061,
+      "name": "TransferDenied",
+      "msg": "TransferDenied"
+    },
+    {
+      "code": 6062,
+      "name": "MissingDenylistEntry",
+      "msg": "MissingDenylistEntry"
+    },
+    {
+      "code": 6063,
+      "name": "InboxItemQuarantined",
+      "msg": "InboxItemQuarantined"
+    },
+    {
+      "code": 6064,
+      "name": "InboxItemNotQuarantined",
+      "msg": "InboxItemNotQuarantined"
+    },
+    {
+      "code": 6065,
+      "name": "InboxItemEscrowed",
+      "msg": "InboxItemEscrowed"
+    },
+    {
+      "code": 6066,
+      "name": "InboxItemNotEscrowed",
+      "msg": "InboxItemNotEscrowed"
+    },
+    {
+      "code": 6067,
+      "name": "CantEscrowYet",
+      "msg": "CantEscrowYet"
+    },
+    {
+      "code": 6068,
+      "name": "InvalidEscrowTimeout",
+      "msg": "InvalidEscrowTimeout"
+    },
+    {
+      "code": 6069,
+      "name": "RecipientCallRequired",
+      "msg": "RecipientCallRequired"
+    },
+    {
+      "code": 6070,
+      "name": "MissingPolicyAccount",
+      "msg": "MissingPolicyAccount"
+    },
+    {
+      "code": 6071,
+      "name": "InvalidPolicyDecision",
+      "msg": "InvalidPolicyDecision"
+    },
+    {
+      "code": 6072,
+      "name": "OutboxItemNotQueued",
+      "msg": "OutboxItemNotQueued"
+    }
+  ]
+}
+export const IDL: ExampleNativeTokenTransfers = {
+  "version": "4.0.0",
+  "name": "example_native_token_transfers",
+  "instructions": [
+    {
+      "name": "initialize",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "deployer",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "programData",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "config",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "mint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rateLimit",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenAuthority",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "In any case, this function is used to set the Config and initialize the program so we",
+            "assume the caller of this function will have total control over the program.",
+            "",
+            "TODO: Using `UncheckedAccount` here leads to \"Access violation in stack frame ...\".",
+            "Could refactor code to use `Box<_>` to reduce stack size."
+          ]
+        },
+        {
+          "name": "multisigTokenAuthority",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "custody",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "The custody account that holds tokens in locking mode and temporarily",
+            "holds tokens in burning mode.",
+            "function if the token account has already been created."
+          ]
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "associated token account for the given mint."
+          ]
+        },
+        {
+          "name": "associatedTokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "bpfLoaderUpgradeableProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "InitializeArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "initializeLut",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "owner",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "lutAddress",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "lut",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "lutProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "entries",
+          "accounts": [
+            {
+              "name": "config",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "custody",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "tokenProgram",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "mint",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "tokenAuthority",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "outboxRateLimit",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "wormhole",
+              "accounts": [
+                {
+                  "name": "bridge",
+                  "isMut": true,
+                  "isSigner": false
+                },
+                {
+                  "name": "feeCollector",
+                  "isMut": true,
+                  "isSigner": false
+                },
+                {
+                  "name": "sequence",
+                  "isMut": true,
+                  "isSigner": false
+                },
+                {
+                  "name": "program",
+                  "isMut": false,
+                  "isSigner": false
+                },
+                {
+                  "name": "systemProgram",
+                  "isMut": false,
+                  "isSigner": false
+                },
+                {
+                  "name": "clock",
+                  "isMut": false,
+                  "isSigner": false
+                },
+                {
+                  "name": "rent",
+                  "isMut": false,
+                  "isSigner": false
+                }
+              ]
+            }
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "recentSlot",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "version",
+      "accounts": [],
+      "args": [],
+      "returns": {
+        "defined": "VersionInfo"
+      }
+    },
+    {
+      "name": "transferBurn",
+      "accounts": [
+        {
+          "name": "common",
+          "accounts": [
+            {
+              "name": "payer",
+              "isMut": true,
+              "isSigner": true
+            },
+            {
+              "name": "config",
+              "accounts": [
+                {
+                  "name": "config",
+                  "isMut": false,
+                  "isSigner": false
+                }
+              ]
+            },
+            {
+              "name": "mint",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "from",
+              "isMut": true,
+              "isSigner": false,
+              "docs": [
+                "account can spend these tokens."
+              ]
+            },
+            {
+              "name": "tokenProgram",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "outboxSequence",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "outboxItem",
+              "isMut": true,
+              "isSigner": false,
+              "docs": [
+                "NOTE: the item is derived from the next sequence number, so if another",
+                "transfer takes it first, this transfer fails instead of being sent",
+                "twice. Clients can then retry with the new sequence number."
+              ]
+            },
+            {
+              "name": "outboxRateLimit",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "custody",
+              "isMut": true,
+              "isSigner": false,
+              "docs": [
+                "Tokens are always transferred to the custody account first regardless of",
+                "the mode.",
+                "For an explanation, see the note in [`transfer_burn`]."
+              ]
+            },
+            {
+              "name": "systemProgram",
+              "isMut": false,
+              "isSigner": false
+            }
+          ]
+        },
+        {
+          "name": "inboxRateLimit",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "outboxChainRateLimit",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "peer",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "sessionAuthority",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "See [`crate::SESSION_AUTHORITY_SEED`] for an explanation of the flow."
+          ]
+        },
+        {
+          "name": "tokenAuthority",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "TransferArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "transferLock",
+      "accounts": [
+        {
+          "name": "common",
+          "accounts": [
+            {
+              "name": "payer",
+              "isMut": true,
+              "isSigner": true
+            },
+            {
+              "name": "config",
+              "accounts": [
+                {
+                  "name": "config",
+                  "isMut": false,
+                  "isSigner": false
+                }
+              ]
+            },
+            {
+              "name": "mint",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "from",
+              "isMut": true,
+              "isSigner": false,
+              "docs": [
+                "account can spend these tokens."
+              ]
+            },
+            {
+              "name": "tokenProgram",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "outboxSequence",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "outboxItem",
+              "isMut": true,
+              "isSigner": false,
+              "docs": [
+                "NOTE: the item is derived from the next sequence number, so if another",
+                "transfer takes it first, this transfer fails instead of being sent",
+                "twice. Clients can then retry with the new sequence number."
+              ]
+            },
+            {
+              "name": "outboxRateLimit",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "custody",
+              "isMut": true,
+              "isSigner": false,
+              "docs": [
+                "Tokens are always transferred to the custody account first regardless of",
+                "the mode.",
+                "For an explanation, see the note in [`transfer_burn`]."
+              ]
+            },
+            {
+              "name": "systemProgram",
+              "isMut": false,
+              "isSigner": false
+            }
+          ]
+        },
+        {
+          "name": "inboxRateLimit",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "outboxChainRateLimit",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "peer",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "sessionAuthority",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "See [`crate::SESSION_AUTHORITY_SEED`] for an explanation of the flow."
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "TransferArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "transferBurnWithPayload",
+      "accounts": [
+        {
+          "name": "common",
+          "accounts": [
+            {
+              "name": "payer",
+              "isMut": true,
+              "isSigner": true
+            },
+            {
+              "name": "config",
+              "accounts": [
+                {
+                  "name": "config",
+                  "isMut": false,
+                  "isSigner": false
+                }
+              ]
+            },
+            {
+              "name": "mint",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "from",
+              "isMut": true,
+              "isSigner": false,
+              "docs": [
+                "account can spend these tokens."
+              ]
+            },
+            {
+              "name": "tokenProgram",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "outboxSequence",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "outboxItem",
+              "isMut": true,
+              "isSigner": false,
+              "docs": [
+                "NOTE: the item is derived from the next sequence number, so if another",
+                "transfer takes it first, this transfer fails instead of being sent",
+                "twice. Clients can then retry with the new sequence number."
+              ]
+            },
+            {
+              "name": "outboxRateLimit",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "custody",
+              "isMut": true,
+              "isSigner": false,
+              "docs": [
+                "Tokens are always transferred to the custody account first regardless of",
+                "the mode.",
+                "For an explanation, see the note in [`transfer_burn`]."
+              ]
+            },
+            {
+              "name": "systemProgram",
+              "isMut": false,
+              "isSigner": false
+            }
+          ]
+        },
+        {
+          "name": "inboxRateLimit",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "outboxChainRateLimit",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "peer",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "sessionAuthority",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "See [`crate::SESSION_AUTHORITY_SEED`] for an explanation of the flow."
+          ]
+        },
+        {
+          "name": "tokenAuthority",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "TransferWithPayloadArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "transferLockWithPayload",
+      "accounts": [
+        {
+          "name": "common",
+          "accounts": [
+            {
+              "name": "payer",
+              "isMut": true,
+              "isSigner": true
+            },
+            {
+              "name": "config",
+              "accounts": [
+                {
+                  "name": "config",
+                  "isMut": false,
+                  "isSigner": false
+                }
+              ]
+            },
+            {
+              "name": "mint",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "from",
+              "isMut": true,
+              "isSigner": false,
+              "docs": [
+                "account can spend these tokens."
+              ]
+            },
+            {
+              "name": "tokenProgram",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "outboxSequence",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "outboxItem",
+              "isMut": true,
+              "isSigner": false,
+              "docs": [
+                "NOTE: the item is derived from the next sequence number, so if another",
+                "transfer takes it first, this transfer fails instead of being sent",
+                "twice. Clients can then retry with the new sequence number."
+              ]
+            },
+            {
+              "name": "outboxRateLimit",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "custody",
+              "isMut": true,
+              "isSigner": false,
+              "docs": [
+                "Tokens are always transferred to the custody account first regardless of",
+                "the mode.",
+                "For an explanation, see the note in [`transfer_burn`]."
+              ]
+            },
+            {
+              "name": "systemProgram",
+              "isMut": false,
+              "isSigner": false
+            }
+          ]
+        },
+        {
+          "name": "inboxRateLimit",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "outboxChainRateLimit",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "peer",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "sessionAuthority",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "See [`crate::SESSION_AUTHORITY_SEED`] for an explanation of the flow."
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "TransferWithPayloadArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "cancelOutboundQueuedTransfer",
+      "accounts": [
+        {
+          "name": "sender",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "config",
+          "accounts": [
+            {
+              "name": "config",
+              "isMut": false,
+              "isSigner": false
+            }
+          ]
+        },
+        {
+          "name": "outboxItem",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "mint",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "senderTokenAccount",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "The token account the tokens are returned to. Must be owned by the",
+            "original sender."
+          ]
+        },
+        {
+          "name": "tokenAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "custody",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "outboxRateLimit",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "outboxChainRateLimit",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "inboxRateLimit",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "multisigTokenAuthority",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Only used in burning mode, when the mint authority is a multisig."
+          ]
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "redeem",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "peer",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "transceiverMessage",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "`Account<T>` and `owner` constraints are mutually-exclusive"
+          ]
+        },
+        {
+          "name": "transceiver",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "mint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "inboxItem",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "NOTE: This account is content-addressed (PDA seeded by the message hash).",
+            "This is because in a multi-transceiver configuration, the different",
+            "transceivers \"vote\" on messages (by delivering them). By making the inbox",
+            "items content-addressed, we can ensure that disagreeing votes don't",
+            "interfere with each other.",
+            "On the first call to [`redeem()`], [`InboxItem`] will be allocated and initialized with",
+            "default values.",
+            "On subsequent calls, we want to modify the `InboxItem` by \"voting\" on it. Therefore the",
+            "program should not fail which would occur when using the `init` constraint.",
+            "The [`InboxItem::init`] field is used to guard against malicious or accidental modification",
+            "InboxItem fields that should remain constant."
+          ]
+        },
+        {
+          "name": "inboxRateLimit",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "outboxRateLimit",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "outboxChainRateLimit",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "RedeemArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "reevaluateInboxItem",
+      "accounts": [
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "peer",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "inboxItem",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "inboxRateLimit",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "outboxRateLimit",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "outboxChainRateLimit",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "freezeInboxItem",
+      "accounts": [
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": true,
+          "docs": [
+            "The owner, or the holder of [`Role::Guardian`]."
+          ]
+        },
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "inboxItem",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "unfreezeInboxItem",
+      "accounts": [
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": true,
+          "docs": [
+            "The owner, or the holder of [`Role::Guardian`]."
+          ]
+        },
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "inboxItem",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "rejectInboxItem",
+      "accounts": [
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": true,
+          "docs": [
+            "The owner, or the holder of [`Role::Guardian`]."
+          ]
+        },
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "inboxItem",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "forceReleaseInboxItem",
+      "accounts": [
+        {
+          "name": "owner",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "inboxItem",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "releaseInboundMint",
+      "accounts": [
+        {
+          "name": "common",
+          "accounts": [
+            {
+              "name": "payer",
+              "isMut": true,
+              "isSigner": true
+            },
+            {
+              "name": "config",
+              "accounts": [
+                {
+                  "name": "config",
+                  "isMut": false,
+                  "isSigner": false
+                }
+              ]
+            },
+            {
+              "name": "inboxItem",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "peer",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "recipient",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "tokenAuthority",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "mint",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "tokenProgram",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "custody",
+              "isMut": true,
+              "isSigner": false
+            }
+          ]
+        },
+        {
+          "name": "multisigTokenAuthority",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "ReleaseInboundArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "releaseInboundUnlock",
+      "accounts": [
+        {
+          "name": "common",
+          "accounts": [
+            {
+              "name": "payer",
+              "isMut": true,
+              "isSigner": true
+            },
+            {
+              "name": "config",
+              "accounts": [
+                {
+                  "name": "config",
+                  "isMut": false,
+                  "isSigner": false
+                }
+              ]
+            },
+            {
+              "name": "inboxItem",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "peer",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "recipient",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "tokenAuthority",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "mint",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "tokenProgram",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "custody",
+              "isMut": true,
+              "isSigner": false
+            }
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "ReleaseInboundArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "releaseInboundMintAndCall",
+      "accounts": [
+        {
+          "name": "release",
+          "accounts": [
+            {
+              "name": "common",
+              "accounts": [
+                {
+                  "name": "payer",
+                  "isMut": true,
+                  "isSigner": true
+                },
+                {
+                  "name": "config",
+                  "accounts": [
+                    {
+                      "name": "config",
+                      "isMut": false,
+                      "isSigner": false
+                    }
+                  ]
+                },
+                {
+                  "name": "inboxItem",
+                  "isMut": true,
+                  "isSigner": false
+                },
+                {
+                  "name": "peer",
+                  "isMut": false,
+                  "isSigner": false
+                },
+                {
+                  "name": "recipient",
+                  "isMut": true,
+                  "isSigner": false
+                },
+                {
+                  "name": "tokenAuthority",
+                  "isMut": false,
+                  "isSigner": false
+                },
+                {
+                  "name": "mint",
+                  "isMut": true,
+                  "isSigner": false
+                },
+                {
+                  "name": "tokenProgram",
+                  "isMut": false,
+                  "isSigner": false
+                },
+                {
+                  "name": "custody",
+                  "isMut": true,
+                  "isSigner": false
+                }
+              ]
+            },
+            {
+              "name": "multisigTokenAuthority",
+              "isMut": false,
+              "isSigner": false,
+              "isOptional": true
+            }
+          ]
+        },
+        {
+          "name": "call",
+          "accounts": [
+            {
+              "name": "callAuthority",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "recipientProgram",
+              "isMut": false,
+              "isSigner": false
+            }
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "ReleaseInboundAndCallArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "releaseInboundUnlockAndCall",
+      "accounts": [
+        {
+          "name": "release",
+          "accounts": [
+            {
+              "name": "common",
+              "accounts": [
+                {
+                  "name": "payer",
+                  "isMut": true,
+                  "isSigner": true
+                },
+                {
+                  "name": "config",
+                  "accounts": [
+                    {
+                      "name": "config",
+                      "isMut": false,
+                      "isSigner": false
+                    }
+                  ]
+                },
+                {
+                  "name": "inboxItem",
+                  "isMut": true,
+                  "isSigner": false
+                },
+                {
+                  "name": "peer",
+                  "isMut": false,
+                  "isSigner": false
+                },
+                {
+                  "name": "recipient",
+                  "isMut": true,
+                  "isSigner": false
+                },
+                {
+                  "name": "tokenAuthority",
+                  "isMut": false,
+                  "isSigner": false
+                },
+                {
+                  "name": "mint",
+                  "isMut": true,
+                  "isSigner": false
+                },
+                {
+                  "name": "tokenProgram",
+                  "isMut": false,
+                  "isSigner": false
+                },
+                {
+                  "name": "custody",
+                  "isMut": true,
+                  "isSigner": false
+                }
+              ]
+            }
+          ]
+        },
+        {
+          "name": "call",
+          "accounts": [
+            {
+              "name": "callAuthority",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "recipientProgram",
+              "isMut": false,
+              "isSigner": false
+            }
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "ReleaseInboundAndCallArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "closeOutboxItem",
+      "accounts": [
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "outboxItem",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "rentPayer",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "closeInboxItem",
+      "accounts": [
+        {
+          "name": "inboxItem",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "`Account<InboxItem>` here, because anchor would serialize the item back",
+            "into the account at the end of the instruction, overwriting the",
+            "tombstone."
+          ]
+        },
+        {
+          "name": "rentPayer",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "escrowInboxItem",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "owner",
+          "isMut": false,
+          "isSigner": true,
+          "isOptional": true,
+          "docs": [
+            "The owner, to escrow the transfer before [`Config::escrow_timeout`] has",
+            "passed (or when it's disabled)."
+          ]
+        },
+        {
+          "name": "config",
+          "accounts": [
+            {
+              "name": "config",
+              "isMut": false,
+              "isSigner": false
+            }
+          ]
+        },
+        {
+          "name": "inboxItem",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "multisigTokenAuthority",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "escrowAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "escrow",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "mint",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "custody",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "associatedTokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "redirectEscrowedInboxItem",
+      "accounts": [
+        {
+          "name": "owner",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "inboxItem",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "escrowAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "escrow",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "recipient",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "The token account the tokens are sent to. It can be owned by anyone."
+          ]
+        },
+        {
+          "name": "mint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "returnEscrowedInboxItem",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "owner",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "inboxItem",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "peer",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "escrowAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "escrow",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "mint",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "custody",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "outboxSequence",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "outboxItem",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "outboxRateLimit",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "inboxRateLimit",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "outboxChainRateLimit",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "transferOwnership",
+      "accounts": [
+        {
+          "name": "config",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "owner",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "newOwner",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "upgradeLock",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "programData",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "bpfLoaderUpgradeableProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "transferOwnershipOneStepUnchecked",
+      "accounts": [
+        {
+          "name": "config",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "owner",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "newOwner",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "upgradeLock",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "programData",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "bpfLoaderUpgradeableProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "claimOwnership",
+      "accounts": [
+        {
+          "name": "config",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "upgradeLock",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "newOwner",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "programData",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "bpfLoaderUpgradeableProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "acceptTokenAuthority",
+      "accounts": [
+        {
+          "name": "common",
+          "accounts": [
+            {
+              "name": "config",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "mint",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "tokenAuthority",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "multisigTokenAuthority",
+              "isMut": false,
+              "isSigner": false,
+              "isOptional": true
+            },
+            {
+              "name": "tokenProgram",
+              "isMut": false,
+              "isSigner": false
+            }
+          ]
+        },
+        {
+          "name": "currentAuthority",
+          "isMut": false,
+          "isSigner": true
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "acceptTokenAuthorityFromMultisig",
+      "accounts": [
+        {
+          "name": "common",
+          "accounts": [
+            {
+              "name": "config",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "mint",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "tokenAuthority",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "multisigTokenAuthority",
+              "isMut": false,
+              "isSigner": false,
+              "isOptional": true
+            },
+            {
+              "name": "tokenProgram",
+              "isMut": false,
+              "isSigner": false
+            }
+          ]
+        },
+        {
+          "name": "currentMultisigAuthority",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "setTokenAuthorityOneStepUnchecked",
+      "accounts": [
+        {
+          "name": "common",
+          "accounts": [
+            {
+              "name": "config",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "owner",
+              "isMut": false,
+              "isSigner": true
+            },
+            {
+              "name": "mint",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "tokenAuthority",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "multisigTokenAuthority",
+              "isMut": false,
+              "isSigner": false,
+              "isOptional": true
+            },
+            {
+              "name": "newAuthority",
+              "isMut": false,
+              "isSigner": false
+            }
+          ]
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "setTokenAuthority",
+      "accounts": [
+        {
+          "name": "common",
+          "accounts": [
+            {
+              "name": "config",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "owner",
+              "isMut": false,
+              "isSigner": true
+            },
+            {
+              "name": "mint",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "tokenAuthority",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "multisigTokenAuthority",
+              "isMut": false,
+              "isSigner": false,
+              "isOptional": true
+            },
+            {
+              "name": "newAuthority",
+              "isMut": false,
+              "isSigner": false
+            }
+          ]
+        },
+        {
+          "name": "rentPayer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "pendingTokenAuthority",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "revertTokenAuthority",
+      "accounts": [
+        {
+          "name": "common",
+          "accounts": [
+            {
+              "name": "config",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "mint",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "tokenAuthority",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "multisigTokenAuthority",
+              "isMut": false,
+              "isSigner": false,
+              "isOptional": true
+            },
+            {
+              "name": "rentPayer",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "pendingTokenAuthority",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "tokenProgram",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "systemProgram",
+              "isMut": false,
+              "isSigner": false
+            }
+          ]
+        },
+        {
+          "name": "owner",
+          "isMut": false,
+          "isSigner": true
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "claimTokenAuthority",
+      "accounts": [
+        {
+          "name": "common",
+          "accounts": [
+            {
+              "name": "config",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "mint",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "tokenAuthority",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "multisigTokenAuthority",
+              "isMut": false,
+              "isSigner": false,
+              "isOptional": true
+            },
+            {
+              "name": "rentPayer",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "pendingTokenAuthority",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "tokenProgram",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "systemProgram",
+              "isMut": false,
+              "isSigner": false
+            }
+          ]
+        },
+        {
+          "name": "newAuthority",
+          "isMut": false,
+          "isSigner": true
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "claimTokenAuthorityToMultisig",
+      "accounts": [
+        {
+          "name": "common",
+          "accounts": [
+            {
+              "name": "config",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "mint",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "tokenAuthority",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "multisigTokenAuthority",
+              "isMut": false,
+              "isSigner": false,
+              "isOptional": true
+            },
+            {
+              "name": "rentPayer",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "pendingTokenAuthority",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "tokenProgram",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "systemProgram",
+              "isMut": false,
+              "isSigner": false
+            }
+          ]
+        },
+        {
+          "name": "newMultisigAuthority",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "setPaused",
+      "accounts": [
+        {
+          "name": "owner",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "config",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "pause",
+          "type": "bool"
+        }
+      ]
+    },
+    {
+      "name": "pause",
+      "accounts": [
+        {
+          "name": "pauser",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "config",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "grantRole",
+      "accounts": [
+        {
+          "name": "owner",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "config",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "role",
+          "type": {
+            "defined": "Role"
+          }
+        },
+        {
+          "name": "holder",
+          "type": "publicKey"
+        }
+      ]
+    },
+    {
+      "name": "revokeRole",
+      "accounts": [
+        {
+          "name": "owner",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "config",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "role",
+          "type": {
+            "defined": "Role"
+          }
+        }
+      ]
+    },
+    {
+      "name": "setOutboundPaused",
+      "accounts": [
+        {
+          "name": "owner",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "config",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "paused",
+          "type": "bool"
+        }
+      ]
+    },
+    {
+      "name": "setInboundPaused",
+      "accounts": [
+        {
+          "name": "owner",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "config",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "paused",
+          "type": "bool"
+        }
+      ]
+    },
+    {
+      "name": "setPeerPaused",
+      "accounts": [
+        {
+          "name": "owner",
+          "isMut": false,
+          "isSigner": true,
+          "docs": [
+            "The owner, or the holder of [`Role::PeerAdmin`]."
+          ]
+        },
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "peer",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "SetPeerPausedArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "pauseOutbound",
+      "accounts": [
+        {
+          "name": "pauser",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "config",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "pauseInbound",
+      "accounts": [
+        {
+          "name": "pauser",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "config",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "pausePeer",
+      "accounts": [
+        {
+          "name": "pauser",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "peer",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "PausePeerArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "setPeerThreshold",
+      "accounts": [
+        {
+          "name": "owner",
+          "isMut": false,
+          "isSigner": true,
+          "docs": [
+            "The owner, or the holder of [`Role::TransceiverAdmin`]."
+          ]
+        },
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "peer",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "SetPeerThresholdArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "executeSetPeerThreshold",
+      "accounts": [
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rentPayer",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "pendingChange",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "peer",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "SetPeerThresholdArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "setLegacyPeerAddresses",
+      "accounts": [
+        {
+          "name": "owner",
+          "isMut": false,
+          "isSigner": true,
+          "docs": [
+            "The owner, or the holder of [`Role::PeerAdmin`]."
+          ]
+        },
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "peer",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "SetLegacyPeerAddressesArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "executeSetLegacyPeerAddresses",
+      "accounts": [
+        {
+          "name": "rentPayer",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "pendingChange",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "peer",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "SetLegacyPeerAddressesArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "removePeer",
+      "accounts": [
+        {
+          "name": "owner",
+          "isMut": false,
+          "isSigner": true,
+          "docs": [
+            "The owner, or the holder of [`Role::PeerAdmin`]."
+          ]
+        },
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "peer",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "RemovePeerArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "closePeer",
+      "accounts": [
+        {
+          "name": "owner",
+          "isMut": false,
+          "isSigner": true,
+          "docs": [
+            "The owner, or the holder of [`Role::PeerAdmin`]."
+          ]
+        },
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "peer",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "inboxRateLimit",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "outboxChainRateLimit",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "rentRecipient",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "ClosePeerArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "setPreciseQueueRelease",
+      "accounts": [
+        {
+          "name": "owner",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "config",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "enabled",
+          "type": "bool"
+        }
+      ]
+    },
+    {
+      "name": "setStrictDust",
+      "accounts": [
+        {
+          "name": "owner",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "config",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "enabled",
+          "type": "bool"
+        }
+      ]
+    },
+    {
+      "name": "setEscrowTimeout",
+      "accounts": [
+        {
+          "name": "owner",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "config",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "timeout",
+          "type": "i64"
+        }
+      ]
+    },
+    {
+      "name": "setDenylistEnabled",
+      "accounts": [
+        {
+          "name": "owner",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "config",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "enabled",
+          "type": "bool"
+        }
+      ]
+    },
+    {
+      "name": "setPolicyProgram",
+      "accounts": [
+        {
+          "name": "owner",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "config",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "policyProgram",
+          "type": {
+            "option": "publicKey"
+          }
+        }
+      ]
+    },
+    {
+      "name": "addToDenylist",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": true,
+          "docs": [
+            "The owner, or the holder of [`Role::ComplianceAdmin`]."
+          ]
+        },
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "entry",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "address",
+          "type": {
+            "array": [
+              "u8",
+              32
+            ]
+          }
+        }
+      ]
+    },
+    {
+      "name": "removeFromDenylist",
+      "accounts": [
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": true,
+          "docs": [
+            "The owner, or the holder of [`Role::ComplianceAdmin`]."
+          ]
+        },
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "entry",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "rentPayer",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "address",
+          "type": {
+            "array": [
+              "u8",
+              32
+            ]
+          }
+        }
+      ]
+    },
+    {
+      "name": "unquarantineInboxItem",
+      "accounts": [
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": true,
+          "docs": [
+            "The owner, or the holder of [`Role::ComplianceAdmin`]."
+          ]
+        },
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "inboxItem",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "setTimelockDelay",
+      "accounts": [
+        {
+          "name": "owner",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "config",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "delay",
+          "type": "i64"
+        }
+      ]
+    },
+    {
+      "name": "proposeChange",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "proposer",
+          "isMut": false,
+          "isSigner": true,
+          "docs": [
+            "The owner, or the holder of the role that can make `change` directly",
+            "(see [`ConfigChange::can_propose`])."
+          ]
+        },
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "pendingChange",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "change",
+          "type": {
+            "defined": "ConfigChange"
+          }
+        }
+      ]
+    },
+    {
+      "name": "vetoChange",
+      "accounts": [
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": true,
+          "docs": [
+            "The owner, or the holder of [`Role::Guardian`]."
+          ]
+        },
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rentPayer",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "pendingChange",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "executeChange",
+      "accounts": [
+        {
+          "name": "config",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "rentPayer",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "pendingChange",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "executeSetPeer",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rentPayer",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "pendingChange",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "peer",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "inboxRateLimit",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "outboxChainRateLimit",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "SetPeerArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "executeRegisterTransceiver",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "config",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "transceiver",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rentPayer",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "pendingChange",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "registeredTransceiver",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "executeSetTokenAuthority",
+      "accounts": [
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "mint",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "multisigTokenAuthority",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "newAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rentPayer",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "pendingChange",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "executeDeregisterTransceiver",
+      "accounts": [
+        {
+          "name": "config",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "registeredTransceiver",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rentPayer",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "pendingChange",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "executeSetOutboundLimit",
+      "accounts": [
+        {
+          "name": "rentPayer",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "pendingChange",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "rateLimit",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "SetOutboundLimitArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "executeSetOutboundChainLimit",
+      "accounts": [
+        {
+          "name": "rentPayer",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "pendingChange",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "rateLimit",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "SetOutboundChainLimitArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "executeSetInboundLimit",
+      "accounts": [
+        {
+          "name": "rentPayer",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "pendingChange",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "rateLimit",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "SetInboundLimitArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "executeSetOutboundRateLimitDuration",
+      "accounts": [
+        {
+          "name": "rentPayer",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "pendingChange",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "rateLimit",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "SetOutboundRateLimitDurationArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "executeSetOutboundChainRateLimitDuration",
+      "accounts": [
+        {
+          "name": "rentPayer",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "pendingChange",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "rateLimit",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "SetOutboundChainRateLimitDurationArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "executeSetInboundRateLimitDuration",
+      "accounts": [
+        {
+          "name": "rentPayer",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "pendingChange",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "rateLimit",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "SetInboundRateLimitDurationArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "setPeer",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "owner",
+          "isMut": false,
+          "isSigner": true,
+          "docs": [
+            "The owner, or the holder of [`Role::PeerAdmin`]."
+          ]
+        },
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "peer",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "inboxRateLimit",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "outboxChainRateLimit",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "SetPeerArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "registerTransceiver",
+      "accounts": [
+        {
+          "name": "config",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "owner",
+          "isMut": false,
+          "isSigner": true,
+          "docs": [
+            "The owner, or the holder of [`Role::TransceiverAdmin`]."
+          ]
+        },
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "transceiver",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "used here that wraps the Transceiver account type."
+          ]
+        },
+        {
+          "name": "registeredTransceiver",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "deregisterTransceiver",
+      "accounts": [
+        {
+          "name": "config",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "owner",
+          "isMut": false,
+          "isSigner": true,
+          "docs": [
+            "The owner, or the holder of [`Role::TransceiverAdmin`]."
+          ]
+        },
+        {
+          "name": "registeredTransceiver",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "setOutboundLimit",
+      "accounts": [
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "owner",
+          "isMut": false,
+          "isSigner": true,
+          "docs": [
+            "The owner, or the holder of [`Role::RateLimitAdmin`]."
+          ]
+        },
+        {
+          "name": "rateLimit",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "SetOutboundLimitArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "setOutboundChainLimit",
+      "accounts": [
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "owner",
+          "isMut": false,
+          "isSigner": true,
+          "docs": [
+            "The owner, or the holder of [`Role::RateLimitAdmin`]."
+          ]
+        },
+        {
+          "name": "rateLimit",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "SetOutboundChainLimitArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "setInboundLimit",
+      "accounts": [
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "owner",
+          "isMut": false,
+          "isSigner": true,
+          "docs": [
+            "The owner, or the holder of [`Role::RateLimitAdmin`]."
+          ]
+        },
+        {
+          "name": "rateLimit",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "SetInboundLimitArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "setOutboundRateLimitDuration",
+      "accounts": [
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "owner",
+          "isMut": false,
+          "isSigner": true,
+          "docs": [
+            "The owner, or the holder of [`Role::RateLimitAdmin`]."
+          ]
+        },
+        {
+          "name": "rateLimit",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "SetOutboundRateLimitDurationArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "setOutboundChainRateLimitDuration",
+      "accounts": [
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "owner",
+          "isMut": false,
+          "isSigner": true,
+          "docs": [
+            "The owner, or the holder of [`Role::RateLimitAdmin`]."
+          ]
+        },
+        {
+          "name": "rateLimit",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "SetOutboundChainRateLimitDurationArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "setInboundRateLimitDuration",
+      "accounts": [
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "owner",
+          "isMut": false,
+          "isSigner": true,
+          "docs": [
+            "The owner, or the holder of [`Role::RateLimitAdmin`]."
+          ]
+        },
+        {
+          "name": "rateLimit",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "SetInboundRateLimitDurationArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "migrate",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "owner",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "so only its owner is read in [`migrate`]. It comes before the fields",
+            "added since, so its offset is the same in every layout."
+          ]
+        },
+        {
+          "name": "account",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "discriminator is checked in [`migrate`]."
+          ]
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "MigrateArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "initOutboxChainRateLimit",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "peer",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "outboxChainRateLimit",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "InitOutboxChainRateLimitArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "markOutboxItemAsReleased",
+      "accounts": [
+        {
+          "name": "signer",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "config",
+          "accounts": [
+            {
+              "name": "config",
+              "isMut": false,
+              "isSigner": false
+            }
+          ]
+        },
+        {
+          "name": "outboxItem",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "peer",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "transceiver",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [],
+      "returns": "bool"
+    },
+    {
+      "name": "setThreshold",
+      "accounts": [
+        {
+          "name": "owner",
+          "isMut": false,
+          "isSigner": true,
+          "docs": [
+            "The owner, or the holder of [`Role::TransceiverAdmin`]."
+          ]
+        },
+        {
+          "name": "config",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "threshold",
+          "type": "u8"
+        }
+      ]
+    },
+    {
+      "name": "setWormholePeer",
+      "accounts": [
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "owner",
+          "isMut": false,
+          "isSigner": true,
+          "docs": [
+            "The owner, or the holder of [`Role::TransceiverAdmin`]."
+          ]
+        },
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "peer",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "SetTransceiverPeerArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "receiveWormholeMessage",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "config",
+          "accounts": [
+            {
+              "name": "config",
+              "isMut": false,
+              "isSigner": false
+            }
+          ]
+        },
+        {
+          "name": "peer",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "vaa",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "transceiverMessage",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "releaseWormholeOutbound",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "config",
+          "accounts": [
+            {
+              "name": "config",
+              "isMut": false,
+              "isSigner": false
+            }
+          ]
+        },
+        {
+          "name": "outboxItem",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "peer",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "transceiver",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "wormholeMessage",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "emitter",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "wormhole",
+          "accounts": [
+            {
+              "name": "bridge",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "feeCollector",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "sequence",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "program",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "systemProgram",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "clock",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "rent",
+              "isMut": false,
+              "isSigner": false
+            }
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "ReleaseOutboundArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "closeWormholeTransceiverMessage",
+      "accounts": [
+        {
+          "name": "transceiverMessage",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "inboxItem",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "message, and [`InboxItem::is_done`] checks the owner."
+          ]
+        },
+        {
+          "name": "rentPayer",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "broadcastWormholeId",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "mint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "wormholeMessage",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "emitter",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "enforced by the [`CpiContext`] call in [`post_message`].",
+            "The seeds constraint ensures that this is the correct address"
+          ]
+        },
+        {
+          "name": "wormhole",
+          "accounts": [
+            {
+              "name": "bridge",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "feeCollector",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "sequence",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "program",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "systemProgram",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "clock",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "rent",
+              "isMut": false,
+              "isSigner": false
+            }
+          ]
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "broadcastWormholePeer",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "peer",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "wormholeMessage",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "emitter",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "wormhole",
+          "accounts": [
+            {
+              "name": "bridge",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "feeCollector",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "sequence",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "program",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "systemProgram",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "clock",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "rent",
+              "isMut": false,
+              "isSigner": false
+            }
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "BroadcastPeerArgs"
+          }
+        }
+      ]
+    }
+  ],
+  "accounts": [
+    {
+      "name": "denylistEntry",
+      "docs": [
+        "Marks an address as denied. Stored in a PDA seeded by the address."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "rentPayer",
+            "docs": [
+              "The account that paid the rent for this entry. The rent is returned to",
+              "this account when the entry is removed."
+            ],
+            "type": "publicKey"
+          }
+        ]
+      }
+    },
+    {
+      "name": "config",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "owner",
+            "docs": [
+              "Owner of the program."
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "pendingOwner",
+            "docs": [
+              "Pending next owner (before claiming ownership)."
+            ],
+            "type": {
+              "option": "publicKey"
+            }
+          },
+          {
+            "name": "mint",
+            "docs": [
+              "Mint address of the token managed by this program."
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "tokenProgram",
+            "docs": [
+              "Address of the token program (token or token22). This could always be queried",
+              "from the [`mint`] account's owner, but storing it here avoids an indirection",
+              "on the client side."
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "mode",
+            "docs": [
+              "The mode that this program is running in. This is used to determine",
+              "whether the program is burning tokens or locking tokens."
+            ],
+            "type": {
+              "defined": "Mode"
+            }
+          },
+          {
+            "name": "chainId",
+            "docs": [
+              "The chain id of the chain that this program is running on. We don't",
+              "hardcode this so that the program is deployable on any potential SVM",
+              "forks."
+            ],
+            "type": {
+              "defined": "ChainId"
+            }
+          },
+          {
+            "name": "nextTransceiverId",
+            "docs": [
+              "The next transceiver id to use when registering an transceiver."
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "threshold",
+            "docs": [
+              "The number of transceivers that must attest to a transfer before it is",
+              "accepted."
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "enabledTransceivers",
+            "docs": [
+              "Bitmap of enabled transceivers.",
+              "The maximum number of transceivers is equal to [`Bitmap::BITS`]."
+            ],
+            "type": {
+              "defined": "Bitmap"
+            }
+          },
+          {
+            "name": "paused",
+            "docs": [
+              "Pause the program. This is useful for upgrades and other maintenance."
+            ],
+            "type": "bool"
+          },
+          {
+            "name": "custody",
+            "docs": [
+              "The custody account that holds tokens in locking mode."
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "preciseQueueRelease",
+            "docs": [
+              "When a transfer exceeds the rate limit, reserve the missing capacity",
+              "and queue the transfer only until it has refilled, instead of delaying",
+              "it by a full rate limit window.",
+              "See [`crate::queue::rate_limit::RateLimitState::consume_or_reserve_all`]."
+            ],
+            "type": "bool"
+          },
+          {
+            "name": "strictDust",
+            "docs": [
+              "Reject outbound transfers whose amount has dust (i.e. it doesn't trim",
+              "cleanly to the recipient chain's decimals), instead of silently",
+              "transferring the amount with the dust removed."
+            ],
+            "type": "bool"
+          },
+          {
+            "name": "pauser",
+            "docs": [
+              "Holder of [`Role::Pauser`]."
+            ],
+            "type": {
+              "option": "publicKey"
+            }
+          },
+          {
+            "name": "outboundPaused",
+            "docs": [
+              "Pause outbound transfers (and releasing queued outbound transfers),",
+              "while inbound transfers keep working."
+            ],
+            "type": "bool"
+          },
+          {
+            "name": "inboundPaused",
+            "docs": [
+              "Pause releasing inbound transfers, while they can still be redeemed",
+              "(and queued)."
+            ],
+            "type": "bool"
+          },
+          {
+            "name": "rateLimitAdmin",
+            "docs": [
+              "Holder of [`Role::RateLimitAdmin`]."
+            ],
+            "type": {
+              "option": "publicKey"
+            }
+          },
+          {
+            "name": "peerAdmin",
+            "docs": [
+              "Holder of [`Role::PeerAdmin`]."
+            ],
+            "type": {
+              "option": "publicKey"
+            }
+          },
+          {
+            "name": "transceiverAdmin",
+            "docs": [
+              "Holder of [`Role::TransceiverAdmin`]."
+            ],
+            "type": {
+              "option": "publicKey"
+            }
+          },
+          {
+            "name": "timelockDelay",
+            "docs": [
+              "Delay (in seconds) between proposing a security-sensitive change and",
+              "executing it (see [`crate::pending_change::ConfigChange`]). Zero",
+              "disables the timelock, in which case these changes are made directly."
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "guardian",
+            "docs": [
+              "Holder of [`Role::Guardian`]."
+            ],
+            "type": {
+              "option": "publicKey"
+            }
+          },
+          {
+            "name": "complianceAdmin",
+            "docs": [
+              "Holder of [`Role::ComplianceAdmin`]."
+            ],
+            "type": {
+              "option": "publicKey"
+            }
+          },
+          {
+            "name": "denylistEnabled",
+            "docs": [
+              "Block transfers from and to the addresses on the denylist (see",
+              "[`crate::compliance`])."
+            ],
+            "type": "bool"
+          },
+          {
+            "name": "policyProgram",
+            "docs": [
+              "A program that is asked whether to allow each transfer, in addition to",
+              "the denylist (see [`crate::compliance`]). `None` disables this check."
+            ],
+            "type": {
+              "option": "publicKey"
+            }
+          },
+          {
+            "name": "escrowTimeout",
+            "docs": [
+              "How long (in seconds) after it became releasable an inbound transfer",
+              "that is still not released can be moved to the escrow by anyone (see",
+              "[`crate::instructions::escrow_inbox_item`]). Zero disables this, so",
+              "only the owner can escrow transfers."
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "version",
+            "docs": [
+              "The layout version of this account (see [`Config::LAYOUT_VERSION`]).",
+              "Fields added in later releases go after this one, and existing",
+              "accounts are upgraded with [`crate::instructions::migrate`]."
+            ],
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "LUT",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "address",
+            "type": "publicKey"
+          }
+        ]
+      }
+    },
+    {
+      "name": "nttManagerPeer",
+      "docs": [
+        "A peer on another chain. Stored in a PDA seeded by the chain id."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "address",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "tokenDecimals",
+            "type": "u8"
+          },
+          {
+            "name": "paused",
+            "docs": [
+              "Pause transfers to and from this peer. Inbound transfers from a paused",
+              "peer can't be redeemed or released, and outbound transfers to it can't",
+              "be released (but can be once it's unpaused)."
+            ],
+            "type": "bool"
+          },
+          {
+            "name": "retiredAt",
+            "docs": [
+              "When the peer was retired (see [`crate::instructions::remove_peer`]),",
+              "if it was. Outbound transfers to a retired peer are rejected, and",
+              "inbound transfers from it can only be redeemed until `closable_at`."
+            ],
+            "type": {
+              "option": "i64"
+            }
+          },
+          {
+            "name": "closableAt",
+            "docs": [
+              "When a retired peer (and its rate limits) can be closed."
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "rentRecipient",
+            "docs": [
+              "Receives the rent of a retired peer when it's closed."
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "legacyAddresses",
+            "docs": [
+              "Previous addresses of the peer that inbound transfers are still",
+              "accepted from for a while, e.g. when the peer's manager was redeployed",
+              "and transfers sent by the old one are still in flight.",
+              "Outbound transfers are only sent to `address`."
+            ],
+            "type": {
+              "vec": {
+                "defined": "LegacyAddress"
+              }
+            }
+          },
+          {
+            "name": "inboundThreshold",
+            "docs": [
+              "Overrides [`Config::threshold`] for inbound transfers from this peer."
+            ],
+            "type": {
+              "option": "u8"
+            }
+          },
+          {
+            "name": "inboundTransceivers",
+            "docs": [
+              "Restricts the transceivers whose votes are accepted for inbound",
+              "transfers from this peer to a subset of",
+              "[`Config::enabled_transceivers`]. All enabled transceivers are accepted",
+              "if unset."
+            ],
+            "type": {
+              "option": {
+                "defined": "Bitmap"
+              }
+            }
+          },
+          {
+            "name": "version",
+            "docs": [
+              "See [`crate::config::Config::version`]."
+            ],
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "pendingChange",
+      "docs": [
+        "A proposed [`ConfigChange`], which anyone can execute once",
+        "`executable_at` is reached. Until then, the owner or the guardian can veto",
+        "it.",
+        "Stored in a PDA seeded by the hash of the change, so the execute",
+        "instructions can check that they apply the proposed change."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "change",
+            "type": {
+              "defined": "ConfigChange"
+            }
+          },
+          {
+            "name": "executableAt",
+            "type": "i64"
+          },
+          {
+            "name": "rentPayer",
+            "type": "publicKey"
+          }
+        ]
+      }
+    },
+    {
+      "name": "pendingTokenAuthority",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "pendingAuthority",
+            "type": "publicKey"
+          },
+          {
+            "name": "rentPayer",
+            "type": "publicKey"
+          }
+        ]
+      }
+    },
+    {
+      "name": "inboxItem",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "init",
+            "type": "bool"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "amount",
+            "type": "u64"
+          },
+          {
+            "name": "recipientAddress",
+            "type": "publicKey"
+          },
+          {
+            "name": "votes",
+            "type": {
+              "defined": "Bitmap"
+            }
+          },
+          {
+            "name": "releaseStatus",
+            "type": {
+              "defined": "ReleaseStatus"
+            }
+          },
+          {
+            "name": "rentPayer",
+            "docs": [
+              "The account that paid the rent for this item. The rent is returned to",
+              "this account when the item is closed."
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "sourceChain",
+            "type": {
+              "defined": "ChainId"
+            }
+          },
+          {
+            "name": "sender",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "additionalPayload",
+            "docs": [
+              "The additional payload sent along with the tokens, if any. It is kept",
+              "after the transfer is released, so the recipient can read it."
+            ],
+            "type": {
+              "defined": "AdditionalPayload"
+            }
+          },
+          {
+            "name": "version",
+            "docs": [
+              "See [`crate::config::Config::version`]."
+            ],
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "inboxItemTombstone",
+      "docs": [
+        "What remains of an [`InboxItem`] after it has been closed.",
+        "",
+        "Inbox items are content-addressed, so closing them outright would allow the",
+        "same message to be redeemed again. Instead, the account is shrunk down to",
+        "just the discriminator of this type, and the rest of the rent is returned.",
+        "[`crate::instructions::redeem`] fails to deserialize the tombstone as an",
+        "[`InboxItem`], so the message can never be redeemed again."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": []
+      }
+    },
+    {
+      "name": "inboxRateLimit",
+      "docs": [
+        "Inbound rate limit per chain.",
+        "SECURITY: must check the PDA (since there are multiple PDAs, namely one for each chain.)"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "rateLimit",
+            "type": {
+              "defined": "RateLimitState"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "outboxItem",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "amount",
+            "type": {
+              "defined": "TrimmedAmount"
+            }
+          },
+          {
+            "name": "sender",
+            "type": "publicKey"
+          },
+          {
+            "name": "recipientChain",
+            "type": {
+              "defined": "ChainId"
+            }
+          },
+          {
+            "name": "recipientNttManager",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "recipientAddress",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "releaseTimestamp",
+            "type": "i64"
+          },
+          {
+            "name": "released",
+            "type": {
+              "defined": "Bitmap"
+            }
+          },
+          {
+            "name": "queued",
+            "docs": [
+              "Whether the transfer was queued because it exceeded the outbound rate",
+              "limit. Queued transfers don't consume outbound capacity (and don't",
+              "refill the inbound capacity) when they are inserted, unless they",
+              "reserved it."
+            ],
+            "type": "bool"
+          },
+          {
+            "name": "reserved",
+            "docs": [
+              "Whether the transfer reserved the outbound capacity it was missing",
+              "when it was queued (see [`crate::config::Config::precise_queue_release`]).",
+              "Reserved capacity counts as consumed, like for transfers that weren't",
+              "queued."
+            ],
+            "type": "bool"
+          },
+          {
+            "name": "cancelled",
+            "docs": [
+              "Whether the transfer has been cancelled by the sender. Cancelled",
+              "transfers can never be released."
+            ],
+            "type": "bool"
+          },
+          {
+            "name": "rentPayer",
+            "docs": [
+              "The account that paid the rent for this item. The rent is returned to",
+              "this account when the item is closed."
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "additionalPayload",
+            "docs": [
+              "An additional payload to be delivered to the recipient along with the",
+              "tokens. Empty for plain transfers."
+            ],
+            "type": {
+              "defined": "AdditionalPayload"
+            }
+          },
+          {
+            "name": "sequence",
+            "docs": [
+              "The outbound sequence number of the transfer. The item's address is",
+              "derived from it (see [`OutboxItem::SEED_PREFIX`])."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "version",
+            "docs": [
+              "See [`crate::config::Config::version`]."
+            ],
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "outboxSequence",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "next",
+            "docs": [
+              "The sequence number of the next outbound transfer."
+            ],
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "outboxRateLimit",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "rateLimit",
+            "type": {
+              "defined": "RateLimitState"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "outboxChainRateLimit",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "rateLimit",
+            "type": {
+              "defined": "RateLimitState"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "registeredTransceiver",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "id",
+            "type": "u8"
+          },
+          {
+            "name": "transceiverAddress",
+            "type": "publicKey"
+          },
+          {
+            "name": "version",
+            "docs": [
+              "See [`crate::config::Config::version`]."
+            ],
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "transceiverPeer",
+      "docs": [
+        "A peer on another chain. Stored in a PDA seeded by the chain id."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "address",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "bridgeData",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "guardianSetIndex",
+            "docs": [
+              "The current guardian set index, used to decide which signature sets to accept."
+            ],
+            "type": "u32"
+          },
+          {
+            "name": "lastLamports",
+            "docs": [
+              "Lamports in the collection account"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "config",
+            "docs": [
+              "Bridge configuration, which is set once upon initialization."
+            ],
+            "type": {
+              "defined": "BridgeConfig"
+            }
+          }
+        ]
+      }
+    }
+  ],
+  "types": [
+    {
+      "name": "Bitmap",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "map",
+            "type": "u128"
+          }
+        ]
+      }
+    },
+    {
+      "name": "SetPeerArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "chainId",
+            "type": {
+              "defined": "ChainId"
+            }
+          },
+          {
+            "name": "address",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "limit",
+            "type": "u64"
+          },
+          {
+            "name": "tokenDecimals",
+            "docs": [
+              "The token decimals on the peer chain."
+            ],
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "SetOutboundLimitArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "limit",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "SetOutboundChainLimitArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "limit",
+            "type": "u64"
+          },
+          {
+            "name": "chainId",
+            "type": {
+              "defined": "ChainId"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "SetInboundLimitArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "limit",
+            "type": "u64"
+          },
+          {
+            "name": "chainId",
+            "type": {
+              "defined": "ChainId"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "SetOutboundRateLimitDurationArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "duration",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "SetOutboundChainRateLimitDurationArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "duration",
+            "type": "i64"
+          },
+          {
+            "name": "chainId",
+            "type": {
+              "defined": "ChainId"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "SetInboundRateLimitDurationArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "duration",
+            "type": "i64"
+          },
+          {
+            "name": "chainId",
+            "type": {
+              "defined": "ChainId"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "SetPeerPausedArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "chainId",
+            "type": {
+              "defined": "ChainId"
+            }
+          },
+          {
+            "name": "paused",
+            "type": "bool"
+          }
+        ]
+      }
+    },
+    {
+      "name": "PausePeerArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "chainId",
+            "type": {
+              "defined": "ChainId"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "SetLegacyPeerAddressesArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "chainId",
+            "type": {
+              "defined": "ChainId"
+            }
+          },
+          {
+            "name": "addresses",
+            "type": {
+              "vec": {
+                "defined": "LegacyAddress"
+              }
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "RemovePeerArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "chainId",
+            "type": {
+              "defined": "ChainId"
+            }
+          },
+          {
+            "name": "gracePeriod",
+            "docs": [
+              "How long (in seconds) inbound transfers from the peer can still be",
+              "redeemed for, before the peer can be closed."
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "rentRecipient",
+            "docs": [
+              "Receives the rent of the peer and its rate limits when they are closed."
+            ],
+            "type": "publicKey"
+          }
+        ]
+      }
+    },
+    {
+      "name": "ClosePeerArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "chainId",
+            "type": {
+              "defined": "ChainId"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "SetPeerThresholdArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "chainId",
+            "type": {
+              "defined": "ChainId"
+            }
+          },
+          {
+            "name": "threshold",
+            "docs": [
+              "See [`NttManagerPeer::inbound_threshold`]."
+            ],
+            "type": {
+              "option": "u8"
+            }
+          },
+          {
+            "name": "transceivers",
+            "docs": [
+              "See [`NttManagerPeer::inbound_transceivers`]."
+            ],
+            "type": {
+              "option": {
+                "defined": "Bitmap"
+              }
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "InitializeArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "chainId",
+            "type": "u16"
+          },
+          {
+            "name": "limit",
+            "type": "u64"
+          },
+          {
+            "name": "mode",
+            "type": {
+              "defined": "Mode"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "MigrateArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "sourceChain",
+            "docs": [
+              "The chain the transfer of an [`InboxItem`] came from, which the 3.0.0",
+              "release didn't store. Required when migrating an inbox item, and",
+              "ignored for other accounts."
+            ],
+            "type": {
+              "option": {
+                "defined": "ChainId"
+              }
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "InitOutboxChainRateLimitArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "chainId",
+            "type": {
+              "defined": "ChainId"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "RedeemArgs",
+      "type": {
+        "kind": "struct",
+        "fields": []
+      }
+    },
+    {
+      "name": "ReleaseInboundArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "revertWhenNotReady",
+            "type": "bool"
+          }
+        ]
+      }
+    },
+    {
+      "name": "ReleaseInboundAndCallArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "revertWhenNotReady",
+            "type": "bool"
+          },
+          {
+            "name": "recipientAccounts",
+            "docs": [
+              "The number of remaining accounts (at the end) that are passed to the",
+              "recipient program. The ones before them are used by the release itself",
+              "(e.g. the transfer hook and denylist accounts)."
+            ],
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "TransferArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "amount",
+            "type": "u64"
+          },
+          {
+            "name": "recipientChain",
+            "type": {
+              "defined": "ChainId"
+            }
+          },
+          {
+            "name": "recipientAddress",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "shouldQueue",
+            "type": "bool"
+          }
+        ]
+      }
+    },
+    {
+      "name": "TransferWithPayloadArgs",
+      "docs": [
+        "Same as [`TransferArgs`], with an additional payload attached to the",
+        "transfer, to be interpreted by the recipient."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "amount",
+            "type": "u64"
+          },
+          {
+            "name": "recipientChain",
+            "type": {
+              "defined": "ChainId"
+            }
+          },
+          {
+            "name": "recipientAddress",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "shouldQueue",
+            "type": "bool"
+          },
+          {
+            "name": "additionalPayload",
+            "type": "bytes"
+          }
+        ]
+      }
+    },
+    {
+      "name": "LegacyAddress",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "address",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "validUntil",
+            "docs": [
+              "Inbound transfers from this address are rejected from then on."
+            ],
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "RateLimitState",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "limit",
+            "docs": [
+              "The maximum capacity of the rate limiter."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "capacityAtLastTx",
+            "docs": [
+              "The capacity of the rate limiter at `last_tx_timestamp`.",
+              "The actual current capacity is calculated in `capacity_at`, by",
+              "accounting for the time that has passed since `last_tx_timestamp` and",
+              "the refill rate."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "lastTxTimestamp",
+            "docs": [
+              "The timestamp of the last transaction that counted towards the current",
+              "capacity. Transactions that exceeded the capacity do not count, they are",
+              "just delayed.",
+              "",
+              "When capacity has been reserved for queued transfers (see",
+              "[`RateLimitState::consume_or_reserve_all`]), this is in the future: the",
+              "rate limiter is empty until then, as everything that refills before",
+              "that is owed to the queued transfers."
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "rateLimitDuration",
+            "docs": [
+              "The time (in seconds) it takes for the capacity to refill from zero to",
+              "`limit`. This is also how long transfers that exceed the capacity are",
+              "delayed for, unless the missing capacity is reserved (see",
+              "[`RateLimitState::consume_or_reserve_all`]).",
+              "",
+              "NOTE: this field was added after the others, so accounts created",
+              "before it have to be migrated (see [`crate::instructions::migrate`])."
+            ],
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "SetTransceiverPeerArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "chainId",
+            "type": {
+              "defined": "ChainId"
+            }
+          },
+          {
+            "name": "address",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "BroadcastPeerArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "chainId",
+            "type": "u16"
+          }
+        ]
+      }
+    },
+    {
+      "name": "ReleaseOutboundArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "revertOnDelay",
+            "type": "bool"
+          }
+        ]
+      }
+    },
+    {
+      "name": "VersionInfo",
+      "docs": [
+        "Returned by the `version` instruction.",
+        "",
+        "The program version comes first, so clients that decode the return value",
+        "as just a string (as it was before the layout version was added) still get",
+        "the program version."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "programVersion",
+            "type": "string"
+          },
+          {
+            "name": "configLayoutVersion",
+            "docs": [
+              "The current layout version of the config account (see",
+              "[`config::Config::LAYOUT_VERSION`])."
+            ],
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "Role",
+      "docs": [
+        "Roles that the owner can delegate to other accounts (one holder each), so",
+        "that routine operations don't need to go through the owner (which is",
+        "typically a governance program or a multisig).",
+        "The owner can always perform these operations too."
+      ],
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "RateLimitAdmin"
+          },
+          {
+            "name": "PeerAdmin"
+          },
+          {
+            "name": "TransceiverAdmin"
+          },
+          {
+            "name": "Pauser"
+          },
+          {
+            "name": "Guardian"
+          },
+          {
+            "name": "ComplianceAdmin"
+          }
+        ]
+      }
+    },
+    {
+      "name": "ConfigChange",
+      "docs": [
+        "A security-sensitive configuration change. When the timelock is enabled",
+        "(see [`Config::timelock_delay`]), these changes can't be made directly, but",
+        "have to be proposed first (see [`PendingChange`])."
+      ],
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "SetPeer",
+            "fields": [
+              {
+                "defined": "SetPeerArgs"
+              }
+            ]
+          },
+          {
+            "name": "RegisterTransceiver",
+            "fields": [
+              {
+                "name": "transceiver",
+                "type": "publicKey"
+              }
+            ]
+          },
+          {
+            "name": "SetThreshold",
+            "fields": [
+              {
+                "name": "threshold",
+                "type": "u8"
+              }
+            ]
+          },
+          {
+            "name": "SetTokenAuthority",
+            "fields": [
+              {
+                "name": "newAuthority",
+                "type": "publicKey"
+              }
+            ]
+          },
+          {
+            "name": "SetTimelockDelay",
+            "fields": [
+              {
+                "name": "delay",
+                "type": "i64"
+              }
+            ]
+          },
+          {
+            "name": "SetGuardian",
+            "fields": [
+              {
+                "name": "guardian",
+                "type": {
+                  "option": "publicKey"
+                }
+              }
+            ]
+          },
+          {
+            "name": "SetLegacyPeerAddresses",
+            "fields": [
+              {
+                "defined": "SetLegacyPeerAddressesArgs"
+              }
+            ]
+          },
+          {
+            "name": "SetPeerThreshold",
+            "fields": [
+              {
+                "defined": "SetPeerThresholdArgs"
+              }
+            ]
+          },
+          {
+            "name": "DeregisterTransceiver",
+            "fields": [
+              {
+                "name": "transceiver",
+                "type": "publicKey"
+              }
+            ]
+          },
+          {
+            "name": "SetOutboundLimit",
+            "fields": [
+              {
+                "defined": "SetOutboundLimitArgs"
+              }
+            ]
+          },
+          {
+            "name": "SetOutboundChainLimit",
+            "fields": [
+              {
+                "defined": "SetOutboundChainLimitArgs"
+              }
+            ]
+          },
+          {
+            "name": "SetInboundLimit",
+            "fields": [
+              {
+                "defined": "SetInboundLimitArgs"
+              }
+            ]
+          },
+          {
+            "name": "SetOutboundRateLimitDuration",
+            "fields": [
+              {
+                "defined": "SetOutboundRateLimitDurationArgs"
+              }
+            ]
+          },
+          {
+            "name": "SetOutboundChainRateLimitDuration",
+            "fields": [
+              {
+                "defined": "SetOutboundChainRateLimitDurationArgs"
+              }
+            ]
+          },
+          {
+            "name": "SetInboundRateLimitDuration",
+            "fields": [
+              {
+                "defined": "SetInboundRateLimitDurationArgs"
+              }
+            ]
+          }
+        ]
+      }
+    },
+    {
+      "name": "ReleaseStatus",
+      "docs": [
+        "The status of an InboxItem. This determines whether the tokens are minted/unlocked to the recipient. As",
+        "such, this must be used as a state machine that moves forward in a linear manner. A state",
+        "should never \"move backward\" to a previous state (e.g. should never move from `Released` to",
+        "`ReleaseAfter`).",
+        "`Frozen` is the exception, as it's left for the status the item was frozen",
+        "in (see [`crate::instructions::freeze_inbox_item`])."
+      ],
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "NotApproved"
+          },
+          {
+            "name": "ReleaseAfter",
+            "fields": [
+              "i64"
+            ]
+          },
+          {
+            "name": "Released"
+          },
+          {
+            "name": "Frozen",
+            "fields": [
+              {
+                "option": "i64"
+              }
+            ]
+          },
+          {
+            "name": "Rejected"
+          },
+          {
+            "name": "Quarantined"
+          },
+          {
+            "name": "Escrowed",
+            "fields": [
+              "u64"
+            ]
+          }
+        ]
+      }
+    },
+    {
+      "name": "ChainId",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "id",
+            "type": "u16"
+          }
+        ]
+      }
+    },
+    {
+      "name": "Mode",
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "Locking"
+          },
+          {
+            "name": "Burning"
+          }
+        ]
+      }
+    },
+    {
+      "name": "TrimmedAmount",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "amount",
+            "type": "u64"
+          },
+          {
+            "name": "decimals",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "AdditionalPayload",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bytes",
+            "type": "bytes"
+          }
+        ]
+      }
+    },
+    {
+      "name": "BridgeConfig",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "guardianSetExpirationTime",
+            "docs": [
+              "Period for how long a guardian set is valid after it has been replaced by a new one.  This",
+              "guarantees that VAAs issued by that set can still be submitted for a certain period.  In",
+              "this period we still trust the old guardian set."
+            ],
+            "type": "u32"
+          },
+          {
+            "name": "fee",
+            "docs": [
+              "Amount of lamports that needs to be paid to the protocol to post a message"
+            ],
+            "type": "u64"
+          }
+        ]
+      }
+    }
+  ],
+  "events": [
+    {
+      "name": "TransferSent",
+      "fields": [
+        {
+          "name": "outboxItem",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "sender",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "amount",
+          "type": {
+            "defined": "TrimmedAmount"
+          },
+          "index": false
+        },
+        {
+          "name": "recipientChain",
+          "type": {
+            "defined": "ChainId"
+          },
+          "index": false
+        },
+        {
+          "name": "recipientNttManager",
+          "type": {
+            "array": [
+              "u8",
+              32
+            ]
+          },
+          "index": false
+        },
+        {
+          "name": "recipientAddress",
+          "type": {
+            "array": [
+              "u8",
+              32
+            ]
+          },
+          "index": false
+        },
+        {
+          "name": "releaseTimestamp",
+          "type": "i64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "OutboundTransferQueued",
+      "fields": [
+        {
+          "name": "outboxItem",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "releaseTimestamp",
+          "type": "i64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "OutboundTransferReleased",
+      "fields": [
+        {
+          "name": "outboxItem",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "transceiver",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "transceiverIndex",
+          "type": "u8",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "OutboundTransferCancelled",
+      "fields": [
+        {
+          "name": "outboxItem",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "sender",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "amount",
+          "type": "u64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "InboundVoteRecorded",
+      "fields": [
+        {
+          "name": "inboxItem",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "fromChain",
+          "type": {
+            "defined": "ChainId"
+          },
+          "index": false
+        },
+        {
+          "name": "messageId",
+          "type": {
+            "array": [
+              "u8",
+              32
+            ]
+          },
+          "index": false
+        },
+        {
+          "name": "transceiver",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "transceiverIndex",
+          "type": "u8",
+          "index": false
+        },
+        {
+          "name": "votes",
+          "type": "u8",
+          "index": false
+        },
+        {
+          "name": "threshold",
+          "type": "u8",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "InboundQuorumReached",
+      "fields": [
+        {
+          "name": "inboxItem",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "releaseTimestamp",
+          "type": "i64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "InboundTransferQueued",
+      "fields": [
+        {
+          "name": "inboxItem",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "releaseTimestamp",
+          "type": "i64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "InboundTransferReleased",
+      "fields": [
+        {
+          "name": "inboxItem",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "recipient",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "amount",
+          "type": "u64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "InboundTransferFrozen",
+      "fields": [
+        {
+          "name": "inboxItem",
+          "type": "publicKey",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "InboundTransferUnfrozen",
+      "fields": [
+        {
+          "name": "inboxItem",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "releaseStatus",
+          "type": {
+            "defined": "ReleaseStatus"
+          },
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "InboundTransferRejected",
+      "fields": [
+        {
+          "name": "inboxItem",
+          "type": "publicKey",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "InboundTransferForceReleased",
+      "fields": [
+        {
+          "name": "inboxItem",
+          "type": "publicKey",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "InboundTransferQuarantined",
+      "fields": [
+        {
+          "name": "inboxItem",
+          "type": "publicKey",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "InboundTransferUnquarantined",
+      "fields": [
+        {
+          "name": "inboxItem",
+          "type": "publicKey",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "InboundTransferEscrowed",
+      "fields": [
+        {
+          "name": "inboxItem",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "amount",
+          "type": "u64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "EscrowedTransferRedirected",
+      "fields": [
+        {
+          "name": "inboxItem",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "recipient",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "amount",
+          "type": "u64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "EscrowedTransferReturned",
+      "fields": [
+        {
+          "name": "inboxItem",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "outboxItem",
+          "type": "publicKey",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "PeerSet",
+      "fields": [
+        {
+          "name": "chainId",
+          "type": {
+            "defined": "ChainId"
+          },
+          "index": false
+        },
+        {
+          "name": "address",
+          "type": {
+            "array": [
+              "u8",
+              32
+            ]
+          },
+          "index": false
+        },
+        {
+          "name": "tokenDecimals",
+          "type": "u8",
+          "index": false
+        },
+        {
+          "name": "inboundLimit",
+          "type": "u64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "OutboundLimitSet",
+      "fields": [
+        {
+          "name": "limit",
+          "type": "u64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "OutboundChainLimitSet",
+      "fields": [
+        {
+          "name": "chainId",
+          "type": {
+            "defined": "ChainId"
+          },
+          "index": false
+        },
+        {
+          "name": "limit",
+          "type": "u64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "InboundLimitSet",
+      "fields": [
+        {
+          "name": "chainId",
+          "type": {
+            "defined": "ChainId"
+          },
+          "index": false
+        },
+        {
+          "name": "limit",
+          "type": "u64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "OutboundRateLimitDurationSet",
+      "fields": [
+        {
+          "name": "duration",
+          "type": "i64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "OutboundChainRateLimitDurationSet",
+      "fields": [
+        {
+          "name": "chainId",
+          "type": {
+            "defined": "ChainId"
+          },
+          "index": false
+        },
+        {
+          "name": "duration",
+          "type": "i64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "InboundRateLimitDurationSet",
+      "fields": [
+        {
+          "name": "chainId",
+          "type": {
+            "defined": "ChainId"
+          },
+          "index": false
+        },
+        {
+          "name": "duration",
+          "type": "i64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "ThresholdSet",
+      "fields": [
+        {
+          "name": "oldThreshold",
+          "type": "u8",
+          "index": false
+        },
+        {
+          "name": "threshold",
+          "type": "u8",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "PausedSet",
+      "fields": [
+        {
+          "name": "paused",
+          "type": "bool",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "RoleGranted",
+      "fields": [
+        {
+          "name": "role",
+          "type": {
+            "defined": "Role"
+          },
+          "index": false
+        },
+        {
+          "name": "holder",
+          "type": "publicKey",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "RoleRevoked",
+      "fields": [
+        {
+          "name": "role",
+          "type": {
+            "defined": "Role"
+          },
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "OutboundPausedSet",
+      "fields": [
+        {
+          "name": "paused",
+          "type": "bool",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "InboundPausedSet",
+      "fields": [
+        {
+          "name": "paused",
+          "type": "bool",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "PeerPausedSet",
+      "fields": [
+        {
+          "name": "chainId",
+          "type": {
+            "defined": "ChainId"
+          },
+          "index": false
+        },
+        {
+          "name": "paused",
+          "type": "bool",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "PeerThresholdSet",
+      "fields": [
+        {
+          "name": "chainId",
+          "type": {
+            "defined": "ChainId"
+          },
+          "index": false
+        },
+        {
+          "name": "threshold",
+          "type": {
+            "option": "u8"
+          },
+          "index": false
+        },
+        {
+          "name": "transceivers",
+          "type": {
+            "option": {
+              "defined": "Bitmap"
+            }
+          },
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "LegacyPeerAddressesSet",
+      "fields": [
+        {
+          "name": "chainId",
+          "type": {
+            "defined": "ChainId"
+          },
+          "index": false
+        },
+        {
+          "name": "addresses",
+          "type": {
+            "vec": {
+              "defined": "LegacyAddress"
+            }
+          },
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "PeerRemoved",
+      "fields": [
+        {
+          "name": "chainId",
+          "type": {
+            "defined": "ChainId"
+          },
+          "index": false
+        },
+        {
+          "name": "closableAt",
+          "type": "i64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "PeerClosed",
+      "fields": [
+        {
+          "name": "chainId",
+          "type": {
+            "defined": "ChainId"
+          },
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "PreciseQueueReleaseSet",
+      "fields": [
+        {
+          "name": "enabled",
+          "type": "bool",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "StrictDustSet",
+      "fields": [
+        {
+          "name": "enabled",
+          "type": "bool",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "EscrowTimeoutSet",
+      "fields": [
+        {
+          "name": "timeout",
+          "type": "i64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "TimelockDelaySet",
+      "fields": [
+        {
+          "name": "delay",
+          "type": "i64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "ChangeProposed",
+      "fields": [
+        {
+          "name": "pendingChange",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "change",
+          "type": {
+            "defined": "ConfigChange"
+          },
+          "index": false
+        },
+        {
+          "name": "executableAt",
+          "type": "i64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "ChangeVetoed",
+      "fields": [
+        {
+          "name": "pendingChange",
+          "type": "publicKey",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "ChangeExecuted",
+      "fields": [
+        {
+          "name": "pendingChange",
+          "type": "publicKey",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "TransceiverRegistered",
+      "fields": [
+        {
+          "name": "transceiver",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "transceiverIndex",
+          "type": "u8",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "TransceiverDeregistered",
+      "fields": [
+        {
+          "name": "transceiver",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "transceiverIndex",
+          "type": "u8",
+          "index": false
+        },
+        {
+          "name": "threshold",
+          "type": "u8",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "OwnershipTransferInitiated",
+      "fields": [
+        {
+          "name": "owner",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "pendingOwner",
+          "type": "publicKey",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "OwnershipTransferred",
+      "fields": [
+        {
+          "name": "previousOwner",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "owner",
+          "type": "publicKey",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "TokenAuthorityAccepted",
+      "fields": [
+        {
+          "name": "mint",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "authority",
+          "type": "publicKey",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "TokenAuthorityTransferInitiated",
+      "fields": [
+        {
+          "name": "mint",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "pendingAuthority",
+          "type": "publicKey",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "TokenAuthorityTransferReverted",
+      "fields": [
+        {
+          "name": "mint",
+          "type": "publicKey",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "TokenAuthorityTransferred",
+      "fields": [
+        {
+          "name": "mint",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "newAuthority",
+          "type": "publicKey",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "DenylistEnabledSet",
+      "fields": [
+        {
+          "name": "enabled",
+          "type": "bool",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "PolicyProgramSet",
+      "fields": [
+        {
+          "name": "policyProgram",
+          "type": {
+            "option": "publicKey"
+          },
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "AddressDenylisted",
+      "fields": [
+        {
+          "name": "address",
+          "type": {
+            "array": [
+              "u8",
+              32
+            ]
+          },
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "AddressRemovedFromDenylist",
+      "fields": [
+        {
+          "name": "address",
+          "type": {
+            "array": [
+              "u8",
+              32
+            ]
+          },
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "WormholePeerSet",
+      "fields": [
+        {
+          "name": "chainId",
+          "type": {
+            "defined": "ChainId"
+          },
+          "index": false
+        },
+        {
+          "name": "address",
+          "type": {
+            "array": [
+              "u8",
+              32
+            ]
+          },
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "WormholeMessageReceived",
+      "fields": [
+        {
+          "name": "transceiverMessage",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "fromChain",
+          "type": {
+            "defined": "ChainId"
+          },
+          "index": false
+        },
+        {
+          "name": "messageId",
+          "type": {
+            "array": [
+              "u8",
+              32
+            ]
+          },
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "WormholeMessageSent",
+      "fields": [
+        {
+          "name": "outboxItem",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "wormholeMessage",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "recipientChain",
+          "type": {
+            "defined": "ChainId"
+          },
+          "index": false
+        }
+      ]
+    }
+  ],
+  "errors": [
+    {
+      "code": 6000,
+      "name": "CantReleaseYet",
+      "msg": "CantReleaseYet"
+    },
+    {
+      "code": 6001,
+      "name": "InvalidPendingOwner",
+      "msg": "InvalidPendingOwner"
+    },
+    {
+      "code": 6002,
+      "name": "InvalidChainId",
+      "msg": "InvalidChainId"
+    },
+    {
+      "code": 6003,
+      "name": "InvalidRecipientAddress",
+      "msg": "InvalidRecipientAddress"
+    },
+    {
+      "code": 6004,
+      "name": "InvalidTransceiverPeer",
+      "msg": "InvalidTransceiverPeer"
+    },
+    {
+      "code": 6005,
+      "name": "InvalidNttManagerPeer",
+      "msg": "InvalidNttManagerPeer"
+    },
+    {
+      "code": 6006,
+      "name": "InvalidRecipientNttManager",
+      "msg": "InvalidRecipientNttManager"
+    },
+    {
+      "code": 6007,
+      "name": "TransferAlreadyRedeemed",
+      "msg": "TransferAlreadyRedeemed"
+    },
+    {
+      "code": 6008,
+      "name": "TransferCannotBeRedeemed",
+      "msg": "TransferCannotBeRedeemed"
+    },
+    {
+      "code": 6009,
+      "name": "TransferNotApproved",
+      "msg": "TransferNotApproved"
+    },
+    {
+      "code": 6010,
+      "name": "MessageAlreadySent",
+      "msg": "MessageAlreadySent"
+    },
+    {
+      "code": 6011,
+      "name": "InvalidMode",
+      "msg": "InvalidMode"
+    },
+    {
+      "code": 6012,
+      "name": "InvalidMintAuthority",
+      "msg": "InvalidMintAuthority"
+    },
+    {
+      "code": 6013,
+      "name": "TransferExceedsRateLimit",
+      "msg": "TransferExceedsRateLimit"
+    },
+    {
+      "code": 6014,
+      "name": "Paused",
+      "msg": "Paused"
+    },
+    {
+      "code": 6015,
+      "name": "DisabledTransceiver",
+      "msg": "DisabledTransceiver"
+    },
+    {
+      "code": 6016,
+      "name": "InvalidDeployer",
+      "msg": "InvalidDeployer"
+    },
+    {
+      "code": 6017,
+      "name": "BadAmountAfterTransfer",
+      "msg": "BadAmountAfterTransfer"
+    },
+    {
+      "code": 6018,
+      "name": "BadAmountAfterBurn",
+      "msg": "BadAmountAfterBurn"
+    },
+    {
+      "code": 6019,
+      "name": "ZeroThreshold",
+      "msg": "ZeroThreshold"
+    },
+    {
+      "code": 6020,
+      "name": "OverflowExponent",
+      "msg": "OverflowExponent"
+    },
+    {
+      "code": 6021,
+      "name": "OverflowScaledAmount",
+      "msg": "OverflowScaledAmount"
+    },
+    {
+      "code": 6022,
+      "name": "BitmapIndexOutOfBounds",
+      "msg": "BitmapIndexOutOfBounds"
+    },
+    {
+      "code": 6023,
+      "name": "NoRegisteredTransceivers",
+      "msg": "NoRegisteredTransceivers"
+    },
+    {
+      "code": 6024,
+      "name": "NotPaused",
+      "msg": "NotPaused"
+    },
+    {
+      "code": 6025,
+      "name": "InvalidPendingTokenAuthority",
+      "msg": "InvalidPendingTokenAuthority"
+    },
+    {
+      "code": 6026,
+      "name": "IncorrectRentPayer",
+      "msg": "IncorrectRentPayer"
+    },
+    {
+      "code": 6027,
+      "name": "InvalidMultisig",
+      "msg": "InvalidMultisig"
+    },
+    {
+      "code": 6028,
+      "name": "ThresholdTooHigh",
+      "msg": "ThresholdTooHigh"
+    },
+    {
+      "code": 6029,
+      "name": "InvalidTransceiverProgram",
+      "msg": "InvalidTransceiverProgram"
+    },
+    {
+      "code": 6030,
+      "name": "OutboxItemCancelled",
+      "msg": "OutboxItemCancelled"
+    },
+    {
+      "code": 6031,
+      "name": "OutboxItemAlreadyReleased",
+      "msg": "OutboxItemAlreadyReleased"
+    },
+    {
+      "code": 6032,
+      "name": "OutboxItemNotReleased",
+      "msg": "OutboxItemNotReleased"
+    },
+    {
+      "code": 6033,
+      "name": "InboxItemNotReleased",
+      "msg": "InboxItemNotReleased"
+    },
+    {
+      "code": 6034,
+      "name": "AdditionalPayloadTooLong",
+      "msg": "AdditionalPayloadTooLong"
+    },
+    {
+      "code": 6035,
+      "name": "InvalidRecipientProgram",
+      "msg": "InvalidRecipientProgram"
+    },
+    {
+      "code": 6036,
+      "name": "MessageEncodingFailed",
+      "msg": "MessageEncodingFailed"
+    },
+    {
+      "code": 6037,
+      "name": "MessageDecodingFailed",
+      "msg": "MessageDecodingFailed"
+    },
+    {
+      "code": 6038,
+      "name": "InvalidRateLimitDuration",
+      "msg": "InvalidRateLimitDuration"
+    },
+    {
+      "code": 6039,
+      "name": "AmountAfterFeeHasDust",
+      "msg": "AmountAfterFeeHasDust"
+    },
+    {
+      "code": 6040,
+      "name": "InvalidTransferFee",
+      "msg": "InvalidTransferFee"
+    },
+    {
+      "code": 6041,
+      "name": "TransferAmountHasDust",
+      "msg": "TransferAmountHasDust"
+    },
+    {
+      "code": 6042,
+      "name": "InvalidPauser",
+      "msg": "InvalidPauser"
+    },
+    {
+      "code": 6043,
+      "name": "OutboundPaused",
+      "msg": "OutboundPaused"
+    },
+    {
+      "code": 6044,
+      "name": "InboundPaused",
+      "msg": "InboundPaused"
+    },
+    {
+      "code": 6045,
+      "name": "PeerPaused",
+      "msg": "PeerPaused"
+    },
+    {
+      "code": 6046,
+      "name": "Unauthorized",
+      "msg": "Unauthorized"
+    },
+    {
+      "code": 6047,
+      "name": "TimelockRequired",
+      "msg": "TimelockRequired"
+    },
+    {
+      "code": 6048,
+      "name": "TimelockNotExpired",
+      "msg": "TimelockNotExpired"
+    },
+    {
+      "code": 6049,
+      "name": "InvalidTimelockDelay",
+      "msg": "InvalidTimelockDelay"
+    },
+    {
+      "code": 6050,
+      "name": "InvalidConfigChange",
+      "msg": "InvalidConfigChange"
+    },
+    {
+      "code": 6051,
+      "name": "AccountAlreadyMigrated",
+      "msg": "AccountAlreadyMigrated"
+    },
+    {
+      "code": 6052,
+      "name": "PeerRetired",
+      "msg": "PeerRetired"
+    },
+    {
+      "code": 6053,
+      "name": "PeerNotRetired",
+      "msg": "PeerNotRetired"
+    },
+    {
+      "code": 6054,
+      "name": "InvalidGracePeriod",
+      "msg": "InvalidGracePeriod"
+    },
+    {
+      "code": 6055,
+      "name": "GracePeriodNotOver",
+      "msg": "GracePeriodNotOver"
+    },
+    {
+      "code": 6056,
+      "name": "TooManyLegacyAddresses",
+      "msg": "TooManyLegacyAddresses"
+    },
+    {
+      "code": 6057,
+      "name": "ThresholdNotReached",
+      "msg": "ThresholdNotReached"
+    },
+    {
+      "code": 6058,
+      "name": "InboxItemFrozen",
+      "msg": "InboxItemFrozen"
+    },
+    {
+      "code": 6059,
+      "name": "InboxItemNotFrozen",
+      "msg": "InboxItemNotFrozen"
+    },
+    {
+      "code": 6060,
+      "name": "InboxItemRejected",
+      "msg": "InboxItemRejected"
+    },
+    {
+      "code": 6061,
+      "name": "TransferDenied",
+      "msg": "TransferDenied"
+    },
+    {
+      "code": 6062,
+      "name": "MissingDenylistEntry",
+      "msg": "MissingDenylistEntry"
+    },
+    {
+      "code": 6063,
+      "name": "InboxItemQuarantined",
+      "msg": "InboxItemQuarantined"
+    },
+    {
+      "code": 6064,
+      "name": "InboxItemNotQuarantined",
+      "msg": "InboxItemNotQuarantined"
+    },
+    {
+      "code": 6065,
+      "name": "InboxItemEscrowed",
+      "msg": "InboxItemEscrowed"
+    },
+    {
+      "code": 6066,
+      "name": "InboxItemNotEscrowed",
+      "msg": "InboxItemNotEscrowed"
+    },
+    {
+      "code": 6067,
+      "name": "CantEscrowYet",
+      "msg": "CantEscrowYet"
+    },
+    {
+      "code": 6068,
+      "name": "InvalidEscrowTimeout",
+      "msg": "InvalidEscrowTimeout"
+    },
+    {
+      "code": 6069,
+      "name": "RecipientCallRequired",
+      "msg": "RecipientCallRequired"
+    },
+    {
+      "code": 6070,
+      "name": "MissingPolicyAccount",
+      "msg": "MissingPolicyAccount"
+    },
+    {
+      "code": 6071,
+      "name": "InvalidPolicyDecision",
+      "msg": "InvalidPolicyDecision"
+    },
+    {
+      "code": 6072,
+      "name": "OutboxItemNotQueued",
+      "msg": "OutboxItemNotQueued"
+    }
+  ]
+}
+
@@ -0,0 +1,2107 @@
+export type NttTransceiver = {
+  "version": "4.0.0",
+  "name": "ntt_transceiver",
+  "instructions": [
+    {
+      "name": "transceiverType",
+      "accounts": [],
+      "args": [],
+      "returns": "string"
+    },
+    {
+      "name": "setWormholePeer",
+      "accounts": [
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "owner",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "peer",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "SetTransceiverPeerArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "receiveWormholeMessage",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "config",
+          "accounts": [
+            {
+              "name": "config",
+              "isMut": false,
+              "isSigner": false
+            }
+          ]
+        },
+        {
+          "name": "peer",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "vaa",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "transceiverMessage",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "releaseWormholeOutbound",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "config",
+          "accounts": [
+            {
+              "name": "config",
+              "isMut": false,
+              "isSigner": false
+            }
+          ]
+        },
+        {
+          "name": "outboxItem",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "transceiver",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "wormholeMessage",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "emitter",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "wormhole",
+          "accounts": [
+            {
+              "name": "bridge",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "feeCollector",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "sequence",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "program",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "systemProgram",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "clock",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "rent",
+              "isMut": false,
+              "isSigner": false
+            }
+          ]
+        },
+        {
+          "name": "manager",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "outboxItemSigner",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "peer",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "ReleaseOutboundArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "closeWormholeTransceiverMessage",
+      "accounts": [
+        {
+          "name": "transceiverMessage",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "inboxItem",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "message, and [`InboxItem::is_done`] checks the owner."
+          ]
+        },
+        {
+          "name": "rentPayer",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "migrateWormholeTransceiverMessage",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "owner",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "transceiverMessage",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "discriminator is checked in [`migrate_transceiver_message`]."
+          ]
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "broadcastWormholeId",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "mint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "wormholeMessage",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "emitter",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "enforced by the [`CpiContext`] call in [`post_message`].",
+            "The seeds constraint ensures that this is the correct address"
+          ]
+        },
+        {
+          "name": "wormhole",
+          "accounts": [
+            {
+              "name": "bridge",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "feeCollector",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "sequence",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "program",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "systemProgram",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "clock",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "rent",
+              "isMut": false,
+              "isSigner": false
+            }
+          ]
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "broadcastWormholePeer",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "peer",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "wormholeMessage",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "emitter",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "wormhole",
+          "accounts": [
+            {
+              "name": "bridge",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "feeCollector",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "sequence",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "program",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "systemProgram",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "clock",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "rent",
+              "isMut": false,
+              "isSigner": false
+            }
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "BroadcastPeerArgs"
+          }
+        }
+      ]
+    }
+  ],
+  "accounts": [
+    {
+      "name": "config",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "owner",
+            "docs": [
+              "Owner of the program."
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "pendingOwner",
+            "docs": [
+              "Pending next owner (before claiming ownership)."
+            ],
+            "type": {
+              "option": "publicKey"
+            }
+          },
+          {
+            "name": "mint",
+            "docs": [
+              "Mint address of the token managed by this program."
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "tokenProgram",
+            "docs": [
+              "Address of the token program (token or token22). This could always be queried",
+              "from the [`mint`] account's owner, but storing it here avoids an indirection",
+              "on the client side."
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "mode",
+            "docs": [
+              "The mode that this program is running in. This is used to determine",
+              "whether the program is burning tokens or locking tokens."
+            ],
+            "type": {
+              "defined": "Mode"
+            }
+          },
+          {
+            "name": "chainId",
+            "docs": [
+              "The chain id of the chain that this program is running on. We don't",
+              "hardcode this so that the program is deployable on any potential SVM",
+              "forks."
+            ],
+            "type": {
+              "defined": "ChainId"
+            }
+          },
+          {
+            "name": "nextTransceiverId",
+            "docs": [
+              "The next transceiver id to use when registering an transceiver."
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "threshold",
+            "docs": [
+              "The number of transceivers that must attest to a transfer before it is",
+              "accepted."
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "enabledTransceivers",
+            "docs": [
+              "Bitmap of enabled transceivers.",
+              "The maximum number of transceivers is equal to [`Bitmap::BITS`]."
+            ],
+            "type": {
+              "defined": "Bitmap"
+            }
+          },
+          {
+            "name": "paused",
+            "docs": [
+              "Pause the program. This is useful for upgrades and other maintenance."
+            ],
+            "type": "bool"
+          },
+          {
+            "name": "custody",
+            "docs": [
+              "The custody account that holds tokens in locking mode."
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "preciseQueueRelease",
+            "docs": [
+              "When a transfer exceeds the rate limit, reserve the missing capacity",
+              "and queue the transfer only until it has refilled, instead of delaying",
+              "it by a full rate limit window.",
+              "See [`crate::queue::rate_limit::RateLimitState::consume_or_reserve_all`]."
+            ],
+            "type": "bool"
+          },
+          {
+            "name": "strictDust",
+            "docs": [
+              "Reject outbound transfers whose amount has dust (i.e. it doesn't trim",
+              "cleanly to the recipient chain's decimals), instead of silently",
+              "transferring the amount with the dust removed."
+            ],
+            "type": "bool"
+          },
+          {
+            "name": "pauser",
+            "docs": [
+              "Holder of [`Role::Pauser`]."
+            ],
+            "type": {
+              "option": "publicKey"
+            }
+          },
+          {
+            "name": "outboundPaused",
+            "docs": [
+              "Pause outbound transfers (and releasing queued outbound transfers),",
+              "while inbound transfers keep working."
+            ],
+            "type": "bool"
+          },
+          {
+            "name": "inboundPaused",
+            "docs": [
+              "Pause releasing inbound transfers, while they can still be redeemed",
+              "(and queued)."
+            ],
+            "type": "bool"
+          },
+          {
+            "name": "rateLimitAdmin",
+            "docs": [
+              "Holder of [`Role::RateLimitAdmin`]."
+            ],
+            "type": {
+              "option": "publicKey"
+            }
+          },
+          {
+            "name": "peerAdmin",
+            "docs": [
+              "Holder of [`Role::PeerAdmin`]."
+            ],
+            "type": {
+              "option": "publicKey"
+            }
+          },
+          {
+            "name": "transceiverAdmin",
+            "docs": [
+              "Holder of [`Role::TransceiverAdmin`]."
+            ],
+            "type": {
+              "option": "publicKey"
+            }
+          },
+          {
+            "name": "timelockDelay",
+            "docs": [
+              "Delay (in seconds) between proposing a security-sensitive change and",
+              "executing it (see [`crate::pending_change::ConfigChange`]). Zero",
+              "disables the timelock, in which case these changes are made directly."
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "guardian",
+            "docs": [
+              "Holder of [`Role::Guardian`]."
+            ],
+            "type": {
+              "option": "publicKey"
+            }
+          },
+          {
+            "name": "complianceAdmin",
+            "docs": [
+              "Holder of [`Role::ComplianceAdmin`]."
+            ],
+            "type": {
+              "option": "publicKey"
+            }
+          },
+          {
+            "name": "denylistEnabled",
+            "docs": [
+              "Block transfers from and to the addresses on the denylist (see",
+              "[`crate::compliance`])."
+            ],
+            "type": "bool"
+          },
+          {
+            "name": "policyProgram",
+            "docs": [
+              "A program that is asked whether to allow each transfer, in addition to",
+              "the denylist (see [`crate::compliance`]). `None` disables this check."
+            ],
+            "type": {
+              "option": "publicKey"
+            }
+          },
+          {
+            "name": "escrowTimeout",
+            "docs": [
+              "How long (in seconds) after it became releasable an inbound transfer",
+              "that is still not released can be moved to the escrow by anyone (see",
+              "[`crate::instructions::escrow_inbox_item`]). Zero disables this, so",
+              "only the owner can escrow transfers."
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "version",
+            "docs": [
+              "The layout version of this account (see [`Config::LAYOUT_VERSION`]).",
+              "Fields added in later releases go after this one, and existing",
+              "accounts are upgraded with [`crate::instructions::migrate`]."
+            ],
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "outboxItem",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "amount",
+            "type": {
+              "defined": "TrimmedAmount"
+            }
+          },
+          {
+            "name": "sender",
+            "type": "publicKey"
+          },
+          {
+            "name": "recipientChain",
+            "type": {
+              "defined": "ChainId"
+            }
+          },
+          {
+            "name": "recipientNttManager",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "recipientAddress",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "releaseTimestamp",
+            "type": "i64"
+          },
+          {
+            "name": "released",
+            "type": {
+              "defined": "Bitmap"
+            }
+          },
+          {
+            "name": "queued",
+            "docs": [
+              "Whether the transfer was queued because it exceeded the outbound rate",
+              "limit. Queued transfers don't consume outbound capacity (and don't",
+              "refill the inbound capacity) when they are inserted, unless they",
+              "reserved it."
+            ],
+            "type": "bool"
+          },
+          {
+            "name": "reserved",
+            "docs": [
+              "Whether the transfer reserved the outbound capacity it was missing",
+              "when it was queued (see [`crate::config::Config::precise_queue_release`]).",
+              "Reserved capacity counts as consumed, like for transfers that weren't",
+              "queued."
+            ],
+            "type": "bool"
+          },
+          {
+            "name": "cancelled",
+            "docs": [
+              "Whether the transfer has been cancelled by the sender. Cancelled",
+              "transfers can never be released."
+            ],
+            "type": "bool"
+          },
+          {
+            "name": "rentPayer",
+            "docs": [
+              "The account that paid the rent for this item. The rent is returned to",
+              "this account when the item is closed."
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "additionalPayload",
+            "docs": [
+              "An additional payload to be delivered to the recipient along with the",
+              "tokens. Empty for plain transfers."
+            ],
+            "type": {
+              "defined": "AdditionalPayload"
+            }
+          },
+          {
+            "name": "sequence",
+            "docs": [
+              "The outbound sequence number of the transfer. The item's address is",
+              "derived from it (see [`OutboxItem::SEED_PREFIX`])."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "version",
+            "docs": [
+              "See [`crate::config::Config::version`]."
+            ],
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "registeredTransceiver",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "id",
+            "type": "u8"
+          },
+          {
+            "name": "transceiverAddress",
+            "type": "publicKey"
+          },
+          {
+            "name": "version",
+            "docs": [
+              "See [`crate::config::Config::version`]."
+            ],
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "transceiverPeer",
+      "docs": [
+        "A peer on another chain. Stored in a PDA seeded by the chain id."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "address",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "bridgeData",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "guardianSetIndex",
+            "docs": [
+              "The current guardian set index, used to decide which signature sets to accept."
+            ],
+            "type": "u32"
+          },
+          {
+            "name": "lastLamports",
+            "docs": [
+              "Lamports in the collection account"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "config",
+            "docs": [
+              "Bridge configuration, which is set once upon initialization."
+            ],
+            "type": {
+              "defined": "BridgeConfig"
+            }
+          }
+        ]
+      }
+    }
+  ],
+  "types": [
+    {
+      "name": "Bitmap",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "map",
+            "type": "u128"
+          }
+        ]
+      }
+    },
+    {
+      "name": "ChainId",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "id",
+            "type": "u16"
+          }
+        ]
+      }
+    },
+    {
+      "name": "Mode",
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "Locking"
+          },
+          {
+            "name": "Burning"
+          }
+        ]
+      }
+    },
+    {
+      "name": "TrimmedAmount",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "amount",
+            "type": "u64"
+          },
+          {
+            "name": "decimals",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "SetTransceiverPeerArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "chainId",
+            "type": {
+              "defined": "ChainId"
+            }
+          },
+          {
+            "name": "address",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "BroadcastPeerArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "chainId",
+            "type": "u16"
+          }
+        ]
+      }
+    },
+    {
+      "name": "ReleaseOutboundArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "revertOnDelay",
+            "type": "bool"
+          }
+        ]
+      }
+    },
+    {
+      "name": "BridgeConfig",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "guardianSetExpirationTime",
+            "docs": [
+              "Period for how long a guardian set is valid after it has been replaced by a new one.  This",
+              "guarantees that VAAs issued by that set can still be submitted for a certain period.  In",
+              "this period we still trust the old guardian set."
+            ],
+            "type": "u32"
+          },
+          {
+            "name": "fee",
+            "docs": [
+              "Amount of lamports that needs to be paid to the protocol to post a message"
+            ],
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "AdditionalPayload",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bytes",
+            "type": "bytes"
+          }
+        ]
+      }
+    }
+  ],
+  "events": [
+    {
+      "name": "WormholePeerSet",
+      "fields": [
+        {
+          "name": "chainId",
+          "type": {
+            "defined": "ChainId"
+          },
+          "index": false
+        },
+        {
+          "name": "address",
+          "type": {
+            "array": [
+              "u8",
+              32
+            ]
+          },
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "WormholeMessageReceived",
+      "fields": [
+        {
+          "name": "transceiverMessage",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "fromChain",
+          "type": {
+            "defined": "ChainId"
+          },
+          "index": false
+        },
+        {
+          "name": "messageId",
+          "type": {
+            "array": [
+              "u8",
+              32
+            ]
+          },
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "WormholeMessageSent",
+      "fields": [
+        {
+          "name": "outboxItem",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "wormholeMessage",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "recipientChain",
+          "type": {
+            "defined": "ChainId"
+          },
+          "index": false
+        }
+      ]
+    }
+  ]
+}
+export const IDL: NttTransceiver = {
+  "version": "4.0.0",
+  "name": "ntt_transceiver",
+  "instructions": [
+    {
+      "name": "transceiverType",
+      "accounts": [],
+      "args": [],
+      "returns": "string"
+    },
+    {
+      "name": "setWormholePeer",
+      "accounts": [
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "owner",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "peer",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "SetTransceiverPeerArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "receiveWormholeMessage",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "config",
+          "accounts": [
+            {
+              "name": "config",
+              "isMut": false,
+              "isSigner": false
+            }
+          ]
+        },
+        {
+          "name": "peer",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "vaa",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "transceiverMessage",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "releaseWormholeOutbound",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "config",
+          "accounts": [
+            {
+              "name": "config",
+              "isMut": false,
+              "isSigner": false
+            }
+          ]
+        },
+        {
+          "name": "outboxItem",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "transceiver",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "wormholeMessage",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "emitter",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "wormhole",
+          "accounts": [
+            {
+              "name": "bridge",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "feeCollector",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "sequence",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "program",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "systemProgram",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "clock",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "rent",
+              "isMut": false,
+              "isSigner": false
+            }
+          ]
+        },
+        {
+          "name": "manager",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "outboxItemSigner",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "peer",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "ReleaseOutboundArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "closeWormholeTransceiverMessage",
+      "accounts": [
+        {
+          "name": "transceiverMessage",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "inboxItem",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "message, and [`InboxItem::is_done`] checks the owner."
+          ]
+        },
+        {
+          "name": "rentPayer",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "migrateWormholeTransceiverMessage",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "owner",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "transceiverMessage",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "discriminator is checked in [`migrate_transceiver_message`]."
+          ]
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "broadcastWormholeId",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "mint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "wormholeMessage",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "emitter",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "enforced by the [`CpiContext`] call in [`post_message`].",
+            "The seeds constraint ensures that this is the correct address"
+          ]
+        },
+        {
+          "name": "wormhole",
+          "accounts": [
+            {
+              "name": "bridge",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "feeCollector",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "sequence",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "program",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "systemProgram",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "clock",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "rent",
+              "isMut": false,
+              "isSigner": false
+            }
+          ]
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "broadcastWormholePeer",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "peer",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "wormholeMessage",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "emitter",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "wormhole",
+          "accounts": [
+            {
+              "name": "bridge",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "feeCollector",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "sequence",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "program",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "systemProgram",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "clock",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "rent",
+              "isMut": false,
+              "isSigner": false
+            }
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "BroadcastPeerArgs"
+          }
+        }
+      ]
+    }
+  ],
+  "accounts": [
+    {
+      "name": "config",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "owner",
+            "docs": [
+              "Owner of the program."
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "pendingOwner",
+            "docs": [
+              "Pending next owner (before claiming ownership)."
+            ],
+            "type": {
+              "option": "publicKey"
+            }
+          },
+          {
+            "name": "mint",
+            "docs": [
+              "Mint address of the token managed by this program."
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "tokenProgram",
+            "docs": [
+              "Address of the token program (token or token22). This could always be queried",
+              "from the [`mint`] account's owner, but storing it here avoids an indirection",
+              "on the client side."
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "mode",
+            "docs": [
+              "The mode that this program is running in. This is used to determine",
+              "whether the program is burning tokens or locking tokens."
+            ],
+            "type": {
+              "defined": "Mode"
+            }
+          },
+          {
+            "name": "chainId",
+            "docs": [
+              "The chain id of the chain that this program is running on. We don't",
+              "hardcode this so that the program is deployable on any potential SVM",
+              "forks."
+            ],
+            "type": {
+              "defined": "ChainId"
+            }
+          },
+          {
+            "name": "nextTransceiverId",
+            "docs": [
+              "The next transceiver id to use when registering an transceiver."
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "threshold",
+            "docs": [
+              "The number of transceivers that must attest to a transfer before it is",
+              "accepted."
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "enabledTransceivers",
+            "docs": [
+              "Bitmap of enabled transceivers.",
+              "The maximum number of transceivers is equal to [`Bitmap::BITS`]."
+            ],
+            "type": {
+              "defined": "Bitmap"
+            }
+          },
+          {
+            "name": "paused",
+            "docs": [
+              "Pause the program. This is useful for upgrades and other maintenance."
+            ],
+            "type": "bool"
+          },
+          {
+            "name": "custody",
+            "docs": [
+              "The custody account that holds tokens in locking mode."
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "preciseQueueRelease",
+            "docs": [
+              "When a transfer exceeds the rate limit, reserve the missing capacity",
+              "and queue the transfer only until it has refilled, instead of delaying",
+              "it by a full rate limit window.",
+              "See [`crate::queue::rate_limit::RateLimitState::consume_or_reserve_all`]."
+            ],
+            "type": "bool"
+          },
+          {
+            "name": "strictDust",
+            "docs": [
+              "Reject outbound transfers whose amount has dust (i.e. it doesn't trim",
+              "cleanly to the recipient chain's decimals), instead of silently",
+              "transferring the amount with the dust removed."
+            ],
+            "type": "bool"
+          },
+          {
+            "name": "pauser",
+            "docs": [
+              "Holder of [`Role::Pauser`]."
+            ],
+            "type": {
+              "option": "publicKey"
+            }
+          },
+          {
+            "name": "outboundPaused",
+            "docs": [
+              "Pause outbound transfers (and releasing queued outbound transfers),",
+              "while inbound transfers keep working."
+            ],
+            "type": "bool"
+          },
+          {
+            "name": "inboundPaused",
+            "docs": [
+              "Pause releasing inbound transfers, while they can still be redeemed",
+              "(and queued)."
+            ],
+            "type": "bool"
+          },
+          {
+            "name": "rateLimitAdmin",
+            "docs": [
+              "Holder of [`Role::RateLimitAdmin`]."
+            ],
+            "type": {
+              "option": "publicKey"
+            }
+          },
+          {
+            "name": "peerAdmin",
+            "docs": [
+              "Holder of [`Role::PeerAdmin`]."
+            ],
+            "type": {
+              "option": "publicKey"
+            }
+          },
+          {
+            "name": "transceiverAdmin",
+            "docs": [
+              "Holder of [`Role::TransceiverAdmin`]."
+            ],
+            "type": {
+              "option": "publicKey"
+            }
+          },
+          {
+            "name": "timelockDelay",
+            "docs": [
+              "Delay (in seconds) between proposing a security-sensitive change and",
+              "executing it (see [`crate::pending_change::ConfigChange`]). Zero",
+              "disables the timelock, in which case these changes are made directly."
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "guardian",
+            "docs": [
+              "Holder of [`Role::Guardian`]."
+            ],
+            "type": {
+              "option": "publicKey"
+            }
+          },
+          {
+            "name": "complianceAdmin",
+            "docs": [
+              "Holder of [`Role::ComplianceAdmin`]."
+            ],
+            "type": {
+              "option": "publicKey"
+            }
+          },
+          {
+            "name": "denylistEnabled",
+            "docs": [
+              "Block transfers from and to the addresses on the denylist (see",
+              "[`crate::compliance`])."
+            ],
+            "type": "bool"
+          },
+          {
+            "name": "policyProgram",
+            "docs": [
+              "A program that is asked whether to allow each transfer, in addition to",
+              "the denylist (see [`crate::compliance`]). `None` disables this check."
+            ],
+            "type": {
+              "option": "publicKey"
+            }
+          },
+          {
+            "name": "escrowTimeout",
+            "docs": [
+              "How long (in seconds) after it became releasable an inbound transfer",
+              "that is still not released can be moved to the escrow by anyone (see",
+              "[`crate::instructions::escrow_inbox_item`]). Zero disables this, so",
+              "only the owner can escrow transfers."
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "version",
+            "docs": [
+              "The layout version of this account (see [`Config::LAYOUT_VERSION`]).",
+              "Fields added in later releases go after this one, and existing",
+              "accounts are upgraded with [`crate::instructions::migrate`]."
+            ],
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "outboxItem",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "amount",
+            "type": {
+              "defined": "TrimmedAmount"
+            }
+          },
+          {
+            "name": "sender",
+            "type": "publicKey"
+          },
+          {
+            "name": "recipientChain",
+            "type": {
+              "defined": "ChainId"
+            }
+          },
+          {
+            "name": "recipientNttManager",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "recipientAddress",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "releaseTimestamp",
+            "type": "i64"
+          },
+          {
+            "name": "released",
+            "type": {
+              "defined": "Bitmap"
+            }
+          },
+          {
+            "name": "queued",
+            "docs": [
+              "Whether the transfer was queued because it exceeded the outbound rate",
+              "limit. Queued transfers don't consume outbound capacity (and don't",
+              "refill the inbound capacity) when they are inserted, unless they",
+              "reserved it."
+            ],
+            "type": "bool"
+          },
+          {
+            "name": "reserved",
+            "docs": [
+              "Whether the transfer reserved the outbound capacity it was missing",
+              "when it was queued (see [`crate::config::Config::precise_queue_release`]).",
+              "Reserved capacity counts as consumed, like for transfers that weren't",
+              "queued."
+            ],
+            "type": "bool"
+          },
+          {
+            "name": "cancelled",
+            "docs": [
+              "Whether the transfer has been cancelled by the sender. Cancelled",
+              "transfers can never be released."
+            ],
+            "type": "bool"
+          },
+          {
+            "name": "rentPayer",
+            "docs": [
+              "The account that paid the rent for this item. The rent is returned to",
+              "this account when the item is closed."
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "additionalPayload",
+            "docs": [
+              "An additional payload to be delivered to the recipient along with the",
+              "tokens. Empty for plain transfers."
+            ],
+            "type": {
+              "defined": "AdditionalPayload"
+            }
+          },
+          {
+            "name": "sequence",
+            "docs": [
+              "The outbound sequence number of the transfer. The item's address is",
+              "derived from it (see [`OutboxItem::SEED_PREFIX`])."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "version",
+            "docs": [
+              "See [`crate::config::Config::version`]."
+            ],
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "registeredTransceiver",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "id",
+            "type": "u8"
+          },
+          {
+            "name": "transceiverAddress",
+            "type": "publicKey"
+          },
+          {
+            "name": "version",
+            "docs": [
+              "See [`crate::config::Config::version`]."
+            ],
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "transceiverPeer",
+      "docs": [
+        "A peer on another chain. Stored in a PDA seeded by the chain id."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "address",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "bridgeData",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "guardianSetIndex",
+            "docs": [
+              "The current guardian set index, used to decide which signature sets to accept."
+            ],
+            "type": "u32"
+          },
+          {
+            "name": "lastLamports",
+            "docs": [
+              "Lamports in the collection account"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "config",
+            "docs": [
+              "Bridge configuration, which is set once upon initialization."
+            ],
+            "type": {
+              "defined": "BridgeConfig"
+            }
+          }
+        ]
+      }
+    }
+  ],
+  "types": [
+    {
+      "name": "Bitmap",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "map",
+            "type": "u128"
+          }
+        ]
+      }
+    },
+    {
+      "name": "ChainId",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "id",
+            "type": "u16"
+          }
+        ]
+      }
+    },
+    {
+      "name": "Mode",
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "Locking"
+          },
+          {
+            "name": "Burning"
+          }
+        ]
+      }
+    },
+    {
+      "name": "TrimmedAmount",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "amount",
+            "type": "u64"
+          },
+          {
+            "name": "decimals",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "SetTransceiverPeerArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "chainId",
+            "type": {
+              "defined": "ChainId"
+            }
+          },
+          {
+            "name": "address",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "BroadcastPeerArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "chainId",
+            "type": "u16"
+          }
+        ]
+      }
+    },
+    {
+      "name": "ReleaseOutboundArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "revertOnDelay",
+            "type": "bool"
+          }
+        ]
+      }
+    },
+    {
+      "name": "BridgeConfig",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "guardianSetExpirationTime",
+            "docs": [
+              "Period for how long a guardian set is valid after it has been replaced by a new one.  This",
+              "guarantees that VAAs issued by that set can still be submitted for a certain period.  In",
+              "this period we still trust the old guardian set."
+            ],
+            "type": "u32"
+          },
+          {
+            "name": "fee",
+            "docs": [
+              "Amount of lamports that needs to be paid to the protocol to post a message"
+            ],
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "AdditionalPayload",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bytes",
+            "type": "bytes"
+          }
+        ]
+      }
+    }
+  ],
+  "events": [
+    {
+      "name": "WormholePeerSet",
+      "fields": [
+        {
+          "name": "chainId",
+          "type": {
+            "defined": "ChainId"
+          },
+          "index": false
+        },
+        {
+          "name": "address",
+          "type": {
+            "array": [
+              "u8",
+              32
+            ]
+          },
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "WormholeMessageReceived",
+      "fields": [
+        {
+          "name": "transceiverMessage",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "fromChain",
+          "type": {
+            "defined": "ChainId"
+          },
+          "index": false
+        },
+        {
+          "name": "messageId",
+          "type": {
+            "array": [
+              "u8",
+              32
+            ]
+          },
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "WormholeMessageSent",
+      "fields": [
+        {
+          "name": "outboxItem",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "wormholeMessage",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "recipientChain",
+          "type": {
+            "defined": "ChainId"
+          },
+          "index": false
+        }
+      ]
+    }
+  ]
+}
+
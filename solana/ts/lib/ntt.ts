@@ -96,6 +96,15 @@ export namespace NTT {
     const upgradeLock = (): PublicKey => derivePda("upgrade_lock", programId);
     const outboxRateLimitAccount = (): PublicKey =>
       derivePda("outbox_rate_limit", programId);
+    const outboxChainRateLimitAccount = (chain: Chain): PublicKey =>
+      derivePda(["outbox_chain_rate_limit", chainToBytes(chain)], programId);
+    const outboxSequenceAccount = (): PublicKey =>
+      derivePda("outbox_sequence", programId);
+    const outboxItemAccount = (sequence: bigint | number): PublicKey =>
+      derivePda(
+        ["outbox_item", encoding.bignum.toBytes(sequence, 8)],
+        programId
+      );
     const tokenAuthority = (): PublicKey =>
       derivePda("token_authority", programId);
     const pendingTokenAuthority = (): PublicKey =>
@@ -130,6 +139,9 @@ export namespace NTT {
     return {
       configAccount,
       outboxRateLimitAccount,
+      outboxChainRateLimitAccount,
+      outboxSequenceAccount,
+      outboxItemAccount,
       inboxRateLimitAccount,
       inboxItemAccount,
       upgradeLock,
@@ -326,6 +338,9 @@ export namespace NTT {
       mint: config.mint,
       tokenAuthority: pdas.tokenAuthority(),
       outboxRateLimit: pdas.outboxRateLimitAccount(),
+      ...(major >= 4 && {
+        outboxSequence: pdas.outboxSequenceAccount(),
+      }),
       wormhole: {
         bridge: whAccs.wormholeBridge,
         feeCollector: whAccs.wormholeFeeCollector,
@@ -397,13 +412,15 @@ export namespace NTT {
     },
     pdas?: Pdas
   ): Promise<TransactionInstruction> {
+    const [major, , ,] = parseVersion(program.idl.version);
+
     pdas = pdas ?? NTT.pdas(program.programId);
 
     const custody = await custodyAccountAddress(pdas, config);
     const recipientChain = toChain(args.transferArgs.recipientChain.id);
     const transferIx = await program.methods
       .transferBurn(args.transferArgs)
-      .accountsStrict({
+      .accounts({
         common: {
           payer: args.payer,
           config: { config: pdas.configAccount() },
@@ -414,9 +431,18 @@ export namespace NTT {
           outboxRateLimit: pdas.outboxRateLimitAccount(),
           systemProgram: SystemProgram.programId,
           custody,
+          // NOTE: outbox items are PDAs for versions >= 4.x.x
+          ...(major >= 4 && {
+            outboxSequence: pdas.outboxSequenceAccount(),
+          }),
         },
         peer: pdas.peerAccount(recipientChain),
         inboxRateLimit: pdas.inboxRateLimitAccount(recipientChain),
+        // NOTE: per-chain outbound limits exist for versions >= 4.x.x
+        ...(major >= 4 && {
+          outboxChainRateLimit:
+            pdas.outboxChainRateLimitAccount(recipientChain),
+        }),
         sessionAuthority: pdas.sessionAuthority(
           args.fromAuthority,
           args.transferArgs
@@ -462,8 +488,10 @@ export namespace NTT {
   }
 
   /**
-   * Creates a transfer_lock instruction. The `payer`, `fromAuthority`, and `outboxItem`
-   * arguments must sign the transaction
+   * Creates a transfer_lock instruction. The `payer` and `fromAuthority`
+   * arguments must sign the transaction. For versions < 4.x.x, the
+   * `outboxItem` must sign it too. For versions >= 4.x.x, it's the PDA
+   * returned by {@link getNextOutboxItemAccount}.
    */
   export async function createTransferLockInstruction(
     program: Program<NttBindings.NativeTokenTransfer<IdlVersion>>,
@@ -479,13 +507,15 @@ export namespace NTT {
   ): Promise<TransactionInstruction> {
     if (config.paused) throw new Error("Contract is paused");
 
+    const [major, , ,] = parseVersion(program.idl.version);
+
     pdas = pdas ?? NTT.pdas(program.programId);
 
     const chain = toChain(args.transferArgs.recipientChain.id);
     const custody = await custodyAccountAddress(pdas, config);
     const transferIx = await program.methods
       .transferLock(args.transferArgs)
-      .accountsStrict({
+      .accounts({
         common: {
           payer: args.payer,
           config: { config: pdas.configAccount() },
@@ -496,9 +526,17 @@ export namespace NTT {
           outboxRateLimit: pdas.outboxRateLimitAccount(),
           custody,
           systemProgram: SystemProgram.programId,
+          // NOTE: outbox items are PDAs for versions >= 4.x.x
+          ...(major >= 4 && {
+            outboxSequence: pdas.outboxSequenceAccount(),
+          }),
         },
         peer: pdas.peerAccount(chain),
         inboxRateLimit: pdas.inboxRateLimitAccount(chain),
+        // NOTE: per-chain outbound limits exist for versions >= 4.x.x
+        ...(major >= 4 && {
+          outboxChainRateLimit: pdas.outboxChainRateLimitAccount(chain),
+        }),
         sessionAuthority: pdas.sessionAuthority(
           args.fromAuthority,
           args.transferArgs
@@ -598,6 +636,10 @@ export namespace NTT {
           tokenAuthority: pdas.tokenAuthority(),
           tokenProgram: config.tokenProgram,
           custody: await custodyAccountAddress(pdas, config),
+          // NOTE: the peer is checked not to be paused for versions >= 4.x.x
+          ...(major >= 4 && {
+            peer: pdas.peerAccount(args.chain),
+          }),
         },
         // NOTE: SPL Multisig token authority is only supported for versions >= 3.x.x
         ...(major >= 3 && {
@@ -654,6 +696,8 @@ export namespace NTT {
       (await NTT.getInboxItem(program, args.chain, args.nttMessage))
         .recipientAddress;
 
+    const [major, , ,] = parseVersion(program.idl.version);
+
     pdas = pdas ?? NTT.pdas(program.programId);
     const custody = await custodyAccountAddress(pdas, config);
 
@@ -664,7 +708,7 @@ export namespace NTT {
         revertOnDelay: args.revertWhenNotReady,
         revertWhenNotReady: args.revertWhenNotReady,
       })
-      .accountsStrict({
+      .accounts({
         common: {
           payer: args.payer,
           config: { config: pdas.configAccount() },
@@ -679,6 +723,10 @@ export namespace NTT {
           tokenAuthority: pdas.tokenAuthority(),
           tokenProgram: config.tokenProgram,
           custody,
+          // NOTE: the peer is checked not to be paused for versions >= 4.x.x
+          ...(major >= 4 && {
+            peer: pdas.peerAccount(args.chain),
+          }),
         },
         custody,
       })
@@ -1008,6 +1056,8 @@ export namespace NTT {
     },
     pdas?: Pdas
   ) {
+    const [major, , ,] = parseVersion(program.idl.version);
+
     pdas = pdas ?? NTT.pdas(program.programId);
     return program.methods
       .setPeer({
@@ -1022,6 +1072,10 @@ export namespace NTT {
         config: pdas.configAccount(),
         peer: pdas.peerAccount(args.chain),
         inboxRateLimit: pdas.inboxRateLimitAccount(args.chain),
+        // NOTE: per-chain outbound limits exist for versions >= 4.x.x
+        ...(major >= 4 && {
+          outboxChainRateLimit: pdas.outboxChainRateLimitAccount(args.chain),
+        }),
       })
       .instruction();
   }
@@ -1118,6 +1172,8 @@ export namespace NTT {
     pdas?: Pdas,
     transceiverPdas?: TransceiverPdas
   ): Promise<TransactionInstruction> {
+    const [major, , ,] = parseVersion(program.idl.version);
+
     pdas = pdas ?? NTT.pdas(program.programId);
     transceiverPdas =
       transceiverPdas ?? NTT.transceiverPdas(transceiverProgramId);
@@ -1141,6 +1197,10 @@ export namespace NTT {
         inboxItem: pdas.inboxItemAccount(chain, nttMessage),
         inboxRateLimit: pdas.inboxRateLimitAccount(chain),
         outboxRateLimit: pdas.outboxRateLimitAccount(),
+        // NOTE: per-chain outbound limits exist for versions >= 4.x.x
+        ...(major >= 4 && {
+          outboxChainRateLimit: pdas.outboxChainRateLimitAccount(chain),
+        }),
       })
       .instruction();
   }
@@ -1172,6 +1232,28 @@ export namespace NTT {
     );
   }
 
+  /**
+   * Returns the address of the outbox item that the next transfer creates.
+   * For versions >= 4.x.x, outbox items are PDAs derived from the sequence
+   * number in the outbox sequence account (which the first transfer creates).
+   *
+   * If another transfer takes the sequence number first, the transfer fails,
+   * and has to be retried with the new address.
+   */
+  export async function getNextOutboxItemAccount(
+    program: Program<NttBindings.NativeTokenTransfer<IdlVersion>>,
+    pdas?: Pdas
+  ): Promise<PublicKey> {
+    pdas = pdas ?? NTT.pdas(program.programId);
+    // @ts-ignore
+    // NOTE: the outbox sequence account only exists for versions >= 4.x.x
+    const outboxSequence = await program.account.outboxSequence.fetchNullable(
+      pdas.outboxSequenceAccount()
+    );
+    const sequence: BN = outboxSequence?.next ?? new BN(0);
+    return pdas.outboxItemAccount(BigInt(sequence.toString()));
+  }
+
   export async function getAddressLookupTable(
     program: Program<NttBindings.NativeTokenTransfer<IdlVersion>>,
     pdas?: Pdas
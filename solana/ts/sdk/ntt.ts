@@ -13,7 +13,12 @@ import {
   VersionedTransaction,
 } from "@solana/web3.js";
 
-import { Chain, Network, toChainId } from "@wormhole-foundation/sdk-base";
+import {
+  Chain,
+  Network,
+  toChain,
+  toChainId,
+} from "@wormhole-foundation/sdk-base";
 import {
   AccountAddress,
   ChainAddress,
@@ -64,7 +69,7 @@ export class SolanaNttWormholeTransceiver<
   constructor(
     readonly manager: SolanaNtt<N, C>,
     readonly program: Program<NttBindings.Transceiver<IdlVersion>>,
-    readonly version: string = "3.0.0"
+    readonly version: string = "4.0.0"
   ) {
     this.programId = program.programId;
     this.pdas = NTT.transceiverPdas(program.programId);
@@ -289,7 +294,8 @@ export class SolanaNttWormholeTransceiver<
   async createReleaseWormholeOutboundIx(
     payer: PublicKey,
     outboxItem: PublicKey,
-    revertOnDelay: boolean
+    revertOnDelay: boolean,
+    recipientChain?: Chain
   ): Promise<web3.TransactionInstruction> {
     const [major, , ,] = parseVersion(this.version);
     const whAccs = utils.getWormholeDerivedAccounts(
@@ -297,6 +303,19 @@ export class SolanaNttWormholeTransceiver<
       this.manager.core.address
     );
 
+    // NOTE: the peer of the recipient chain is checked not to be paused for
+    // versions >= 4.x.x
+    let peer: PublicKey | undefined;
+    if (major >= 4) {
+      recipientChain =
+        recipientChain ??
+        toChain(
+          (await this.manager.program.account.outboxItem.fetch(outboxItem))
+            .recipientChain.id
+        );
+      peer = this.manager.pdas.peerAccount(recipientChain);
+    }
+
     return this.program.methods
       .releaseWormholeOutbound({
         revertOnDelay: revertOnDelay,
@@ -322,6 +341,9 @@ export class SolanaNttWormholeTransceiver<
           manager: this.manager.program.programId,
           outboxItemSigner: this.pdas.outboxItemSigner(),
         }),
+        ...(major >= 4 && {
+          peer,
+        }),
       })
       .instruction();
   }
@@ -352,7 +374,7 @@ export class SolanaNtt<N extends Network, C extends SolanaChains>
     readonly chain: C,
     readonly connection: Connection,
     readonly contracts: Contracts & { ntt?: Ntt.Contracts },
-    readonly version: string = "3.0.0"
+    readonly version: string = "4.0.0"
   ) {
     if (!contracts.ntt) throw new Error("Ntt contracts not found");
 
@@ -618,7 +640,7 @@ export class SolanaNtt<N extends Network, C extends SolanaChains>
       );
     } catch (e) {
       // This might happen if e.g. the program is not deployed yet.
-      const version = "3.0.0";
+      const version = "4.0.0";
       return version;
     }
   }
@@ -815,12 +837,15 @@ export class SolanaNtt<N extends Network, C extends SolanaChains>
     amount: bigint,
     destination: ChainAddress,
     options: Ntt.TransferOptions,
-    outboxItem?: Keypair
+    outboxItem?: Keypair | PublicKey
   ): AsyncGenerator<UnsignedTransaction<N, C>, any, unknown> {
     const config = await this.getConfig();
     if (config.paused) throw new Error("Contract is paused");
 
-    outboxItem = outboxItem ?? Keypair.generate();
+    outboxItem = outboxItem ?? (await this.createOutboxItem());
+    const outboxItemAddress =
+      outboxItem instanceof Keypair ? outboxItem.publicKey : outboxItem;
+    const signers = outboxItem instanceof Keypair ? [outboxItem] : [];
 
     const payerAddress = new SolanaAddress(sender).unwrap();
     const fromAuthority = payerAddress;
@@ -884,7 +909,7 @@ export class SolanaNtt<N extends Network, C extends SolanaChains>
       payer: payerAddress,
       from,
       fromAuthority,
-      outboxItem: outboxItem.publicKey,
+      outboxItem: outboxItemAddress,
     };
 
     const approveIx = splToken.createApproveInstruction(
@@ -921,8 +946,9 @@ export class SolanaNtt<N extends Network, C extends SolanaChains>
         }
         const releaseIx = whTransceiver.createReleaseWormholeOutboundIx(
           payerAddress,
-          outboxItem.publicKey,
-          !options.queue
+          outboxItemAddress,
+          !options.queue,
+          destination.chain
         );
         asyncIxs.push(releaseIx);
       }
@@ -942,7 +968,7 @@ export class SolanaNtt<N extends Network, C extends SolanaChains>
 
       const relayIx = await this.quoter.createRequestRelayInstruction(
         payerAddress,
-        outboxItem.publicKey,
+        outboxItemAddress,
         destination.chain,
         Number(fee) / LAMPORTS_PER_SOL,
         0
@@ -964,11 +990,24 @@ export class SolanaNtt<N extends Network, C extends SolanaChains>
     const vtx = new VersionedTransaction(messageV0);
 
     yield this.createUnsignedTx(
-      { transaction: vtx, signers: [outboxItem] },
+      { transaction: vtx, signers },
       "Ntt.Transfer"
     );
   }
 
+  /**
+   * Returns the outbox item for a new transfer. For versions < 4.x.x, this is
+   * a fresh keypair that must sign the transfer. For versions >= 4.x.x, it's
+   * the PDA derived from the next outbound sequence number.
+   */
+  async createOutboxItem(): Promise<Keypair | PublicKey> {
+    const [major, , ,] = parseVersion(this.version);
+    if (major < 4) {
+      return Keypair.generate();
+    }
+    return NTT.getNextOutboxItemAccount(this.program, this.pdas);
+  }
+
   private async getTokenAccount(sender: PublicKey): Promise<PublicKey> {
     const config = await this.getConfig();
     const tokenAccount = await splToken.getAssociatedTokenAddress(
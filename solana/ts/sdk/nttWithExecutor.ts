@@ -95,7 +95,9 @@ export class SolanaNttWithExecutor<N extends Network, C extends SolanaChains>
     const senderPk = new SolanaAddress(sender).unwrap();
 
     const options = { queue: false, automatic: false, wrapNative };
-    const outboxItem = Keypair.generate();
+    const outboxItem = await ntt.createOutboxItem();
+    const outboxItemAddress =
+      outboxItem instanceof Keypair ? outboxItem.publicKey : outboxItem;
 
     const txs = ntt.transfer(
       sender,
@@ -186,7 +188,7 @@ export class SolanaNttWithExecutor<N extends Network, C extends SolanaChains>
               payee: new PublicKey(quote.payeeAddress),
               nttProgramId,
               nttPeer,
-              nttMessage: outboxItem.publicKey,
+              nttMessage: outboxItemAddress,
               executorProgram: this.executorProgramId,
             })
             .instruction()